@@ -2,8 +2,7 @@ use bevy_app::{App, Update};
 use bevy_asset::AssetServer;
 use bevy_ecs::prelude::Component;
 use fluent::{FluentResource, concurrent::FluentBundle};
-use masonry::peniko::Blob;
-use std::{fs, io, path::Path, sync::Arc};
+use std::{fs, io, path::Path};
 use unic_langid::LanguageIdentifier;
 
 use crate::{
@@ -13,7 +12,7 @@ use crate::{
     components::{
         RegisteredUiComponentTypes, UiComponentTemplate, expand_added_ui_component_templates,
     },
-    set_active_stylesheet_asset_path,
+    register_locale_stylesheet_ron, set_active_stylesheet_asset_path,
 };
 
 /// Synchronous source for binary assets (fonts).
@@ -46,9 +45,7 @@ fn flush_pending_font_registrations(app: &mut App) {
 
     let mut runtime = app.world_mut().non_send_resource_mut::<MasonryRuntime>();
     for font_bytes in pending {
-        runtime
-            .render_root
-            .register_fonts(Blob::new(Arc::new(font_bytes)));
+        runtime.register_fonts(font_bytes);
     }
 }
 
@@ -85,7 +82,7 @@ fn flush_pending_font_registrations(app: &mut App) {
 /// }
 ///
 /// let mut app = App::new();
-/// app.add_plugins(PicusPlugin)
+/// app.add_plugins(PicusPlugin::default())
 ///     .register_ui_component::<Root>()
 ///     .add_systems(Startup, setup);
 /// ```
@@ -124,8 +121,17 @@ pub trait AppPicusExt {
     ///
     /// This bypasses filesystem asset loading and applies the stylesheet as the
     /// active tier with the same precedence as file-based active stylesheets.
+    /// `Var(..)` colors resolve through the [`StyleVariables`](crate::StyleVariables)
+    /// already in the world.
     fn load_style_sheet_ron(&mut self, ron_text: &str) -> &mut Self;
 
+    /// Parse a stylesheet from embedded RON text and register it as the override
+    /// layer for `locale`.
+    ///
+    /// The layer applies on top of the runtime stylesheet while `locale` is the
+    /// active [`AppI18n`] locale (e.g. a taller line height for CJK scripts).
+    fn register_locale_style_sheet_ron(&mut self, locale: &str, ron_text: &str) -> &mut Self;
+
     /// Register a selector type alias usable by `Selector::Type("...")` in stylesheet RON.
     fn register_style_selector_type<T: Component>(
         &mut self,
@@ -227,6 +233,16 @@ impl AppPicusExt for App {
         self
     }
 
+    fn register_locale_style_sheet_ron(&mut self, locale: &str, ron_text: &str) -> &mut Self {
+        let locale_id: LanguageIdentifier = locale
+            .parse()
+            .unwrap_or_else(|_| panic!("locale `{locale}` should parse"));
+        register_locale_stylesheet_ron(self.world_mut(), locale_id, ron_text).unwrap_or_else(
+            |error| panic!("failed to parse stylesheet RON for locale `{locale}`: {error}"),
+        );
+        self
+    }
+
     fn register_style_selector_type<T: Component>(
         &mut self,
         selector_name: impl Into<String>,
@@ -25,6 +25,78 @@ impl UiBadge {
     }
 }
 
+/// Default cap past which a [`Badge`] shows `"{max}+"`.
+pub const DEFAULT_BADGE_MAX_DISPLAY: u32 = 99;
+
+/// Corner of the decorated widget a [`Badge`] bubble is pinned to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BadgeCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Notification-count decorator.
+///
+/// Attach to any projected entity (tab, nav item, button, ...) to overlay a
+/// small count bubble at one of its corners. Unlike [`UiBadge`] this is not a
+/// standalone widget: synthesis wraps the entity's own view with the bubble.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Badge {
+    pub count: u32,
+    /// Largest count shown verbatim; larger counts render as `"{max_display}+"`.
+    pub max_display: u32,
+    /// Keep the bubble visible (showing `0`) when `count` is zero.
+    pub show_zero: bool,
+    pub corner: BadgeCorner,
+}
+
+impl Badge {
+    #[must_use]
+    pub fn new(count: u32) -> Self {
+        Self {
+            count,
+            max_display: DEFAULT_BADGE_MAX_DISPLAY,
+            show_zero: false,
+            corner: BadgeCorner::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_max_display(mut self, max_display: u32) -> Self {
+        self.max_display = max_display;
+        self
+    }
+
+    #[must_use]
+    pub fn with_show_zero(mut self, show_zero: bool) -> Self {
+        self.show_zero = show_zero;
+        self
+    }
+
+    #[must_use]
+    pub fn with_corner(mut self, corner: BadgeCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Text shown in the bubble, or `None` when the badge is hidden.
+    #[must_use]
+    pub fn display_text(&self) -> Option<String> {
+        if self.count == 0 && !self.show_zero {
+            return None;
+        }
+
+        if self.count > self.max_display {
+            Some(format!("{}+", self.max_display))
+        } else {
+            Some(self.count.to_string())
+        }
+    }
+}
+
 impl UiComponentTemplate for UiBadge {
     fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
         crate::projection::elements::project_badge(component, ctx)
@@ -33,7 +105,7 @@ impl UiComponentTemplate for UiBadge {
 
 #[cfg(test)]
 mod tests {
-    use super::UiBadge;
+    use super::{Badge, UiBadge};
 
     #[test]
     fn badge_builder_keeps_text_and_key() {
@@ -41,4 +113,19 @@ mod tests {
         assert_eq!(badge.text, "Beta");
         assert_eq!(badge.text_key.as_deref(), Some("demo-badge"));
     }
+
+    #[test]
+    fn count_badge_caps_display_and_hides_zero() {
+        assert_eq!(Badge::new(7).display_text().as_deref(), Some("7"));
+        assert_eq!(Badge::new(100).display_text().as_deref(), Some("99+"));
+        assert_eq!(
+            Badge::new(12).with_max_display(9).display_text().as_deref(),
+            Some("9+")
+        );
+        assert_eq!(Badge::new(0).display_text(), None);
+        assert_eq!(
+            Badge::new(0).with_show_zero(true).display_text().as_deref(),
+            Some("0")
+        );
+    }
 }
@@ -59,6 +59,41 @@ impl UiComboBox {
         }
     }
 
+    /// Build a combo from `(value, label)` pairs.
+    #[must_use]
+    pub fn from_pairs<V, L>(pairs: impl IntoIterator<Item = (V, L)>) -> Self
+    where
+        V: Into<String>,
+        L: Into<String>,
+    {
+        Self::new(
+            pairs
+                .into_iter()
+                .map(|(value, label)| UiComboOption::new(value, label))
+                .collect(),
+        )
+    }
+
+    /// Build a combo whose option labels equal their values.
+    #[must_use]
+    pub fn from_values<V: Into<String>>(values: impl IntoIterator<Item = V>) -> Self {
+        Self::new(
+            values
+                .into_iter()
+                .map(|value| {
+                    let value = value.into();
+                    UiComboOption::new(value.clone(), value)
+                })
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.selected = index;
+        self
+    }
+
     #[must_use]
     pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = placeholder.into();
@@ -199,4 +234,29 @@ mod tests {
         combo.selected = 9;
         assert_eq!(combo.clamped_selected(), None);
     }
+
+    #[test]
+    fn combo_box_builds_options_from_pairs_with_selection() {
+        let combo = UiComboBox::from_pairs([("en-US", "English"), ("ja-JP", "日本語")])
+            .with_selected(1)
+            .with_placeholder("Language");
+
+        assert_eq!(
+            combo.options,
+            vec![
+                UiComboOption::new("en-US", "English"),
+                UiComboOption::new("ja-JP", "日本語"),
+            ]
+        );
+        assert_eq!(combo.clamped_selected(), Some(1));
+        assert_eq!(combo.placeholder, "Language");
+    }
+
+    #[test]
+    fn combo_box_from_values_uses_value_as_label() {
+        let combo = UiComboBox::from_values(["small", "large"]);
+
+        assert_eq!(combo.options[1], UiComboOption::new("large", "large"));
+        assert_eq!(combo.clamped_selected(), None);
+    }
 }
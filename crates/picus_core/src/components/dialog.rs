@@ -1,8 +1,11 @@
+use std::marker::PhantomData;
+
 use bevy_ecs::{entity::Entity, prelude::*};
 
 use crate::{
-    ProjectionCtx, StyleClass, UiEvent, UiLabel, UiView, components::UiComponentTemplate,
-    templates::ensure_template_part,
+    ProjectionCtx, StyleClass, UiEvent, UiEventQueue, UiLabel, UiTextInput, UiView,
+    components::UiComponentTemplate,
+    templates::{ensure_template_part, find_template_part},
 };
 
 /// Modal dialog entity projected in the overlay layer.
@@ -16,6 +19,10 @@ pub struct UiDialog {
     pub dismiss_key: Option<String>,
     pub width: Option<f64>,
     pub height: Option<f64>,
+    /// Label of the footer confirm button. `None` hides the button.
+    pub confirm_label: Option<String>,
+    /// Label of the footer cancel button. `None` hides the button.
+    pub cancel_label: Option<String>,
 }
 
 #[derive(Component, Debug)]
@@ -48,9 +55,31 @@ impl UiDialog {
             dismiss_key: None,
             width: None,
             height: None,
+            confirm_label: None,
+            cancel_label: None,
         }
     }
 
+    /// Show footer confirm/cancel buttons.
+    ///
+    /// Clicking them closes the dialog and, when a [`UiDialogResultEmitter`] is
+    /// attached, reports a [`DialogResult`].
+    #[must_use]
+    pub fn with_actions(
+        mut self,
+        confirm_label: impl Into<String>,
+        cancel_label: impl Into<String>,
+    ) -> Self {
+        self.confirm_label = Some(confirm_label.into());
+        self.cancel_label = Some(cancel_label.into());
+        self
+    }
+
+    #[must_use]
+    pub fn has_actions(&self) -> bool {
+        self.confirm_label.is_some() || self.cancel_label.is_some()
+    }
+
     #[must_use]
     pub fn with_localized_keys(
         mut self,
@@ -84,6 +113,113 @@ impl UiDialog {
     }
 }
 
+/// How a dialog spawned with [`crate::spawn_dialog_with_result`] was closed.
+///
+/// Pushed through [`crate::UiEventQueue`] with the dialog entity as source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogResult<T> {
+    /// The confirm button was clicked; carries the dialog's value.
+    Confirmed(T),
+    /// The dialog was cancelled or dismissed.
+    Cancelled,
+}
+
+impl<T> DialogResult<T> {
+    #[must_use]
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, Self::Confirmed(_))
+    }
+
+    #[must_use]
+    pub fn into_confirmed(self) -> Option<T> {
+        match self {
+            Self::Confirmed(value) => Some(value),
+            Self::Cancelled => None,
+        }
+    }
+}
+
+/// Value type a dialog can produce when confirmed.
+///
+/// `()` backs plain confirm dialogs and `String` backs prompt dialogs, which
+/// read the text of their [`PartDialogPrompt`] input.
+pub trait DialogValue: Send + Sync + Sized + 'static {
+    /// Spawn any extra dialog content this value needs (e.g. a prompt input).
+    fn prepare(_world: &mut World, _dialog: Entity) {}
+
+    /// Read the confirmed value from the dialog entity.
+    fn read(world: &World, dialog: Entity) -> Self;
+}
+
+impl DialogValue for () {
+    fn read(_world: &World, _dialog: Entity) -> Self {}
+}
+
+impl DialogValue for String {
+    fn prepare(world: &mut World, dialog: Entity) {
+        let _ = ensure_template_part::<PartDialogPrompt, _>(world, dialog, || UiTextInput::new(""));
+    }
+
+    fn read(world: &World, dialog: Entity) -> Self {
+        find_template_part::<PartDialogPrompt>(world, dialog)
+            .and_then(|prompt| world.get::<UiTextInput>(prompt))
+            .map(|input| input.value.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Type-erased hook that turns a dialog close into a typed [`DialogResult`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiDialogResultEmitter {
+    emit: fn(&World, Entity, bool) -> UiEvent,
+}
+
+impl UiDialogResultEmitter {
+    #[must_use]
+    pub fn new<T: DialogValue>() -> Self {
+        Self {
+            emit: emit_dialog_result::<T>,
+        }
+    }
+
+    pub(crate) fn emit(&self, world: &World, dialog: Entity, confirmed: bool) -> UiEvent {
+        (self.emit)(world, dialog, confirmed)
+    }
+}
+
+fn emit_dialog_result<T: DialogValue>(world: &World, dialog: Entity, confirmed: bool) -> UiEvent {
+    let result = if confirmed {
+        DialogResult::Confirmed(T::read(world, dialog))
+    } else {
+        DialogResult::<T>::Cancelled
+    };
+    UiEvent::typed(dialog, result)
+}
+
+/// Handle returned by [`crate::spawn_dialog_with_result`] for polling the outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogHandle<T> {
+    pub dialog: Entity,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DialogValue> DialogHandle<T> {
+    #[must_use]
+    pub fn new(dialog: Entity) -> Self {
+        Self {
+            dialog,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Take this dialog's result from `queue`, if it has been closed.
+    ///
+    /// Results belonging to other dialogs stay queued in their original order.
+    pub fn poll(&self, queue: &mut UiEventQueue) -> Option<DialogResult<T>> {
+        queue.take_action_for::<DialogResult<T>>(self.dialog)
+    }
+}
+
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PartDialogTitle;
 
@@ -93,6 +229,10 @@ pub struct PartDialogBody;
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PartDialogDismiss;
 
+/// Text input whose value a `String` [`DialogResult`] reports.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartDialogPrompt;
+
 impl UiComponentTemplate for UiDialog {
     fn expand(world: &mut World, entity: Entity) {
         let dialog = world.get::<UiDialog>(entity).cloned();
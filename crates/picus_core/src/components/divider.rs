@@ -0,0 +1,88 @@
+use bevy_ecs::prelude::*;
+
+use crate::{ProjectionCtx, UiView, components::UiComponentTemplate};
+
+/// Default divider line thickness in logical pixels.
+pub const DEFAULT_DIVIDER_THICKNESS: f32 = 1.0;
+
+/// Direction a [`UiDivider`] line runs in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DividerOrientation {
+    /// A horizontal rule spanning the parent's width (use inside columns).
+    #[default]
+    Horizontal,
+    /// A vertical rule spanning the parent's height (use inside rows).
+    Vertical,
+}
+
+/// A thin separator line.
+///
+/// The line color comes from `color` when set, otherwise from the resolved
+/// style's `bg` (the built-in theme uses `border-default`).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiDivider {
+    pub orientation: DividerOrientation,
+    /// Line thickness in logical pixels.
+    pub thickness: f32,
+    pub color: Option<xilem::Color>,
+}
+
+impl UiDivider {
+    #[must_use]
+    pub fn horizontal() -> Self {
+        Self {
+            orientation: DividerOrientation::Horizontal,
+            thickness: DEFAULT_DIVIDER_THICKNESS,
+            color: None,
+        }
+    }
+
+    #[must_use]
+    pub fn vertical() -> Self {
+        Self {
+            orientation: DividerOrientation::Vertical,
+            ..Self::horizontal()
+        }
+    }
+
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    #[must_use]
+    pub fn with_color(mut self, color: xilem::Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Default for UiDivider {
+    fn default() -> Self {
+        Self::horizontal()
+    }
+}
+
+impl UiComponentTemplate for UiDivider {
+    fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
+        crate::projection::layout::project_divider(component, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DEFAULT_DIVIDER_THICKNESS, DividerOrientation, UiDivider};
+
+    #[test]
+    fn divider_defaults_to_thin_horizontal_line() {
+        let divider = UiDivider::default();
+        assert_eq!(divider.orientation, DividerOrientation::Horizontal);
+        assert_eq!(divider.thickness, DEFAULT_DIVIDER_THICKNESS);
+        assert_eq!(divider.color, None);
+        assert_eq!(
+            UiDivider::vertical().with_thickness(2.0).orientation,
+            DividerOrientation::Vertical
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use bevy_ecs::prelude::*;
+
+use crate::{ProjectionCtx, Teleport, UiView, components::UiComponentTemplate};
+
+/// Which part of a [`UiFloatingPanel`] a drag handle controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FloatingPanelHandle {
+    /// The title bar; dragging moves the panel.
+    Move,
+    /// The right edge; dragging changes the width.
+    ResizeRight,
+    /// The bottom edge; dragging changes the height.
+    ResizeBottom,
+    /// The bottom-right corner; dragging changes both dimensions.
+    ResizeBottomRight,
+}
+
+/// A window-like panel that floats above the UI and can be moved and resized.
+///
+/// The panel lives in the overlay layer (it requires [`Teleport`]), is drawn at
+/// `position` with `size` in logical pixels, and lays out its ECS children in a
+/// column below the title bar. Dragging the title bar moves it when `movable`;
+/// the right/bottom edges and the corner resize it when `resizable`. Both are
+/// clamped so the panel stays inside the primary window.
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(Teleport)]
+pub struct UiFloatingPanel {
+    pub title: String,
+    /// Top-left corner in window coordinates.
+    pub position: (f64, f64),
+    pub size: (f64, f64),
+    /// Smallest size edge handles can shrink the panel to.
+    pub min_size: (f64, f64),
+    pub movable: bool,
+    pub resizable: bool,
+}
+
+impl UiFloatingPanel {
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            position: (32.0, 32.0),
+            size: (320.0, 240.0),
+            min_size: (120.0, 80.0),
+            movable: true,
+            resizable: true,
+        }
+    }
+
+    #[must_use]
+    pub fn with_position(mut self, x: f64, y: f64) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    #[must_use]
+    pub fn with_size(mut self, width: f64, height: f64) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    #[must_use]
+    pub fn with_min_size(mut self, width: f64, height: f64) -> Self {
+        self.min_size = (width, height);
+        self
+    }
+
+    #[must_use]
+    pub fn movable(mut self, movable: bool) -> Self {
+        self.movable = movable;
+        self
+    }
+
+    #[must_use]
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Apply a drag `delta` on `handle`, keeping the panel inside `bounds`.
+    ///
+    /// Returns `true` if the position or size changed.
+    pub fn apply_drag(
+        &mut self,
+        handle: FloatingPanelHandle,
+        delta: (f64, f64),
+        bounds: Option<(f64, f64)>,
+    ) -> bool {
+        let before = (self.position, self.size);
+        let (dx, dy) = delta;
+
+        match handle {
+            FloatingPanelHandle::Move if self.movable => {
+                self.position = (self.position.0 + dx, self.position.1 + dy);
+            }
+            FloatingPanelHandle::ResizeRight if self.resizable => {
+                self.size.0 += dx;
+            }
+            FloatingPanelHandle::ResizeBottom if self.resizable => {
+                self.size.1 += dy;
+            }
+            FloatingPanelHandle::ResizeBottomRight if self.resizable => {
+                self.size = (self.size.0 + dx, self.size.1 + dy);
+            }
+            _ => return false,
+        }
+
+        self.size = (
+            self.size.0.max(self.min_size.0),
+            self.size.1.max(self.min_size.1),
+        );
+        if let Some((width, height)) = bounds {
+            if !matches!(handle, FloatingPanelHandle::Move) {
+                self.size = (
+                    self.size
+                        .0
+                        .min(width - self.position.0)
+                        .max(self.min_size.0),
+                    self.size
+                        .1
+                        .min(height - self.position.1)
+                        .max(self.min_size.1),
+                );
+            }
+            self.position = (
+                self.position.0.clamp(0.0, (width - self.size.0).max(0.0)),
+                self.position.1.clamp(0.0, (height - self.size.1).max(0.0)),
+            );
+        }
+
+        (self.position, self.size) != before
+    }
+}
+
+/// Emitted when a [`UiFloatingPanel`] is moved or resized by dragging.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiFloatingPanelChanged {
+    pub panel: Entity,
+    pub position: (f64, f64),
+    pub size: (f64, f64),
+}
+
+impl UiComponentTemplate for UiFloatingPanel {
+    fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
+        crate::projection::widgets::project_floating_panel(component, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FloatingPanelHandle, UiFloatingPanel};
+
+    #[test]
+    fn drag_is_clamped_to_bounds_and_min_size() {
+        let mut panel = UiFloatingPanel::new("Tools")
+            .with_position(10.0, 10.0)
+            .with_size(200.0, 100.0);
+
+        assert!(panel.apply_drag(
+            FloatingPanelHandle::Move,
+            (-50.0, 700.0),
+            Some((800.0, 600.0))
+        ));
+        assert_eq!(panel.position, (0.0, 500.0));
+
+        assert!(panel.apply_drag(
+            FloatingPanelHandle::ResizeBottomRight,
+            (-500.0, -500.0),
+            Some((800.0, 600.0)),
+        ));
+        assert_eq!(panel.size, panel.min_size);
+    }
+
+    #[test]
+    fn locked_panel_ignores_drags() {
+        let mut panel = UiFloatingPanel::new("Fixed")
+            .movable(false)
+            .resizable(false);
+        let before = panel.clone();
+
+        assert!(!panel.apply_drag(FloatingPanelHandle::Move, (5.0, 5.0), None));
+        assert!(!panel.apply_drag(FloatingPanelHandle::ResizeRight, (5.0, 0.0), None));
+        assert_eq!(panel, before);
+    }
+}
@@ -1,14 +1,21 @@
-use bevy_ecs::prelude::*;
+use bevy_ecs::{entity::Entity, prelude::*};
+use bevy_tween::interpolate::Interpolator;
 
 use crate::{ProjectionCtx, UiView, components::UiComponentTemplate};
 
 /// A titled group box that visually groups related content.
 ///
-/// Place content entities as ECS children.
+/// Place content entities as ECS children. Children tagged with
+/// [`PartGroupBoxTitleAction`] are laid out in the title bar (e.g. help or
+/// close icons) instead of the body.
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct UiGroupBox {
     /// Title displayed at the top of the group box.
     pub title: String,
+    /// Whether the title bar shows a chevron that toggles the body.
+    pub collapsible: bool,
+    /// Whether the body is currently hidden. Only honored when `collapsible`.
+    pub collapsed: bool,
 }
 
 impl UiGroupBox {
@@ -16,8 +23,91 @@ impl UiGroupBox {
     pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
+            collapsible: false,
+            collapsed: false,
         }
     }
+
+    #[must_use]
+    pub fn collapsible(mut self) -> Self {
+        self.collapsible = true;
+        self
+    }
+
+    #[must_use]
+    pub fn collapsed(mut self) -> Self {
+        self.collapsible = true;
+        self.collapsed = true;
+        self
+    }
+
+    /// Whether the body should currently be projected.
+    #[must_use]
+    pub fn is_body_visible(&self) -> bool {
+        !(self.collapsible && self.collapsed)
+    }
+}
+
+/// Marks a group box child that belongs in the title bar rather than the body.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartGroupBoxTitleAction;
+
+/// Animates a collapsible [`UiGroupBox`] body's height instead of hiding it instantly.
+///
+/// [`animate_group_box_collapse`](crate::animate_group_box_collapse) measures the
+/// expanded body and tweens [`UiCollapseHeight`] between that height and 0.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct AnimatedCollapse {
+    /// Animation length in seconds.
+    pub duration: f32,
+}
+
+impl Default for AnimatedCollapse {
+    fn default() -> Self {
+        Self { duration: 0.2 }
+    }
+}
+
+/// Animated max height of an [`AnimatedCollapse`] body, in logical pixels.
+///
+/// While [`Self::is_animating`], the body is projected clipped to `current`.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct UiCollapseHeight {
+    /// Height the body is clipped to this frame.
+    pub current: f64,
+    /// Height the body is animating toward (0 when collapsing).
+    pub target: f64,
+    /// Last measured height of the fully expanded body.
+    pub full: f64,
+}
+
+impl UiCollapseHeight {
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        (self.current - self.target).abs() > 0.5
+    }
+}
+
+/// Tween lens interpolating [`UiCollapseHeight::current`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiCollapseHeightLens {
+    pub start: f64,
+    pub end: f64,
+}
+
+impl Interpolator for UiCollapseHeightLens {
+    type Item = UiCollapseHeight;
+
+    fn interpolate(&self, target: &mut Self::Item, ratio: f32, _previous_value: f32) {
+        target.current = self.start + (self.end - self.start) * f64::from(ratio);
+    }
+}
+
+/// Emitted when a collapsible group box is collapsed or expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiGroupBoxToggled {
+    pub group_box: Entity,
+    pub collapsed: bool,
 }
 
 impl UiComponentTemplate for UiGroupBox {
@@ -19,15 +19,51 @@ impl UiMenuItem {
     }
 }
 
+/// Split a menu label into its display text and mnemonic letter.
+///
+/// A single `&` marks the following character as the mnemonic (`"&File"` →
+/// `("File", Some('f'))`); `&&` produces a literal `&`. The mnemonic is
+/// returned lowercased.
+#[must_use]
+pub fn parse_mnemonic(label: &str) -> (String, Option<char>) {
+    let mut display = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '&' {
+            display.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('&') => display.push('&'),
+            Some(next) => {
+                if mnemonic.is_none() {
+                    mnemonic = next.to_lowercase().next();
+                }
+                display.push(next);
+            }
+            None => {}
+        }
+    }
+
+    (display, mnemonic)
+}
+
 /// A top-level entry in a menu bar with a dropdown list of menu items.
+///
+/// Prefix a label character with `&` to make it the Alt+letter mnemonic.
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct UiMenuBarItem {
-    /// Label displayed on the menu bar button.
+    /// Label displayed on the menu bar button (may contain a `&` mnemonic marker).
     pub label: String,
     /// Items shown in the dropdown panel.
     pub items: Vec<UiMenuItem>,
     /// Whether the dropdown is currently open.
     pub is_open: bool,
+    /// Item highlighted by keyboard navigation while the dropdown is open.
+    pub highlighted: Option<usize>,
 }
 
 impl UiMenuBarItem {
@@ -37,8 +73,21 @@ impl UiMenuBarItem {
             label: label.into(),
             items: items.into_iter().collect(),
             is_open: false,
+            highlighted: None,
         }
     }
+
+    /// Label text with mnemonic markers removed.
+    #[must_use]
+    pub fn display_label(&self) -> String {
+        parse_mnemonic(&self.label).0
+    }
+
+    /// Lowercased mnemonic letter, if the label declares one.
+    #[must_use]
+    pub fn mnemonic(&self) -> Option<char> {
+        parse_mnemonic(&self.label).1
+    }
 }
 
 /// Marker for a horizontal menu bar container.
@@ -78,3 +127,22 @@ impl UiComponentTemplate for UiMenuItemPanel {
         crate::projection::widgets::project_menu_item_panel(component, ctx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_mnemonic;
+
+    #[test]
+    fn mnemonic_marker_is_stripped_and_lowercased() {
+        assert_eq!(parse_mnemonic("&File"), ("File".to_string(), Some('f')));
+        assert_eq!(
+            parse_mnemonic("Save &As"),
+            ("Save As".to_string(), Some('a'))
+        );
+        assert_eq!(
+            parse_mnemonic("Fish && Chips"),
+            ("Fish & Chips".to_string(), None)
+        );
+        assert_eq!(parse_mnemonic("View"), ("View".to_string(), None));
+    }
+}
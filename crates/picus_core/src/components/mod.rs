@@ -12,15 +12,21 @@ mod color_picker;
 mod combo_box;
 mod date_picker;
 mod dialog;
+mod divider;
+mod floating_panel;
 mod group_box;
 mod menu;
+mod perf_overlay;
 mod popover;
 mod progress_bar;
 mod radio_group;
 mod scroll_view;
+mod selection;
 mod slider;
+mod spacer;
 mod spinner;
 mod split_pane;
+mod suspense;
 mod switch;
 mod tab_bar;
 mod table;
@@ -29,6 +35,7 @@ mod theme_picker;
 mod toast;
 mod tooltip;
 mod tree_node;
+mod virtual_grid;
 
 pub use badge::*;
 pub use button::*;
@@ -37,15 +44,21 @@ pub use color_picker::*;
 pub use combo_box::*;
 pub use date_picker::*;
 pub use dialog::*;
+pub use divider::*;
+pub use floating_panel::*;
 pub use group_box::*;
 pub use menu::*;
+pub use perf_overlay::*;
 pub use popover::*;
 pub use progress_bar::*;
 pub use radio_group::*;
 pub use scroll_view::*;
+pub use selection::*;
 pub use slider::*;
+pub use spacer::*;
 pub use spinner::*;
 pub use split_pane::*;
+pub use suspense::*;
 pub use switch::*;
 pub use tab_bar::*;
 pub use table::*;
@@ -54,6 +67,7 @@ pub use theme_picker::*;
 pub use toast::*;
 pub use tooltip::*;
 pub use tree_node::*;
+pub use virtual_grid::*;
 
 /// Unified contract for ECS-native UI components.
 ///
@@ -162,5 +176,11 @@ pub fn register_builtin_ui_components(app: &mut App) {
         .register_ui_component::<date_picker::UiDatePicker>()
         .register_ui_component::<date_picker::UiDatePickerPanel>()
         .register_ui_component::<theme_picker::UiThemePicker>()
-        .register_ui_component::<theme_picker::UiThemePickerMenu>();
+        .register_ui_component::<theme_picker::UiThemePickerMenu>()
+        .register_ui_component::<virtual_grid::UiVirtualGrid>()
+        .register_ui_component::<perf_overlay::UiPerfOverlay>()
+        .register_ui_component::<spacer::UiSpacer>()
+        .register_ui_component::<divider::UiDivider>()
+        .register_ui_component::<suspense::UiSuspense>()
+        .register_ui_component::<floating_panel::UiFloatingPanel>();
 }
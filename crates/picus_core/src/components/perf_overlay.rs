@@ -0,0 +1,101 @@
+use bevy_ecs::prelude::*;
+use bevy_tween::bevy_time_runner::TimeRunner;
+
+use crate::{
+    OverlayStack, ProjectionCtx, UiSynthesisStats, UiView, components::UiComponentTemplate,
+};
+
+/// Developer HUD listing synthesis, animation, and overlay counts.
+///
+/// Renders only while [`UiPerfOverlaySettings::enabled`] is set; otherwise it projects a
+/// zero-size placeholder so the entity can stay in the tree. Synthesis runs every frame,
+/// so the HUD always shows the stats recorded by the previous pass.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UiPerfOverlay;
+
+/// Resource toggle for [`UiPerfOverlay`]. Disabled by default.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UiPerfOverlaySettings {
+    pub enabled: bool,
+}
+
+/// Counts shown by [`UiPerfOverlay`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UiPerfSnapshot {
+    pub synthesis: UiSynthesisStats,
+    /// Live `bevy_tween` time runners (style transitions and app animations).
+    pub animator_count: usize,
+    /// Entries in [`OverlayStack::active_overlays`].
+    pub overlay_count: usize,
+}
+
+impl UiPerfSnapshot {
+    #[must_use]
+    pub fn capture(world: &World) -> Self {
+        let animator_count = world
+            .try_query_filtered::<Entity, With<TimeRunner>>()
+            .map_or(0, |mut query| query.iter(world).count());
+
+        Self {
+            synthesis: world
+                .get_resource::<UiSynthesisStats>()
+                .cloned()
+                .unwrap_or_default(),
+            animator_count,
+            overlay_count: world
+                .get_resource::<OverlayStack>()
+                .map_or(0, |stack| stack.active_overlays.len()),
+        }
+    }
+
+    /// One HUD row per metric, in display order.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        let stats = &self.synthesis;
+        vec![
+            format!("nodes: {}", stats.node_count),
+            format!("roots: {}", stats.root_count),
+            format!(
+                "fallbacks: {} missing / {} cycles / {} unhandled / {} panicked",
+                stats.missing_entity_count,
+                stats.cycle_count,
+                stats.unhandled_count,
+                stats.panicked_count
+            ),
+            format!("synthesis: {:.2} ms", stats.elapsed.as_secs_f64() * 1000.0),
+            format!("animators: {}", self.animator_count),
+            format!("overlays: {}", self.overlay_count),
+        ]
+    }
+}
+
+impl UiComponentTemplate for UiPerfOverlay {
+    fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
+        crate::projection::widgets::project_perf_overlay(component, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UiPerfSnapshot;
+    use crate::UiSynthesisStats;
+
+    #[test]
+    fn perf_snapshot_lines_list_every_metric() {
+        let snapshot = UiPerfSnapshot {
+            synthesis: UiSynthesisStats {
+                node_count: 12,
+                root_count: 2,
+                ..UiSynthesisStats::default()
+            },
+            animator_count: 3,
+            overlay_count: 1,
+        };
+
+        let lines = snapshot.lines();
+        assert_eq!(lines[0], "nodes: 12");
+        assert_eq!(lines[1], "roots: 2");
+        assert!(lines.contains(&"animators: 3".to_string()));
+        assert!(lines.contains(&"overlays: 1".to_string()));
+    }
+}
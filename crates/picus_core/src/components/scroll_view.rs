@@ -91,6 +91,44 @@ pub struct UiScrollViewChanged {
     pub scroll_offset: Vec2,
 }
 
+/// Marks a direct content child of a [`UiScrollView`] as a sticky section header.
+///
+/// A sticky header stays pinned to the top of the viewport while its section
+/// (everything up to the next sticky header) scrolls, and is pushed up by the
+/// next section's header.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StickyHeader;
+
+/// Measured layout and pin offset of a [`StickyHeader`].
+///
+/// `section_top` and `header_height` are in scroll-content space and are read
+/// back from Masonry each frame; `pinned_offset` is the extra vertical
+/// translation the scroll view projector applies to keep the header pinned.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct StickyHeaderLayout {
+    pub section_top: f32,
+    pub header_height: f32,
+    pub pinned_offset: f32,
+}
+
+/// Vertical translation that keeps a sticky header pinned at `scroll_y`.
+///
+/// The header never moves above its own `section_top`, and stops at
+/// `next_section_top - header_height` so the next header pushes it out.
+#[must_use]
+pub fn sticky_header_offset(
+    scroll_y: f32,
+    section_top: f32,
+    header_height: f32,
+    next_section_top: Option<f32>,
+) -> f32 {
+    let mut pinned_y = scroll_y.max(section_top);
+    if let Some(next_top) = next_section_top {
+        pinned_y = pinned_y.min((next_top - header_height).max(section_top));
+    }
+    pinned_y - section_top
+}
+
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PartScrollViewport;
 
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use bevy_ecs::{entity::Entity, prelude::*};
+use bevy_input::{ButtonInput, keyboard::KeyCode};
+
+/// Whether a [`Selection`] holds one item or many.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    Multi,
+}
+
+/// Keyboard modifiers that shape how a click changes a [`Selection`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SelectionModifiers {
+    /// Ctrl (or Cmd): toggle the clicked item without clearing the rest.
+    pub ctrl: bool,
+    /// Shift: extend from the anchor to the clicked item in child order.
+    pub shift: bool,
+}
+
+impl SelectionModifiers {
+    pub const NONE: Self = Self {
+        ctrl: false,
+        shift: false,
+    };
+    pub const CTRL: Self = Self {
+        ctrl: true,
+        shift: false,
+    };
+    pub const SHIFT: Self = Self {
+        ctrl: false,
+        shift: true,
+    };
+
+    /// Read the currently held modifiers from Bevy keyboard input.
+    #[must_use]
+    pub fn from_keyboard(keys: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            ctrl: keys.any_pressed([
+                KeyCode::ControlLeft,
+                KeyCode::ControlRight,
+                KeyCode::SuperLeft,
+                KeyCode::SuperRight,
+            ]),
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+        }
+    }
+}
+
+/// Selection state stored on a list container.
+///
+/// Items are the container's ECS children. Selected items also carry the
+/// [`Selected`] marker so stylesheets can target them with `:selected`.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub mode: SelectionMode,
+    pub selected: HashSet<Entity>,
+    /// Item that Shift-range extension starts from.
+    pub anchor: Option<Entity>,
+}
+
+impl Selection {
+    #[must_use]
+    pub fn single() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn multi() -> Self {
+        Self {
+            mode: SelectionMode::Multi,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn is_selected(&self, item: Entity) -> bool {
+        self.selected.contains(&item)
+    }
+
+    /// Apply a click on `item` using desktop list semantics.
+    ///
+    /// `order` lists the container's items in child order and is used to
+    /// resolve Shift ranges. Returns `true` when the selected set changed.
+    pub fn apply_click(
+        &mut self,
+        item: Entity,
+        order: &[Entity],
+        modifiers: SelectionModifiers,
+    ) -> bool {
+        let before = self.selected.clone();
+
+        match self.mode {
+            SelectionMode::Single => {
+                self.selected.clear();
+                self.selected.insert(item);
+                self.anchor = Some(item);
+            }
+            SelectionMode::Multi => {
+                let range = self.anchor.filter(|_| modifiers.shift).and_then(|anchor| {
+                    let from = order.iter().position(|entity| *entity == anchor)?;
+                    let to = order.iter().position(|entity| *entity == item)?;
+                    Some(&order[from.min(to)..=from.max(to)])
+                });
+
+                if let Some(range) = range {
+                    if !modifiers.ctrl {
+                        self.selected.clear();
+                    }
+                    self.selected.extend(range.iter().copied());
+                } else if modifiers.ctrl {
+                    if !self.selected.remove(&item) {
+                        self.selected.insert(item);
+                    }
+                    self.anchor = Some(item);
+                } else {
+                    self.selected.clear();
+                    self.selected.insert(item);
+                    self.anchor = Some(item);
+                }
+            }
+        }
+
+        self.selected != before
+    }
+}
+
+/// Marker placed on items that are part of their container's [`Selection`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Selected;
+
+/// Emitted when a container's [`Selection`] changes.
+///
+/// `selected` is listed in child order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionChanged {
+    pub list: Entity,
+    pub selected: Vec<Entity>,
+}
@@ -0,0 +1,44 @@
+use bevy_ecs::prelude::*;
+
+use crate::{ProjectionCtx, UiView, components::UiComponentTemplate};
+
+/// Flexible empty space inside a [`UiFlexColumn`](crate::UiFlexColumn),
+/// [`UiFlexRow`](crate::UiFlexRow) or [`UiRoot`](crate::UiRoot).
+///
+/// The parent container gives the spacer a share of its leftover main-axis space
+/// proportional to `flex`, exactly like any other flex-weighted child.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiSpacer {
+    /// Flex weight relative to sibling spacers.
+    pub flex: f32,
+}
+
+impl UiSpacer {
+    #[must_use]
+    pub const fn new(flex: f32) -> Self {
+        Self { flex }
+    }
+}
+
+impl Default for UiSpacer {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl UiComponentTemplate for UiSpacer {
+    fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
+        crate::projection::layout::project_spacer(component, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UiSpacer;
+
+    #[test]
+    fn spacer_defaults_to_unit_flex() {
+        assert_eq!(UiSpacer::default().flex, 1.0);
+        assert_eq!(UiSpacer::new(2.5).flex, 2.5);
+    }
+}
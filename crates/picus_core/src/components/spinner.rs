@@ -1,18 +1,56 @@
 use bevy_ecs::prelude::*;
+use bevy_tween::interpolate::Interpolator;
 
 use crate::{ProjectionCtx, UiView, components::UiComponentTemplate};
 
-/// An animated loading spinner (indefinite progress indicator).
-#[derive(Component, Debug, Clone, PartialEq, Eq)]
+/// Default spinner ring diameter in logical pixels.
+pub const DEFAULT_SPINNER_SIZE: f32 = 20.0;
+/// Default spinner ring stroke width in logical pixels.
+pub const DEFAULT_SPINNER_THICKNESS: f32 = 2.5;
+/// Default indeterminate rotation speed in full turns per second.
+pub const DEFAULT_SPINNER_SPEED: f32 = 1.0;
+
+/// Arc length (as a fraction of a full turn) drawn by an indeterminate spinner.
+const INDETERMINATE_ARC_FRACTION: f32 = 0.25;
+
+/// A loading spinner ring.
+///
+/// With `progress: None` the spinner is indeterminate and rotates at `speed`
+/// turns per second. With `progress: Some(p)` it is determinate and draws an
+/// arc covering `p` of a full turn (`p` is clamped to `0.0..=1.0`).
+#[derive(Component, Debug, Clone, PartialEq)]
 pub struct UiSpinner {
     /// Optional label shown next to the spinner.
     pub label: Option<String>,
+    /// Ring diameter in logical pixels.
+    pub size: f32,
+    /// Ring stroke width in logical pixels.
+    pub thickness: f32,
+    /// Rotation speed in full turns per second (indeterminate mode only).
+    ///
+    /// Negative speeds turn counter-clockwise. Zero, non-finite speeds, and speeds
+    /// too slow for a [`Duration`](std::time::Duration) turn leave the ring still.
+    pub speed: f32,
+    /// Determinate progress in `0.0..=1.0`, or `None` for indeterminate.
+    pub progress: Option<f32>,
 }
 
 impl UiSpinner {
     #[must_use]
     pub fn new() -> Self {
-        Self { label: None }
+        Self {
+            label: None,
+            size: DEFAULT_SPINNER_SIZE,
+            thickness: DEFAULT_SPINNER_THICKNESS,
+            speed: DEFAULT_SPINNER_SPEED,
+            progress: None,
+        }
+    }
+
+    /// Create a determinate spinner showing `progress` of a full turn.
+    #[must_use]
+    pub fn determinate(progress: f32) -> Self {
+        Self::new().with_progress(progress)
     }
 
     #[must_use]
@@ -20,6 +58,47 @@ impl UiSpinner {
         self.label = Some(label.into());
         self
     }
+
+    #[must_use]
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size.max(0.0);
+        self
+    }
+
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness.max(0.0);
+        self
+    }
+
+    /// Set the rotation speed; non-finite values stop the ring (`0.0`).
+    #[must_use]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = if speed.is_finite() { speed } else { 0.0 };
+        self
+    }
+
+    #[must_use]
+    pub fn with_progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress.clamp(0.0, 1.0));
+        self
+    }
+
+    #[must_use]
+    pub fn is_determinate(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    /// Arc sweep in radians drawn for the current mode.
+    #[must_use]
+    pub fn arc_sweep(&self) -> f32 {
+        let fraction = self
+            .progress
+            .map_or(INDETERMINATE_ARC_FRACTION, |progress| {
+                progress.clamp(0.0, 1.0)
+            });
+        fraction * std::f32::consts::TAU
+    }
 }
 
 impl Default for UiSpinner {
@@ -28,6 +107,32 @@ impl Default for UiSpinner {
     }
 }
 
+/// Current rotation of an indeterminate [`UiSpinner`], in radians.
+///
+/// Inserted by [`crate::sync_spinner_rotation_tweens`] and driven by a looping
+/// [`UiSpinnerRotationLens`] tween; determinate spinners keep their arc anchored
+/// at 12 o'clock.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct UiSpinnerRotation {
+    pub angle: f32,
+}
+
+/// Tween lens turning [`UiSpinnerRotation::angle`] from `start` to `end`, wrapped to one turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiSpinnerRotationLens {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl Interpolator for UiSpinnerRotationLens {
+    type Item = UiSpinnerRotation;
+
+    fn interpolate(&self, target: &mut Self::Item, ratio: f32, _previous_value: f32) {
+        let angle = self.start + (self.end - self.start) * ratio;
+        target.angle = angle.rem_euclid(std::f32::consts::TAU);
+    }
+}
+
 impl UiComponentTemplate for UiSpinner {
     fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
         crate::projection::widgets::project_spinner(component, ctx)
@@ -0,0 +1,112 @@
+use std::{fmt, sync::Arc};
+
+use bevy_ecs::{entity::Entity, prelude::*};
+
+use crate::{ProjectionCtx, UiView, components::UiComponentTemplate, synthesize::ordered_children};
+
+/// Readiness predicate for a [`UiSuspense`], called with the suspense entity.
+pub type SuspenseReadyFn = Arc<dyn Fn(&World, Entity) -> bool + Send + Sync>;
+
+/// Marks a node whose content is still loading (e.g. an image being fetched).
+///
+/// Remove it once the content is available. A [`UiSuspense`] without a custom
+/// predicate waits until none of its content descendants carry this marker.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UiLoading;
+
+/// Shows a fallback subtree until its content signals it is ready.
+///
+/// Both the fallback and the content are ECS children of the suspense entity;
+/// `fallback` names the child to show while waiting. Once ready, every other
+/// child is projected in a column and the fallback is hidden. Readiness is
+/// re-evaluated on every synthesis pass, so the swap happens on the first frame
+/// the predicate returns `true`.
+#[derive(Component, Clone)]
+pub struct UiSuspense {
+    /// Child shown while the content is not ready.
+    pub fallback: Entity,
+    /// Custom readiness predicate; [`content_loaded`] when `None`.
+    pub ready: Option<SuspenseReadyFn>,
+}
+
+impl UiSuspense {
+    #[must_use]
+    pub fn new(fallback: Entity) -> Self {
+        Self {
+            fallback,
+            ready: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_ready(
+        mut self,
+        ready: impl Fn(&World, Entity) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.ready = Some(Arc::new(ready));
+        self
+    }
+
+    /// Whether `entity`'s content should replace the fallback.
+    #[must_use]
+    pub fn is_ready(&self, world: &World, entity: Entity) -> bool {
+        match &self.ready {
+            Some(ready) => ready(world, entity),
+            None => content_loaded(world, entity, self.fallback),
+        }
+    }
+}
+
+impl fmt::Debug for UiSuspense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UiSuspense")
+            .field("fallback", &self.fallback)
+            .field("ready", &self.ready.as_ref().map(|_| "<fn>"))
+            .finish()
+    }
+}
+
+/// Default readiness: no descendant of `suspense` outside the fallback subtree
+/// carries [`UiLoading`].
+#[must_use]
+pub fn content_loaded(world: &World, suspense: Entity, fallback: Entity) -> bool {
+    let mut stack = ordered_children(world, suspense);
+    while let Some(entity) = stack.pop() {
+        if entity == fallback {
+            continue;
+        }
+        if world.get::<UiLoading>(entity).is_some() {
+            return false;
+        }
+        stack.extend(ordered_children(world, entity));
+    }
+    true
+}
+
+impl UiComponentTemplate for UiSuspense {
+    fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
+        crate::projection::layout::project_suspense(component, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{hierarchy::ChildOf, world::World};
+
+    use super::{UiLoading, UiSuspense};
+
+    #[test]
+    fn default_readiness_ignores_loading_markers_inside_fallback() {
+        let mut world = World::new();
+        let suspense = world.spawn_empty().id();
+        let fallback = world.spawn((UiLoading, ChildOf(suspense))).id();
+        let content = world.spawn(ChildOf(suspense)).id();
+        let image = world.spawn((UiLoading, ChildOf(content))).id();
+        let component = UiSuspense::new(fallback);
+
+        assert!(!component.is_ready(&world, suspense));
+
+        world.entity_mut(image).remove::<UiLoading>();
+        assert!(component.is_ready(&world, suspense));
+    }
+}
@@ -9,14 +9,41 @@ pub struct UiTable {
     pub columns: Vec<String>,
     /// Table data rows (each row is a list of cell strings).
     pub rows: Vec<Vec<String>>,
+    /// Columns whose cells can be edited in place by double-clicking them.
+    pub editable_columns: Vec<usize>,
+    /// The cell currently being edited, if any.
+    pub editing: Option<UiTableCellEdit>,
 }
 
+/// In-progress inline edit of one [`UiTable`] cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiTableCellEdit {
+    pub row: usize,
+    pub col: usize,
+    /// Draft text; written back to the cell only on commit.
+    pub value: String,
+}
+
+/// Emitted when an inline cell edit is committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiCellEdited {
+    pub table: Entity,
+    pub row: usize,
+    pub col: usize,
+    pub value: String,
+}
+
+/// Maximum delay between two clicks on the same cell for them to count as a double-click.
+pub const TABLE_DOUBLE_CLICK_SECS: f64 = 0.4;
+
 impl UiTable {
     #[must_use]
     pub fn new(columns: impl IntoIterator<Item = impl Into<String>>) -> Self {
         Self {
             columns: columns.into_iter().map(Into::into).collect(),
             rows: Vec::new(),
+            editable_columns: Vec::new(),
+            editing: None,
         }
     }
 
@@ -25,6 +52,43 @@ impl UiTable {
         self.rows.push(cells.into_iter().map(Into::into).collect());
         self
     }
+
+    /// Allow double-click editing of every cell in column `col`.
+    #[must_use]
+    pub fn with_editable_column(mut self, col: usize) -> Self {
+        if !self.editable_columns.contains(&col) {
+            self.editable_columns.push(col);
+        }
+        self
+    }
+
+    /// Whether the cell at `row`/`col` exists and belongs to an editable column.
+    #[must_use]
+    pub fn is_cell_editable(&self, row: usize, col: usize) -> bool {
+        self.editable_columns.contains(&col)
+            && self.rows.get(row).is_some_and(|cells| col < cells.len())
+    }
+
+    /// Start editing `row`/`col` with its current text; returns `false` if the cell is not editable.
+    pub fn begin_edit(&mut self, row: usize, col: usize) -> bool {
+        if !self.is_cell_editable(row, col) {
+            return false;
+        }
+        self.editing = Some(UiTableCellEdit {
+            row,
+            col,
+            value: self.rows[row][col].clone(),
+        });
+        true
+    }
+
+    /// Write the draft back into its cell and end editing.
+    pub fn commit_edit(&mut self) -> Option<UiTableCellEdit> {
+        let edit = self.editing.take()?;
+        let cell = self.rows.get_mut(edit.row)?.get_mut(edit.col)?;
+        cell.clone_from(&edit.value);
+        Some(edit)
+    }
 }
 
 impl UiComponentTemplate for UiTable {
@@ -32,3 +96,32 @@ impl UiComponentTemplate for UiTable {
         crate::projection::widgets::project_table(component, ctx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_edits_only_editable_cells_and_commits_draft() {
+        let mut table = UiTable::new(["Name", "Score"])
+            .with_row(["Alice", "98"])
+            .with_editable_column(1);
+
+        assert!(!table.begin_edit(0, 0));
+        assert!(!table.begin_edit(3, 1));
+        assert!(table.begin_edit(0, 1));
+        assert_eq!(
+            table.editing.as_ref().map(|edit| edit.value.as_str()),
+            Some("98")
+        );
+
+        if let Some(edit) = table.editing.as_mut() {
+            edit.value = "99".to_string();
+        }
+        let committed = table.commit_edit().expect("edit should commit");
+
+        assert_eq!((committed.row, committed.col), (0, 1));
+        assert_eq!(table.rows[0][1], "99");
+        assert!(table.editing.is_none());
+    }
+}
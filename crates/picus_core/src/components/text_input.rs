@@ -1,3 +1,5 @@
+use std::{fmt, sync::Arc};
+
 use bevy_ecs::{entity::Entity, prelude::*};
 
 use crate::{
@@ -5,11 +7,115 @@ use crate::{
     templates::ensure_template_part,
 };
 
+/// Restricts what a [`UiTextInput`] accepts.
+///
+/// Character filters drop disallowed characters from each edit. [`InputFilter::Regex`]
+/// validates the whole value instead and rejects edits that would not match it.
+#[derive(Clone)]
+pub enum InputFilter {
+    /// ASCII digits only.
+    Numeric,
+    /// Alphabetic or numeric characters (Unicode aware).
+    Alphanumeric,
+    /// The whole value must match; anchor the pattern (`^...$`) for full matches.
+    Regex(regex::Regex),
+    /// Keep characters for which the predicate returns `true`.
+    Custom(Arc<dyn Fn(char) -> bool + Send + Sync>),
+}
+
+impl InputFilter {
+    #[must_use]
+    pub fn custom(predicate: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(predicate))
+    }
+
+    /// Filter a proposed edit, given the value it replaces.
+    #[must_use]
+    pub fn apply(&self, previous: &str, proposed: &str) -> String {
+        match self {
+            Self::Numeric => proposed.chars().filter(char::is_ascii_digit).collect(),
+            Self::Alphanumeric => proposed.chars().filter(|c| c.is_alphanumeric()).collect(),
+            Self::Regex(regex) => {
+                if regex.is_match(proposed) {
+                    proposed.to_string()
+                } else {
+                    previous.to_string()
+                }
+            }
+            Self::Custom(predicate) => proposed.chars().filter(|c| predicate(*c)).collect(),
+        }
+    }
+}
+
+impl fmt::Debug for InputFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric => f.write_str("Numeric"),
+            Self::Alphanumeric => f.write_str("Alphanumeric"),
+            Self::Regex(regex) => f.debug_tuple("Regex").field(&regex.as_str()).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for InputFilter {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Numeric, Self::Numeric) | (Self::Alphanumeric, Self::Alphanumeric) => true,
+            (Self::Regex(a), Self::Regex(b)) => a.as_str() == b.as_str(),
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for InputFilter {}
+
+/// Display string for a value rendered with `mask`: one mask character per value character.
+#[must_use]
+pub fn masked_text(value: &str, mask: char) -> String {
+    std::iter::repeat_n(mask, value.chars().count()).collect()
+}
+
+/// Recover the real value after the user edited the masked text of `previous`.
+///
+/// Unchanged characters still show as `mask`, so the edit is located from the leading
+/// and trailing runs of mask characters; typed characters in between are taken as-is.
+/// Deletions inside a run are indistinguishable and are assumed to happen at its end.
+#[must_use]
+pub fn unmask_edit(previous: &str, displayed: &str, mask: char) -> String {
+    let previous = previous.chars().collect::<Vec<_>>();
+    let displayed = displayed.chars().collect::<Vec<_>>();
+
+    let prefix = displayed
+        .iter()
+        .take_while(|c| **c == mask)
+        .count()
+        .min(previous.len());
+    let suffix = displayed[prefix..]
+        .iter()
+        .rev()
+        .take_while(|c| **c == mask)
+        .count()
+        .min(previous.len() - prefix);
+
+    previous[..prefix]
+        .iter()
+        .chain(&displayed[prefix..displayed.len() - suffix])
+        .chain(&previous[previous.len() - suffix..])
+        .collect()
+}
+
 /// Built-in text input UI component with ECS-owned content.
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct UiTextInput {
     pub value: String,
     pub placeholder: String,
+    /// Applied to every edit before [`UiTextInputChanged`] is emitted.
+    pub filter: Option<InputFilter>,
+    /// Render each character as this mask (e.g. `'•'` for passwords) while `value`
+    /// keeps the real text.
+    pub mask: Option<char>,
 }
 
 impl UiTextInput {
@@ -18,6 +124,8 @@ impl UiTextInput {
         Self {
             value: value.into(),
             placeholder: String::new(),
+            filter: None,
+            mask: None,
         }
     }
 
@@ -26,6 +134,18 @@ impl UiTextInput {
         self.placeholder = placeholder.into();
         self
     }
+
+    #[must_use]
+    pub fn with_filter(mut self, filter: InputFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
 }
 
 /// Emitted when [`UiTextInput`] value changes.
@@ -63,3 +183,34 @@ impl UiComponentTemplate for UiTextInput {
         crate::projection::elements::project_text_input(component, ctx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{InputFilter, masked_text, unmask_edit};
+
+    #[test]
+    fn numeric_filter_rejects_letters() {
+        assert_eq!(InputFilter::Numeric.apply("12", "12a3"), "123");
+        assert_eq!(InputFilter::Alphanumeric.apply("", "a-b c1"), "abc1");
+        assert_eq!(InputFilter::custom(|c| c != ' ').apply("", "a b"), "ab");
+    }
+
+    #[test]
+    fn regex_filter_keeps_previous_value_on_mismatch() {
+        let filter = InputFilter::Regex(regex::Regex::new(r"^\d{0,4}$").expect("valid regex"));
+        assert_eq!(filter.apply("123", "1234"), "1234");
+        assert_eq!(filter.apply("1234", "12345"), "1234");
+        assert_eq!(filter.apply("12", "12x"), "12");
+    }
+
+    #[test]
+    fn unmask_edit_recovers_real_value_from_masked_display() {
+        assert_eq!(masked_text("hunter2", '•'), "•••••••");
+        assert_eq!(unmask_edit("abc", "•••d", '•'), "abcd");
+        assert_eq!(unmask_edit("abc", "x•••", '•'), "xabc");
+        assert_eq!(unmask_edit("abc", "•x••", '•'), "axbc");
+        assert_eq!(unmask_edit("abc", "••", '•'), "ab");
+        assert_eq!(unmask_edit("abc", "", '•'), "");
+        assert_eq!(unmask_edit("", "pw", '•'), "pw");
+    }
+}
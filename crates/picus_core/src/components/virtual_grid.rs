@@ -0,0 +1,117 @@
+use bevy_ecs::prelude::*;
+
+use crate::{ProjectionCtx, UiView, components::UiComponentTemplate};
+
+/// A resize-aware, row-virtualized grid of equally sized cells.
+///
+/// Cells are the grid's ECS children, in child order. The column count is
+/// derived from the grid's live layout width (see [`UiVirtualGridLayout`]) so
+/// that as many `min_item_width` columns as fit are used, and only the rows
+/// intersecting the viewport are projected.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiVirtualGrid {
+    /// Number of cells in the grid. Missing children render as empty cells.
+    pub item_count: usize,
+    /// Minimum cell width in logical pixels.
+    pub min_item_width: f64,
+    /// Gap between rows and columns in logical pixels.
+    pub gap: f64,
+    /// Fixed row height in logical pixels.
+    pub row_height: f64,
+    /// Height of the scrolling viewport in logical pixels.
+    pub viewport_height: f64,
+    /// Upper bound on the column count.
+    pub max_columns: usize,
+}
+
+impl Default for UiVirtualGrid {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            min_item_width: 220.0,
+            gap: 12.0,
+            row_height: 240.0,
+            viewport_height: 480.0,
+            max_columns: 12,
+        }
+    }
+}
+
+impl UiVirtualGrid {
+    #[must_use]
+    pub fn new(item_count: usize) -> Self {
+        Self {
+            item_count,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_min_item_width(mut self, min_item_width: f64) -> Self {
+        self.min_item_width = min_item_width.max(1.0);
+        self
+    }
+
+    #[must_use]
+    pub fn with_gap(mut self, gap: f64) -> Self {
+        self.gap = gap.max(0.0);
+        self
+    }
+
+    #[must_use]
+    pub fn with_row_height(mut self, row_height: f64) -> Self {
+        self.row_height = row_height.max(1.0);
+        self
+    }
+
+    #[must_use]
+    pub fn with_viewport_height(mut self, viewport_height: f64) -> Self {
+        self.viewport_height = viewport_height.max(1.0);
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_columns(mut self, max_columns: usize) -> Self {
+        self.max_columns = max_columns.max(1);
+        self
+    }
+
+    /// Number of columns that fit into `available_width`, accounting for gaps.
+    #[must_use]
+    pub fn column_count(&self, available_width: f64) -> usize {
+        let min_item_width = self.min_item_width.max(1.0);
+        let available_width = available_width.max(min_item_width);
+        // n columns fit when n * C + (n - 1) * G <= W, i.e. n <= (W + G) / (C + G).
+        let columns = ((available_width + self.gap) / (min_item_width + self.gap)).floor();
+        (columns as usize).clamp(1, self.max_columns.max(1))
+    }
+
+    /// Cell width used when laying out `columns` columns across `available_width`.
+    #[must_use]
+    pub fn cell_width(&self, available_width: f64, columns: usize) -> f64 {
+        let columns = columns.max(1);
+        let spacing = self.gap * columns.saturating_sub(1) as f64;
+        ((available_width - spacing) / columns as f64).max(self.min_item_width)
+    }
+
+    /// Number of rows needed to show every item with `columns` columns.
+    #[must_use]
+    pub fn row_count(&self, columns: usize) -> usize {
+        self.item_count.div_ceil(columns.max(1))
+    }
+}
+
+/// Live layout of a [`UiVirtualGrid`], synchronized from Masonry each frame.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct UiVirtualGridLayout {
+    /// Width available to the grid in logical pixels.
+    pub available_width: f64,
+    /// Column count derived from `available_width`.
+    pub columns: usize,
+}
+
+impl UiComponentTemplate for UiVirtualGrid {
+    fn project(component: &Self, ctx: ProjectionCtx<'_>) -> UiView {
+        crate::projection::widgets::project_virtual_grid(component, ctx)
+    }
+}
@@ -1,10 +1,22 @@
 use bevy_ecs::{entity::Entity, prelude::Component, prelude::Resource};
 use bevy_time::{Timer, TimerMode};
+use bevy_tween::interpolate::Interpolator;
+
+use crate::icons::PicusIcon;
+use crate::styling::{AlignItems, JustifyContent};
 
 /// Marker component for UI tree roots.
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct UiRoot;
 
+/// Canvas color for a [`UiRoot`].
+///
+/// The window surface is cleared to this color before painting, so apps get a
+/// themed background without a full-size container. Only the bottom-most
+/// (first synthesized) root's background is used.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct RootBackground(pub xilem::Color);
+
 /// Marker component for the global overlay/portal root.
 ///
 /// Overlay entities (dialogs, dropdowns, tooltips, etc.) should be attached as
@@ -12,27 +24,274 @@ pub struct UiRoot;
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct UiOverlayRoot;
 
-/// Built-in vertical container marker.
+/// Reparents this entity (and its subtree) under [`UiOverlayRoot`].
+///
+/// Works like a portal for app-defined floating content that is not one of the
+/// built-in overlay controls. Removing the marker moves the subtree back to its
+/// original parent and sibling position.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Teleport;
+
+/// Original placement of a [`Teleport`]ed entity, restored when the marker is removed.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeleportOrigin {
+    pub parent: Option<Entity>,
+    pub index: usize,
+}
+
+/// Built-in vertical container.
+///
+/// Unset fields fall back to the resolved style (`gap`, `justify_content`,
+/// `align_items`), so `UiFlexColumn::default()` behaves like a plain styled column.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct UiFlexColumn {
+    /// Space between children in logical px.
+    pub gap: Option<f64>,
+    pub main_align: Option<JustifyContent>,
+    pub cross_align: Option<AlignItems>,
+}
+
+/// Built-in horizontal container.
+///
+/// Field semantics match [`UiFlexColumn`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct UiFlexRow {
+    /// Space between children in logical px.
+    pub gap: Option<f64>,
+    pub main_align: Option<JustifyContent>,
+    pub cross_align: Option<AlignItems>,
+}
+
+macro_rules! impl_flex_container_builders {
+    ($container:ty) => {
+        impl $container {
+            #[must_use]
+            pub fn with_gap(mut self, gap: f64) -> Self {
+                self.gap = Some(gap);
+                self
+            }
+
+            #[must_use]
+            pub fn with_main_align(mut self, main_align: JustifyContent) -> Self {
+                self.main_align = Some(main_align);
+                self
+            }
+
+            #[must_use]
+            pub fn with_cross_align(mut self, cross_align: AlignItems) -> Self {
+                self.cross_align = Some(cross_align);
+                self
+            }
+        }
+    };
+}
+
+impl_flex_container_builders!(UiFlexColumn);
+impl_flex_container_builders!(UiFlexRow);
+
+/// Explicit sibling order used during synthesis.
+///
+/// When any child of an entity carries this component, its children are sorted
+/// by it before projection (ascending, stable on `Children` order). Children
+/// without it count as `UiOrder(0)`.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UiOrder(pub i32);
+
+/// Explicit z-order of a [`UiRoot`] among the synthesized roots.
+///
+/// Roots are synthesized (and stacked) in ascending order; roots without it count
+/// as `UiRootOrder(0)` and ties keep entity order. The overlay root is always last,
+/// whatever its order.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UiRootOrder(pub i32);
+
+/// Hides an entity and its whole subtree from synthesis without despawning it.
+///
+/// Hidden children are left out of [`ordered_children`](crate::ordered_children), so
+/// their parent projects as if they were absent; hidden roots are skipped by
+/// [`gather_ui_roots`](crate::gather_ui_roots). Remove the marker to show the subtree again.
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
-pub struct UiFlexColumn;
+pub struct UiHidden;
 
-/// Built-in horizontal container marker.
+/// Marks a [`UiRoot`] that is synthesized only to be measured, never shown.
+///
+/// Such roots are skipped by [`gather_ui_roots`](crate::gather_ui_roots);
+/// [`measure_offscreen_roots`](crate::measure_offscreen_roots) lays them out
+/// offscreen and reports the result as [`OffscreenMeasuredSize`].
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
-pub struct UiFlexRow;
+pub struct OffscreenMeasure;
+
+/// Natural logical size of an [`OffscreenMeasure`] root.
+///
+/// Written once; remove it to have the root measured again.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct OffscreenMeasuredSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Remount key for a UI node's Masonry widget subtree.
+///
+/// Synthesis wraps every entity's view in an entity scope widget. Masonry keeps
+/// that widget (and everything below it, including focus and caret state) as
+/// long as the scope's position among its siblings and its view type stay the
+/// same, even when a sibling is reprojected. Changing this key tears the
+/// subtree down and builds a fresh one, e.g. to reset a text input's caret and
+/// undo state when it starts editing a different record.
+///
+/// The key only forces remounts. It does not make Masonry reuse widgets that
+/// would otherwise be rebuilt: siblings are still matched by position, so
+/// inserting or removing an earlier sibling, or projecting a view of a
+/// different type, rebuilds the subtree whatever the key.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UiRemountKey(pub u64);
+
+/// How a node treats content that extends past its bounds.
+///
+/// Applied by synthesis around the entity's projected view: `Clip` wraps it in a
+/// [`clip_box`](crate::clip_box) using the resolved style's corner radius, and
+/// `Scroll` wraps it in a scrollable portal.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    #[default]
+    Visible,
+    Clip,
+    Scroll,
+}
+
+/// Visual transform applied around an entity's projected view.
+///
+/// Synthesis wraps the view in a Masonry `transformed` view: the entity is
+/// rotated (radians), then scaled, then translated (logical pixels). Layout is
+/// unaffected, so siblings keep their positions. Animate it with
+/// [`UiTransformLens`] like any other `bevy_tween` component tween.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiTransform {
+    pub scale: f32,
+    pub translate: (f64, f64),
+    pub rotate: f32,
+}
+
+impl UiTransform {
+    pub const IDENTITY: Self = Self {
+        scale: 1.0,
+        translate: (0.0, 0.0),
+        rotate: 0.0,
+    };
+
+    #[must_use]
+    pub const fn from_scale(scale: f32) -> Self {
+        Self {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub const fn from_translation(x: f64, y: f64) -> Self {
+        Self {
+            translate: (x, y),
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub const fn with_rotation(mut self, radians: f32) -> Self {
+        self.rotate = radians;
+        self
+    }
+
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+}
+
+impl Default for UiTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Tween lens interpolating [`UiTransform`] component-wise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiTransformLens {
+    pub start: UiTransform,
+    pub end: UiTransform,
+}
+
+impl Interpolator for UiTransformLens {
+    type Item = UiTransform;
+
+    fn interpolate(&self, target: &mut Self::Item, ratio: f32, _previous_value: f32) {
+        let lerp = |start: f64, end: f64| start + (end - start) * f64::from(ratio);
+        target.scale = self.start.scale + (self.end.scale - self.start.scale) * ratio;
+        target.rotate = self.start.rotate + (self.end.rotate - self.start.rotate) * ratio;
+        target.translate = (
+            lerp(self.start.translate.0, self.end.translate.0),
+            lerp(self.start.translate.1, self.end.translate.1),
+        );
+    }
+}
+
+/// Stable, app-assigned identity for a UI node.
+///
+/// Synthesis passes it to projectors as [`ProjectionCtx::node_id`](crate::ProjectionCtx)
+/// (falling back to the entity bits), and [`UiNodeIndex`](crate::UiNodeIndex) maps it
+/// back to the entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UiNodeId(pub u64);
+
+/// How a [`UiLabel`] handles text that does not fit its box width.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TextOverflow {
+    /// Wrap onto as many lines as needed.
+    #[default]
+    Wrap,
+    /// Keep a single line and cut it at the box edge.
+    Clip,
+    /// Wrap up to `max_lines`, truncating the rest with "…".
+    Ellipsis { max_lines: u32 },
+}
+
+impl TextOverflow {
+    /// Single-line ellipsis truncation.
+    #[must_use]
+    pub const fn ellipsis() -> Self {
+        Self::Ellipsis { max_lines: 1 }
+    }
+}
 
 /// Built-in text label component.
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct UiLabel {
     pub text: String,
+    pub overflow: TextOverflow,
 }
 
 impl UiLabel {
     #[must_use]
     pub fn new(text: impl Into<String>) -> Self {
-        Self { text: text.into() }
+        Self {
+            text: text.into(),
+            overflow: TextOverflow::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
     }
 }
 
+/// Truncated display text for a [`UiLabel`] using [`TextOverflow::Ellipsis`].
+///
+/// Maintained by `ellipsize_overflowing_labels` from the label's laid-out width;
+/// absent while the full text fits.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct EllipsizedText(pub String);
+
 /// Translation key marker for localized text projection.
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct LocalizeText {
@@ -46,6 +305,41 @@ impl LocalizeText {
     }
 }
 
+/// Placeholder shown by a container projector while it has no children.
+///
+/// Attach next to a container component (e.g. [`UiFlexColumn`]). When
+/// `localize` is set, the message is translated through [`crate::AppI18n`] and
+/// `message` acts as the fallback text.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct EmptyState {
+    pub message: String,
+    pub localize: Option<LocalizeText>,
+    pub icon: Option<PicusIcon>,
+}
+
+impl EmptyState {
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            localize: None,
+            icon: None,
+        }
+    }
+
+    #[must_use]
+    pub fn localized(mut self, key: impl Into<String>) -> Self {
+        self.localize = Some(LocalizeText::new(key));
+        self
+    }
+
+    #[must_use]
+    pub fn with_icon(mut self, icon: PicusIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
 /// Universal placement hints for floating overlays.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum OverlayPlacement {
@@ -115,6 +409,39 @@ pub struct OverlayStack {
     pub active_overlays: Vec<Entity>,
 }
 
+impl OverlayStack {
+    /// The overlay drawn above all others, if any is open.
+    #[must_use]
+    pub fn topmost(&self) -> Option<Entity> {
+        self.active_overlays.last().copied()
+    }
+
+    /// Whether `entity` is a currently tracked overlay.
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.active_overlays.contains(&entity)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.active_overlays.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.active_overlays.is_empty()
+    }
+}
+
+/// Request that every overlay in the [`OverlayStack`] be dismissed.
+///
+/// Push it with [`UiEventQueue::push_typed`](crate::UiEventQueue::push_typed) on any
+/// entity (the target is ignored), e.g. before switching screens. Overlays close
+/// top-most first through their regular dismiss paths, so dialogs still report a
+/// cancelled result and anchored owners reset their open flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CloseAllOverlays;
+
 /// Behavioral state for an overlay instance.
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct OverlayState {
@@ -1,5 +1,6 @@
 use std::{
-    any::Any,
+    any::{Any, TypeId, type_name},
+    collections::{HashMap, HashSet},
     fmt,
     sync::{Arc, OnceLock, PoisonError, RwLock},
 };
@@ -100,6 +101,86 @@ pub struct TypedUiEvent<T> {
     pub action: T,
 }
 
+type CloneActionFn = fn(&(dyn Any + Send + Sync)) -> Box<dyn Any + Send + Sync>;
+
+fn clone_action<T: Any + Send + Sync + Clone>(
+    action: &(dyn Any + Send + Sync),
+) -> Box<dyn Any + Send + Sync> {
+    let action = action
+        .downcast_ref::<T>()
+        .expect("recorded action payload should match its registered type");
+    Box::new(action.clone())
+}
+
+#[derive(Clone, Copy)]
+struct RecordableActionType {
+    type_name: &'static str,
+    clone_action: CloneActionFn,
+}
+
+/// Cloneable, type-tagged copy of a queued UI action.
+///
+/// Produced by [`UiEventQueue::snapshot`] or built directly with
+/// [`RecordedAction::new`] to script a sequence for [`UiEventQueue::replay`].
+pub struct RecordedAction {
+    /// Source ECS entity for this action.
+    pub entity: Entity,
+    type_name: &'static str,
+    action: Box<dyn Any + Send + Sync>,
+    clone_action: CloneActionFn,
+}
+
+impl RecordedAction {
+    /// Record a typed action payload for an entity.
+    #[must_use]
+    pub fn new<T: Any + Send + Sync + Clone>(entity: Entity, action: T) -> Self {
+        Self {
+            entity,
+            type_name: type_name::<T>(),
+            action: Box::new(action),
+            clone_action: clone_action::<T>,
+        }
+    }
+
+    /// Fully qualified Rust type name of the recorded payload.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Borrow the recorded payload when it has type `T`.
+    #[must_use]
+    pub fn action<T: Any>(&self) -> Option<&T> {
+        self.action.downcast_ref::<T>()
+    }
+
+    /// Materialize a fresh queue entry carrying a copy of the payload.
+    #[must_use]
+    pub fn to_event(&self) -> UiEvent {
+        UiEvent::new(self.entity, (self.clone_action)(self.action.as_ref()))
+    }
+}
+
+impl Clone for RecordedAction {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            type_name: self.type_name,
+            action: (self.clone_action)(self.action.as_ref()),
+            clone_action: self.clone_action,
+        }
+    }
+}
+
+impl fmt::Debug for RecordedAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordedAction")
+            .field("entity", &self.entity)
+            .field("type_name", &self.type_name)
+            .finish()
+    }
+}
+
 /// Lock-free queue shared between Bevy systems and Masonry widgets.
 ///
 /// # Example
@@ -118,15 +199,25 @@ pub struct TypedUiEvent<T> {
 /// assert_eq!(drained[0].entity, entity);
 /// assert_eq!(drained[0].action, 7);
 /// ```
-#[derive(Resource, Clone, Debug)]
+#[derive(Resource, Clone)]
 pub struct UiEventQueue {
     queue: Arc<SegQueue<UiEvent>>,
+    recordable: Arc<RwLock<HashMap<TypeId, RecordableActionType>>>,
+}
+
+impl fmt::Debug for UiEventQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UiEventQueue")
+            .field("queue", &self.queue)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for UiEventQueue {
     fn default() -> Self {
         Self {
             queue: Arc::new(SegQueue::new()),
+            recordable: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -148,6 +239,61 @@ impl UiEventQueue {
         self.push(UiEvent::typed(entity, action));
     }
 
+    /// Allow actions of type `T` to be captured by [`Self::snapshot`].
+    pub fn register_recordable<T: Any + Send + Sync + Clone>(&self) {
+        self.recordable
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                TypeId::of::<T>(),
+                RecordableActionType {
+                    type_name: type_name::<T>(),
+                    clone_action: clone_action::<T>,
+                },
+            );
+    }
+
+    /// Copy the queued actions without consuming them.
+    ///
+    /// Only payload types registered through [`Self::register_recordable`] are
+    /// captured; other entries are left queued but omitted from the snapshot.
+    /// Queue order is preserved. Intended for deterministic tests: events pushed
+    /// concurrently while the snapshot runs may be reordered behind it.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<RecordedAction> {
+        let recordable = self
+            .recordable
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let mut pending = Vec::new();
+        while let Some(event) = self.queue.pop() {
+            pending.push(event);
+        }
+
+        let mut recorded = Vec::new();
+        for event in pending {
+            if let Some(kind) = recordable.get(&event.action.as_ref().type_id()) {
+                recorded.push(RecordedAction {
+                    entity: event.entity,
+                    type_name: kind.type_name,
+                    action: (kind.clone_action)(event.action.as_ref()),
+                    clone_action: kind.clone_action,
+                });
+            }
+            self.queue.push(event);
+        }
+
+        recorded
+    }
+
+    /// Push copies of recorded actions back onto the queue, in order.
+    pub fn replay(&self, actions: &[RecordedAction]) {
+        for action in actions {
+            self.push(action.to_event());
+        }
+    }
+
     /// Drain every queued event, regardless of payload type.
     #[must_use]
     pub fn drain_all(&mut self) -> Vec<UiEvent> {
@@ -178,6 +324,47 @@ impl UiEventQueue {
 
         drained
     }
+
+    /// Take the first `T` action queued for `entity`.
+    ///
+    /// Every other entry, including `T` actions for other entities, stays queued in its
+    /// original order.
+    #[must_use]
+    pub fn take_action_for<T: Any + Send + Sync>(&mut self, entity: Entity) -> Option<T> {
+        let mut pending = Vec::new();
+        while let Some(event) = self.queue.pop() {
+            pending.push(event);
+        }
+
+        let mut taken = None;
+        for event in pending {
+            if taken.is_none() && event.entity == entity && event.action.is::<T>() {
+                taken = event.into_action::<T>().map(|typed| typed.action);
+            } else {
+                self.queue.push(event);
+            }
+        }
+        taken
+    }
+
+    /// Drain typed actions like [`Self::drain_actions`], keeping only the latest
+    /// action per entity.
+    ///
+    /// Use this for value-change actions (slider drags, text edits) where handlers
+    /// only care about the final value of the frame. Each entity's surviving action
+    /// keeps the position of its last occurrence.
+    #[must_use]
+    pub fn drain_actions_coalesced<T: Any + Send + Sync>(&mut self) -> Vec<TypedUiEvent<T>> {
+        let mut seen = HashSet::new();
+        let mut latest = self
+            .drain_actions::<T>()
+            .into_iter()
+            .rev()
+            .filter(|event| seen.insert(event.entity))
+            .collect::<Vec<_>>();
+        latest.reverse();
+        latest
+    }
 }
 
 static GLOBAL_UI_EVENT_QUEUE: OnceLock<RwLock<Option<Arc<SegQueue<UiEvent>>>>> = OnceLock::new();
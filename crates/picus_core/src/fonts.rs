@@ -4,15 +4,12 @@ use std::{
     hash::{Hash, Hasher},
     io,
     path::Path,
-    sync::Arc,
 };
 
+use crate::MasonryRuntime;
 use bevy_asset::{AssetEvent, Assets};
 use bevy_ecs::{message::MessageReader, prelude::*, system::NonSendMut};
 use bevy_text::Font;
-use masonry::peniko::Blob;
-
-use crate::MasonryRuntime;
 
 /// Font bridge resource that stores pending font files for registration in Masonry/Parley.
 ///
@@ -101,8 +98,6 @@ pub fn sync_fonts_to_xilem(
     }
 
     for font_bytes in pending {
-        runtime
-            .render_root
-            .register_fonts(Blob::new(Arc::new(font_bytes)));
+        runtime.register_fonts(font_bytes);
     }
 }
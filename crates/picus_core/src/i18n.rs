@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use bevy_ecs::prelude::*;
+pub use fluent::{FluentArgs, FluentValue};
 use fluent::{FluentResource, concurrent::FluentBundle};
 use tracing::{debug, trace};
 use unic_langid::{LanguageIdentifier, langid};
@@ -18,6 +19,12 @@ pub struct AppI18n {
     pub default_font_stack: Vec<String>,
     pub bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
     pub font_stacks: HashMap<LanguageIdentifier, Vec<String>>,
+    /// Whether Fluent wraps placeables in Unicode bidi isolation marks.
+    ///
+    /// Fluent's default (`true`) keeps right-to-left values from reordering the
+    /// surrounding text; apps with left-to-right locales only may opt out with
+    /// [`Self::with_use_isolating`] so formatted strings carry no invisible marks.
+    pub use_isolating: bool,
 }
 
 impl Default for AppI18n {
@@ -27,6 +34,7 @@ impl Default for AppI18n {
             default_font_stack: vec![],
             bundles: HashMap::new(),
             font_stacks: HashMap::new(),
+            use_isolating: true,
         }
     }
 }
@@ -39,6 +47,21 @@ impl AppI18n {
             default_font_stack: vec![],
             bundles: HashMap::new(),
             font_stacks: HashMap::new(),
+            use_isolating: true,
+        }
+    }
+
+    #[must_use]
+    pub fn with_use_isolating(mut self, use_isolating: bool) -> Self {
+        self.set_use_isolating(use_isolating);
+        self
+    }
+
+    /// Apply `use_isolating` to every registered bundle and to bundles inserted later.
+    pub fn set_use_isolating(&mut self, use_isolating: bool) {
+        self.use_isolating = use_isolating;
+        for bundle in self.bundles.values_mut() {
+            bundle.set_use_isolating(use_isolating);
         }
     }
 
@@ -49,9 +72,10 @@ impl AppI18n {
     pub fn insert_bundle(
         &mut self,
         locale: LanguageIdentifier,
-        bundle: FluentBundle<FluentResource>,
+        mut bundle: FluentBundle<FluentResource>,
         font_stack: Vec<String>,
     ) {
+        bundle.set_use_isolating(self.use_isolating);
         if self.default_font_stack.is_empty() && !font_stack.is_empty() {
             self.default_font_stack = font_stack.clone();
         }
@@ -70,14 +94,32 @@ impl AppI18n {
 
     #[must_use]
     pub fn translate(&self, key: &str) -> String {
+        self.format_message(key, None)
+    }
+
+    /// Translate a message that takes Fluent arguments.
+    ///
+    /// Arguments feed placeables (`{ $query }`) and selectors, including plural
+    /// categories resolved with the active locale's rules (`{ $count -> [one] ... }`).
+    /// Falls back to the key when the message is missing, like [`Self::translate`].
+    #[must_use]
+    pub fn translate_args(&self, key: &str, args: &FluentArgs<'_>) -> String {
+        self.format_message(key, Some(args))
+    }
+
+    fn format_message(&self, key: &str, args: Option<&FluentArgs<'_>>) -> String {
         if let Some(bundle) = self.bundles.get(&self.active_locale)
             && let Some(message) = bundle.get_message(key)
             && let Some(pattern) = message.value()
         {
             let mut errors = vec![];
-            return bundle
-                .format_pattern(pattern, None, &mut errors)
+            let formatted = bundle
+                .format_pattern(pattern, args, &mut errors)
                 .into_owned();
+            if !errors.is_empty() {
+                debug!(key, ?errors, "fluent message formatted with errors");
+            }
+            return formatted;
         }
 
         key.to_string()
@@ -120,6 +162,50 @@ pub fn resolve_localized_text(world: &World, entity: Entity, fallback: &str) ->
 mod tests {
     use super::*;
 
+    fn bundle_from(locale: &str, ftl: &str) -> (LanguageIdentifier, FluentBundle<FluentResource>) {
+        let locale: LanguageIdentifier = locale.parse().expect("locale should parse");
+        let resource = FluentResource::try_new(ftl.to_string()).expect("ftl should parse");
+        let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
+        bundle
+            .add_resource(resource)
+            .expect("resource should be added");
+        (locale, bundle)
+    }
+
+    #[test]
+    fn app_i18n_translate_args_selects_plural_category() {
+        let (locale, bundle) = bundle_from(
+            "en-US",
+            "items = { $count ->\n    [one] { $count } item\n   *[other] { $count } items\n}\n",
+        );
+        let mut i18n = AppI18n::new(locale.clone()).with_use_isolating(false);
+        i18n.insert_bundle(locale, bundle, vec![]);
+
+        let mut args = FluentArgs::new();
+        args.set("count", 1);
+        assert_eq!(i18n.translate_args("items", &args), "1 item");
+        args.set("count", 2);
+        assert_eq!(i18n.translate_args("items", &args), "2 items");
+        assert_eq!(i18n.translate_args("missing", &args), "missing");
+    }
+
+    #[test]
+    fn app_i18n_isolates_placeables_unless_opted_out() {
+        let (locale, bundle) = bundle_from("en-US", "greeting = Hello { $name }\n");
+        let mut i18n = AppI18n::new(locale.clone());
+        i18n.insert_bundle(locale, bundle, vec![]);
+
+        let mut args = FluentArgs::new();
+        args.set("name", "Ada");
+        assert_eq!(
+            i18n.translate_args("greeting", &args),
+            "Hello \u{2068}Ada\u{2069}"
+        );
+
+        i18n.set_use_isolating(false);
+        assert_eq!(i18n.translate_args("greeting", &args), "Hello Ada");
+    }
+
     #[test]
     fn app_i18n_translate_falls_back_to_key() {
         let i18n = AppI18n::default();
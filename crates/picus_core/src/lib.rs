@@ -41,7 +41,7 @@
 //! }
 //!
 //! let mut app = App::new();
-//! app.add_plugins(PicusPlugin)
+//! app.add_plugins(PicusPlugin::default())
 //!     .register_ui_component::<Root>()
 //!     .add_systems(Startup, setup)
 //!     .add_systems(PreUpdate, drain);
@@ -56,8 +56,10 @@ pub mod fonts;
 pub mod i18n;
 pub mod icons;
 pub mod overlay;
+pub mod platform;
 pub mod plugin;
 pub mod projection;
+pub mod reconcile;
 pub mod runner;
 pub mod runtime;
 pub mod styling;
@@ -88,8 +90,10 @@ pub use fonts::*;
 pub use i18n::*;
 pub use icons::*;
 pub use overlay::*;
+pub use platform::*;
 pub use plugin::*;
 pub use projection::*;
+pub use reconcile::*;
 pub use runner::*;
 pub use runtime::*;
 pub use styling::*;
@@ -104,43 +108,58 @@ pub mod prelude {
     pub use bevy_ecs::hierarchy::{ChildOf, Children};
 
     pub use crate::{
-        AppI18n, AppPicusExt, AutoDismiss, BevyWindowOptions, BuiltinUiAction, ColorStyle,
-        ComputedStyle, CurrentColorStyle, EcsButtonView, HasTooltip, InlineStyle, InteractionState,
-        LayoutStyle, LocalizeText, MasonryRuntime, OverlayComputedPosition, OverlayConfig,
+        AnimatedCollapse, AppI18n, AppPicusExt, AutoDismiss, Badge, BadgeCorner, BevyWindowOptions,
+        BuiltinUiAction, CloseAllOverlays, ColorStyle, ComputedStyle, CurrentColorStyle,
+        DeferredDespawn, DespawnDeferred, DialogHandle, DialogResult, DialogValue,
+        DividerOrientation, EcsButtonView, EllipsizedText, EmptyState, FloatingPanelHandle,
+        HasTooltip, InlineStyle, InputFilter, InteractionState, LayoutStyle, LineBreakMode,
+        LocaleStyleOverrides, LocalizeText, MasonryRuntime, NthKind, OffscreenMeasure,
+        OffscreenMeasuredSize, Overflow, OverlayComputedPosition, OverlayConfig,
         OverlayMouseButtonCursor, OverlayPlacement, OverlayPointerRoutingState, OverlayStack,
         OverlayState, OverlayUiAction, PicusBuiltinsPlugin, PicusPlugin, ProjectionCtx,
-        PseudoClass, ScrollAxis, Selector, SplitDirection, StopUiPointerPropagation, StyleClass,
-        StyleDirty, StyleRule, StyleSetter, StyleSheet, StyleTransition, SyncAssetSource,
-        SyncTextSource, SynthesizedUiViews, TargetColorStyle, TextStyle, ToastKind, TypedUiEvent,
-        UiAnyView, UiBadge, UiButton, UiCheckbox, UiCheckboxChanged, UiColorPicker,
+        PseudoClass, RecordedAction, RootBackground, ScrollAxis, Selected, Selection,
+        SelectionChanged, SelectionMode, Selector, SplitDirection, StickyHeader,
+        StopUiPointerPropagation, StyleAnimationDefaults, StyleAttributes, StyleBaseFontSize,
+        StyleClass, StyleConfig, StyleDirty, StyleEase, StyleRule, StyleRuleIndex, StyleSetter,
+        StyleSheet, StyleTransition, StyleVariables, SyncAssetSource, SyncTextSource,
+        SynthesisConfig, SynthesisFallbacks, SynthesizedUiViews, TargetColorStyle, Teleport,
+        TextOverflow, TextStyle, ToastKind, TypedUiEvent, UiAnyView, UiBadge, UiButton,
+        UiCellEdited, UiCheckbox, UiCheckboxChanged, UiCollapseHeight, UiColorPicker,
         UiColorPickerChanged, UiColorPickerPanel, UiComboBox, UiComboBoxChanged, UiComboOption,
         UiComponentTemplate, UiDatePicker, UiDatePickerChanged, UiDatePickerPanel, UiDialog,
-        UiDropdownItem, UiDropdownMenu, UiDropdownPlacement, UiEvent, UiEventQueue, UiFlexColumn,
-        UiFlexRow, UiGroupBox, UiInteractionEvent, UiLabel, UiMenuBar, UiMenuBarItem, UiMenuItem,
-        UiMenuItemPanel, UiMenuItemSelected, UiOverlayRoot, UiPointerEvent, UiPointerHitEvent,
-        UiPointerPhase, UiPopover, UiProgressBar, UiProjector, UiProjectorRegistry, UiRadioGroup,
-        UiRadioGroupChanged, UiRoot, UiScrollView, UiScrollViewChanged, UiSlider, UiSliderChanged,
-        UiSpinner, UiSplitPane, UiSwitch, UiSwitchChanged, UiSynthesisStats, UiTabBar,
-        UiTabChanged, UiTable, UiTextInput, UiTextInputChanged, UiThemePicker,
+        UiDivider, UiDropdownItem, UiDropdownMenu, UiDropdownPlacement, UiEvent, UiEventQueue,
+        UiFlexColumn, UiFlexRow, UiFloatingPanel, UiFloatingPanelChanged, UiGroupBox,
+        UiGroupBoxToggled, UiHidden, UiInteractionEvent, UiLabel, UiLoading, UiMenuBar,
+        UiMenuBarItem, UiMenuItem, UiMenuItemPanel, UiMenuItemSelected, UiMountedNodes, UiNodeId,
+        UiNodeIndex, UiNodeMounted, UiNodeUnmounted, UiOrder, UiOverlayRoot, UiPerfOverlay,
+        UiPerfOverlaySettings, UiPointerEvent, UiPointerHitEvent, UiPointerPhase, UiPopover,
+        UiProgressBar, UiProjector, UiProjectorRegistry, UiRadioGroup, UiRadioGroupChanged,
+        UiRemountKey, UiRoot, UiRootOrder, UiScrollView, UiScrollViewChanged, UiSlider,
+        UiSliderChanged, UiSpacer, UiSpinner, UiSpinnerRotation, UiSplitPane, UiStatsHistory,
+        UiSuspense, UiSwitch, UiSwitchChanged, UiSynthesisLimits, UiSynthesisStats, UiTabBar,
+        UiTabChanged, UiTable, UiTableCellEdit, UiTextInput, UiTextInputChanged, UiThemePicker,
         UiThemePickerChanged, UiThemePickerMenu, UiThemePickerOption, UiToast, UiTooltip,
-        UiTreeNode, UiTreeNodeToggled, UiView, WidgetUiAction, XilemFontBridge,
-        bubble_ui_pointer_events, button, button_with_child, checkbox, collect_bevy_font_assets,
-        dismiss_overlays_on_click, ecs_button, ecs_button_with_child, ecs_checkbox, ecs_slider,
-        ecs_switch, ecs_text_button, ecs_text_input, emit_ui_action, ensure_overlay_root,
-        ensure_overlay_root_entity, ensure_template_part, expand_builtin_ui_component_templates,
-        find_template_part, gather_ui_roots, handle_global_overlay_clicks, handle_overlay_actions,
-        handle_tooltip_hovers, handle_widget_actions, inject_bevy_input_into_masonry,
-        mark_style_dirty, rebuild_masonry_runtime, register_builtin_projectors,
+        UiTransform, UiTransformLens, UiTreeNode, UiTreeNodeToggled, UiView, UiViewCache,
+        WidgetUiAction, XilemFontBridge, bubble_ui_pointer_events, button, button_with_child,
+        checkbox, collect_bevy_font_assets, defer_despawn, dismiss_overlays_on_click, ecs_button,
+        ecs_button_with_child, ecs_checkbox, ecs_slider, ecs_switch, ecs_text_button,
+        ecs_text_input, emit_ui_action, ensure_overlay_root, ensure_overlay_root_entity,
+        ensure_template_part, expand_builtin_ui_component_templates, find_template_part,
+        gather_ui_roots, handle_combo_type_ahead, handle_global_overlay_clicks,
+        handle_overlay_actions, handle_tooltip_hovers, handle_widget_actions,
+        inject_bevy_input_into_masonry, mark_style_dirty, measure_offscreen_roots,
+        prewarm_first_frame, rebuild_masonry_runtime, register_builtin_projectors,
         register_builtin_style_type_aliases, register_builtin_ui_components,
         resolve_localized_text, resolve_style, resolve_style_for_classes,
         resolve_style_for_entity_classes, run_app, run_app_with_window_options, slider,
-        spawn_in_overlay_root, spawn_popover_in_overlay_root, sync_dropdown_positions,
-        sync_fonts_to_xilem, sync_overlay_positions, sync_overlay_stack_lifecycle,
-        synthesize_roots, synthesize_roots_with_stats, synthesize_ui, synthesize_world,
-        text_button, text_input, tick_auto_dismiss, tick_toasts, xilem_badge, xilem_badge_count,
-        xilem_badge_text, xilem_button, xilem_button_any_pointer, xilem_checkbox, xilem_image,
-        xilem_progress_bar, xilem_slider, xilem_switch, xilem_text_button, xilem_text_input,
-        xilem_zstack,
+        spawn_dialog_with_result, spawn_in_overlay_root, spawn_popover_in_overlay_root,
+        sync_dropdown_positions, sync_fonts_to_xilem, sync_overlay_positions,
+        sync_overlay_stack_lifecycle, sync_style_rule_index, synthesize_roots,
+        synthesize_roots_with_stats, synthesize_subtree, synthesize_ui, synthesize_world,
+        text_button, text_input, tick_auto_dismiss, tick_toasts, type_ahead_match, xilem_badge,
+        xilem_badge_count, xilem_badge_text, xilem_button, xilem_button_any_pointer,
+        xilem_checkbox, xilem_image, xilem_progress_bar, xilem_slider, xilem_switch,
+        xilem_text_button, xilem_text_input, xilem_zstack,
     };
 
     pub use crate::{
@@ -9,29 +9,33 @@ use bevy_ecs::{
     prelude::*,
 };
 use bevy_input::{
-    ButtonInput,
+    ButtonInput, ButtonState,
+    keyboard::{Key, KeyCode, KeyboardInput},
     mouse::{MouseButton, MouseButtonInput},
 };
 use bevy_math::Vec2;
+use bevy_time::Time;
 use bevy_window::{PrimaryWindow, Window};
 use masonry::core::{Widget, WidgetRef};
 
 use crate::projection::dialog::{
-    dialog_surface_gap, dialog_surface_padding, estimate_dialog_surface_height_px,
-    estimate_dialog_surface_width_px,
+    dialog_extra_content_height, dialog_surface_gap, dialog_surface_padding,
+    estimate_dialog_surface_height_px, estimate_dialog_surface_width_px,
 };
 use crate::{
-    AnchoredTo, AppI18n, AutoDismiss, OverlayAnchorRect, OverlayComputedPosition, OverlayConfig,
-    OverlayPlacement, OverlayStack, OverlayState, StopUiPointerPropagation, UiColorPicker,
-    UiColorPickerChanged, UiColorPickerPanel, UiComboBox, UiComboBoxChanged, UiDatePicker,
-    UiDatePickerChanged, UiDatePickerPanel, UiDialog, UiDropdownItem, UiDropdownMenu, UiEventQueue,
-    UiInteractionEvent, UiMenuBarItem, UiMenuItemPanel, UiMenuItemSelected, UiOverlayRoot,
-    UiPointerEvent, UiPointerHitEvent, UiPopover, UiRoot, UiThemePicker, UiThemePickerChanged,
-    UiThemePickerMenu, UiToast, UiTooltip,
+    AnchoredTo, AppI18n, AutoDismiss, CloseAllOverlays, OverlayAnchorRect, OverlayComputedPosition,
+    OverlayConfig, OverlayPlacement, OverlayStack, OverlayState, StopUiPointerPropagation,
+    Teleport, TeleportOrigin, UiColorPicker, UiColorPickerChanged, UiColorPickerPanel, UiComboBox,
+    UiComboBoxChanged, UiComboOption, UiDatePicker, UiDatePickerChanged, UiDatePickerPanel,
+    UiDialog, UiDropdownItem, UiDropdownMenu, UiEventQueue, UiInteractionEvent, UiMenuBar,
+    UiMenuBarItem, UiMenuItemPanel, UiMenuItemSelected, UiOverlayRoot, UiPointerEvent,
+    UiPointerHitEvent, UiPopover, UiRoot, UiThemePicker, UiThemePickerChanged, UiThemePickerMenu,
+    UiToast, UiTooltip,
     events::UiEvent,
     runtime::MasonryRuntime,
     set_active_style_variant_by_name,
     styling::{resolve_style, resolve_style_for_classes},
+    widgets::parse_entity_debug_text,
 };
 
 const OVERLAY_ANCHOR_GAP: f64 = 4.0;
@@ -42,6 +46,7 @@ const DROPDOWN_ITEM_HOVER_ENTER_DELAY_SECS: f32 = 0.015;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OverlayUiAction {
     DismissDialog,
+    ConfirmDialog,
     ToggleCombo,
     SelectComboItem { dropdown: Entity, index: usize },
     DismissDropdown,
@@ -207,6 +212,46 @@ pub fn spawn_in_overlay_root<B: Bundle>(world: &mut World, bundle: B) -> Entity
     entity
 }
 
+/// Fluent id of the default confirm button label of [`spawn_dialog_with_result`].
+pub const DIALOG_CONFIRM_KEY: &str = "picus-dialog-confirm";
+/// Fluent id of the default cancel button label of [`spawn_dialog_with_result`].
+pub const DIALOG_CANCEL_KEY: &str = "picus-dialog-cancel";
+
+/// Translate `key` for the active locale, or return `english` when there is no message.
+fn translate_or_english(world: &World, key: &str, english: &str) -> String {
+    world
+        .get_resource::<AppI18n>()
+        .map(|i18n| i18n.translate(key))
+        .filter(|text| text != key)
+        .unwrap_or_else(|| english.to_string())
+}
+
+/// Spawn a modal dialog whose confirm/cancel outcome is reported as a typed
+/// [`crate::DialogResult<T>`] through [`UiEventQueue`].
+///
+/// Dialogs without explicit actions get default confirm/cancel buttons, labelled by
+/// the [`DIALOG_CONFIRM_KEY`] and [`DIALOG_CANCEL_KEY`] Fluent messages of the active
+/// locale, or "OK"/"Cancel" when [`AppI18n`] has no such message. The close (X)
+/// button reports [`crate::DialogResult::Cancelled`].
+pub fn spawn_dialog_with_result<T: crate::DialogValue>(
+    world: &mut World,
+    dialog: UiDialog,
+) -> crate::DialogHandle<T> {
+    let dialog = if dialog.has_actions() {
+        dialog
+    } else {
+        dialog.with_actions(
+            translate_or_english(world, DIALOG_CONFIRM_KEY, "OK"),
+            translate_or_english(world, DIALOG_CANCEL_KEY, "Cancel"),
+        )
+    };
+
+    let entity = spawn_in_overlay_root(world, (dialog, crate::UiDialogResultEmitter::new::<T>()));
+    T::prepare(world, entity);
+
+    crate::DialogHandle::new(entity)
+}
+
 fn ensure_popover_overlay_components(world: &mut World, entity: Entity, popover: UiPopover) {
     if world.get::<AnchoredTo>(entity).is_none() {
         world.entity_mut(entity).insert(AnchoredTo(popover.anchor));
@@ -291,7 +336,15 @@ fn despawn_overlay_entity(world: &mut World, entity: Entity) {
     remove_overlay_from_stack(world, entity);
 }
 
-fn dismiss_dialog_overlay(world: &mut World, dialog_entity: Entity) {
+fn dismiss_dialog_overlay(world: &mut World, dialog_entity: Entity, confirmed: bool) {
+    if let Some(emitter) = world
+        .get::<crate::UiDialogResultEmitter>(dialog_entity)
+        .copied()
+    {
+        let event = emitter.emit(world, dialog_entity, confirmed);
+        world.resource::<UiEventQueue>().push(event);
+    }
+
     if let Some(mut close_action) = world.get_mut::<crate::UiDialogCloseAction>(dialog_entity)
         && let Some(event) = close_action.take_event()
     {
@@ -660,6 +713,85 @@ pub fn reparent_overlay_entities(world: &mut World) {
     }
 }
 
+/// Move [`Teleport`] subtrees into the overlay layer and restore them once the
+/// marker is removed.
+///
+/// A teleported subtree still belongs to its original parent: despawning that
+/// parent despawns the subtree too, as it would without the teleport.
+pub fn sync_teleported_entities(world: &mut World) {
+    let orphans = {
+        let mut query = world.query::<(Entity, &TeleportOrigin)>();
+        query
+            .iter(world)
+            .filter(|(_, origin)| {
+                origin
+                    .parent
+                    .is_some_and(|parent| world.get_entity(parent).is_err())
+            })
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>()
+    };
+
+    for entity in orphans {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+
+    let restores = {
+        let mut query = world.query_filtered::<(Entity, &TeleportOrigin), Without<Teleport>>();
+        query
+            .iter(world)
+            .map(|(entity, origin)| (entity, *origin))
+            .collect::<Vec<_>>()
+    };
+
+    for (entity, origin) in restores {
+        world.entity_mut(entity).remove::<TeleportOrigin>();
+
+        match origin.parent {
+            Some(parent) => {
+                let len = world
+                    .get::<Children>(parent)
+                    .map_or(0, |children| children.len());
+                world
+                    .entity_mut(parent)
+                    .insert_children(origin.index.min(len), &[entity]);
+            }
+            None => {
+                world.entity_mut(entity).remove::<ChildOf>();
+            }
+        }
+    }
+
+    let teleports = {
+        let mut query = world.query_filtered::<Entity, (
+            With<Teleport>,
+            Without<TeleportOrigin>,
+            Without<UiOverlayRoot>,
+        )>();
+        query.iter(world).collect::<Vec<_>>()
+    };
+
+    if teleports.is_empty() {
+        return;
+    }
+
+    let overlay_root = ensure_overlay_root_entity(world);
+
+    for entity in teleports {
+        let parent = world.get::<ChildOf>(entity).map(ChildOf::parent);
+        let index = parent
+            .and_then(|parent| world.get::<Children>(parent))
+            .and_then(|children| children.iter().position(|child| child == entity))
+            .unwrap_or(0);
+
+        world
+            .entity_mut(entity)
+            .insert((TeleportOrigin { parent, index }, ChildOf(overlay_root)));
+    }
+}
+
 fn collect_menu_panels_for_item(world: &mut World, anchor: Entity) -> Vec<Entity> {
     let mut query = world.query::<(Entity, &UiMenuItemPanel)>();
     query
@@ -688,6 +820,7 @@ fn close_menu_panel(world: &mut World, panel_entity: Entity) {
     let anchor = world.get::<UiMenuItemPanel>(panel_entity).map(|p| p.anchor);
     close_anchored_overlay::<UiMenuBarItem>(world, panel_entity, anchor, |item| {
         item.is_open = false;
+        item.highlighted = None;
     });
 }
 
@@ -711,7 +844,7 @@ fn close_date_picker_panel(world: &mut World, panel_entity: Entity) {
 
 fn close_overlay_entity(world: &mut World, overlay_entity: Entity) {
     if world.get::<UiDialog>(overlay_entity).is_some() {
-        dismiss_dialog_overlay(world, overlay_entity);
+        dismiss_dialog_overlay(world, overlay_entity, false);
     } else if world.get::<UiDropdownMenu>(overlay_entity).is_some() {
         close_dropdown(world, overlay_entity);
     } else if world.get::<UiThemePickerMenu>(overlay_entity).is_some() {
@@ -727,8 +860,246 @@ fn close_overlay_entity(world: &mut World, overlay_entity: Entity) {
     }
 }
 
+/// PreUpdate keyboard navigation for [`UiMenuBar`] menus.
+///
+/// Alt+letter opens the top-level item whose label mnemonic matches. While a
+/// menu is open, Up/Down move the highlighted item, Left/Right switch to the
+/// neighbouring top-level item, Enter selects and Escape dismisses. Opening and
+/// closing goes through the regular [`OverlayUiAction`] queue.
+pub fn handle_menu_bar_keyboard(
+    mut keyboard_input: MessageReader<KeyboardInput>,
+    mut alt_held: Local<bool>,
+    menu_bars: Query<&Children, With<UiMenuBar>>,
+    mut bar_items: Query<&mut UiMenuBarItem>,
+    panels: Query<(Entity, &UiMenuItemPanel)>,
+    event_queue: Option<Res<UiEventQueue>>,
+) {
+    let Some(event_queue) = event_queue else {
+        keyboard_input.clear();
+        return;
+    };
+
+    let ordered_items = menu_bars
+        .iter()
+        .flat_map(|children| children.iter())
+        .filter(|entity| bar_items.contains(*entity))
+        .collect::<Vec<_>>();
+
+    for event in keyboard_input.read() {
+        if matches!(event.key_code, KeyCode::AltLeft | KeyCode::AltRight) {
+            *alt_held = event.state == ButtonState::Pressed;
+            continue;
+        }
+
+        if event.state != ButtonState::Pressed || ordered_items.is_empty() {
+            continue;
+        }
+
+        let open_item = ordered_items
+            .iter()
+            .copied()
+            .find(|entity| bar_items.get(*entity).is_ok_and(|item| item.is_open));
+
+        if *alt_held {
+            let Key::Character(text) = &event.logical_key else {
+                continue;
+            };
+            let Some(letter) = text.chars().next().and_then(|ch| ch.to_lowercase().next()) else {
+                continue;
+            };
+            let Some(target) = ordered_items.iter().copied().find(|entity| {
+                bar_items
+                    .get(*entity)
+                    .is_ok_and(|item| item.mnemonic() == Some(letter))
+            }) else {
+                continue;
+            };
+
+            if let Some(open_item) = open_item.filter(|open_item| *open_item != target) {
+                event_queue.push_typed(open_item, OverlayUiAction::ToggleMenuBarItem);
+            }
+            if open_item != Some(target) {
+                event_queue.push_typed(target, OverlayUiAction::ToggleMenuBarItem);
+            }
+            if let Ok(mut item) = bar_items.get_mut(target) {
+                item.highlighted = (!item.items.is_empty()).then_some(0);
+            }
+            continue;
+        }
+
+        let Some(open_item) = open_item else {
+            continue;
+        };
+
+        match event.key_code {
+            KeyCode::ArrowDown | KeyCode::ArrowUp => {
+                let Ok(mut item) = bar_items.get_mut(open_item) else {
+                    continue;
+                };
+                let len = item.items.len();
+                if len == 0 {
+                    continue;
+                }
+                let forward = event.key_code == KeyCode::ArrowDown;
+                item.highlighted = Some(match item.highlighted {
+                    Some(index) if forward => (index + 1) % len,
+                    Some(index) => (index + len - 1) % len,
+                    None if forward => 0,
+                    None => len - 1,
+                });
+            }
+            KeyCode::ArrowLeft | KeyCode::ArrowRight => {
+                let Some(position) = ordered_items.iter().position(|entity| *entity == open_item)
+                else {
+                    continue;
+                };
+                let len = ordered_items.len();
+                let next = if event.key_code == KeyCode::ArrowRight {
+                    ordered_items[(position + 1) % len]
+                } else {
+                    ordered_items[(position + len - 1) % len]
+                };
+                if next == open_item {
+                    continue;
+                }
+
+                event_queue.push_typed(open_item, OverlayUiAction::ToggleMenuBarItem);
+                event_queue.push_typed(next, OverlayUiAction::ToggleMenuBarItem);
+                if let Ok(mut item) = bar_items.get_mut(next) {
+                    item.highlighted = (!item.items.is_empty()).then_some(0);
+                }
+            }
+            KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Escape => {
+                let Some(panel) = panels
+                    .iter()
+                    .find_map(|(panel, menu)| (menu.anchor == open_item).then_some(panel))
+                else {
+                    continue;
+                };
+
+                if event.key_code == KeyCode::Escape {
+                    event_queue.push_typed(panel, OverlayUiAction::DismissMenuBarItem);
+                } else if let Some(index) = bar_items
+                    .get(open_item)
+                    .ok()
+                    .and_then(|item| item.highlighted)
+                {
+                    event_queue.push_typed(panel, OverlayUiAction::SelectMenuBarItem { index });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Keyboard idle time after which combo type-ahead starts a new prefix.
+pub const TYPE_AHEAD_RESET_SECS: f64 = 1.0;
+
+/// Index of the first option whose label starts with `prefix`, ignoring case.
+///
+/// Options whose label does not match are tried by value, so natively labelled
+/// entries (e.g. `日本語` with value `ja-JP`) stay reachable from a Latin keyboard.
+#[must_use]
+pub fn type_ahead_match(options: &[UiComboOption], prefix: &str) -> Option<usize> {
+    let prefix = prefix.to_lowercase();
+    if prefix.is_empty() {
+        return None;
+    }
+
+    options
+        .iter()
+        .position(|option| option.label.to_lowercase().starts_with(&prefix))
+        .or_else(|| {
+            options
+                .iter()
+                .position(|option| option.value.to_lowercase().starts_with(&prefix))
+        })
+}
+
+/// Type-ahead prefix of the open combo, reset after [`TYPE_AHEAD_RESET_SECS`].
+#[derive(Debug, Default)]
+pub struct ComboTypeAheadBuffer {
+    combo: Option<Entity>,
+    prefix: String,
+    last_input_secs: f64,
+}
+
+/// PreUpdate type-ahead for open [`UiComboBox`] dropdowns.
+///
+/// Typed characters extend a prefix (reset after [`TYPE_AHEAD_RESET_SECS`] idle or
+/// when another combo opens), and the first option matching it via
+/// [`type_ahead_match`] is selected and reported as [`UiComboBoxChanged`]. The
+/// dropdown stays open so arrow keys and further typing continue from there.
+pub fn handle_combo_type_ahead(
+    mut keyboard_input: MessageReader<KeyboardInput>,
+    mut buffer: Local<ComboTypeAheadBuffer>,
+    time: Option<Res<Time>>,
+    mut combos: Query<(Entity, &mut UiComboBox)>,
+    event_queue: Option<Res<UiEventQueue>>,
+) {
+    let Some(event_queue) = event_queue else {
+        keyboard_input.clear();
+        return;
+    };
+    let now = time.map_or(0.0, |time| time.elapsed_secs_f64());
+
+    for event in keyboard_input.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let Key::Character(text) = &event.logical_key else {
+            continue;
+        };
+        let Some((combo, mut combo_box)) = combos.iter_mut().find(|(_, combo)| combo.is_open)
+        else {
+            continue;
+        };
+
+        if buffer.combo != Some(combo) || now - buffer.last_input_secs > TYPE_AHEAD_RESET_SECS {
+            buffer.combo = Some(combo);
+            buffer.prefix.clear();
+        }
+        buffer.prefix.push_str(text);
+        buffer.last_input_secs = now;
+
+        let Some(selected) = type_ahead_match(&combo_box.options, &buffer.prefix) else {
+            continue;
+        };
+        if combo_box.selected == selected {
+            continue;
+        }
+        combo_box.selected = selected;
+        event_queue.push_typed(
+            combo,
+            UiComboBoxChanged {
+                combo,
+                selected,
+                value: combo_box.options[selected].value.clone(),
+            },
+        );
+    }
+}
+
+/// Dismiss every tracked overlay, top-most first.
+fn close_all_overlays(world: &mut World) {
+    sync_overlay_stack_lifecycle(world);
+    let overlays = world.resource::<OverlayStack>().active_overlays.clone();
+    for overlay in overlays.into_iter().rev() {
+        if world.get_entity(overlay).is_ok() {
+            close_overlay_entity(world, overlay);
+        }
+    }
+}
+
 /// Consume built-in overlay actions and mutate ECS overlay state.
 pub fn handle_overlay_actions(world: &mut World) {
+    let close_all = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<CloseAllOverlays>();
+    if !close_all.is_empty() {
+        close_all_overlays(world);
+    }
+
     let actions = world
         .resource_mut::<UiEventQueue>()
         .drain_actions::<OverlayUiAction>();
@@ -741,7 +1112,12 @@ pub fn handle_overlay_actions(world: &mut World) {
         match event.action {
             OverlayUiAction::DismissDialog => {
                 if world.get::<UiDialog>(event.entity).is_some() {
-                    dismiss_dialog_overlay(world, event.entity);
+                    dismiss_dialog_overlay(world, event.entity, false);
+                }
+            }
+            OverlayUiAction::ConfirmDialog => {
+                if world.get::<UiDialog>(event.entity).is_some() {
+                    dismiss_dialog_overlay(world, event.entity, true);
                 }
             }
             OverlayUiAction::ToggleCombo => {
@@ -1162,21 +1538,15 @@ fn parse_entity_from_ecs_button(widget: WidgetRef<'_, dyn Widget>) -> Option<Ent
     }
 
     let debug = widget.get_debug_text()?;
-    let bits = debug.strip_prefix("entity=")?.parse::<u64>().ok()?;
+    let (kind, bits) = parse_entity_debug_text(&debug)?;
+    if !kind.is_empty() {
+        return None;
+    }
     Entity::try_from_bits(bits)
 }
 
 fn parse_entity_bits_from_debug(debug: &str) -> Option<u64> {
-    if let Some(bits) = debug.strip_prefix("opaque_hitbox_entity=") {
-        return bits.parse::<u64>().ok();
-    }
-    if let Some(bits) = debug.strip_prefix("entity_scope=") {
-        return bits.parse::<u64>().ok();
-    }
-    if let Some(bits) = debug.strip_prefix("entity=") {
-        return bits.parse::<u64>().ok();
-    }
-    None
+    parse_entity_debug_text(debug).map(|(_, bits)| bits)
 }
 
 fn collect_entity_hit_boxes(widget: WidgetRef<'_, dyn Widget>, out: &mut Vec<EntityHitBox>) {
@@ -1314,6 +1684,13 @@ fn overlay_size_for_entity(
             dialog_surface_padding(dialog_style.layout.padding),
         );
 
+        let estimated_height = estimated_height
+            + dialog_extra_content_height(
+                world,
+                entity,
+                dialog,
+                dialog_surface_gap(dialog_style.layout.gap),
+            );
         let height = dialog.height.unwrap_or(estimated_height);
 
         return (width, height);
@@ -1556,6 +1933,22 @@ fn clamp_overlay_origin(
     (x.clamp(0.0, max_x), y.clamp(0.0, max_y))
 }
 
+/// Placement inputs used for an overlay's last computed position.
+///
+/// [`sync_overlay_positions`] skips overlays whose inputs are unchanged, so idle
+/// overlays do not rewrite [`OverlayComputedPosition`] every frame.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+struct OverlayPositionInputs {
+    placement: OverlayPlacement,
+    auto_flip: bool,
+    anchor_rect: OverlayAnchorRect,
+    anchor_gap: f64,
+    width: f64,
+    height: f64,
+    viewport_width: f64,
+    viewport_height: f64,
+}
+
 /// Universal placement + collision-detection system for overlay entities.
 ///
 /// Runs after layout/input updates and computes final window-space coordinates that
@@ -1659,6 +2052,23 @@ pub fn sync_overlay_positions(world: &mut World) {
             )
         };
 
+        let inputs = OverlayPositionInputs {
+            placement: preferred_placement,
+            auto_flip,
+            anchor_rect,
+            anchor_gap,
+            width,
+            height,
+            viewport_width,
+            viewport_height,
+        };
+        let is_positioned = world
+            .get::<OverlayComputedPosition>(entity)
+            .is_some_and(|computed| computed.is_positioned);
+        if is_positioned && world.get::<OverlayPositionInputs>(entity) == Some(&inputs) {
+            continue;
+        }
+
         let mut chosen_placement = preferred_placement;
         let mut _did_flip = false;
         let (mut x, mut y) = overlay_origin_for_placement(
@@ -1736,6 +2146,8 @@ pub fn sync_overlay_positions(world: &mut World) {
                 world.entity_mut(entity).insert(anchor_rect);
             }
         }
+
+        world.entity_mut(entity).insert(inputs);
     }
 
     for stale in stale_overlays {
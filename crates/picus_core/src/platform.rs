@@ -0,0 +1,249 @@
+//! Desktop platform services exposed as ECS actions.
+//!
+//! Controls push these actions through [`UiEventQueue`] like any other UI action;
+//! built-in systems perform the platform call and report the outcome back through
+//! the queue, so apps never touch the platform APIs directly.
+
+use std::process::Command;
+
+use bevy_ecs::prelude::*;
+
+use crate::events::UiEventQueue;
+
+/// Writes text to the system clipboard.
+///
+/// Installed as the [`UiClipboard`] resource; tests and headless apps swap in their own.
+pub trait ClipboardAccess: Send + Sync + 'static {
+    fn set_text(&mut self, text: &str) -> Result<(), String>;
+}
+
+/// [`ClipboardAccess`] backed by the OS clipboard via `arboard`.
+///
+/// The `arboard::Clipboard` is created on first use and kept alive afterwards. On
+/// X11 and Wayland the copying process owns the selection, so dropping the handle
+/// right after `set_text` would take the copied text with it.
+#[derive(Default)]
+pub struct SystemClipboard {
+    // `Mutex` only to make the handle `Sync`; access goes through `&mut self`.
+    clipboard: std::sync::Mutex<Option<arboard::Clipboard>>,
+}
+
+impl ClipboardAccess for SystemClipboard {
+    fn set_text(&mut self, text: &str) -> Result<(), String> {
+        let slot = self
+            .clipboard
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if slot.is_none() {
+            *slot = Some(arboard::Clipboard::new().map_err(|error| error.to_string())?);
+        }
+        let result = match slot.as_mut() {
+            Some(clipboard) => clipboard.set_text(text),
+            None => return Err("clipboard unavailable".to_string()),
+        };
+        if result.is_err() {
+            // Reconnect on the next copy in case the handle went stale.
+            *slot = None;
+        }
+        result.map_err(|error| error.to_string())
+    }
+}
+
+/// Clipboard backend used by [`handle_clipboard_actions`].
+#[derive(Resource)]
+pub struct UiClipboard(pub Box<dyn ClipboardAccess>);
+
+impl UiClipboard {
+    #[must_use]
+    pub fn new(access: impl ClipboardAccess) -> Self {
+        Self(Box::new(access))
+    }
+}
+
+impl Default for UiClipboard {
+    fn default() -> Self {
+        Self::new(SystemClipboard::default())
+    }
+}
+
+/// Action: copy the text to the clipboard.
+///
+/// Push it with [`UiEventQueue::push_typed`] (or `emit_ui_action` from a widget); the
+/// outcome arrives as [`ClipboardCopied`] or [`ClipboardCopyFailed`] for the same entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyToClipboard(pub String);
+
+/// Emitted after a [`CopyToClipboard`] action succeeded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardCopied {
+    pub text: String,
+}
+
+/// Emitted when a [`CopyToClipboard`] action could not reach the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardCopyFailed {
+    pub error: String,
+}
+
+/// Perform queued [`CopyToClipboard`] actions through [`UiClipboard`].
+pub fn handle_clipboard_actions(world: &mut World) {
+    let Some(actions) = world
+        .get_resource_mut::<UiEventQueue>()
+        .map(|mut queue| queue.drain_actions::<CopyToClipboard>())
+    else {
+        return;
+    };
+    if actions.is_empty() {
+        return;
+    }
+
+    world.init_resource::<UiClipboard>();
+    world.resource_scope(|world, mut clipboard: Mut<UiClipboard>| {
+        let queue = world.resource::<UiEventQueue>();
+        for event in actions {
+            let CopyToClipboard(text) = event.action;
+            match clipboard.0.set_text(&text) {
+                Ok(()) => queue.push_typed(event.entity, ClipboardCopied { text }),
+                Err(error) => {
+                    tracing::warn!(%error, "clipboard copy failed");
+                    queue.push_typed(event.entity, ClipboardCopyFailed { error });
+                }
+            }
+        }
+    });
+}
+
+/// Opens URLs in the user's browser.
+///
+/// Installed as the [`UiUrlOpener`] resource; tests swap in a recording mock.
+pub trait UrlOpener: Send + Sync + 'static {
+    fn open(&mut self, url: &str) -> Result<(), String>;
+}
+
+/// [`UrlOpener`] using `webbrowser`, falling back to the platform launcher
+/// (`open`, `xdg-open`, or `rundll32 url.dll,FileProtocolHandler`).
+///
+/// Only `http`, `https` and `mailto` URLs are opened. The URL always reaches the
+/// launcher as a single argument and never passes through a shell, so characters like
+/// `&` or `|` in URLs from user or network data cannot run commands.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemUrlOpener;
+
+impl UrlOpener for SystemUrlOpener {
+    fn open(&mut self, url: &str) -> Result<(), String> {
+        check_openable_url(url)?;
+        if webbrowser::open(url).is_ok() {
+            return Ok(());
+        }
+
+        let (program, args) = browser_launcher(url)?;
+        let status = Command::new(program)
+            .args(&args)
+            .status()
+            .map_err(|error| format!("failed to run `{program}`: {error}"))?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("`{program}` exited with status {status}"))
+        }
+    }
+}
+
+/// Schemes [`SystemUrlOpener`] hands to the system.
+const OPENABLE_URL_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+/// Fails for URLs whose scheme is not in [`OPENABLE_URL_SCHEMES`].
+fn check_openable_url(url: &str) -> Result<(), String> {
+    let scheme = url
+        .split_once(':')
+        .map(|(scheme, _)| scheme.to_ascii_lowercase())
+        .unwrap_or_default();
+    if !OPENABLE_URL_SCHEMES.contains(&scheme.as_str()) {
+        return Err(format!(
+            "refusing to open `{url}`: only http, https and mailto URLs are allowed"
+        ));
+    }
+    Ok(())
+}
+
+/// Fallback launcher program and arguments for `url` on this platform.
+pub(crate) fn browser_launcher(url: &str) -> Result<(&'static str, Vec<String>), String> {
+    check_openable_url(url)?;
+    let url = url.to_string();
+    if cfg!(target_os = "macos") {
+        Ok(("open", vec![url]))
+    } else if cfg!(target_os = "windows") {
+        Ok((
+            "rundll32",
+            vec!["url.dll,FileProtocolHandler".to_string(), url],
+        ))
+    } else if cfg!(target_os = "linux") {
+        Ok(("xdg-open", vec![url]))
+    } else {
+        Err("no browser launcher available on this platform".to_string())
+    }
+}
+
+/// URL opener used by [`handle_open_url_actions`].
+#[derive(Resource)]
+pub struct UiUrlOpener(pub Box<dyn UrlOpener>);
+
+impl UiUrlOpener {
+    #[must_use]
+    pub fn new(opener: impl UrlOpener) -> Self {
+        Self(Box::new(opener))
+    }
+}
+
+impl Default for UiUrlOpener {
+    fn default() -> Self {
+        Self::new(SystemUrlOpener)
+    }
+}
+
+/// Action: open the URL in the system browser.
+///
+/// The outcome arrives as [`UrlOpened`] or [`OpenUrlFailed`] for the same entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenUrl(pub String);
+
+/// Emitted after an [`OpenUrl`] action launched the browser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlOpened {
+    pub url: String,
+}
+
+/// Emitted when an [`OpenUrl`] action could not launch a browser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenUrlFailed {
+    pub url: String,
+    pub error: String,
+}
+
+/// Perform queued [`OpenUrl`] actions through [`UiUrlOpener`].
+pub fn handle_open_url_actions(world: &mut World) {
+    let Some(actions) = world
+        .get_resource_mut::<UiEventQueue>()
+        .map(|mut queue| queue.drain_actions::<OpenUrl>())
+    else {
+        return;
+    };
+    if actions.is_empty() {
+        return;
+    }
+
+    world.init_resource::<UiUrlOpener>();
+    world.resource_scope(|world, mut opener: Mut<UiUrlOpener>| {
+        let queue = world.resource::<UiEventQueue>();
+        for event in actions {
+            let OpenUrl(url) = event.action;
+            match opener.0.open(&url) {
+                Ok(()) => queue.push_typed(event.entity, UrlOpened { url }),
+                Err(error) => {
+                    tracing::warn!(%url, %error, "failed to open URL");
+                    queue.push_typed(event.entity, OpenUrlFailed { url, error });
+                }
+            }
+        }
+    });
+}
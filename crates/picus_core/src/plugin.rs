@@ -14,42 +14,65 @@ use bevy_window::{
 };
 
 use crate::{
-    AppPicusExt, OverlayStack,
+    AppPicusExt, OverlayStack, UiPerfOverlaySettings,
     components::register_builtin_ui_components,
     events::UiEventQueue,
     fonts::{XilemFontBridge, collect_bevy_font_assets, sync_fonts_to_xilem},
     i18n::AppI18n,
     overlay::{
         OverlayPointerRoutingState, bubble_ui_pointer_events, ensure_overlay_defaults,
-        ensure_overlay_root, handle_global_overlay_clicks, handle_overlay_actions,
-        reparent_overlay_entities, sync_overlay_positions, sync_overlay_stack_lifecycle,
+        ensure_overlay_root, handle_combo_type_ahead, handle_global_overlay_clicks,
+        handle_menu_bar_keyboard, handle_overlay_actions, reparent_overlay_entities,
+        sync_overlay_positions, sync_overlay_stack_lifecycle, sync_teleported_entities,
     },
+    platform::{handle_clipboard_actions, handle_open_url_actions},
     projection::{UiProjectorRegistry, register_core_projectors},
     runtime::{
-        MasonryRuntime, initialize_masonry_runtime_from_primary_window,
-        inject_bevy_input_into_masonry, paint_masonry_ui, rebuild_masonry_runtime,
+        MasonryRuntime, ellipsize_overflowing_labels,
+        initialize_masonry_runtime_from_primary_window, inject_bevy_input_into_masonry,
+        measure_offscreen_roots, paint_masonry_ui, rebuild_masonry_runtime,
         sync_masonry_ime_state_to_bevy_window,
     },
     styling::{
         ActiveStyleSheet, ActiveStyleSheetAsset, ActiveStyleSheetSelectors,
         ActiveStyleSheetTokenNames, ActiveStyleVariant, AppliedStyleVariant, BaseStyleSheet,
-        RegisteredStyleVariants, StyleAssetEventCursor, StyleSheet, StyleSheetRonLoader,
-        activate_debounced_hovers, animate_style_transitions,
-        ensure_active_stylesheet_asset_handle, mark_style_dirty,
+        LocaleStyleOverrides, RegisteredStyleVariants, StyleAnimationDefaults,
+        StyleAssetEventCursor, StyleBaseFontSize, StyleConfig, StyleSheet, StyleSheetRonLoader,
+        StyleVariables, StyleVariablesSnapshot, activate_debounced_hovers,
+        animate_style_transitions, ensure_active_stylesheet_asset_handle, mark_style_dirty,
         register_builtin_style_type_aliases, register_embedded_fluent_theme_variants,
         set_active_style_variant_to_registered_default, sync_active_style_variant,
-        sync_style_targets, sync_stylesheet_asset_events, sync_ui_interaction_markers,
+        sync_style_rule_index, sync_style_targets, sync_style_variables,
+        sync_stylesheet_asset_events, sync_ui_interaction_markers,
+    },
+    synthesize::{
+        DeferredDespawn, ResynthesisRequest, SynthesisConfig, SynthesisFallbacks,
+        SynthesizedUiViews, UiMountedNodes, UiNodeIndex, UiSynthesisLimits, UiSynthesisStats,
+        apply_deferred_despawns, record_ui_stats_history, sync_ui_node_index, synthesize_ui,
     },
-    synthesize::{SynthesizedUiViews, UiSynthesisStats, synthesize_ui},
     widget_actions::{
-        handle_scroll_view_wheel, handle_tooltip_hovers, handle_widget_actions,
-        sync_scroll_view_layout_geometry, tick_auto_dismiss,
+        animate_group_box_collapse, handle_scroll_view_wheel, handle_tooltip_hovers,
+        handle_widget_actions, measure_sticky_headers, sync_scroll_view_layout_geometry,
+        sync_virtual_grid_layouts, tick_auto_dismiss, update_sticky_header_offsets,
     },
 };
 
 /// Bevy plugin for headless Masonry runtime + ECS projection synthesis.
 #[derive(Default)]
-pub struct PicusPlugin;
+pub struct PicusPlugin {
+    view_cache: Option<bool>,
+}
+
+impl PicusPlugin {
+    /// Set [`SynthesisConfig::view_cache`] when the plugin is added.
+    ///
+    /// Left unset, the plugin keeps whatever `SynthesisConfig` the app already has.
+    #[must_use]
+    pub fn with_view_cache(mut self, view_cache: bool) -> Self {
+        self.view_cache = Some(view_cache);
+        self
+    }
+}
 
 /// Registers all built-in ECS UI components.
 ///
@@ -75,20 +98,42 @@ impl Plugin for PicusPlugin {
         if !app.is_plugin_added::<TweenCorePlugin<()>>() {
             app.add_plugins(DefaultTweenPlugins::<()>::in_schedule(Update));
         }
+        if let Some(view_cache) = self.view_cache {
+            app.world_mut()
+                .get_resource_or_init::<SynthesisConfig>()
+                .view_cache = view_cache;
+        }
 
         app.add_plugins((TimePlugin, PicusBuiltinsPlugin))
             .add_tween_systems(
                 Update,
-                component_tween_system::<crate::styling::ColorStyleLens>(),
+                (
+                    component_tween_system::<crate::styling::ColorStyleLens>(),
+                    component_tween_system::<crate::ecs::UiTransformLens>(),
+                    component_tween_system::<crate::ecs::UiCollapseHeightLens>(),
+                    component_tween_system::<crate::ecs::UiSpinnerRotationLens>(),
+                ),
             )
             .register_xilem_font_bytes(crate::icons::LUCIDE_FONT_BYTES)
             .init_asset::<StyleSheet>()
+            .init_resource::<StyleVariables>()
+            .init_resource::<StyleVariablesSnapshot>()
             .init_asset_loader::<StyleSheetRonLoader>()
             .init_resource::<UiProjectorRegistry>()
             .init_resource::<SynthesizedUiViews>()
             .init_resource::<UiSynthesisStats>()
+            .init_resource::<SynthesisFallbacks>()
+            .init_resource::<SynthesisConfig>()
+            .init_resource::<UiSynthesisLimits>()
+            .init_resource::<ResynthesisRequest>()
+            .init_resource::<UiMountedNodes>()
+            .init_resource::<DeferredDespawn>()
+            .init_resource::<UiNodeIndex>()
             .init_resource::<UiEventQueue>()
             .init_resource::<StyleSheet>()
+            .init_resource::<StyleBaseFontSize>()
+            .init_resource::<StyleAnimationDefaults>()
+            .init_resource::<StyleConfig>()
             .init_resource::<BaseStyleSheet>()
             .init_resource::<ActiveStyleSheet>()
             .init_resource::<ActiveStyleSheetAsset>()
@@ -97,10 +142,12 @@ impl Plugin for PicusPlugin {
             .init_resource::<ActiveStyleVariant>()
             .init_resource::<AppliedStyleVariant>()
             .init_resource::<RegisteredStyleVariants>()
+            .init_resource::<LocaleStyleOverrides>()
             .init_resource::<StyleAssetEventCursor>()
             .init_resource::<XilemFontBridge>()
             .init_resource::<AppI18n>()
             .init_resource::<OverlayStack>()
+            .init_resource::<UiPerfOverlaySettings>()
             .init_resource::<OverlayPointerRoutingState>()
             .init_non_send_resource::<MasonryRuntime>()
             .add_message::<CursorMoved>()
@@ -123,10 +170,15 @@ impl Plugin for PicusPlugin {
                     handle_global_overlay_clicks,
                     sync_scroll_view_layout_geometry,
                     handle_scroll_view_wheel,
+                    handle_menu_bar_keyboard,
+                    handle_combo_type_ahead,
                     inject_bevy_input_into_masonry,
                     sync_masonry_ime_state_to_bevy_window,
                     handle_widget_actions,
                     sync_ui_interaction_markers,
+                    measure_sticky_headers,
+                    update_sticky_header_offsets,
+                    sync_virtual_grid_layouts,
                 )
                     .chain(),
             )
@@ -135,18 +187,28 @@ impl Plugin for PicusPlugin {
                 (
                     ensure_overlay_root,
                     reparent_overlay_entities,
+                    sync_teleported_entities,
                     ensure_overlay_defaults,
                     handle_overlay_actions,
                     handle_widget_actions,
+                    handle_clipboard_actions,
+                    handle_open_url_actions,
                     activate_debounced_hovers,
                     handle_tooltip_hovers,
                     tick_auto_dismiss,
+                    sync_spinner_rotation_tweens,
+                    animate_group_box_collapse,
                     sync_overlay_stack_lifecycle,
-                    ensure_active_stylesheet_asset_handle,
-                    sync_stylesheet_asset_events,
-                    sync_active_style_variant,
-                    mark_style_dirty,
-                    sync_style_targets,
+                    (
+                        sync_style_variables,
+                        ensure_active_stylesheet_asset_handle,
+                        sync_stylesheet_asset_events,
+                        sync_active_style_variant,
+                        sync_style_rule_index,
+                        mark_style_dirty,
+                        sync_style_targets,
+                    )
+                        .chain(),
                 )
                     .chain()
                     .before(TweenSystemSet::UpdateInterpolationValue),
@@ -158,7 +220,9 @@ impl Plugin for PicusPlugin {
             .add_systems(
                 PostUpdate,
                 (
+                    sync_ui_node_index,
                     synthesize_ui,
+                    record_ui_stats_history,
                     rebuild_masonry_runtime,
                     sync_masonry_ime_state_to_bevy_window,
                 )
@@ -169,7 +233,19 @@ impl Plugin for PicusPlugin {
         // so anchor/widget geometry is up-to-date for this frame.
         app.add_systems(
             PostUpdate,
-            sync_overlay_positions.after(rebuild_masonry_runtime),
+            (
+                sync_overlay_positions,
+                ellipsize_overflowing_labels,
+                measure_offscreen_roots,
+            )
+                .after(rebuild_masonry_runtime),
+        );
+        app.add_systems(
+            PostUpdate,
+            apply_deferred_despawns
+                .after(sync_overlay_positions)
+                .after(ellipsize_overflowing_labels)
+                .after(measure_offscreen_roots),
         );
 
         app.add_systems(Last, paint_masonry_ui);
@@ -10,13 +10,15 @@ pub mod utils;
 pub mod widgets;
 
 pub use core::*;
+pub use layout::empty_state_view;
 
 use crate::ecs::{
     UiBadge, UiButton, UiCheckbox, UiColorPicker, UiColorPickerPanel, UiComboBox, UiDatePicker,
-    UiDatePickerPanel, UiDialog, UiDropdownMenu, UiFlexColumn, UiFlexRow, UiGroupBox, UiLabel,
-    UiMenuBar, UiMenuBarItem, UiMenuItemPanel, UiOverlayRoot, UiPopover, UiProgressBar,
-    UiRadioGroup, UiRoot, UiScrollView, UiSlider, UiSpinner, UiSplitPane, UiSwitch, UiTabBar,
-    UiTable, UiTextInput, UiThemePicker, UiThemePickerMenu, UiToast, UiTooltip, UiTreeNode,
+    UiDatePickerPanel, UiDialog, UiDivider, UiDropdownMenu, UiFlexColumn, UiFlexRow,
+    UiFloatingPanel, UiGroupBox, UiLabel, UiMenuBar, UiMenuBarItem, UiMenuItemPanel, UiOverlayRoot,
+    UiPerfOverlay, UiPopover, UiProgressBar, UiRadioGroup, UiRoot, UiScrollView, UiSlider,
+    UiSpacer, UiSpinner, UiSplitPane, UiSuspense, UiSwitch, UiTabBar, UiTable, UiTextInput,
+    UiThemePicker, UiThemePickerMenu, UiToast, UiTooltip, UiTreeNode, UiVirtualGrid,
 };
 
 /// Register non-UI-component foundational projectors.
@@ -66,5 +68,11 @@ pub fn register_builtin_projectors(registry: &mut UiProjectorRegistry) {
         .register_component::<UiDatePicker>(widgets::project_date_picker)
         .register_component::<UiDatePickerPanel>(widgets::project_date_picker_panel)
         .register_component::<UiThemePicker>(theme_picker::project_theme_picker)
-        .register_component::<UiThemePickerMenu>(theme_picker::project_theme_picker_menu);
+        .register_component::<UiThemePickerMenu>(theme_picker::project_theme_picker_menu)
+        .register_component::<UiVirtualGrid>(widgets::project_virtual_grid)
+        .register_component::<UiPerfOverlay>(widgets::project_perf_overlay)
+        .register_component::<UiSpacer>(layout::project_spacer)
+        .register_component::<UiDivider>(layout::project_divider)
+        .register_component::<UiSuspense>(layout::project_suspense)
+        .register_component::<UiFloatingPanel>(widgets::project_floating_panel);
 }
@@ -1,7 +1,9 @@
 use bevy_ecs::prelude::*;
-use std::{fmt, marker::PhantomData, sync::Arc};
+use std::{any::type_name, fmt, marker::PhantomData, sync::Arc};
 use xilem_masonry::AnyWidgetView;
 
+use crate::synthesize::{ResynthesisRequest, ordered_children};
+
 /// Xilem state used by synthesized UI views.
 pub type UiXilemState = ();
 /// Xilem action type used by synthesized UI views.
@@ -26,6 +28,112 @@ pub struct ProjectionCtx<'a> {
     pub children: Vec<UiView>,
 }
 
+impl<'a> ProjectionCtx<'a> {
+    /// Ask [`synthesize_ui`](crate::synthesize_ui) for another pass once this one ends,
+    /// e.g. to place content using state measured during this pass.
+    ///
+    /// Extra passes run in the same frame, capped by
+    /// [`SynthesisConfig::max_resynthesis_passes`](crate::SynthesisConfig::max_resynthesis_passes).
+    pub fn request_resynthesis(&self) {
+        if let Some(request) = self.world.get_resource::<ResynthesisRequest>() {
+            request.request();
+        }
+    }
+
+    /// Resource `R`, if inserted.
+    #[must_use]
+    pub fn get_resource<R: Resource>(&self) -> Option<&'a R> {
+        self.world.get_resource::<R>()
+    }
+
+    /// Another component `P` on the entity being projected, e.g. a [`TextStyle`](crate::TextStyle)
+    /// next to the component the projector is bound to.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use picus_core::{
+    ///     ProjectionCtx, TextStyle, UiProjectorRegistry, UiRoot, UiView,
+    ///     bevy_ecs::{component::Component, world::World},
+    ///     synthesize_roots_with_stats,
+    ///     xilem_masonry::view::label,
+    /// };
+    ///
+    /// #[derive(Component)]
+    /// struct Caption(&'static str);
+    ///
+    /// fn project_caption(caption: &Caption, ctx: ProjectionCtx<'_>) -> UiView {
+    ///     let size = ctx.props::<TextStyle>().and_then(|style| style.size);
+    ///     assert_eq!(size, Some(20.0));
+    ///     Arc::new(label(caption.0))
+    /// }
+    ///
+    /// let mut registry = UiProjectorRegistry::default();
+    /// registry.register_component::<Caption>(project_caption);
+    ///
+    /// let mut world = World::new();
+    /// let root = world
+    ///     .spawn((
+    ///         UiRoot,
+    ///         Caption("Title"),
+    ///         TextStyle {
+    ///             size: Some(20.0),
+    ///             ..TextStyle::default()
+    ///         },
+    ///     ))
+    ///     .id();
+    ///
+    /// let (_views, stats) = synthesize_roots_with_stats(&world, &registry, [root]);
+    /// assert_eq!(stats.unhandled_count, 0);
+    /// ```
+    #[must_use]
+    pub fn props<P: Component>(&self) -> Option<&'a P> {
+        self.world.get::<P>(self.entity)
+    }
+
+    /// `C` on the entity's direct parent.
+    #[must_use]
+    pub fn parent_component<C: Component>(&self) -> Option<&'a C> {
+        let parent = self.world.get::<ChildOf>(self.entity)?.parent();
+        self.world.get::<C>(parent)
+    }
+
+    /// `C` on the nearest ancestor that has it, starting at the parent.
+    #[must_use]
+    pub fn ancestor_component<C: Component>(&self) -> Option<&'a C> {
+        let mut visited = vec![self.entity];
+        let mut current = self.entity;
+        while let Some(child_of) = self.world.get::<ChildOf>(current) {
+            current = child_of.parent();
+            if visited.contains(&current) {
+                return None;
+            }
+            if let Some(component) = self.world.get::<C>(current) {
+                return Some(component);
+            }
+            visited.push(current);
+        }
+        None
+    }
+
+    /// Siblings carrying `C`, excluding the entity itself, in synthesis order.
+    #[must_use]
+    pub fn sibling_components<C: Component>(&self) -> Vec<(Entity, &'a C)> {
+        let Some(child_of) = self.world.get::<ChildOf>(self.entity) else {
+            return Vec::new();
+        };
+        ordered_children(self.world, child_of.parent())
+            .into_iter()
+            .filter(|sibling| *sibling != self.entity)
+            .filter_map(|sibling| {
+                self.world
+                    .get::<C>(sibling)
+                    .map(|component| (sibling, component))
+            })
+            .collect()
+    }
+}
+
 impl fmt::Debug for ProjectionCtx<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ProjectionCtx")
@@ -39,6 +147,11 @@ impl fmt::Debug for ProjectionCtx<'_> {
 /// Maps ECS entity data into a concrete Xilem Masonry view.
 pub trait UiProjector: Send + Sync + 'static {
     fn project(&self, ctx: ProjectionCtx<'_>) -> Option<UiView>;
+
+    /// Component type this projector is bound to, for registry introspection.
+    fn component_type_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 struct ComponentProjector<C: Component> {
@@ -51,6 +164,10 @@ impl<C: Component> UiProjector for ComponentProjector<C> {
         let component = ctx.world.get::<C>(ctx.entity)?;
         Some((self.projector)(component, ctx))
     }
+
+    fn component_type_name(&self) -> Option<&'static str> {
+        Some(type_name::<C>())
+    }
 }
 
 /// Registry of projector implementations.
@@ -77,6 +194,28 @@ impl UiProjectorRegistry {
         })
     }
 
+    /// Number of registered projectors, including raw and overridden ones.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.projectors.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.projectors.is_empty()
+    }
+
+    /// Component type names of component-bound projectors, in registration order.
+    ///
+    /// Re-registering a type lists it again; the later entry is the one that wins.
+    #[must_use]
+    pub fn registered_component_types(&self) -> Vec<&'static str> {
+        self.projectors
+            .iter()
+            .filter_map(|projector| projector.component_type_name())
+            .collect()
+    }
+
     pub(crate) fn project_node(
         &self,
         world: &World,
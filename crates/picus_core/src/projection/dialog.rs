@@ -6,15 +6,20 @@ use super::{
     },
 };
 use crate::{
-    ecs::{OverlayComputedPosition, PartDialogBody, PartDialogDismiss, PartDialogTitle, UiDialog},
+    ecs::{
+        OverlayComputedPosition, PartDialogBody, PartDialogDismiss, PartDialogPrompt,
+        PartDialogTitle, UiDialog,
+    },
     overlay::OverlayUiAction,
     styling::{
         apply_direct_widget_style, apply_flex_alignment, apply_label_style, apply_widget_style,
         resolve_style, resolve_style_for_classes,
     },
+    synthesize::ordered_children,
+    templates::find_template_part,
     views::{ecs_button_with_child, opaque_hitbox_for_entity},
 };
-use bevy_ecs::{hierarchy::Children, prelude::Entity};
+use bevy_ecs::prelude::{Entity, World};
 use masonry::layout::{Dim, Length};
 use std::sync::Arc;
 use xilem::{palette::css::BLACK, style::BoxShadow, style::Style as _};
@@ -26,6 +31,24 @@ pub(crate) const DIALOG_SURFACE_MIN_WIDTH: f64 = 240.0;
 pub(crate) const DIALOG_SURFACE_MAX_WIDTH: f64 = 400.0;
 pub(crate) const DIALOG_DISMISS_ICON_SIZE_PX: f64 = 16.0;
 pub(crate) const DIALOG_DISMISS_BUTTON_SIZE_PX: f64 = 32.0;
+pub(crate) const DIALOG_ACTION_ROW_HEIGHT_PX: f64 = 36.0;
+
+/// Extra surface height needed for the footer action row and prompt input.
+pub(crate) fn dialog_extra_content_height(
+    world: &World,
+    entity: Entity,
+    dialog: &UiDialog,
+    gap: f64,
+) -> f64 {
+    let mut extra = 0.0;
+    if dialog.has_actions() {
+        extra += DIALOG_ACTION_ROW_HEIGHT_PX + gap;
+    }
+    if find_template_part::<PartDialogPrompt>(world, entity).is_some() {
+        extra += DIALOG_ACTION_ROW_HEIGHT_PX + gap;
+    }
+    extra
+}
 
 pub(crate) fn dialog_surface_padding(layout_padding: f64) -> f64 {
     layout_padding.max(12.0)
@@ -161,6 +184,11 @@ pub(crate) fn project_dialog(dialog: &UiDialog, ctx: ProjectionCtx<'_>) -> UiVie
         dialog_surface_gap(dialog_gap),
         dialog_surface_padding(dialog_style.layout.padding),
         dialog_surface_padding(dialog_style.layout.padding),
+    ) + dialog_extra_content_height(
+        ctx.world,
+        ctx.entity,
+        dialog,
+        dialog_surface_gap(dialog_gap),
     );
 
     let dialog_surface_width = if computed_position.width > 1.0 {
@@ -179,11 +207,7 @@ pub(crate) fn project_dialog(dialog: &UiDialog, ctx: ProjectionCtx<'_>) -> UiVie
         estimated_height
     };
 
-    let child_entities = ctx
-        .world
-        .get::<Children>(ctx.entity)
-        .map(|children| children.iter().copied().collect::<Vec<_>>())
-        .unwrap_or_default();
+    let child_entities = ordered_children(ctx.world, ctx.entity);
 
     let child_parts = child_entities
         .into_iter()
@@ -244,7 +268,49 @@ pub(crate) fn project_dialog(dialog: &UiDialog, ctx: ProjectionCtx<'_>) -> UiVie
         .width(Dim::Stretch)
         .gap(Length::px(dialog_gap));
 
-    let dialog_children = vec![header.into_any_flex(), body.flex(1.0).into_any_flex()];
+    let mut dialog_children = vec![header.into_any_flex(), body.flex(1.0).into_any_flex()];
+    if dialog.has_actions() {
+        let mut action_style = resolve_style_for_classes(ctx.world, ["overlay.dialog.action"]);
+        if action_style.layout.padding <= 0.0 {
+            action_style.layout.padding = 8.0;
+        }
+        if let Some(stack) = &title_style.font_family {
+            action_style
+                .font_family
+                .get_or_insert_with(|| stack.clone());
+        }
+        if !is_positioned {
+            hide_style_without_collapsing_layout(&mut action_style);
+        }
+
+        let action_button = |action: OverlayUiAction, text: &str| {
+            apply_direct_widget_style(
+                ecs_button_with_child(
+                    ctx.entity,
+                    action,
+                    apply_label_style(label(text.to_string()), &action_style),
+                ),
+                &action_style,
+            )
+            .into_any_flex()
+        };
+
+        let mut actions = Vec::new();
+        if let Some(cancel_label) = &dialog.cancel_label {
+            actions.push(action_button(OverlayUiAction::DismissDialog, cancel_label));
+        }
+        if let Some(confirm_label) = &dialog.confirm_label {
+            actions.push(action_button(OverlayUiAction::ConfirmDialog, confirm_label));
+        }
+
+        dialog_children.push(
+            flex_row(actions)
+                .main_axis_alignment(MainAxisAlignment::End)
+                .width(Dim::Stretch)
+                .gap(Length::px(dialog_gap))
+                .into_any_flex(),
+        );
+    }
 
     let dialog_surface = xilem_masonry::view::sized_box(apply_widget_style(
         apply_flex_alignment(
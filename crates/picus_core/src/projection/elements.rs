@@ -4,32 +4,31 @@ use super::{
 };
 use crate::{
     ecs::{
-        LocalizeText, PartSwitchThumb, PartSwitchTrack, UiBadge, UiButton, UiCheckbox, UiLabel,
-        UiProgressBar, UiSlider, UiSwitch, UiTextInput,
+        Badge, BadgeCorner, EllipsizedText, LocalizeText, Overflow, PartSwitchThumb,
+        PartSwitchTrack, TextOverflow, UiBadge, UiButton, UiCheckbox, UiLabel, UiProgressBar,
+        UiSlider, UiSwitch, UiTextInput, UiTransform,
     },
     i18n::resolve_localized_text,
     styling::{
-        apply_direct_widget_style, apply_label_style, apply_widget_style, font_stack_from_style,
-        resolve_style,
+        apply_direct_widget_style, apply_label_style, apply_label_style_with_overflow,
+        apply_widget_style, effective_label_overflow, font_stack_from_style,
+        placeholder_color_from_style, resolve_style, resolve_style_for_classes,
     },
-    views::{ecs_button_with_child, ecs_checkbox, ecs_slider, ecs_text_input},
+    synthesize::ordered_children,
+    views::{clip_box, ecs_button_with_child, ecs_checkbox, ecs_slider, ecs_text_input},
     widget_actions::WidgetUiAction,
 };
-use bevy_ecs::{hierarchy::Children, prelude::*};
-use masonry::layout::Length;
+use bevy_ecs::prelude::*;
+use masonry::layout::{Dim, Length, UnitPoint};
 use std::sync::Arc;
 use tracing::trace;
 use xilem_masonry::style::Style as _;
 use xilem_masonry::view::{
-    FlexExt as _, badge, flex_row, label, progress_bar, sized_box, transformed,
+    FlexExt as _, badge, flex_row, label, portal, progress_bar, sized_box, transformed, zstack,
 };
 
 fn child_entity_views(ctx: &ProjectionCtx<'_>) -> Vec<(Entity, UiView)> {
-    let child_entities = ctx
-        .world
-        .get::<Children>(ctx.entity)
-        .map(|children| children.iter().collect::<Vec<_>>())
-        .unwrap_or_default();
+    let child_entities = ordered_children(ctx.world, ctx.entity);
 
     child_entities
         .into_iter()
@@ -46,14 +45,6 @@ fn first_part_view<P: Component>(
         .find_map(|(entity, view)| ctx.world.get::<P>(*entity).map(|_| view.clone()))
 }
 
-fn placeholder_color_from_style(style: &crate::styling::ResolvedStyle) -> xilem::Color {
-    style
-        .colors
-        .text
-        .unwrap_or(xilem::Color::WHITE)
-        .with_alpha(0.72)
-}
-
 fn map_text_alignment_for_input(
     text_align: crate::styling::TextAlign,
 ) -> masonry::parley::Alignment {
@@ -81,7 +72,29 @@ pub(crate) fn project_label(label_component: &UiLabel, ctx: ProjectionCtx<'_>) -
         resolved_text = %text,
         "projected UiLabel text"
     );
-    Arc::new(apply_label_style(label(text), &style))
+    let overflow = effective_label_overflow(label_component.overflow, &style);
+    if !matches!(overflow, TextOverflow::Ellipsis { .. }) {
+        return Arc::new(apply_label_style_with_overflow(
+            label(text),
+            &style,
+            overflow,
+        ));
+    }
+
+    // Ellipsis labels take the full available width so the measured box reflects the
+    // space on offer rather than the (possibly already truncated) text.
+    let text = ctx
+        .world
+        .get::<EllipsizedText>(ctx.entity)
+        .map_or(text, |ellipsized| ellipsized.0.clone());
+    Arc::new(
+        sized_box(apply_label_style_with_overflow(
+            label(text),
+            &style,
+            overflow,
+        ))
+        .width(Dim::Stretch),
+    )
 }
 
 pub(crate) fn project_button(button_component: &UiButton, ctx: ProjectionCtx<'_>) -> UiView {
@@ -128,6 +141,79 @@ pub(crate) fn project_badge(badge_component: &UiBadge, ctx: ProjectionCtx<'_>) -
     ))
 }
 
+/// Clip or scroll `view` according to the entity's [`Overflow`].
+pub(crate) fn decorate_with_overflow(world: &World, entity: Entity, view: UiView) -> UiView {
+    match world.get::<Overflow>(entity).copied().unwrap_or_default() {
+        Overflow::Visible => view,
+        Overflow::Clip => {
+            let corner_radius = resolve_style(world, entity).layout.box_corner_radius();
+            Arc::new(clip_box(corner_radius, view))
+        }
+        Overflow::Scroll => Arc::new(portal(view)),
+    }
+}
+
+/// Wrap `view` in the entity's [`UiTransform`], if it has a non-identity one.
+pub(crate) fn decorate_with_transform(world: &World, entity: Entity, view: UiView) -> UiView {
+    let Some(transform) = world.get::<UiTransform>(entity).copied() else {
+        return view;
+    };
+    if transform.is_identity() {
+        return view;
+    }
+
+    Arc::new(
+        transformed(view)
+            .rotate(f64::from(transform.rotate))
+            .scale(f64::from(transform.scale))
+            .translate(transform.translate),
+    )
+}
+
+/// Overlap (in logical px) between a count badge bubble and its host's corner.
+const COUNT_BADGE_OFFSET: f64 = 6.0;
+
+/// Wrap `view` with the [`Badge`] count bubble configured on `entity`, if any.
+pub(crate) fn decorate_with_count_badge(world: &World, entity: Entity, view: UiView) -> UiView {
+    let Some(count_badge) = world.get::<Badge>(entity) else {
+        return view;
+    };
+    let Some(text) = count_badge.display_text() else {
+        return view;
+    };
+
+    let mut style = resolve_style_for_classes(world, ["template.count_badge"]);
+    if style.colors.bg.is_none() {
+        style.colors.bg = Some(xilem::Color::from_rgb8(0xC4, 0x2B, 0x1C));
+    }
+    if style.colors.text.is_none() {
+        style.colors.text = Some(xilem::Color::WHITE);
+    }
+
+    let (alignment, offset) = match count_badge.corner {
+        BadgeCorner::TopLeft => (
+            UnitPoint::TOP_LEFT,
+            (-COUNT_BADGE_OFFSET, -COUNT_BADGE_OFFSET),
+        ),
+        BadgeCorner::TopRight => (
+            UnitPoint::TOP_RIGHT,
+            (COUNT_BADGE_OFFSET, -COUNT_BADGE_OFFSET),
+        ),
+        BadgeCorner::BottomLeft => (
+            UnitPoint::BOTTOM_LEFT,
+            (-COUNT_BADGE_OFFSET, COUNT_BADGE_OFFSET),
+        ),
+        BadgeCorner::BottomRight => (
+            UnitPoint::BOTTOM_RIGHT,
+            (COUNT_BADGE_OFFSET, COUNT_BADGE_OFFSET),
+        ),
+    };
+
+    let bubble = apply_widget_style(badge(apply_label_style(label(text), &style)), &style);
+
+    Arc::new(zstack((view, transformed(bubble).translate(offset))).alignment(alignment))
+}
+
 pub(crate) fn project_checkbox(checkbox: &UiCheckbox, ctx: ProjectionCtx<'_>) -> UiView {
     let style = resolve_style(ctx.world, ctx.entity);
 
@@ -201,12 +287,12 @@ pub(crate) fn project_progress_bar(progress: &UiProgressBar, ctx: ProjectionCtx<
         transformed(
             sized_box(
                 progress_bar(progress.progress)
-                    .corner_radius(style.layout.corner_radius)
+                    .corner_radius(style.layout.box_corner_radius())
                     .border(
-                        style.colors.border.unwrap_or(xilem::Color::TRANSPARENT),
+                        style.faded(style.colors.border.unwrap_or(xilem::Color::TRANSPARENT)),
                         style.layout.border_width,
                     )
-                    .background_color(style.colors.bg.unwrap_or(xilem::Color::TRANSPARENT)),
+                    .background(style.background()),
             )
             .padding(style.layout.padding),
         )
@@ -224,7 +310,10 @@ pub(crate) fn project_text_input(input: &UiTextInput, ctx: ProjectionCtx<'_>) ->
         }
     })
     .placeholder(input.placeholder.clone())
+    .filter(input.filter.clone())
+    .mask(input.mask)
     .text_size(style.text.size)
+    .font_style(style.text.font_style())
     .text_alignment(map_text_alignment_for_input(style.text.text_align));
 
     if let Some(font_stack) = font_stack_from_style(&style) {
@@ -237,14 +326,14 @@ pub(crate) fn project_text_input(input: &UiTextInput, ctx: ProjectionCtx<'_>) ->
         return Arc::new(
             transformed(
                 styled
-                    .text_color(text_color)
+                    .text_color(style.faded(text_color))
                     .padding(style.layout.padding)
-                    .corner_radius(style.layout.corner_radius)
+                    .corner_radius(style.layout.box_corner_radius())
                     .border(
-                        style.colors.border.unwrap_or(xilem::Color::TRANSPARENT),
+                        style.faded(style.colors.border.unwrap_or(xilem::Color::TRANSPARENT)),
                         style.layout.border_width,
                     )
-                    .background_color(style.colors.bg.unwrap_or(xilem::Color::TRANSPARENT))
+                    .background(style.background())
                     .box_shadow(style.box_shadow.unwrap_or_default()),
             )
             .scale(scale),
@@ -255,12 +344,12 @@ pub(crate) fn project_text_input(input: &UiTextInput, ctx: ProjectionCtx<'_>) ->
         transformed(
             styled
                 .padding(style.layout.padding)
-                .corner_radius(style.layout.corner_radius)
+                .corner_radius(style.layout.box_corner_radius())
                 .border(
-                    style.colors.border.unwrap_or(xilem::Color::TRANSPARENT),
+                    style.faded(style.colors.border.unwrap_or(xilem::Color::TRANSPARENT)),
                     style.layout.border_width,
                 )
-                .background_color(style.colors.bg.unwrap_or(xilem::Color::TRANSPARENT))
+                .background(style.background())
                 .box_shadow(style.box_shadow.unwrap_or_default()),
         )
         .scale(scale),
@@ -1,20 +1,83 @@
-use super::core::{ProjectionCtx, UiView};
+use super::{
+    core::{ProjectionCtx, UiView},
+    utils::{app_i18n_font_stack, lucide_icon, translate_text},
+};
 use crate::{
-    ecs::{UiFlexColumn, UiFlexRow, UiRoot},
-    styling::{apply_flex_alignment, apply_widget_style, resolve_style},
+    ecs::{
+        DividerOrientation, EmptyState, UiDivider, UiFlexColumn, UiFlexRow, UiRoot, UiSpacer,
+        UiSuspense,
+    },
+    styling::{
+        AlignItems, JustifyContent, ResolvedStyle, apply_flex_alignment, apply_label_style,
+        apply_widget_style, resolve_style, resolve_style_for_classes,
+    },
+    synthesize::ordered_children,
 };
+use bevy_ecs::{entity::Entity, world::World};
 use masonry::layout::{Dim, Length};
 use std::sync::Arc;
+use xilem::Color;
 use xilem_masonry::style::Style;
-use xilem_masonry::view::{FlexExt as _, flex_col, flex_row};
+use xilem_masonry::view::{
+    AnyFlexChild, CrossAxisAlignment, FlexExt as _, flex_col, flex_row, label, sized_box,
+};
+
+const EMPTY_STATE_ICON_SIZE: f64 = 32.0;
+
+/// Build the [`EmptyState`] placeholder configured on `entity`, if any.
+///
+/// Container projectors call this when they have no children to show. The
+/// placeholder is styled through the `template.empty_state` class.
+#[must_use]
+pub fn empty_state_view(world: &World, entity: Entity) -> Option<UiView> {
+    let empty_state = world.get::<EmptyState>(entity)?;
+    let mut style = resolve_style_for_classes(world, ["template.empty_state"]);
+    if style.colors.text.is_none() {
+        style.colors.text = Some(Color::from_rgb8(0x9A, 0x9A, 0x9A));
+    }
+    if style.font_family.is_none() && empty_state.localize.is_some() {
+        style.font_family = app_i18n_font_stack(world);
+    }
+
+    let message = translate_text(
+        world,
+        empty_state.localize.as_ref().map(|text| text.key.as_str()),
+        &empty_state.message,
+    );
+    let text_color = style.colors.text.unwrap_or(Color::WHITE);
+
+    let mut items = Vec::new();
+    if let Some(icon) = empty_state.icon {
+        items.push(lucide_icon(icon, EMPTY_STATE_ICON_SIZE, text_color).into_any_flex());
+    }
+    items.push(apply_label_style(label(message), &style).into_any_flex());
+
+    Some(Arc::new(
+        flex_col(items)
+            .cross_axis_alignment(CrossAxisAlignment::Center)
+            .gap(Length::px(8.0))
+            .padding(style.layout.padding.max(16.0))
+            .width(Dim::Stretch),
+    ))
+}
+
+/// Wrap a flex container's child views, giving [`UiSpacer`] children their flex weight.
+fn flex_container_children(ctx: ProjectionCtx<'_>) -> Vec<AnyFlexChild<()>> {
+    ordered_children(ctx.world, ctx.entity)
+        .into_iter()
+        .zip(ctx.children)
+        .map(
+            |(child_entity, child)| match ctx.world.get::<UiSpacer>(child_entity) {
+                Some(spacer) => child.flex(f64::from(spacer.flex.max(0.0))).into_any_flex(),
+                None => child.into_any_flex(),
+            },
+        )
+        .collect()
+}
 
 pub(crate) fn project_ui_root(_: &UiRoot, ctx: ProjectionCtx<'_>) -> UiView {
     let style = resolve_style(ctx.world, ctx.entity);
-    let children = ctx
-        .children
-        .into_iter()
-        .map(|child| child.into_any_flex())
-        .collect::<Vec<_>>();
+    let children = flex_container_children(ctx);
 
     Arc::new(apply_widget_style(
         apply_flex_alignment(flex_col(children), &style)
@@ -25,13 +88,35 @@ pub(crate) fn project_ui_root(_: &UiRoot, ctx: ProjectionCtx<'_>) -> UiView {
     ))
 }
 
-pub(crate) fn project_flex_column(_: &UiFlexColumn, ctx: ProjectionCtx<'_>) -> UiView {
-    let style = resolve_style(ctx.world, ctx.entity);
-    let children = ctx
-        .children
-        .into_iter()
-        .map(|child| child.into_any_flex())
-        .collect::<Vec<_>>();
+/// Resolve a flex container's style with its component-level overrides applied.
+fn flex_container_style(
+    ctx: &ProjectionCtx<'_>,
+    gap: Option<f64>,
+    main_align: Option<JustifyContent>,
+    cross_align: Option<AlignItems>,
+) -> ResolvedStyle {
+    let mut style = resolve_style(ctx.world, ctx.entity);
+    if let Some(gap) = gap {
+        style.layout.gap = gap;
+    }
+    if let Some(main_align) = main_align {
+        style.layout.justify_content = main_align;
+    }
+    if let Some(cross_align) = cross_align {
+        style.layout.align_items = cross_align;
+    }
+    style
+}
+
+pub(crate) fn project_flex_column(column: &UiFlexColumn, ctx: ProjectionCtx<'_>) -> UiView {
+    let style = flex_container_style(&ctx, column.gap, column.main_align, column.cross_align);
+    if ctx.children.is_empty()
+        && let Some(placeholder) = empty_state_view(ctx.world, ctx.entity)
+    {
+        return placeholder;
+    }
+
+    let children = flex_container_children(ctx);
 
     Arc::new(apply_widget_style(
         apply_flex_alignment(flex_col(children), &style).gap(Length::px(style.layout.gap)),
@@ -39,16 +124,67 @@ pub(crate) fn project_flex_column(_: &UiFlexColumn, ctx: ProjectionCtx<'_>) -> U
     ))
 }
 
-pub(crate) fn project_flex_row(_: &UiFlexRow, ctx: ProjectionCtx<'_>) -> UiView {
+pub(crate) fn project_flex_row(row: &UiFlexRow, ctx: ProjectionCtx<'_>) -> UiView {
+    let style = flex_container_style(&ctx, row.gap, row.main_align, row.cross_align);
+    if ctx.children.is_empty()
+        && let Some(placeholder) = empty_state_view(ctx.world, ctx.entity)
+    {
+        return placeholder;
+    }
+
+    let children = flex_container_children(ctx);
+
+    Arc::new(apply_widget_style(
+        apply_flex_alignment(flex_row(children), &style).gap(Length::px(style.layout.gap)),
+        &style,
+    ))
+}
+
+/// Spacers are empty boxes; the parent flex container sizes them by weight.
+pub(crate) fn project_spacer(_: &UiSpacer, _ctx: ProjectionCtx<'_>) -> UiView {
+    Arc::new(sized_box(label("")))
+}
+
+pub(crate) fn project_divider(divider: &UiDivider, ctx: ProjectionCtx<'_>) -> UiView {
     let style = resolve_style(ctx.world, ctx.entity);
-    let children = ctx
-        .children
+    let color = divider
+        .color
+        .or(style.colors.bg)
+        .unwrap_or(Color::from_rgba8(0x80, 0x80, 0x80, 0x66));
+    let thickness = Dim::Fixed(Length::px(f64::from(divider.thickness.max(0.0))));
+
+    let line = sized_box(label(""));
+    let line = match divider.orientation {
+        DividerOrientation::Horizontal => line.width(Dim::Stretch).height(thickness),
+        DividerOrientation::Vertical => line.width(thickness).height(Dim::Stretch),
+    };
+
+    Arc::new(line.background_color(color))
+}
+
+/// Project the fallback child until the suspense is ready, then the remaining children.
+pub(crate) fn project_suspense(suspense: &UiSuspense, ctx: ProjectionCtx<'_>) -> UiView {
+    let ready = suspense.is_ready(ctx.world, ctx.entity);
+    let mut fallback = None;
+    let mut content = Vec::new();
+    for (child_entity, child) in ordered_children(ctx.world, ctx.entity)
         .into_iter()
-        .map(|child| child.into_any_flex())
-        .collect::<Vec<_>>();
+        .zip(ctx.children)
+    {
+        if child_entity == suspense.fallback {
+            fallback = Some(child);
+        } else {
+            content.push(child.into_any_flex());
+        }
+    }
+
+    if !ready {
+        return fallback.unwrap_or_else(|| Arc::new(label("")));
+    }
 
+    let style = resolve_style(ctx.world, ctx.entity);
     Arc::new(apply_widget_style(
-        apply_flex_alignment(flex_row(children), &style).gap(Length::px(style.layout.gap)),
+        apply_flex_alignment(flex_col(content), &style).gap(Length::px(style.layout.gap)),
         &style,
     ))
 }
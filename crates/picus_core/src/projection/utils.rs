@@ -25,7 +25,7 @@ pub(crate) enum VectorIcon {
 }
 
 pub(crate) fn vector_icon(icon: VectorIcon, size_px: f64, color: xilem::Color) -> UiView {
-    let lucide_icon = match icon {
+    let picus_icon = match icon {
         VectorIcon::Check => PicusIcon::Check,
         VectorIcon::ChevronDown => PicusIcon::ChevronDown,
         VectorIcon::ChevronUp => PicusIcon::ChevronUp,
@@ -36,6 +36,10 @@ pub(crate) fn vector_icon(icon: VectorIcon, size_px: f64, color: xilem::Color) -
         VectorIcon::X => PicusIcon::X,
     };
 
+    lucide_icon(picus_icon, size_px, color)
+}
+
+pub(crate) fn lucide_icon(icon: PicusIcon, size_px: f64, color: xilem::Color) -> UiView {
     let mut icon_style = ResolvedStyle::default();
     icon_style.colors.text = Some(color);
     icon_style.text.size = (size_px * 0.90) as f32;
@@ -43,7 +47,7 @@ pub(crate) fn vector_icon(icon: VectorIcon, size_px: f64, color: xilem::Color) -
 
     Arc::new(
         sized_box(apply_label_style(
-            label(lucide_icon.glyph().to_string()),
+            label(icon.glyph().to_string()),
             &icon_style,
         ))
         .width(Dim::Fixed(Length::px(size_px)))
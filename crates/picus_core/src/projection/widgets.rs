@@ -1,35 +1,36 @@
 use std::sync::Arc;
 
-use bevy_ecs::{
-    entity::Entity,
-    hierarchy::{ChildOf, Children},
-    prelude::Component,
-};
+use bevy_ecs::{entity::Entity, hierarchy::ChildOf, prelude::Component};
 use masonry::kurbo::{Axis, Point};
 use masonry::layout::{Dim, Length};
 use xilem::Color;
 use xilem::style::Style as _;
 use xilem_masonry::view::{
     CrossAxisAlignment, FlexExt as _, MainAxisAlignment, flex_col, flex_row, label,
-    radio_group as xilem_radio_group, sized_box, spinner, split, transformed, zstack,
+    radio_group as xilem_radio_group, sized_box, split, transformed, virtual_scroll, zstack,
 };
 
 use crate::{
     ecs::{
-        AnchoredTo, OverlayComputedPosition, PartScrollBarHorizontal, PartScrollBarVertical,
-        PartScrollThumbHorizontal, PartScrollThumbVertical, PartScrollViewport, ScrollAxis,
-        SplitDirection, ToastKind, UiColorPicker, UiColorPickerPanel, UiDatePicker,
-        UiDatePickerPanel, UiGroupBox, UiMenuBar, UiMenuBarItem, UiMenuItemPanel, UiRadioGroup,
-        UiScrollView, UiSpinner, UiSplitPane, UiTabBar, UiTable, UiToast, UiTooltip, UiTreeNode,
+        AnchoredTo, FloatingPanelHandle, OverlayComputedPosition, PartGroupBoxTitleAction,
+        PartScrollBarHorizontal, PartScrollBarVertical, PartScrollThumbHorizontal,
+        PartScrollThumbVertical, PartScrollViewport, ScrollAxis, SplitDirection, StickyHeader,
+        StickyHeaderLayout, ToastKind, UiCollapseHeight, UiColorPicker, UiColorPickerPanel,
+        UiDatePicker, UiDatePickerPanel, UiFloatingPanel, UiGroupBox, UiMenuBar, UiMenuBarItem,
+        UiMenuItemPanel, UiPerfOverlay, UiPerfOverlaySettings, UiPerfSnapshot, UiRadioGroup,
+        UiScrollView, UiSpinner, UiSpinnerRotation, UiSplitPane, UiTabBar, UiTable, UiToast,
+        UiTooltip, UiTreeNode, UiVirtualGrid, UiVirtualGridLayout,
     },
     overlay::OverlayUiAction,
     styling::{
         ResolvedStyle, apply_direct_widget_style, apply_flex_alignment, apply_label_style,
         apply_widget_style, font_stack_from_style, resolve_style, resolve_style_for_classes,
     },
+    synthesize::ordered_children,
     views::{
-        ecs_button, ecs_button_with_child, ecs_drag_thumb, ecs_radio_button,
-        opaque_hitbox_for_entity, scroll_portal,
+        clip_box, ecs_button, ecs_button_with_child, ecs_drag_handle, ecs_drag_thumb,
+        ecs_radio_button, ecs_text_input, opaque_hitbox_for_entity, scroll_portal, spinner_arc,
+        table_cell_editor,
     },
     widget_actions::WidgetUiAction,
 };
@@ -159,11 +160,7 @@ fn hidden_placeholder() -> UiView {
 }
 
 fn child_entity_views(ctx: &ProjectionCtx<'_>) -> Vec<(Entity, UiView)> {
-    let child_entities = ctx
-        .world
-        .get::<Children>(ctx.entity)
-        .map(|children| children.iter().copied().collect::<Vec<_>>())
-        .unwrap_or_default();
+    let child_entities = ordered_children(ctx.world, ctx.entity);
 
     child_entities
         .into_iter()
@@ -229,7 +226,24 @@ pub(crate) fn project_scroll_view(scroll_view: &UiScrollView, ctx: ProjectionCtx
                     .world
                     .get::<PartScrollThumbHorizontal>(*entity)
                     .is_some();
-            (!is_template_part).then_some(view.clone().into_any_flex())
+            if is_template_part {
+                return None;
+            }
+
+            let pinned_offset = ctx
+                .world
+                .get::<StickyHeaderLayout>(*entity)
+                .filter(|_| ctx.world.get::<StickyHeader>(*entity).is_some())
+                .map_or(0.0, |layout| layout.pinned_offset as f64);
+            if pinned_offset > 0.0 {
+                Some(
+                    transformed(view.clone())
+                        .translate((0.0, pinned_offset))
+                        .into_any_flex(),
+                )
+            } else {
+                Some(view.clone().into_any_flex())
+            }
         })
         .collect::<Vec<_>>();
 
@@ -386,6 +400,64 @@ pub(crate) fn project_scroll_view(scroll_view: &UiScrollView, ctx: ProjectionCtx
     ))
 }
 
+// ---------------------------------------------------------------------------
+// Virtual Grid
+// ---------------------------------------------------------------------------
+
+const VIRTUAL_GRID_FALLBACK_WIDTH: f64 = 960.0;
+
+pub(crate) fn project_virtual_grid(grid: &UiVirtualGrid, ctx: ProjectionCtx<'_>) -> UiView {
+    let style = resolve_style(ctx.world, ctx.entity);
+    let available_width = ctx
+        .world
+        .get::<UiVirtualGridLayout>(ctx.entity)
+        .map(|layout| layout.available_width)
+        .filter(|width| *width > 1.0)
+        .unwrap_or(VIRTUAL_GRID_FALLBACK_WIDTH);
+
+    let columns = grid.column_count(available_width);
+    let cell_width = grid.cell_width(available_width, columns);
+    let row_count = grid.row_count(columns);
+    let item_count = grid.item_count;
+    let row_height = grid.row_height;
+    let gap = grid.gap;
+    let cells = Arc::new(ctx.children);
+
+    let rows = virtual_scroll(0..row_count as i64, move |_, row| {
+        let row = usize::try_from(row).unwrap_or(0);
+        let start = row * columns;
+        let end = (start + columns).min(item_count);
+        let row_cells = (start..end)
+            .map(|index| {
+                let cell = cells
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| Arc::new(label("")));
+                sized_box(cell)
+                    .width(Dim::Fixed(Length::px(cell_width)))
+                    .height(Dim::Fixed(Length::px(row_height)))
+                    .into_any_flex()
+            })
+            .collect::<Vec<_>>();
+
+        Arc::new(
+            sized_box(
+                flex_row(row_cells)
+                    .main_axis_alignment(MainAxisAlignment::Start)
+                    .gap(Length::px(gap)),
+            )
+            .padding(gap * 0.5),
+        ) as UiView
+    });
+
+    Arc::new(apply_widget_style(
+        sized_box(rows)
+            .width(Dim::Stretch)
+            .height(Dim::Fixed(Length::px(grid.viewport_height))),
+        &style,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Radio Group
 // ---------------------------------------------------------------------------
@@ -511,7 +583,10 @@ pub(crate) fn project_tab_bar(tab_bar: &UiTabBar, ctx: ProjectionCtx<'_>) -> UiV
             );
 
             let mut indicator_style = pipe_style.clone();
-            indicator_style.transition = Some(crate::StyleTransition { duration: 0.12 });
+            indicator_style.transition = Some(crate::StyleTransition {
+                duration: 0.12,
+                ease: None,
+            });
             indicator_style.layout.scale = if is_active { 1.0 } else { 0.45 };
             indicator_style.colors.bg = Some(if is_active {
                 pipe_color
@@ -660,14 +735,57 @@ pub(crate) fn project_table(table: &UiTable, ctx: ProjectionCtx<'_>) -> UiView {
             }
             let cells = row
                 .iter()
-                .map(|cell| {
-                    apply_widget_style(
-                        sized_box(apply_label_style(label(cell.clone()), &cell_style))
-                            .width(Dim::Stretch),
-                        &row_style,
-                    )
-                    .flex(1.0)
-                    .into_any_flex()
+                .enumerate()
+                .map(|(col_idx, cell)| {
+                    let table_entity = ctx.entity;
+                    if let Some(edit) = table
+                        .editing
+                        .as_ref()
+                        .filter(|edit| (edit.row, edit.col) == (row_idx, col_idx))
+                    {
+                        let mut input =
+                            ecs_text_input(table_entity, edit.value.clone(), move |value| {
+                                WidgetUiAction::SetTableCellDraft {
+                                    table: table_entity,
+                                    value,
+                                }
+                            })
+                            .on_enter(move |_| WidgetUiAction::CommitTableCellEdit {
+                                table: table_entity,
+                            })
+                            .text_size(cell_style.text.size);
+                        if let Some(text_color) = cell_style.colors.text {
+                            input = input.text_color(text_color);
+                        }
+                        return table_cell_editor(
+                            table_entity,
+                            apply_direct_widget_style(input, &row_style),
+                        )
+                        .flex(1.0)
+                        .into_any_flex();
+                    }
+
+                    let cell_label = apply_label_style(label(cell.clone()), &cell_style);
+                    if table.is_cell_editable(row_idx, col_idx) {
+                        return apply_direct_widget_style(
+                            ecs_button_with_child(
+                                table_entity,
+                                WidgetUiAction::ClickTableCell {
+                                    table: table_entity,
+                                    row: row_idx,
+                                    col: col_idx,
+                                },
+                                cell_label,
+                            ),
+                            &row_style,
+                        )
+                        .flex(1.0)
+                        .into_any_flex();
+                    }
+
+                    apply_widget_style(sized_box(cell_label).width(Dim::Stretch), &row_style)
+                        .flex(1.0)
+                        .into_any_flex()
                 })
                 .collect::<Vec<_>>();
             flex_row(cells).into_any_flex()
@@ -712,7 +830,7 @@ pub(crate) fn project_menu_bar_item(item: &UiMenuBarItem, ctx: ProjectionCtx<'_>
         vector_icon(VectorIcon::ChevronDown, 10.0, icon_color)
     };
     let content = flex_row(vec![
-        apply_label_style(label(item.label.clone()), &style).into_any_flex(),
+        apply_label_style(label(item.display_label()), &style).into_any_flex(),
         icon.into_any_flex(),
     ])
     .gap(Length::px(4.0));
@@ -732,6 +850,12 @@ pub(crate) fn project_menu_item_panel(_: &UiMenuItemPanel, ctx: ProjectionCtx<'_
 
     let menu_style = default_panel_style(ctx.world, "overlay.dropdown.menu");
     let item_style = default_item_style(ctx.world, "overlay.dropdown.item");
+    let mut highlighted_style = item_style.clone();
+    highlighted_style.colors.bg =
+        resolve_style_for_classes(ctx.world, ["overlay.dropdown.item.highlighted"])
+            .colors
+            .bg
+            .or(Some(Color::from_rgb8(0x3A, 0x3A, 0x3A)));
 
     let items: Vec<_> = anchor
         .and_then(|a| ctx.world.get::<UiMenuBarItem>(a))
@@ -741,13 +865,18 @@ pub(crate) fn project_menu_item_panel(_: &UiMenuItemPanel, ctx: ProjectionCtx<'_
                 .iter()
                 .enumerate()
                 .map(|(i, menu_item)| {
+                    let item_style = if bar_item.highlighted == Some(i) {
+                        &highlighted_style
+                    } else {
+                        &item_style
+                    };
                     apply_direct_widget_style(
                         ecs_button_with_child(
                             ctx.entity,
                             OverlayUiAction::SelectMenuBarItem { index: i },
-                            apply_label_style(label(menu_item.label.clone()), &item_style),
+                            apply_label_style(label(menu_item.label.clone()), item_style),
                         ),
-                        &item_style,
+                        item_style,
                     )
                     .into_any_flex()
                 })
@@ -824,11 +953,26 @@ pub(crate) fn project_tooltip(tooltip: &UiTooltip, ctx: ProjectionCtx<'_>) -> Ui
 
 pub(crate) fn project_spinner(sp: &UiSpinner, ctx: ProjectionCtx<'_>) -> UiView {
     let style = resolve_style(ctx.world, ctx.entity);
-    let spin_view: UiView = if let Some(color) = style.colors.text {
-        Arc::new(spinner().color(color))
+    let ring_style = resolve_style_for_classes(ctx.world, ["widget.spinner.ring"]);
+    let color = ring_style
+        .colors
+        .text
+        .or(style.colors.text)
+        .unwrap_or(masonry::theme::TEXT_COLOR);
+    let start_angle = if sp.is_determinate() {
+        0.0
     } else {
-        Arc::new(spinner())
+        ctx.world
+            .get::<UiSpinnerRotation>(ctx.entity)
+            .map_or(0.0, |rotation| rotation.angle)
     };
+    let spin_view: UiView = Arc::new(spinner_arc(
+        sp.size as f64,
+        sp.thickness as f64,
+        start_angle as f64,
+        sp.arc_sweep() as f64,
+        color,
+    ));
 
     if let Some(lbl) = &sp.label {
         let label_view = apply_label_style(label(lbl.clone()), &style);
@@ -1006,10 +1150,74 @@ pub(crate) fn project_group_box(group_box: &UiGroupBox, ctx: ProjectionCtx<'_>)
     }
 
     let title_style = resolve_style_for_classes(ctx.world, ["widget.group_box.title"]);
-    let title_view = apply_label_style(label(group_box.title.clone()), &title_style);
+    let title_label = apply_label_style(label(group_box.title.clone()), &title_style);
+
+    let title_view: UiView = if group_box.collapsible {
+        let icon_color = title_style
+            .colors
+            .text
+            .unwrap_or(Color::from_rgb8(0xE7, 0xEC, 0xF8));
+        let icon = if group_box.collapsed {
+            vector_icon(VectorIcon::ChevronRight, 12.0, icon_color)
+        } else {
+            vector_icon(VectorIcon::ChevronDown, 12.0, icon_color)
+        };
+        let content =
+            flex_row(vec![icon.into_any_flex(), title_label.into_any_flex()]).gap(Length::px(6.0));
+        Arc::new(apply_direct_widget_style(
+            ecs_button_with_child(
+                ctx.entity,
+                WidgetUiAction::ToggleGroupBox {
+                    group_box: ctx.entity,
+                },
+                content,
+            ),
+            &title_style,
+        ))
+    } else {
+        Arc::new(title_label)
+    };
 
-    let mut content_items = vec![title_view.into_any_flex()];
-    content_items.extend(ctx.children.into_iter().map(|c| c.into_any_flex()));
+    let (title_actions, body): (Vec<_>, Vec<_>) = child_entity_views(&ctx)
+        .into_iter()
+        .partition(|(entity, _)| ctx.world.get::<PartGroupBoxTitleAction>(*entity).is_some());
+
+    let title_row = if title_actions.is_empty() {
+        title_view.into_any_flex()
+    } else {
+        let mut title_items = vec![title_view.flex(1.0).into_any_flex()];
+        title_items.extend(
+            title_actions
+                .into_iter()
+                .map(|(_, view)| view.into_any_flex()),
+        );
+        flex_row(title_items)
+            .cross_axis_alignment(CrossAxisAlignment::Center)
+            .gap(Length::px(4.0))
+            .into_any_flex()
+    };
+
+    let mut content_items = vec![title_row];
+    let collapse_height = ctx
+        .world
+        .get::<UiCollapseHeight>(ctx.entity)
+        .filter(|height| height.is_animating());
+    if let Some(height) = collapse_height {
+        // Mid-animation the body keeps its full layout, clipped to the animated height.
+        let body = flex_col(
+            body.into_iter()
+                .map(|(_, view)| view.into_any_flex())
+                .collect::<Vec<_>>(),
+        )
+        .gap(Length::px(style.layout.gap.max(6.0)));
+        content_items.push(
+            sized_box(clip_box(0.0, body))
+                .height(Dim::Fixed(Length::px(height.current.max(0.0))))
+                .into_any_flex(),
+        );
+    } else if group_box.is_body_visible() {
+        content_items.extend(body.into_iter().map(|(_, view)| view.into_any_flex()));
+    }
 
     Arc::new(apply_widget_style(
         apply_flex_alignment(flex_col(content_items), &style)
@@ -1123,6 +1331,120 @@ pub(crate) fn project_toast(toast: &UiToast, ctx: ProjectionCtx<'_>) -> UiView {
     Arc::new(transformed(opaque_hitbox_for_entity(ctx.entity, panel)).translate(pos))
 }
 
+// ---------------------------------------------------------------------------
+// Floating Panel
+// ---------------------------------------------------------------------------
+
+const FLOATING_PANEL_HANDLE_THICKNESS: f64 = 6.0;
+
+fn floating_panel_edge(
+    panel: Entity,
+    handle: FloatingPanelHandle,
+    width: Dim,
+    height: Dim,
+) -> impl xilem_masonry::WidgetView<(), ()> {
+    ecs_drag_handle(
+        panel,
+        handle,
+        sized_box(label("")).width(width).height(height),
+    )
+}
+
+pub(crate) fn project_floating_panel(panel: &UiFloatingPanel, ctx: ProjectionCtx<'_>) -> UiView {
+    let mut style = default_panel_style(ctx.world, "overlay.floating_panel");
+    let body_padding = style.layout.padding;
+    style.layout.padding = 0.0;
+
+    let mut title_style = resolve_style_for_classes(ctx.world, ["overlay.floating_panel.title"]);
+    if title_style.colors.text.is_none() {
+        title_style.colors.text = style
+            .colors
+            .text
+            .or(Some(Color::from_rgb8(0xF3, 0xF3, 0xF3)));
+    }
+    if title_style.layout.padding <= 0.0 {
+        title_style.layout.padding = 6.0;
+    }
+
+    let title_bar = ecs_drag_handle(
+        ctx.entity,
+        FloatingPanelHandle::Move,
+        apply_widget_style(
+            sized_box(apply_label_style(label(panel.title.clone()), &title_style))
+                .width(Dim::Stretch),
+            &title_style,
+        ),
+    );
+
+    let body_items = ctx
+        .children
+        .into_iter()
+        .map(|child| child.into_any_flex())
+        .collect::<Vec<_>>();
+    let body = sized_box(
+        apply_flex_alignment(flex_col(body_items), &style).gap(Length::px(style.layout.gap)),
+    )
+    .padding(body_padding)
+    .width(Dim::Stretch);
+
+    let column = flex_col(vec![
+        title_bar.into_any_flex(),
+        body.flex(1.0).into_any_flex(),
+    ])
+    .cross_axis_alignment(CrossAxisAlignment::Stretch);
+
+    let thickness = Dim::Fixed(Length::px(FLOATING_PANEL_HANDLE_THICKNESS));
+    let content = if panel.resizable {
+        let bottom_row = flex_row(vec![
+            floating_panel_edge(
+                ctx.entity,
+                FloatingPanelHandle::ResizeBottom,
+                Dim::Stretch,
+                thickness,
+            )
+            .flex(1.0)
+            .into_any_flex(),
+            floating_panel_edge(
+                ctx.entity,
+                FloatingPanelHandle::ResizeBottomRight,
+                thickness,
+                thickness,
+            )
+            .into_any_flex(),
+        ]);
+        flex_row(vec![
+            flex_col(vec![
+                column.flex(1.0).into_any_flex(),
+                bottom_row.into_any_flex(),
+            ])
+            .cross_axis_alignment(CrossAxisAlignment::Stretch)
+            .flex(1.0)
+            .into_any_flex(),
+            floating_panel_edge(
+                ctx.entity,
+                FloatingPanelHandle::ResizeRight,
+                thickness,
+                Dim::Stretch,
+            )
+            .into_any_flex(),
+        ])
+        .cross_axis_alignment(CrossAxisAlignment::Stretch)
+        .flex(1.0)
+        .into_any_flex()
+    } else {
+        column.flex(1.0).into_any_flex()
+    };
+
+    let surface = apply_widget_style(
+        sized_box(flex_col(vec![content]).cross_axis_alignment(CrossAxisAlignment::Stretch))
+            .width(Dim::Fixed(Length::px(panel.size.0.max(1.0))))
+            .height(Dim::Fixed(Length::px(panel.size.1.max(1.0)))),
+        &style,
+    );
+
+    Arc::new(transformed(opaque_hitbox_for_entity(ctx.entity, surface)).translate(panel.position))
+}
+
 // ---------------------------------------------------------------------------
 // Date Picker
 // ---------------------------------------------------------------------------
@@ -1288,3 +1610,31 @@ pub(crate) fn project_date_picker_panel(
         .translate(pos),
     )
 }
+
+pub(crate) fn project_perf_overlay(_: &UiPerfOverlay, ctx: ProjectionCtx<'_>) -> UiView {
+    let enabled = ctx
+        .world
+        .get_resource::<UiPerfOverlaySettings>()
+        .is_some_and(|settings| settings.enabled);
+    if !enabled {
+        return hidden_placeholder();
+    }
+
+    let mut style = default_panel_style(ctx.world, "overlay.perf");
+    if style.colors.text.is_none() {
+        style.colors.text = Some(Color::from_rgb8(0x9C, 0xE6, 0x9C));
+    }
+
+    let rows = UiPerfSnapshot::capture(ctx.world)
+        .lines()
+        .into_iter()
+        .map(|line| apply_label_style(label(line), &style).into_any_flex())
+        .collect::<Vec<_>>();
+
+    Arc::new(apply_widget_style(
+        flex_col(rows)
+            .cross_axis_alignment(CrossAxisAlignment::Start)
+            .gap(Length::px(2.0)),
+        &style,
+    ))
+}
@@ -0,0 +1,102 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    hash::Hash,
+};
+
+use bevy_ecs::{
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    prelude::*,
+};
+
+/// Data item with a stable identity used to match it against an existing entity.
+pub trait Keyed {
+    type Key: Eq + Hash + Clone;
+
+    fn key(&self) -> Self::Key;
+}
+
+/// Reconcile the `T` children of `parent` against `items`, matched by [`Keyed::key`].
+///
+/// See [`reconcile_children_with`]; this variant spawns new children with only `T`.
+pub fn reconcile_children<T>(world: &mut World, parent: Entity, items: &[T]) -> Vec<Entity>
+where
+    T: Component + Keyed + PartialEq + Clone,
+{
+    reconcile_children_with(world, parent, items, |_, _, _| {})
+}
+
+/// Reconcile the `T` children of `parent` against `items`, matched by [`Keyed::key`].
+///
+/// - children whose key is gone (or repeats an earlier child's key) are despawned,
+/// - matching children are reused; `T` is re-inserted only when it changed,
+/// - items without a matching child are spawned with `T` + `ChildOf(parent)`, then
+///   `on_spawn` runs so callers can attach the rest of the bundle.
+///
+/// The `T` children end up in `items` order, ahead of any children without `T`.
+/// Returns the reconciled entities in `items` order.
+pub fn reconcile_children_with<T>(
+    world: &mut World,
+    parent: Entity,
+    items: &[T],
+    mut on_spawn: impl FnMut(&mut World, Entity, &T),
+) -> Vec<Entity>
+where
+    T: Component + Keyed + PartialEq + Clone,
+{
+    let current_children = world
+        .get::<Children>(parent)
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+
+    let mut existing = HashMap::new();
+    let mut untracked = Vec::new();
+    let mut stale = Vec::new();
+    for child in current_children {
+        let Some(value) = world.get::<T>(child) else {
+            untracked.push(child);
+            continue;
+        };
+        match existing.entry(value.key()) {
+            Entry::Vacant(slot) => {
+                slot.insert(child);
+            }
+            Entry::Occupied(_) => stale.push(child),
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(items.len());
+    for item in items {
+        let entity = match existing.remove(&item.key()) {
+            Some(entity) => {
+                if world.get::<T>(entity) != Some(item) {
+                    world.entity_mut(entity).insert(item.clone());
+                }
+                entity
+            }
+            None => {
+                let entity = world.spawn((item.clone(), ChildOf(parent))).id();
+                on_spawn(world, entity, item);
+                entity
+            }
+        };
+        ordered.push(entity);
+    }
+
+    for entity in existing.into_values().chain(stale) {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+
+    let mut next_children = ordered.clone();
+    next_children.extend(untracked);
+    let unchanged = world
+        .get::<Children>(parent)
+        .is_some_and(|children| children.iter().eq(next_children.iter().copied()));
+    if !unchanged {
+        world.entity_mut(parent).replace_children(&next_children);
+    }
+
+    ordered
+}
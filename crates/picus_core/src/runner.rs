@@ -1,5 +1,5 @@
 use bevy_a11y::AccessibilityPlugin;
-use bevy_app::App;
+use bevy_app::{App, PluginsState};
 use bevy_input::InputPlugin;
 use bevy_window::{PrimaryWindow, Window, WindowPlugin};
 use xilem::winit::{dpi::Size, error::EventLoopError};
@@ -10,6 +10,7 @@ pub struct BevyWindowOptions {
     resizable: Option<bool>,
     initial_inner_size: Option<Size>,
     min_inner_size: Option<Size>,
+    prewarm_first_frame: bool,
 }
 
 impl BevyWindowOptions {
@@ -33,6 +34,14 @@ impl BevyWindowOptions {
         self.min_inner_size = Some(size.into());
         self
     }
+
+    /// Runs one app update (and synthesis pass) before the window is created,
+    /// so the first visible frame already has content. Off by default.
+    #[must_use]
+    pub fn with_prewarm_first_frame(mut self, prewarm: bool) -> Self {
+        self.prewarm_first_frame = prewarm;
+        self
+    }
 }
 
 fn size_to_logical(size: Size) -> (f32, f32) {
@@ -117,6 +126,24 @@ fn configure_primary_window(app: &mut App, title: &str, options: &BevyWindowOpti
     app.world_mut().spawn((window, PrimaryWindow));
 }
 
+/// Run one `update()` ahead of the event loop so `Startup` systems and the first
+/// synthesis pass have run before the window shows.
+///
+/// Finishes plugin setup first. Does nothing while plugins are still being built.
+pub fn prewarm_first_frame(app: &mut App) {
+    match app.plugins_state() {
+        PluginsState::Adding => return,
+        PluginsState::Ready => {
+            app.finish();
+            app.cleanup();
+        }
+        PluginsState::Finished => app.cleanup(),
+        PluginsState::Cleaned => {}
+    }
+
+    app.update();
+}
+
 /// Run a Bevy app using Bevy's native runner and default `bevy_winit` event loop.
 ///
 /// This no longer creates a separate Xilem runner/event loop.
@@ -139,6 +166,10 @@ pub fn run_app_with_window_options(
     ensure_native_windowing_plugins(&mut bevy_app, &primary_window);
     configure_primary_window(&mut bevy_app, &title, &options);
 
+    if options.prewarm_first_frame {
+        prewarm_first_frame(&mut bevy_app);
+    }
+
     let _ = bevy_app.run();
     Ok(())
 }
@@ -146,6 +177,9 @@ pub fn run_app_with_window_options(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{PicusPlugin, SynthesizedUiViews, UiLabel, UiRoot};
+    use bevy_app::Startup;
+    use bevy_ecs::prelude::Commands;
     use xilem::winit::dpi::{LogicalSize, PhysicalSize};
 
     #[test]
@@ -165,4 +199,30 @@ mod tests {
         assert_eq!(window.resize_constraints.min_height, 200.0);
         assert!(!window.resizable);
     }
+
+    #[test]
+    fn prewarm_synthesizes_first_root_before_event_loop() {
+        let mut app = App::new();
+        app.add_plugins(PicusPlugin::default());
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((UiRoot, UiLabel::new("ready")));
+        });
+
+        assert!(
+            app.world()
+                .resource::<SynthesizedUiViews>()
+                .first_root()
+                .is_none()
+        );
+
+        prewarm_first_frame(&mut app);
+
+        assert_eq!(app.plugins_state(), PluginsState::Cleaned);
+        assert!(
+            app.world()
+                .resource::<SynthesizedUiViews>()
+                .first_root()
+                .is_some()
+        );
+    }
 }
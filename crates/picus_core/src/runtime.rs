@@ -6,7 +6,10 @@ use std::{
 use bevy_ecs::{
     entity::Entity,
     message::MessageReader,
-    prelude::{Added, FromWorld, NonSendMut, Query, Res, ResMut, With, World},
+    prelude::{
+        Added, Changed, Component, FromWorld, Mut, NonSendMut, Or, Query, Res, ResMut, With,
+        Without, World,
+    },
 };
 use bevy_input::{
     ButtonState,
@@ -29,7 +32,9 @@ use masonry::{
         keyboard::{Key, KeyState, Modifiers, NamedKey},
     },
     dpi::{PhysicalPosition, PhysicalSize},
-    peniko::Color,
+    kurbo::Size,
+    parley::{FontContext, Layout, LayoutContext, StyleProperty},
+    peniko::{Blob, Color},
     theme::default_property_set,
     vello::{Renderer, wgpu},
     widgets::Passthrough,
@@ -44,10 +49,17 @@ use xilem_masonry::{
 };
 
 use crate::{
+    ecs::{
+        EllipsizedText, LocalizeText, OffscreenMeasure, OffscreenMeasuredSize, RootBackground,
+        TextOverflow, UiLabel, UiOverlayRoot, UiRoot,
+    },
     events::{UiEventQueue, install_global_ui_event_queue},
+    i18n::{AppI18n, resolve_localized_text},
     overlay::OverlayPointerRoutingState,
-    projection::{UiAnyView, UiView},
-    synthesize::SynthesizedUiViews,
+    projection::{UiAnyView, UiProjectorRegistry, UiView, utils::localized_font_stack},
+    styling::{ComputedStyle, effective_label_overflow, resolve_style},
+    synthesize::{SynthesizedUiViews, synthesize_subtree},
+    widgets::{ENTITY_SCOPE_DEBUG_KIND, OPAQUE_HITBOX_DEBUG_KIND, parse_entity_debug_text},
 };
 
 #[derive(Debug)]
@@ -63,6 +75,9 @@ impl RawProxy for NoopProxy {
     }
 }
 
+/// Surface clear color used when no root carries a [`RootBackground`].
+pub const DEFAULT_CLEAR_COLOR: Color = Color::BLACK;
+
 type RuntimeViewState = <UiAnyView as View<(), (), ViewCtx>>::ViewState;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -102,6 +117,9 @@ pub struct MasonryRuntime {
     viewport_height: f64,
     window_surface: Option<ExternalWindowSurface>,
     renderer: Option<Renderer>,
+    clear_color: Color,
+    text_measure: TextMeasureContext,
+    registered_fonts: Vec<Blob>,
     #[cfg(test)]
     pointer_trace: Vec<PointerTraceEvent>,
 }
@@ -181,12 +199,45 @@ impl FromWorld for MasonryRuntime {
             viewport_height: initial_viewport.1,
             window_surface: None,
             renderer: None,
+            clear_color: DEFAULT_CLEAR_COLOR,
+            text_measure: TextMeasureContext::default(),
+            registered_fonts: Vec::new(),
             #[cfg(test)]
             pointer_trace: Vec::new(),
         }
     }
 }
 
+/// Parley contexts backing [`MasonryRuntime::measure_text`].
+///
+/// Masonry keeps its own font context private, so registered fonts are mirrored
+/// here to make measurements match what labels render with.
+#[derive(Default)]
+struct TextMeasureContext {
+    fonts: FontContext,
+    layout: LayoutContext<()>,
+}
+
+impl TextMeasureContext {
+    fn build(
+        &mut self,
+        text: &str,
+        font_stack: &[String],
+        size: f32,
+        max_width: Option<f32>,
+    ) -> Layout<()> {
+        let mut builder = self.layout.ranged_builder(&mut self.fonts, text, 1.0, true);
+        builder.push_default(StyleProperty::FontSize(size));
+        if let Some(stack) = crate::styling::font_stack_from_families(font_stack) {
+            builder.push_default(StyleProperty::FontStack(stack));
+        }
+
+        let mut text_layout = builder.build(text);
+        text_layout.break_all_lines(max_width);
+        text_layout
+    }
+}
+
 fn focus_fallback_widget(render_root: &RenderRoot) -> Option<WidgetId> {
     render_root
         .get_layer_root(0)
@@ -233,15 +284,11 @@ fn build_widget_path(
 }
 
 fn parse_entity_debug_binding(debug: &str) -> Option<(u64, bool)> {
-    if let Some(bits) = debug.strip_prefix("opaque_hitbox_entity=") {
-        return Some((bits.parse::<u64>().ok()?, true));
-    }
-
-    if let Some(bits) = debug.strip_prefix("entity_scope=") {
-        return Some((bits.parse::<u64>().ok()?, false));
+    match parse_entity_debug_text(debug)? {
+        (OPAQUE_HITBOX_DEBUG_KIND, bits) => Some((bits, true)),
+        (ENTITY_SCOPE_DEBUG_KIND, bits) => Some((bits, false)),
+        _ => None,
     }
-
-    None
 }
 
 impl MasonryRuntime {
@@ -259,6 +306,16 @@ impl MasonryRuntime {
         self.active_window
     }
 
+    /// Color the window surface is cleared to before the Masonry scene is painted.
+    #[must_use]
+    pub fn clear_color(&self) -> Color {
+        self.clear_color
+    }
+
+    pub fn set_clear_color(&mut self, color: Color) {
+        self.clear_color = color;
+    }
+
     #[must_use]
     pub fn viewport_size(&self) -> (f64, f64) {
         (self.viewport_width.max(1.0), self.viewport_height.max(1.0))
@@ -364,6 +421,127 @@ impl MasonryRuntime {
         matches
     }
 
+    /// Register font bytes with Masonry and the text measurement context.
+    pub fn register_fonts(&mut self, font_bytes: Vec<u8>) {
+        let blob = Blob::new(Arc::new(font_bytes));
+        self.render_root.register_fonts(blob.clone());
+        self.text_measure
+            .fonts
+            .collection
+            .register_fonts(blob.clone(), None);
+        self.registered_fonts.push(blob);
+    }
+
+    /// Lay out `view` in a throwaway render root sized to its content and return that size.
+    ///
+    /// The visible tree is untouched; the view is torn down again before returning.
+    pub fn measure_view(&mut self, view: &UiView) -> Size {
+        let (element, mut view_state) =
+            <UiAnyView as View<(), (), ViewCtx>>::build(view.as_ref(), &mut self.view_ctx, &mut ());
+        let mut render_root = RenderRoot::new(
+            element.new_widget.erased(),
+            |_| {},
+            RenderRootOptions {
+                default_properties: Arc::new(default_property_set()),
+                use_system_fonts: true,
+                size_policy: WindowSizePolicy::Content,
+                size: PhysicalSize::new(self.viewport_width as u32, self.viewport_height as u32),
+                scale_factor: 1.0,
+                test_font: None,
+            },
+        );
+        for blob in &self.registered_fonts {
+            render_root.register_fonts(blob.clone());
+        }
+
+        let size = render_root.get_layer_root(0).ctx().border_box_size();
+        render_root.edit_base_layer(|mut root| {
+            let mut root = root.downcast::<Passthrough>();
+            <UiAnyView as View<(), (), ViewCtx>>::teardown(
+                view.as_ref(),
+                &mut view_state,
+                &mut self.view_ctx,
+                root.reborrow_mut(),
+            );
+        });
+        size
+    }
+
+    /// Logical size of `text` laid out on a single line (explicit newlines still break).
+    ///
+    /// `font_stack` uses the same family names as the `font_family` style property; an
+    /// empty stack falls back to the default sans-serif family.
+    #[must_use]
+    pub fn measure_text(&mut self, text: &str, font_stack: &[String], size: f32) -> Size {
+        let text_layout = self.text_measure.build(text, font_stack, size, None);
+        Size::new(
+            f64::from(text_layout.width()),
+            f64::from(text_layout.height()),
+        )
+    }
+
+    /// Truncate `text` with "…" so it fits `max_width` within `max_lines` lines.
+    ///
+    /// Returns `None` when the full text already fits. Truncation happens on character
+    /// boundaries, keeping the longest prefix that still fits with the ellipsis appended.
+    #[must_use]
+    pub fn ellipsize_text(
+        &mut self,
+        text: &str,
+        font_stack: &[String],
+        size: f32,
+        max_width: f64,
+        max_lines: u32,
+    ) -> Option<String> {
+        let max_lines = max_lines.max(1) as usize;
+        let mut fits = |candidate: &str| {
+            if max_lines == 1 {
+                let width = self
+                    .text_measure
+                    .build(candidate, font_stack, size, None)
+                    .width();
+                return f64::from(width) <= max_width;
+            }
+            self.text_measure
+                .build(candidate, font_stack, size, Some(max_width as f32))
+                .len()
+                <= max_lines
+        };
+
+        if fits(text) {
+            return None;
+        }
+
+        let boundaries = text
+            .char_indices()
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let with_ellipsis = |end: usize| format!("{}…", text[..end].trim_end());
+        let prefix_end = |chars: usize| boundaries.get(chars).copied().unwrap_or(text.len());
+
+        // Longest prefix (in chars) whose ellipsized form fits; 0 leaves a bare "…".
+        let (mut low, mut high) = (0, boundaries.len().saturating_sub(1));
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if fits(&with_ellipsis(prefix_end(mid))) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        Some(with_ellipsis(prefix_end(low)))
+    }
+
+    /// Window scale factor currently applied to the render root.
+    ///
+    /// Masonry lays out in logical pixels and rasterizes text at this factor, so it must
+    /// track the primary window's scale for fonts to stay crisp on HiDPI displays.
+    #[must_use]
+    pub fn scale_factor(&self) -> f64 {
+        self.window_scale_factor
+    }
+
     /// Returns `(bevy_window_scale_factor, masonry_global_scale_factor)` for diagnostics.
     #[must_use]
     pub fn masonry_scale_factors(&self) -> (f64, f64) {
@@ -664,7 +842,7 @@ impl MasonryRuntime {
             paint_result.composite(),
             logical_size.width.max(1),
             logical_size.height.max(1),
-            Color::BLACK,
+            self.clear_color,
         );
     }
 
@@ -1058,18 +1236,19 @@ pub fn inject_bevy_input_into_masonry(
         );
     }
 
-    for event in window_scale_factor_changed.read() {
-        if event.window != primary_window_entity {
-            continue;
-        }
-
-        runtime.handle_window_scale_factor_changed(
-            primary_window_entity,
-            primary_window.scale_factor() as f64,
-        );
+    let scale_factor_changed = window_scale_factor_changed
+        .read()
+        .fold(false, |changed, event| {
+            changed || event.window == primary_window_entity
+        });
+    // Like resizes, `Window` state is authoritative. Comparing against it also covers windows
+    // created on a HiDPI monitor before any scale-change message was observed.
+    let window_scale_factor = primary_window.scale_factor() as f64;
+    if scale_factor_changed || (runtime.scale_factor() - window_scale_factor).abs() > f64::EPSILON {
+        runtime.handle_window_scale_factor_changed(primary_window_entity, window_scale_factor);
         tracing::trace!(
             "Window Scale Factor - Bevy Scale: {}, Injected into Masonry.",
-            primary_window.scale_factor()
+            window_scale_factor
         );
     }
 }
@@ -1129,24 +1308,206 @@ pub fn initialize_masonry_runtime_from_primary_window(
     );
 }
 
+/// Clear color for the bottom-most (first synthesized) non-overlay root.
+///
+/// Roots stacked above it paint over the cleared surface, so only the lowest
+/// root's [`RootBackground`] is visible as the canvas color.
+fn root_clear_color(world: &mut World) -> Color {
+    let mut query = world
+        .query_filtered::<(Entity, Option<&RootBackground>), (With<UiRoot>, Without<UiOverlayRoot>)>();
+    query
+        .iter(world)
+        .min_by_key(|(entity, _)| entity.to_bits())
+        .and_then(|(_, background)| background.map(|background| background.0))
+        .unwrap_or(DEFAULT_CLEAR_COLOR)
+}
+
 /// PostUpdate rebuild step: diff synthesized root against retained Masonry tree.
 pub fn rebuild_masonry_runtime(world: &mut World) {
     let Some(roots) = world
         .get_resource::<SynthesizedUiViews>()
-        .map(|views| views.roots.clone())
+        .map(|views| views.front().to_vec())
     else {
         return;
     };
 
     let next_root = compose_runtime_root(&roots);
+    let clear_color = root_clear_color(world);
 
     let Some(mut runtime) = world.get_non_send_resource_mut::<MasonryRuntime>() else {
         return;
     };
 
+    runtime.set_clear_color(clear_color);
     runtime.rebuild_root_view(next_root);
 }
 
+/// PostUpdate step: measure [`OffscreenMeasure`] roots that have no
+/// [`OffscreenMeasuredSize`] yet.
+///
+/// Each root is synthesized with [`synthesize_subtree`] and laid out by
+/// [`MasonryRuntime::measure_view`], so it never enters [`SynthesizedUiViews`].
+pub fn measure_offscreen_roots(world: &mut World) {
+    let pending = {
+        let mut query = world.query_filtered::<Entity, (
+            With<UiRoot>,
+            With<OffscreenMeasure>,
+            Without<OffscreenMeasuredSize>,
+        )>();
+        query.iter(world).collect::<Vec<_>>()
+    };
+    if pending.is_empty() || !world.contains_resource::<UiProjectorRegistry>() {
+        return;
+    }
+
+    let views = world.resource_scope(|world, registry: Mut<UiProjectorRegistry>| {
+        pending
+            .into_iter()
+            .map(|entity| (entity, synthesize_subtree(world, &registry, entity).0))
+            .collect::<Vec<_>>()
+    });
+
+    let Some(mut runtime) = world.get_non_send_resource_mut::<MasonryRuntime>() else {
+        return;
+    };
+    let sizes = views
+        .into_iter()
+        .map(|(entity, view)| (entity, runtime.measure_view(&view)))
+        .collect::<Vec<_>>();
+
+    for (entity, size) in sizes {
+        world.entity_mut(entity).insert(OffscreenMeasuredSize {
+            width: size.width,
+            height: size.height,
+        });
+    }
+}
+
+/// Laid-out width an ellipsis label was last truncated against (`None` before layout).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+struct EllipsisLayoutWidth(Option<f64>);
+
+fn laid_out_label_width(runtime: &MasonryRuntime, entity: Entity) -> Option<f64> {
+    runtime
+        .find_widget_id_for_entity_bits(entity.to_bits(), false)
+        .and_then(|id| runtime.render_root.get_widget(id))
+        .map(|widget| widget.ctx().border_box_size().width)
+}
+
+/// PostUpdate step after rebuild: truncate [`TextOverflow::Ellipsis`] labels to the box
+/// width Masonry just laid out, storing the result as [`EllipsizedText`] for the next
+/// synthesis pass.
+///
+/// Only labels whose text, computed style or locale changed are re-resolved, plus
+/// ellipsis labels Masonry laid out at a different width since their last pass.
+pub fn ellipsize_overflowing_labels(world: &mut World) {
+    let mut dirty = if world.is_resource_changed::<AppI18n>() {
+        let mut query = world.query_filtered::<Entity, With<UiLabel>>();
+        query.iter(world).collect::<Vec<_>>()
+    } else {
+        let mut query = world.query_filtered::<Entity, (
+            With<UiLabel>,
+            Or<(
+                Changed<UiLabel>,
+                Changed<ComputedStyle>,
+                Changed<LocalizeText>,
+            )>,
+        )>();
+        query.iter(world).collect::<Vec<_>>()
+    };
+
+    let tracked = {
+        let mut query = world.query_filtered::<(Entity, &EllipsisLayoutWidth), With<UiLabel>>();
+        query
+            .iter(world)
+            .map(|(entity, width)| (entity, width.0))
+            .collect::<Vec<_>>()
+    };
+    if !tracked.is_empty()
+        && let Some(runtime) = world.get_non_send_resource::<MasonryRuntime>()
+    {
+        dirty.extend(
+            tracked
+                .into_iter()
+                .filter(|(entity, width)| laid_out_label_width(runtime, *entity) != *width)
+                .map(|(entity, _)| entity),
+        );
+    }
+    if dirty.is_empty() {
+        return;
+    }
+    dirty.sort_unstable();
+    dirty.dedup();
+
+    let mut labels = Vec::new();
+    for entity in dirty {
+        let Some(label) = world.get::<UiLabel>(entity) else {
+            continue;
+        };
+        let style = resolve_style(world, entity);
+        match effective_label_overflow(label.overflow, &style) {
+            TextOverflow::Ellipsis { max_lines } => {
+                let font_stack = localized_font_stack(world, entity)
+                    .or(style.font_family)
+                    .unwrap_or_default();
+                labels.push((
+                    entity,
+                    resolve_localized_text(world, entity, &label.text),
+                    font_stack,
+                    style.text.size,
+                    max_lines,
+                ));
+            }
+            _ => {
+                world
+                    .entity_mut(entity)
+                    .remove::<(EllipsizedText, EllipsisLayoutWidth)>();
+            }
+        }
+    }
+    if labels.is_empty() {
+        return;
+    }
+
+    let Some(mut runtime) = world.get_non_send_resource_mut::<MasonryRuntime>() else {
+        return;
+    };
+
+    let mut updates = Vec::new();
+    for (entity, text, font_stack, size, max_lines) in labels {
+        let width = laid_out_label_width(&runtime, entity);
+        // Not laid out yet: keep whatever truncation we had and retry after layout.
+        let ellipsized =
+            width.map(|width| runtime.ellipsize_text(&text, &font_stack, size, width, max_lines));
+        updates.push((entity, width, ellipsized));
+    }
+
+    for (entity, width, ellipsized) in updates {
+        let mut entity_mut = world.entity_mut(entity);
+        if entity_mut.get::<EllipsisLayoutWidth>() != Some(&EllipsisLayoutWidth(width)) {
+            entity_mut.insert(EllipsisLayoutWidth(width));
+        }
+        let Some(ellipsized) = ellipsized else {
+            continue;
+        };
+        let current = entity_mut
+            .get::<EllipsizedText>()
+            .map(|text| text.0.as_str());
+        if current == ellipsized.as_deref() {
+            continue;
+        }
+
+        match ellipsized {
+            Some(text) => {
+                entity_mut.insert(EllipsizedText(text));
+            }
+            None => {
+                entity_mut.remove::<EllipsizedText>();
+            }
+        }
+    }
+}
+
 /// Last-stage paint pass: submit Masonry scene through Vello and present to the primary window.
 pub fn paint_masonry_ui(
     runtime: Option<NonSendMut<MasonryRuntime>>,
@@ -1,8 +1,9 @@
 use std::{
     any::TypeId,
     borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     io,
+    sync::{Arc, PoisonError, RwLock},
     time::Duration,
 };
 
@@ -26,9 +27,10 @@ use bevy_tween::{
     tween::{ComponentTween, TweenInterpolationValue, TweenPreviousValue},
 };
 use masonry::core::HasProperty;
+use masonry::kurbo::Size;
 use masonry::theme;
 use serde::{
-    Deserialize,
+    Deserialize, Serialize, Serializer,
     de::{
         self, EnumAccess, IntoDeserializer, VariantAccess, Visitor,
         value::{MapAccessDeserializer, SeqAccessDeserializer},
@@ -36,22 +38,42 @@ use serde::{
 };
 use xilem::{Color, style::Style as _};
 use xilem_masonry::masonry::parley::{
-    Alignment as ParleyTextAlign, FontFamily, GenericFamily, style::FontStack,
+    Alignment as ParleyTextAlign, FontFamily, GenericFamily,
+    style::{FontStack, FontStyle, FontWeight},
 };
+use xilem_masonry::masonry::peniko::color::{Srgb, parse_color};
 use xilem_masonry::masonry::properties::{
     Background, BorderColor, BorderWidth, BoxShadow, CornerRadius, LineBreaking, Padding,
+    types::Gradient as MasonryGradient,
 };
 use xilem_masonry::{
     WidgetView,
     view::{CrossAxisAlignment, Flex, Label, MainAxisAlignment, TextInput, sized_box, transformed},
 };
 
-use crate::UiEventQueue;
+use unic_langid::LanguageIdentifier;
+
+use crate::{
+    AppI18n, CornerRadiiPaint, Selected, TextOverflow, UiEventQueue, corner_radii_box,
+    label_font_style, size_bounds,
+};
 
 /// Marker component for CSS-like class names attached to an entity.
 #[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
 pub struct StyleClass(pub Vec<String>);
 
+/// Free-form string data matched by [`Selector::Attribute`], like HTML `data-*` attributes.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleAttributes(pub HashMap<String, String>);
+
+impl StyleAttributes {
+    #[must_use]
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+}
+
 /// Marker component for entities whose style cache needs recomputation.
 #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[component(storage = "SparseSet")]
@@ -96,10 +118,19 @@ pub struct LayoutStyle {
     pub padding: Option<f64>,
     pub gap: Option<f64>,
     pub corner_radius: Option<f64>,
+    /// Per-corner radii (top-left, top-right, bottom-right, bottom-left); overrides
+    /// `corner_radius` when set.
+    pub corner_radii: Option<[f64; 4]>,
     pub border_width: Option<f64>,
     pub justify_content: Option<JustifyContent>,
     pub align_items: Option<AlignItems>,
     pub scale: Option<f64>,
+    /// Transparent outer spacing around the styled box.
+    pub margin: Option<f64>,
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_width: Option<f64>,
+    pub max_height: Option<f64>,
 }
 
 /// Inline color style that can be attached to entities.
@@ -114,6 +145,10 @@ pub struct ColorStyle {
     pub pressed_bg: Option<Color>,
     pub pressed_text: Option<Color>,
     pub pressed_border: Option<Color>,
+    /// Placeholder text color for text inputs; defaults to dimmed `text`.
+    pub placeholder: Option<Color>,
+    /// Alpha multiplier (`0..=1`) for the box and text colors; defaults to opaque.
+    pub opacity: Option<f32>,
 }
 
 /// Inline text style that can be attached to entities.
@@ -121,10 +156,28 @@ pub struct ColorStyle {
 pub struct TextStyle {
     pub size: Option<f32>,
     pub text_align: Option<TextAlign>,
+    /// CSS-style font weight, `100..=900` (`400` normal, `700` bold).
+    pub weight: Option<u16>,
+    pub italic: Option<bool>,
+    pub line_break: Option<LineBreakMode>,
+}
+
+/// How label text that does not fit its box width breaks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum LineBreakMode {
+    /// Wrap onto as many lines as needed.
+    #[default]
+    WordWrap,
+    /// Keep a single line and cut it at the box edge.
+    Clip,
+    /// Keep a single line and let it paint past the box edge.
+    Overflow,
+    /// Keep a single line and truncate it with "…", like [`TextOverflow::ellipsis`].
+    Ellipsis,
 }
 
 /// Main-axis content distribution for flex layouts.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum JustifyContent {
     #[default]
     Start,
@@ -134,7 +187,7 @@ pub enum JustifyContent {
 }
 
 /// Cross-axis alignment for flex layouts.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum AlignItems {
     #[default]
     Start,
@@ -144,7 +197,7 @@ pub enum AlignItems {
 }
 
 /// Text alignment for label-like UI components.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum TextAlign {
     #[default]
     Start,
@@ -153,14 +206,130 @@ pub enum TextAlign {
 }
 
 /// Transition settings for style animation.
-#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
 pub struct StyleTransition {
     /// Duration in seconds.
     pub duration: f32,
+    /// Easing curve; [`StyleAnimationDefaults::ease`] when `None`.
+    #[serde(default)]
+    pub ease: Option<StyleEase>,
+}
+
+/// Linear gradient painted as a box background in place of the flat `bg` color.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Gradient {
+    /// Direction in degrees, CSS-style: `0` paints bottom to top, `90` left to right.
+    pub angle_deg: f32,
+    /// `(offset, color)` pairs with offsets in `0..=1`.
+    pub stops: Vec<(f32, Color)>,
+}
+
+impl Gradient {
+    fn to_background(&self, opacity: f32) -> Background {
+        let stops = self
+            .stops
+            .iter()
+            .map(|(offset, color)| (*offset, color.multiply_alpha(opacity)))
+            .collect::<Vec<_>>();
+        Background::Gradient(
+            MasonryGradient::new_linear(f64::from(self.angle_deg).to_radians())
+                .with_stops(stops.as_slice()),
+        )
+    }
+}
+
+/// Named easing curves that style transitions can select in stylesheets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum StyleEase {
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+}
+
+impl StyleEase {
+    #[must_use]
+    pub const fn to_ease_kind(self) -> EaseKind {
+        match self {
+            Self::Linear => EaseKind::Linear,
+            Self::QuadraticIn => EaseKind::QuadraticIn,
+            Self::QuadraticOut => EaseKind::QuadraticOut,
+            Self::QuadraticInOut => EaseKind::QuadraticInOut,
+            Self::CubicIn => EaseKind::CubicIn,
+            Self::CubicOut => EaseKind::CubicOut,
+            Self::CubicInOut => EaseKind::CubicInOut,
+            Self::SineIn => EaseKind::SineIn,
+            Self::SineOut => EaseKind::SineOut,
+            Self::SineInOut => EaseKind::SineInOut,
+            Self::ExponentialIn => EaseKind::ExponentialIn,
+            Self::ExponentialOut => EaseKind::ExponentialOut,
+            Self::ExponentialInOut => EaseKind::ExponentialInOut,
+            Self::BackIn => EaseKind::BackIn,
+            Self::BackOut => EaseKind::BackOut,
+            Self::BackInOut => EaseKind::BackInOut,
+            Self::BounceIn => EaseKind::BounceIn,
+            Self::BounceOut => EaseKind::BounceOut,
+            Self::BounceInOut => EaseKind::BounceInOut,
+            Self::ElasticIn => EaseKind::ElasticIn,
+            Self::ElasticOut => EaseKind::ElasticOut,
+            Self::ElasticInOut => EaseKind::ElasticInOut,
+        }
+    }
+}
+
+/// Global defaults for style transition tweens.
+///
+/// [`sync_style_targets`] uses `ease` for every transition that does not name
+/// its own [`StyleTransition::ease`]. Changing it affects tweens spawned
+/// afterwards; running tweens keep their curve.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct StyleAnimationDefaults {
+    pub ease: EaseKind,
+}
+
+impl Default for StyleAnimationDefaults {
+    fn default() -> Self {
+        Self {
+            ease: EaseKind::QuadraticInOut,
+        }
+    }
+}
+
+/// Runtime tuning for style recomputation.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StyleConfig {
+    /// Upper bound on entities [`sync_style_targets`] recomputes per run.
+    ///
+    /// `None` recomputes every [`StyleDirty`] entity immediately. With a cap, the
+    /// remaining entities stay dirty and are processed oldest-first on later
+    /// runs, so every entity is eventually recomputed.
+    pub max_recompute_per_frame: Option<usize>,
 }
 
+/// FIFO of dirty entities waiting for a capped recompute pass.
+#[derive(Resource, Debug, Default)]
+struct StyleRecomputeBacklog(VecDeque<Entity>);
+
 /// Cached resolved style used by projectors.
-#[derive(Component, Debug, Clone, Default, PartialEq)]
+#[derive(Component, Debug, Clone, PartialEq)]
 pub struct ComputedStyle {
     pub layout: ResolvedLayoutStyle,
     pub colors: ResolvedColorStyle,
@@ -168,6 +337,23 @@ pub struct ComputedStyle {
     pub font_family: Option<Vec<String>>,
     pub box_shadow: Option<BoxShadow>,
     pub transition: Option<StyleTransition>,
+    pub opacity: f32,
+    pub bg_gradient: Option<Gradient>,
+}
+
+impl Default for ComputedStyle {
+    fn default() -> Self {
+        Self {
+            layout: ResolvedLayoutStyle::default(),
+            colors: ResolvedColorStyle::default(),
+            text: ResolvedTextStyle::default(),
+            font_family: None,
+            box_shadow: None,
+            transition: None,
+            opacity: 1.0,
+            bg_gradient: None,
+        }
+    }
 }
 
 /// Interpolated color state currently rendered by projectors.
@@ -177,6 +363,7 @@ pub struct CurrentColorStyle {
     pub text: Option<Color>,
     pub border: Option<Color>,
     pub scale: f64,
+    pub opacity: f32,
 }
 
 /// Target color state derived from classes + inline style + pseudo state.
@@ -186,6 +373,7 @@ pub struct TargetColorStyle {
     pub text: Option<Color>,
     pub border: Option<Color>,
     pub scale: f64,
+    pub opacity: f32,
 }
 
 impl Default for CurrentColorStyle {
@@ -195,6 +383,7 @@ impl Default for CurrentColorStyle {
             text: None,
             border: None,
             scale: 1.0,
+            opacity: 1.0,
         }
     }
 }
@@ -206,6 +395,7 @@ impl Default for TargetColorStyle {
             text: None,
             border: None,
             scale: 1.0,
+            opacity: 1.0,
         }
     }
 }
@@ -215,10 +405,67 @@ impl Default for TargetColorStyle {
 struct StyleManagedTween;
 
 /// Pseudo classes supported by selectors.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum PseudoClass {
     Hovered,
     Pressed,
+    /// Matches entities carrying the [`crate::Selected`] marker.
+    Selected,
+    /// First entry of the parent's `Children`.
+    FirstChild,
+    /// Last entry of the parent's `Children`.
+    LastChild,
+    /// Position in the parent's `Children`, counted from 1 like CSS `:nth-child`.
+    NthChild(NthKind),
+}
+
+impl PseudoClass {
+    /// Whether matching depends on the entity's position among its siblings.
+    const fn is_structural(self) -> bool {
+        matches!(self, Self::FirstChild | Self::LastChild | Self::NthChild(_))
+    }
+}
+
+/// Argument of [`PseudoClass::NthChild`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum NthKind {
+    /// Positions 1, 3, 5, ...
+    Odd,
+    /// Positions 2, 4, 6, ...
+    Even,
+    /// Exactly this 1-based position.
+    Index(usize),
+}
+
+impl NthKind {
+    const fn matches(self, position: usize) -> bool {
+        match self {
+            Self::Odd => position % 2 == 1,
+            Self::Even => position.is_multiple_of(2),
+            Self::Index(index) => position == index,
+        }
+    }
+}
+
+/// CSS-like weight of a selector; rules with higher specificity win the cascade.
+///
+/// Compared field by field: any number of type selectors loses to one more class-level
+/// selector (class, pseudo class or attribute).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity {
+    pub classes: u32,
+    pub types: u32,
+}
+
+impl std::ops::Add for Specificity {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            classes: self.classes + other.classes,
+            types: self.types + other.types,
+        }
+    }
 }
 
 /// CSS-like selector AST for style rules.
@@ -233,6 +480,22 @@ pub enum Selector {
         ancestor: Box<Selector>,
         descendant: Box<Selector>,
     },
+    /// CSS `parent > child`: only the immediate `ChildOf` parent is checked against `parent`.
+    Child {
+        parent: Box<Selector>,
+        child: Box<Selector>,
+    },
+    /// CSS `before + after`: the sibling right before the entity in the parent's
+    /// `Children` must match `before`.
+    AdjacentSibling {
+        before: Box<Selector>,
+        after: Box<Selector>,
+    },
+    /// Matches [`StyleAttributes`]: `value: None` tests presence of `key`, `Some` equality.
+    Attribute {
+        key: String,
+        value: Option<String>,
+    },
 }
 
 impl Selector {
@@ -269,33 +532,202 @@ impl Selector {
         }
     }
 
+    #[must_use]
+    pub fn child(parent: Selector, child: Selector) -> Self {
+        Self::Child {
+            parent: Box::new(parent),
+            child: Box::new(child),
+        }
+    }
+
+    #[must_use]
+    pub fn adjacent_sibling(before: Selector, after: Selector) -> Self {
+        Self::AdjacentSibling {
+            before: Box::new(before),
+            after: Box::new(after),
+        }
+    }
+
+    /// Attribute selector matching entities whose [`StyleAttributes`] contain `key`.
+    #[must_use]
+    pub fn attribute(key: impl Into<String>) -> Self {
+        Self::Attribute {
+            key: key.into(),
+            value: None,
+        }
+    }
+
+    /// Attribute selector matching entities whose [`StyleAttributes`] map `key` to `value`.
+    #[must_use]
+    pub fn attribute_eq(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Attribute {
+            key: key.into(),
+            value: Some(value.into()),
+        }
+    }
+
+    /// Sum of the [`Specificity`] of every simple selector, across combinators.
+    #[must_use]
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            Selector::Type(_) | Selector::TypeName(_) => Specificity {
+                classes: 0,
+                types: 1,
+            },
+            Selector::Class(_) | Selector::PseudoClass(_) | Selector::Attribute { .. } => {
+                Specificity {
+                    classes: 1,
+                    types: 0,
+                }
+            }
+            Selector::And(selectors) => selectors
+                .iter()
+                .map(Self::specificity)
+                .fold(Specificity::default(), std::ops::Add::add),
+            Selector::Descendant {
+                ancestor: first,
+                descendant: second,
+            }
+            | Selector::Child {
+                parent: first,
+                child: second,
+            }
+            | Selector::AdjacentSibling {
+                before: first,
+                after: second,
+            } => first.specificity() + second.specificity(),
+        }
+    }
+
     #[must_use]
     fn contains_type(&self) -> bool {
         match self {
             Selector::Type(_) | Selector::TypeName(_) => true,
-            Selector::Class(_) | Selector::PseudoClass(_) => false,
+            Selector::Class(_) | Selector::PseudoClass(_) | Selector::Attribute { .. } => false,
             Selector::And(selectors) => selectors.iter().any(Self::contains_type),
             Selector::Descendant {
                 ancestor,
                 descendant,
             } => ancestor.contains_type() || descendant.contains_type(),
+            Selector::Child { parent, child } => parent.contains_type() || child.contains_type(),
+            Selector::AdjacentSibling { before, after } => {
+                before.contains_type() || after.contains_type()
+            }
+        }
+    }
+
+    #[must_use]
+    fn contains_structural(&self) -> bool {
+        match self {
+            Selector::PseudoClass(pseudo) => pseudo.is_structural(),
+            Selector::And(selectors) => selectors.iter().any(Self::contains_structural),
+            Selector::Descendant {
+                ancestor,
+                descendant,
+            } => ancestor.contains_structural() || descendant.contains_structural(),
+            Selector::Child { parent, child } => {
+                parent.contains_structural() || child.contains_structural()
+            }
+            Selector::AdjacentSibling { .. } => true,
+            Selector::Type(_)
+            | Selector::TypeName(_)
+            | Selector::Class(_)
+            | Selector::Attribute { .. } => false,
+        }
+    }
+
+    #[must_use]
+    fn contains_sibling(&self) -> bool {
+        match self {
+            Selector::AdjacentSibling { .. } => true,
+            Selector::And(selectors) => selectors.iter().any(Self::contains_sibling),
+            Selector::Descendant {
+                ancestor,
+                descendant,
+            } => ancestor.contains_sibling() || descendant.contains_sibling(),
+            Selector::Child { parent, child } => {
+                parent.contains_sibling() || child.contains_sibling()
+            }
+            Selector::Type(_)
+            | Selector::TypeName(_)
+            | Selector::Class(_)
+            | Selector::PseudoClass(_)
+            | Selector::Attribute { .. } => false,
         }
     }
 
     #[must_use]
     fn contains_descendant(&self) -> bool {
         match self {
-            Selector::Descendant { .. } => true,
+            // A parent change must re-dirty its children for `Child` too.
+            Selector::Descendant { .. } | Selector::Child { .. } => true,
             Selector::And(selectors) => selectors.iter().any(Self::contains_descendant),
+            Selector::AdjacentSibling { before, after } => {
+                before.contains_descendant() || after.contains_descendant()
+            }
             Selector::Type(_)
             | Selector::TypeName(_)
             | Selector::Class(_)
-            | Selector::PseudoClass(_) => false,
+            | Selector::PseudoClass(_)
+            | Selector::Attribute { .. } => false,
+        }
+    }
+
+    /// A class every element matched by this selector must carry, if any.
+    #[must_use]
+    fn required_class(&self) -> Option<&str> {
+        match self {
+            Selector::Class(name) => Some(name),
+            Selector::And(selectors) => selectors.iter().find_map(Self::required_class),
+            Selector::Descendant { descendant, .. } => descendant.required_class(),
+            Selector::Child { child, .. } => child.required_class(),
+            Selector::AdjacentSibling { after, .. } => after.required_class(),
+            Selector::Type(_)
+            | Selector::TypeName(_)
+            | Selector::PseudoClass(_)
+            | Selector::Attribute { .. } => None,
         }
     }
 }
 
+/// `!important` flags for [`LayoutStyle`] fields set by a rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayoutStyleImportant {
+    pub padding: bool,
+    pub gap: bool,
+    pub corner_radius: bool,
+    pub corner_radii: bool,
+    pub border_width: bool,
+    pub justify_content: bool,
+    pub align_items: bool,
+    pub scale: bool,
+    pub margin: bool,
+    pub min_width: bool,
+    pub min_height: bool,
+    pub max_width: bool,
+    pub max_height: bool,
+}
+
+/// `!important` flags for [`ColorStyle`] fields set by a rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColorStyleImportant {
+    pub bg: bool,
+    pub text: bool,
+    pub border: bool,
+    pub hover_bg: bool,
+    pub hover_text: bool,
+    pub hover_border: bool,
+    pub pressed_bg: bool,
+    pub pressed_text: bool,
+    pub pressed_border: bool,
+    pub placeholder: bool,
+    pub opacity: bool,
+}
+
 /// Style payload set by a matching rule.
+///
+/// A field flagged in `layout_important`/`colors_important` can only be overwritten by
+/// a later rule that flags the same field as important too.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct StyleSetter {
     pub layout: LayoutStyle,
@@ -304,13 +736,24 @@ pub struct StyleSetter {
     pub font_family: Option<Vec<String>>,
     pub box_shadow: Option<BoxShadow>,
     pub transition: Option<StyleTransition>,
+    /// Background gradient; takes precedence over `colors.bg`.
+    pub bg_gradient: Option<Gradient>,
+    pub layout_important: LayoutStyleImportant,
+    pub colors_important: ColorStyleImportant,
 }
 
 /// Style payload value that can be either an explicit value or a token reference.
+///
+/// Length fields (`padding`, `gap`, `corner_radius`, `border_width`, `margin`, the
+/// `min_*`/`max_*` sizes, `text.size`) additionally
+/// accept [`StyleValue::Rem`] and [`StyleValue::Em`] multipliers, resolved during style
+/// computation against [`StyleBaseFontSize`] and the entity's own text size respectively.
 #[derive(Debug, Clone, PartialEq)]
 pub enum StyleValue<T> {
     Value(T),
     Var(String),
+    Rem(f64),
+    Em(f64),
 }
 
 impl<T> StyleValue<T> {
@@ -323,6 +766,45 @@ impl<T> StyleValue<T> {
     pub fn var(name: impl Into<String>) -> Self {
         Self::Var(name.into())
     }
+
+    #[must_use]
+    pub fn rem(multiplier: f64) -> Self {
+        Self::Rem(multiplier)
+    }
+
+    #[must_use]
+    pub fn em(multiplier: f64) -> Self {
+        Self::Em(multiplier)
+    }
+}
+
+/// Root font size that `Rem(..)` style lengths are multiplied against.
+///
+/// Also used as the text size of entities whose style does not set `text.size`.
+/// Changing it re-resolves every styled entity.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct StyleBaseFontSize(pub f32);
+
+impl Default for StyleBaseFontSize {
+    fn default() -> Self {
+        Self(theme::TEXT_SIZE_NORMAL)
+    }
+}
+
+/// Reference sizes used to turn relative lengths into pixels.
+#[derive(Debug, Clone, Copy)]
+struct LengthUnits {
+    rem: f64,
+    em: f64,
+}
+
+impl LengthUnits {
+    fn from_base(base_font_size: f32) -> Self {
+        Self {
+            rem: f64::from(base_font_size),
+            em: f64::from(base_font_size),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -330,10 +812,16 @@ pub struct LayoutStyleValue {
     pub padding: Option<StyleValue<f64>>,
     pub gap: Option<StyleValue<f64>>,
     pub corner_radius: Option<StyleValue<f64>>,
+    pub corner_radii: Option<StyleValue<[f64; 4]>>,
     pub border_width: Option<StyleValue<f64>>,
     pub justify_content: Option<StyleValue<JustifyContent>>,
     pub align_items: Option<StyleValue<AlignItems>>,
     pub scale: Option<StyleValue<f64>>,
+    pub margin: Option<StyleValue<f64>>,
+    pub min_width: Option<StyleValue<f64>>,
+    pub min_height: Option<StyleValue<f64>>,
+    pub max_width: Option<StyleValue<f64>>,
+    pub max_height: Option<StyleValue<f64>>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -347,12 +835,17 @@ pub struct ColorStyleValue {
     pub pressed_bg: Option<StyleValue<Color>>,
     pub pressed_text: Option<StyleValue<Color>>,
     pub pressed_border: Option<StyleValue<Color>>,
+    pub placeholder: Option<StyleValue<Color>>,
+    pub opacity: Option<StyleValue<f32>>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct TextStyleValue {
     pub size: Option<StyleValue<f32>>,
     pub text_align: Option<StyleValue<TextAlign>>,
+    pub weight: Option<StyleValue<u16>>,
+    pub italic: Option<StyleValue<bool>>,
+    pub line_break: Option<StyleValue<LineBreakMode>>,
 }
 
 /// Token-aware style payload attached to stylesheet rules.
@@ -364,6 +857,9 @@ pub struct StyleSetterValue {
     pub font_family: Option<StyleValue<Vec<String>>>,
     pub box_shadow: Option<StyleValue<BoxShadow>>,
     pub transition: Option<StyleValue<StyleTransition>>,
+    pub bg_gradient: Option<StyleValue<Gradient>>,
+    pub layout_important: LayoutStyleImportant,
+    pub colors_important: ColorStyleImportant,
 }
 
 /// Token value stored in [`StyleSheet::tokens`].
@@ -382,10 +878,16 @@ impl From<LayoutStyle> for LayoutStyleValue {
             padding: value.padding.map(StyleValue::value),
             gap: value.gap.map(StyleValue::value),
             corner_radius: value.corner_radius.map(StyleValue::value),
+            corner_radii: value.corner_radii.map(StyleValue::value),
             border_width: value.border_width.map(StyleValue::value),
             justify_content: value.justify_content.map(StyleValue::value),
             align_items: value.align_items.map(StyleValue::value),
             scale: value.scale.map(StyleValue::value),
+            margin: value.margin.map(StyleValue::value),
+            min_width: value.min_width.map(StyleValue::value),
+            min_height: value.min_height.map(StyleValue::value),
+            max_width: value.max_width.map(StyleValue::value),
+            max_height: value.max_height.map(StyleValue::value),
         }
     }
 }
@@ -402,6 +904,8 @@ impl From<ColorStyle> for ColorStyleValue {
             pressed_bg: value.pressed_bg.map(StyleValue::value),
             pressed_text: value.pressed_text.map(StyleValue::value),
             pressed_border: value.pressed_border.map(StyleValue::value),
+            placeholder: value.placeholder.map(StyleValue::value),
+            opacity: value.opacity.map(StyleValue::value),
         }
     }
 }
@@ -411,6 +915,9 @@ impl From<TextStyle> for TextStyleValue {
         Self {
             size: value.size.map(StyleValue::value),
             text_align: value.text_align.map(StyleValue::value),
+            weight: value.weight.map(StyleValue::value),
+            italic: value.italic.map(StyleValue::value),
+            line_break: value.line_break.map(StyleValue::value),
         }
     }
 }
@@ -424,6 +931,9 @@ impl From<StyleSetter> for StyleSetterValue {
             font_family: value.font_family.map(StyleValue::value),
             box_shadow: value.box_shadow.map(StyleValue::value),
             transition: value.transition.map(StyleValue::value),
+            bg_gradient: value.bg_gradient.map(StyleValue::value),
+            layout_important: value.layout_important,
+            colors_important: value.colors_important,
         }
     }
 }
@@ -456,10 +966,198 @@ impl StyleRule {
 }
 
 /// Global class-based style table.
-#[derive(Resource, Asset, TypePath, Debug, Clone, Default)]
+#[derive(Resource, Asset, TypePath, Debug, Clone, Default, PartialEq)]
 pub struct StyleSheet {
     pub tokens: HashMap<String, TokenValue>,
     pub rules: Vec<StyleRule>,
+    /// Tokens whose color was resolved from [`StyleVariables`] at load, mapped to the
+    /// variable name.
+    pub variable_tokens: HashMap<String, String>,
+}
+
+/// Named colors stylesheets can reference as `Var("name")`, like CSS custom properties.
+///
+/// Resolved when a sheet is parsed or loaded: `Color(Var("accent"))` tokens and
+/// `BoxShadow` token colors must name a known variable, and a `colors` field
+/// `Var("accent")` that matches no token of its own sheet falls back to the variable.
+/// A leading `--` on a name is ignored, so `--accent` and `accent` are the same variable.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct StyleVariables(pub HashMap<String, Color>);
+
+impl StyleVariables {
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.insert(name, color);
+        self
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, color: Color) {
+        let name = name.into();
+        self.0.insert(style_variable_name(&name).to_string(), color);
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.0.get(style_variable_name(name)).copied()
+    }
+}
+
+fn style_variable_name(name: &str) -> &str {
+    let name = name.trim();
+    name.strip_prefix("--").unwrap_or(name)
+}
+
+/// Copy of [`StyleVariables`] that [`StyleSheetRonLoader`] reads off the main world.
+///
+/// Kept current by [`sync_style_variables`].
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StyleVariablesSnapshot(Arc<RwLock<StyleVariables>>);
+
+impl StyleVariablesSnapshot {
+    fn get(&self) -> StyleVariables {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn set(&self, variables: StyleVariables) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = variables;
+    }
+}
+
+/// Apply [`StyleVariables`] changes to loaded stylesheets.
+///
+/// Re-resolves every variable-bound token (see [`StyleSheet::variable_tokens`]) in the
+/// runtime [`StyleSheet`] and in the stored tiers, variants and locale layers, so a later
+/// merge does not bring back the old colors. Changing the runtime sheet lets
+/// [`mark_style_dirty`] restyle the entities. Also publishes the variables to the
+/// stylesheet asset loader.
+pub fn sync_style_variables(world: &mut World) {
+    if !world.is_resource_changed::<StyleVariables>() {
+        return;
+    }
+    let variables = world.resource::<StyleVariables>().clone();
+
+    if let Some(mut sheet) = world.get_resource_mut::<StyleSheet>()
+        && rebind_variable_tokens(sheet.bypass_change_detection(), &variables)
+    {
+        sheet.set_changed();
+    }
+    if let Some(mut base) = world.get_resource_mut::<BaseStyleSheet>() {
+        rebind_variable_tokens(&mut base.0, &variables);
+    }
+    if let Some(mut active) = world.get_resource_mut::<ActiveStyleSheet>() {
+        rebind_variable_tokens(&mut active.0, &variables);
+    }
+    if let Some(mut registered) = world.get_resource_mut::<RegisteredStyleVariants>() {
+        for sheet in registered.variants.values_mut() {
+            rebind_variable_tokens(sheet, &variables);
+        }
+    }
+    if let Some(mut overrides) = world.get_resource_mut::<LocaleStyleOverrides>() {
+        let mut changed = false;
+        for sheet in overrides.bypass_change_detection().by_locale.values_mut() {
+            changed |= rebind_variable_tokens(sheet, &variables);
+        }
+        if changed {
+            overrides.set_changed();
+        }
+    }
+
+    world
+        .get_resource_or_init::<StyleVariablesSnapshot>()
+        .set(variables);
+}
+
+/// Point `sheet`'s variable-bound tokens at the current variable colors.
+///
+/// Tokens whose variable was removed keep their last color. Returns whether a token changed.
+fn rebind_variable_tokens(sheet: &mut StyleSheet, variables: &StyleVariables) -> bool {
+    let mut changed = false;
+    for (name, variable) in &sheet.variable_tokens {
+        let Some(color) = variables.get(variable) else {
+            continue;
+        };
+        match sheet.tokens.get_mut(name) {
+            Some(TokenValue::Color(current)) if *current != color => {
+                *current = color;
+                changed = true;
+            }
+            Some(TokenValue::BoxShadow(shadow)) if shadow.color != color => {
+                shadow.color = color;
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+    changed
+}
+
+/// Precomputed candidate lookup for the rules of the [`StyleSheet`] resource.
+///
+/// Rules whose selector requires a class are filed under that class; the rest
+/// (type, pseudo-class, and class-less descendant selectors) are kept in a
+/// separate list. Resolution then runs the full selector match only on the rules
+/// that can possibly apply. [`sync_style_rule_index`] rebuilds it whenever the
+/// sheet changes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StyleRuleIndex {
+    by_class: HashMap<String, Vec<usize>>,
+    unindexed: Vec<usize>,
+    rule_count: usize,
+}
+
+impl StyleRuleIndex {
+    #[must_use]
+    pub fn build(sheet: &StyleSheet) -> Self {
+        let mut index = Self {
+            rule_count: sheet.rules.len(),
+            ..Self::default()
+        };
+        for (rule_index, rule) in sheet.rules.iter().enumerate() {
+            match rule.selector.required_class() {
+                Some(class_name) => index
+                    .by_class
+                    .entry(class_name.to_string())
+                    .or_default()
+                    .push(rule_index),
+                None => index.unindexed.push(rule_index),
+            }
+        }
+        index
+    }
+
+    /// Number of rules in the sheet this index was built from.
+    #[must_use]
+    pub fn rule_count(&self) -> usize {
+        self.rule_count
+    }
+
+    /// Indices of rules that may match an element with `class_names`, in sheet order.
+    ///
+    /// Every bucket is built in sheet order, so this lazily merges the class-less
+    /// bucket with the buckets of `class_names` without allocating.
+    pub fn candidates<'a>(
+        &'a self,
+        class_names: &'a [&'a str],
+    ) -> impl Iterator<Item = usize> + 'a {
+        let mut next_rule = 0;
+        std::iter::from_fn(move || {
+            let first_from = |rules: &[usize]| {
+                let at = rules.partition_point(|rule_index| *rule_index < next_rule);
+                rules.get(at).copied()
+            };
+            let rule_index = class_names
+                .iter()
+                .filter_map(|class_name| self.by_class.get(*class_name))
+                .filter_map(|rules| first_from(rules))
+                .chain(first_from(&self.unindexed))
+                .min()?;
+            next_rule = rule_index + 1;
+            Some(rule_index)
+        })
+    }
 }
 
 /// Baseline stylesheet tier populated from the embedded built-in theme.
@@ -507,6 +1205,34 @@ pub struct ActiveStyleVariant(pub Option<String>);
 #[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
 pub struct AppliedStyleVariant(pub Option<String>);
 
+/// Per-locale stylesheet override layers.
+///
+/// The layer registered for [`AppI18n::active_locale`] is applied on top of the
+/// runtime [`StyleSheet`]: its rules cascade after the sheet rules and its
+/// tokens shadow sheet tokens of the same name. Switching the active locale
+/// marks all styled entities dirty.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LocaleStyleOverrides {
+    by_locale: HashMap<LanguageIdentifier, StyleSheet>,
+}
+
+impl LocaleStyleOverrides {
+    /// Register (or replace) the override layer for `locale`.
+    pub fn insert(&mut self, locale: LanguageIdentifier, sheet: StyleSheet) {
+        self.by_locale.insert(locale, sheet);
+    }
+
+    /// Remove the override layer for `locale`, returning it when present.
+    pub fn remove(&mut self, locale: &LanguageIdentifier) -> Option<StyleSheet> {
+        self.by_locale.remove(locale)
+    }
+
+    #[must_use]
+    pub fn get(&self, locale: &LanguageIdentifier) -> Option<&StyleSheet> {
+        self.by_locale.get(locale)
+    }
+}
+
 /// Name-to-component-type map used by selector type tags loaded from RON assets.
 #[derive(Resource, Debug, Clone, Default)]
 pub struct StyleTypeRegistry {
@@ -533,6 +1259,38 @@ impl StyleTypeRegistry {
 }
 
 impl StyleSheet {
+    /// Insert a token, recording or clearing its [`Self::variable_tokens`] entry.
+    fn insert_token(&mut self, name: String, token: TokenValue, variable: Option<String>) {
+        match variable {
+            Some(variable) => {
+                self.variable_tokens.insert(name.clone(), variable);
+            }
+            None => {
+                self.variable_tokens.remove(&name);
+            }
+        }
+        self.tokens.insert(name, token);
+    }
+
+    fn retain_tokens(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.tokens.retain(|name, _| keep(name));
+        self.variable_tokens.retain(|name, _| keep(name));
+    }
+
+    /// Serialize the sheet into the RON shape [`parse_stylesheet_ron`] accepts.
+    ///
+    /// Colors are written as `Hex("#RRGGBB")`, or `#RRGGBBAA` when not opaque, and tokens
+    /// are sorted by name so the output is stable. Tokens bound to [`StyleVariables`] are
+    /// written as `Var(..)` references. Parsing the result with the same variables yields an
+    /// equal sheet.
+    ///
+    /// Fails for content RON cannot express: [`Selector::Type`] built from a Rust type, and
+    /// token references in fields that only take literals (`justify_content`, `align_items`,
+    /// and every text field but `size`).
+    pub fn to_ron_string(&self) -> io::Result<String> {
+        stylesheet_to_ron(self)
+    }
+
     #[must_use]
     pub fn with_rule(mut self, rule: StyleRule) -> Self {
         self.rules.push(rule);
@@ -580,8 +1338,13 @@ impl StyleSheet {
 
     #[must_use]
     pub fn get_class(&self, class_name: &str) -> Option<StyleSetter> {
-        self.get_class_values(class_name)
-            .map(|setter| resolve_setter_values(setter, &self.tokens))
+        self.get_class_values(class_name).map(|setter| {
+            resolve_setter_values(
+                setter,
+                &StyleTokens::sheet(&self.tokens),
+                StyleBaseFontSize::default().0,
+            )
+        })
     }
 
     #[must_use]
@@ -606,6 +1369,18 @@ impl StyleSheet {
             .iter()
             .any(|rule| rule.selector.contains_descendant())
     }
+
+    fn has_sibling_selectors(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.selector.contains_sibling())
+    }
+
+    fn has_structural_selectors(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.selector.contains_structural())
+    }
 }
 
 fn upsert_rule_by_selector(sheet: &mut StyleSheet, incoming: StyleRule) {
@@ -626,9 +1401,10 @@ fn upsert_rules_by_selector(sheet: &mut StyleSheet, incoming: Vec<StyleRule>) {
     }
 }
 
-fn merge_sheet_inplace(sheet: &mut StyleSheet, incoming: StyleSheet) {
+fn merge_sheet_inplace(sheet: &mut StyleSheet, mut incoming: StyleSheet) {
     for (name, token) in incoming.tokens {
-        sheet.tokens.insert(name, token);
+        let variable = incoming.variable_tokens.remove(&name);
+        sheet.insert_token(name, token, variable);
     }
     upsert_rules_by_selector(sheet, incoming.rules);
 }
@@ -667,12 +1443,13 @@ fn apply_base_stylesheet(world: &mut World, new_base: StyleSheet) {
             || active_selectors.contains(&rule.selector)
     });
     runtime_sheet
-        .tokens
-        .retain(|name, _| !previous_base_tokens.contains(name) || active_tokens.contains(name));
+        .retain_tokens(|name| !previous_base_tokens.contains(name) || active_tokens.contains(name));
 
+    let mut base_variable_tokens = new_base.variable_tokens;
     for (name, token) in new_base.tokens {
         if !active_tokens.contains(name.as_str()) {
-            runtime_sheet.tokens.insert(name, token);
+            let variable = base_variable_tokens.remove(&name);
+            runtime_sheet.insert_token(name, token, variable);
         }
     }
 
@@ -725,6 +1502,7 @@ pub fn register_builtin_style_type_aliases(world: &mut World) {
     registry.register_type_aliases::<UiToast>();
     registry.register_type_aliases::<UiDatePicker>();
     registry.register_type_aliases::<UiDatePickerPanel>();
+    registry.register_type_aliases::<UiVirtualGrid>();
 }
 
 /// Set the active stylesheet asset path used for loading + hot-reload.
@@ -746,29 +1524,77 @@ pub fn set_active_stylesheet_asset_path(world: &mut World, asset_path: impl Into
 }
 
 /// Parse stylesheet RON text into a runtime [`StyleSheet`].
+///
+/// This is the parser behind [`StyleSheetRonLoader`], for apps that embed theme text
+/// instead of loading it as an asset. Syntax errors, invalid colors and malformed
+/// selectors (empty names, an empty `And`, `NthChild(Index(0))`) come back as
+/// [`io::ErrorKind::InvalidData`] errors naming the problem.
 pub fn parse_stylesheet_ron(ron_text: &str) -> io::Result<StyleSheet> {
-    stylesheet_from_ron_bytes(ron_text.as_bytes())
+    parse_stylesheet_ron_with_variables(ron_text, &StyleVariables::default())
 }
 
-/// Parse and apply an active stylesheet from embedded RON text.
+/// Like [`parse_stylesheet_ron`], resolving `Var(..)` colors through `variables`.
 ///
-/// This updates [`ActiveStyleSheet`] and overlays the parsed rules/tokens onto
-/// the runtime [`StyleSheet`] as the active tier (same precedence as file-based
-/// active stylesheets), without requiring filesystem asset loading.
-pub fn apply_active_stylesheet_ron(world: &mut World, ron_text: &str) -> io::Result<()> {
-    let sheet = parse_stylesheet_ron(ron_text)?;
+/// An unknown variable in a token color is an [`io::ErrorKind::InvalidData`] error.
+pub fn parse_stylesheet_ron_with_variables(
+    ron_text: &str,
+    variables: &StyleVariables,
+) -> io::Result<StyleSheet> {
+    let parsed: StyleSheetDef = ron::de::from_str(ron_text).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to parse stylesheet RON: {error}"),
+        )
+    })?;
+
+    stylesheet_from_def(parsed, variables)
+}
+
+fn parse_stylesheet_ron_in_world(world: &World, ron_text: &str) -> io::Result<StyleSheet> {
+    match world.get_resource::<StyleVariables>() {
+        Some(variables) => parse_stylesheet_ron_with_variables(ron_text, variables),
+        None => parse_stylesheet_ron(ron_text),
+    }
+}
+
+/// Parse and apply an active stylesheet from embedded RON text.
+///
+/// This updates [`ActiveStyleSheet`] and overlays the parsed rules/tokens onto
+/// the runtime [`StyleSheet`] as the active tier (same precedence as file-based
+/// active stylesheets), without requiring filesystem asset loading.
+pub fn apply_active_stylesheet_ron(world: &mut World, ron_text: &str) -> io::Result<()> {
+    let sheet = parse_stylesheet_ron_in_world(world, ron_text)?;
     apply_active_stylesheet(world, sheet);
     Ok(())
 }
 
+/// Parse stylesheet RON text and register it as the override layer for `locale`.
+pub fn register_locale_stylesheet_ron(
+    world: &mut World,
+    locale: LanguageIdentifier,
+    ron_text: &str,
+) -> io::Result<()> {
+    let sheet = parse_stylesheet_ron_in_world(world, ron_text)?;
+    world
+        .get_resource_or_insert_with(LocaleStyleOverrides::default)
+        .insert(locale, sheet);
+    Ok(())
+}
+
 /// Parse a multi-variant stylesheet bundle RON into registered variants.
 pub fn parse_stylesheet_variants_ron(ron_text: &str) -> io::Result<RegisteredStyleVariants> {
-    stylesheet_variants_from_ron_bytes(ron_text.as_bytes())
+    stylesheet_variants_from_ron_bytes(ron_text.as_bytes(), &StyleVariables::default())
 }
 
 /// Parse + register named stylesheet variants in the world.
 pub fn register_stylesheet_variants_ron(world: &mut World, ron_text: &str) -> io::Result<()> {
-    let variants = parse_stylesheet_variants_ron(ron_text)?;
+    let variants = stylesheet_variants_from_ron_bytes(
+        ron_text.as_bytes(),
+        &world
+            .get_resource::<StyleVariables>()
+            .cloned()
+            .unwrap_or_default(),
+    )?;
     world.insert_resource(variants);
     Ok(())
 }
@@ -876,7 +1702,7 @@ pub fn register_embedded_fluent_theme_variants(world: &mut World) -> io::Result<
 /// Runtime insertion preserves active stylesheet precedence by skipping selectors
 /// and tokens currently owned by the active stylesheet asset.
 pub fn merge_base_stylesheet_ron(world: &mut World, ron_text: &str) -> io::Result<()> {
-    let parsed = parse_stylesheet_ron(ron_text)?;
+    let parsed = parse_stylesheet_ron_in_world(world, ron_text)?;
 
     world.init_resource::<BaseStyleSheet>();
     world.init_resource::<StyleSheet>();
@@ -884,11 +1710,16 @@ pub fn merge_base_stylesheet_ron(world: &mut World, ron_text: &str) -> io::Resul
 
     let incoming_tokens = parsed.tokens;
     let incoming_rules = parsed.rules;
+    let incoming_variable_tokens = parsed.variable_tokens;
 
     {
         let mut base_sheet = world.resource_mut::<BaseStyleSheet>();
         for (name, token) in &incoming_tokens {
-            base_sheet.0.tokens.insert(name.clone(), token.clone());
+            base_sheet.0.insert_token(
+                name.clone(),
+                token.clone(),
+                incoming_variable_tokens.get(name).cloned(),
+            );
         }
         upsert_rules_by_selector(&mut base_sheet.0, incoming_rules.clone());
     }
@@ -911,7 +1742,8 @@ pub fn merge_base_stylesheet_ron(world: &mut World, ron_text: &str) -> io::Resul
         let mut runtime_sheet = world.resource_mut::<StyleSheet>();
         for (name, token) in incoming_tokens {
             if !active_tokens.contains(name.as_str()) {
-                runtime_sheet.tokens.insert(name, token);
+                let variable = incoming_variable_tokens.get(&name).cloned();
+                runtime_sheet.insert_token(name, token, variable);
             }
         }
         upsert_rules_by_selector(&mut runtime_sheet, filtered);
@@ -987,11 +1819,13 @@ fn apply_active_stylesheet_impl(
     runtime_sheet
         .rules
         .retain(|rule| !previous_asset_selectors.contains(&rule.selector));
-    runtime_sheet
-        .tokens
-        .retain(|name, _| !previous_asset_token_names.contains(name));
+    runtime_sheet.retain_tokens(|name| !previous_asset_token_names.contains(name));
     runtime_sheet.rules.extend(loaded_stylesheet.rules);
-    runtime_sheet.tokens.extend(loaded_stylesheet.tokens);
+    let mut loaded_variable_tokens = loaded_stylesheet.variable_tokens;
+    for (name, token) in loaded_stylesheet.tokens {
+        let variable = loaded_variable_tokens.remove(&name);
+        runtime_sheet.insert_token(name, token, variable);
+    }
 
     world.resource_mut::<ActiveStyleSheetSelectors>().0 = incoming_selectors;
     world.resource_mut::<ActiveStyleSheetTokenNames>().0 = incoming_token_names;
@@ -1066,10 +1900,34 @@ pub struct ResolvedLayoutStyle {
     pub padding: f64,
     pub gap: f64,
     pub corner_radius: f64,
+    pub corner_radii: Option<[f64; 4]>,
     pub border_width: f64,
     pub justify_content: JustifyContent,
     pub align_items: AlignItems,
     pub scale: f64,
+    pub margin: f64,
+    /// Size bounds of the styled box; `None` leaves the axis to the content.
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_width: Option<f64>,
+    pub max_height: Option<f64>,
+}
+
+impl ResolvedLayoutStyle {
+    /// Radii in top-left, top-right, bottom-right, bottom-left order.
+    #[must_use]
+    pub fn corner_radii(&self) -> [f64; 4] {
+        self.corner_radii.unwrap_or([self.corner_radius; 4])
+    }
+
+    /// Radius handed to Masonry's uniform [`CornerRadius`] property.
+    ///
+    /// Masonry rounds every corner of a box alike, so per-corner radii collapse to the
+    /// largest one there.
+    #[must_use]
+    pub fn box_corner_radius(&self) -> f64 {
+        self.corner_radii().into_iter().fold(0.0, f64::max)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
@@ -1077,12 +1935,17 @@ pub struct ResolvedColorStyle {
     pub bg: Option<Color>,
     pub text: Option<Color>,
     pub border: Option<Color>,
+    pub placeholder: Option<Color>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ResolvedTextStyle {
     pub size: f32,
     pub text_align: TextAlign,
+    /// Font weight clamped to `100..=900`; `None` keeps the widget's default weight.
+    pub weight: Option<u16>,
+    pub italic: bool,
+    pub line_break: LineBreakMode,
 }
 
 impl Default for ResolvedTextStyle {
@@ -1090,11 +1953,32 @@ impl Default for ResolvedTextStyle {
         Self {
             size: theme::TEXT_SIZE_NORMAL,
             text_align: TextAlign::Start,
+            weight: None,
+            italic: false,
+            line_break: LineBreakMode::WordWrap,
         }
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq)]
+impl ResolvedTextStyle {
+    /// The parley weight for [`weight`](Self::weight), if set.
+    #[must_use]
+    pub fn font_weight(&self) -> Option<FontWeight> {
+        self.weight.map(|weight| FontWeight::new(f32::from(weight)))
+    }
+
+    /// The parley style for [`italic`](Self::italic).
+    #[must_use]
+    pub fn font_style(&self) -> FontStyle {
+        if self.italic {
+            FontStyle::Italic
+        } else {
+            FontStyle::Normal
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ResolvedStyle {
     pub layout: ResolvedLayoutStyle,
     pub colors: ResolvedColorStyle,
@@ -1102,6 +1986,74 @@ pub struct ResolvedStyle {
     pub font_family: Option<Vec<String>>,
     pub box_shadow: Option<BoxShadow>,
     pub transition: Option<StyleTransition>,
+    /// Alpha multiplier applied to background, border, and text colors when rendering.
+    pub opacity: f32,
+    /// Background gradient painted instead of `colors.bg` when set.
+    pub bg_gradient: Option<Gradient>,
+}
+
+impl Default for ResolvedStyle {
+    fn default() -> Self {
+        Self {
+            layout: ResolvedLayoutStyle::default(),
+            colors: ResolvedColorStyle::default(),
+            text: ResolvedTextStyle::default(),
+            font_family: None,
+            box_shadow: None,
+            transition: None,
+            opacity: 1.0,
+            bg_gradient: None,
+        }
+    }
+}
+
+impl ResolvedStyle {
+    /// `color` with its alpha multiplied by the resolved [`opacity`](Self::opacity).
+    #[must_use]
+    pub fn faded(&self, color: Color) -> Color {
+        color.multiply_alpha(self.opacity.clamp(0.0, 1.0))
+    }
+
+    /// The box background: [`bg_gradient`](Self::bg_gradient) when set, else `colors.bg`.
+    #[must_use]
+    pub fn background(&self) -> Background {
+        match &self.bg_gradient {
+            Some(gradient) => gradient.to_background(self.opacity.clamp(0.0, 1.0)),
+            None => Background::Color(self.faded(self.colors.bg.unwrap_or(Color::TRANSPARENT))),
+        }
+    }
+
+    /// Box decoration for [`corner_radii_box`](crate::corner_radii_box), present only
+    /// when `layout.corner_radii` is set.
+    #[must_use]
+    pub fn corner_radii_paint(&self) -> Option<CornerRadiiPaint> {
+        self.layout.corner_radii.map(|radii| CornerRadiiPaint {
+            radii,
+            background: self.background(),
+            border_color: self.faded(self.colors.border.unwrap_or(Color::TRANSPARENT)),
+            border_width: self.layout.border_width,
+            box_shadow: self.box_shadow,
+        })
+    }
+
+    /// Border color, background and shadow handed to Masonry's box properties.
+    ///
+    /// All transparent when [`Self::corner_radii_paint`] paints them instead, so the
+    /// uniformly rounded box does not cover the per-corner one.
+    fn box_properties(&self) -> (Color, Background, BoxShadow) {
+        if self.layout.corner_radii.is_some() {
+            return (
+                Color::TRANSPARENT,
+                Background::Color(Color::TRANSPARENT),
+                BoxShadow::default(),
+            );
+        }
+        (
+            self.faded(self.colors.border.unwrap_or(Color::TRANSPARENT)),
+            self.background(),
+            self.box_shadow.unwrap_or_default(),
+        )
+    }
 }
 
 /// Structural interaction events emitted by ECS-backed widgets.
@@ -1113,58 +2065,177 @@ pub enum UiInteractionEvent {
     PointerReleased,
 }
 
-fn merge_layout_values(dst: &mut LayoutStyleValue, src: &LayoutStyleValue) {
-    if src.padding.is_some() {
-        dst.padding = src.padding.clone();
-    }
-    if src.gap.is_some() {
-        dst.gap = src.gap.clone();
-    }
-    if src.corner_radius.is_some() {
-        dst.corner_radius = src.corner_radius.clone();
-    }
-    if src.border_width.is_some() {
-        dst.border_width = src.border_width.clone();
-    }
-    if src.justify_content.is_some() {
-        dst.justify_content = src.justify_content.clone();
-    }
-    if src.align_items.is_some() {
-        dst.align_items = src.align_items.clone();
-    }
-    if src.scale.is_some() {
-        dst.scale = src.scale.clone();
+/// Take `src` when set, unless `dst` already holds an important value and `src` does not.
+fn merge_field<T: Clone>(
+    dst: &mut Option<T>,
+    dst_important: &mut bool,
+    src: &Option<T>,
+    src_important: bool,
+) {
+    if src.is_some() && (src_important || !*dst_important) {
+        dst.clone_from(src);
+        *dst_important |= src_important;
     }
 }
 
-fn merge_colors_values(dst: &mut ColorStyleValue, src: &ColorStyleValue) {
-    if src.bg.is_some() {
-        dst.bg = src.bg.clone();
-    }
-    if src.text.is_some() {
-        dst.text = src.text.clone();
-    }
-    if src.border.is_some() {
-        dst.border = src.border.clone();
-    }
-    if src.hover_bg.is_some() {
-        dst.hover_bg = src.hover_bg.clone();
-    }
-    if src.hover_text.is_some() {
-        dst.hover_text = src.hover_text.clone();
-    }
-    if src.hover_border.is_some() {
-        dst.hover_border = src.hover_border.clone();
-    }
-    if src.pressed_bg.is_some() {
-        dst.pressed_bg = src.pressed_bg.clone();
-    }
-    if src.pressed_text.is_some() {
-        dst.pressed_text = src.pressed_text.clone();
-    }
-    if src.pressed_border.is_some() {
-        dst.pressed_border = src.pressed_border.clone();
-    }
+fn merge_layout_values(
+    dst: &mut LayoutStyleValue,
+    dst_important: &mut LayoutStyleImportant,
+    src: &LayoutStyleValue,
+    src_important: &LayoutStyleImportant,
+) {
+    merge_field(
+        &mut dst.padding,
+        &mut dst_important.padding,
+        &src.padding,
+        src_important.padding,
+    );
+    merge_field(
+        &mut dst.gap,
+        &mut dst_important.gap,
+        &src.gap,
+        src_important.gap,
+    );
+    merge_field(
+        &mut dst.corner_radius,
+        &mut dst_important.corner_radius,
+        &src.corner_radius,
+        src_important.corner_radius,
+    );
+    merge_field(
+        &mut dst.corner_radii,
+        &mut dst_important.corner_radii,
+        &src.corner_radii,
+        src_important.corner_radii,
+    );
+    merge_field(
+        &mut dst.border_width,
+        &mut dst_important.border_width,
+        &src.border_width,
+        src_important.border_width,
+    );
+    merge_field(
+        &mut dst.justify_content,
+        &mut dst_important.justify_content,
+        &src.justify_content,
+        src_important.justify_content,
+    );
+    merge_field(
+        &mut dst.align_items,
+        &mut dst_important.align_items,
+        &src.align_items,
+        src_important.align_items,
+    );
+    merge_field(
+        &mut dst.scale,
+        &mut dst_important.scale,
+        &src.scale,
+        src_important.scale,
+    );
+    merge_field(
+        &mut dst.margin,
+        &mut dst_important.margin,
+        &src.margin,
+        src_important.margin,
+    );
+    merge_field(
+        &mut dst.min_width,
+        &mut dst_important.min_width,
+        &src.min_width,
+        src_important.min_width,
+    );
+    merge_field(
+        &mut dst.min_height,
+        &mut dst_important.min_height,
+        &src.min_height,
+        src_important.min_height,
+    );
+    merge_field(
+        &mut dst.max_width,
+        &mut dst_important.max_width,
+        &src.max_width,
+        src_important.max_width,
+    );
+    merge_field(
+        &mut dst.max_height,
+        &mut dst_important.max_height,
+        &src.max_height,
+        src_important.max_height,
+    );
+}
+
+fn merge_colors_values(
+    dst: &mut ColorStyleValue,
+    dst_important: &mut ColorStyleImportant,
+    src: &ColorStyleValue,
+    src_important: &ColorStyleImportant,
+) {
+    merge_field(
+        &mut dst.bg,
+        &mut dst_important.bg,
+        &src.bg,
+        src_important.bg,
+    );
+    merge_field(
+        &mut dst.text,
+        &mut dst_important.text,
+        &src.text,
+        src_important.text,
+    );
+    merge_field(
+        &mut dst.border,
+        &mut dst_important.border,
+        &src.border,
+        src_important.border,
+    );
+    merge_field(
+        &mut dst.hover_bg,
+        &mut dst_important.hover_bg,
+        &src.hover_bg,
+        src_important.hover_bg,
+    );
+    merge_field(
+        &mut dst.hover_text,
+        &mut dst_important.hover_text,
+        &src.hover_text,
+        src_important.hover_text,
+    );
+    merge_field(
+        &mut dst.hover_border,
+        &mut dst_important.hover_border,
+        &src.hover_border,
+        src_important.hover_border,
+    );
+    merge_field(
+        &mut dst.pressed_bg,
+        &mut dst_important.pressed_bg,
+        &src.pressed_bg,
+        src_important.pressed_bg,
+    );
+    merge_field(
+        &mut dst.pressed_text,
+        &mut dst_important.pressed_text,
+        &src.pressed_text,
+        src_important.pressed_text,
+    );
+    merge_field(
+        &mut dst.pressed_border,
+        &mut dst_important.pressed_border,
+        &src.pressed_border,
+        src_important.pressed_border,
+    );
+    merge_field(
+        &mut dst.placeholder,
+        &mut dst_important.placeholder,
+        &src.placeholder,
+        src_important.placeholder,
+    );
+    merge_field(
+        &mut dst.opacity,
+        &mut dst_important.opacity,
+        &src.opacity,
+        src_important.opacity,
+    );
 }
 
 fn merge_text_values(dst: &mut TextStyleValue, src: &TextStyleValue) {
@@ -1174,11 +2245,30 @@ fn merge_text_values(dst: &mut TextStyleValue, src: &TextStyleValue) {
     if src.text_align.is_some() {
         dst.text_align = src.text_align.clone();
     }
+    if src.weight.is_some() {
+        dst.weight = src.weight.clone();
+    }
+    if src.italic.is_some() {
+        dst.italic = src.italic.clone();
+    }
+    if src.line_break.is_some() {
+        dst.line_break = src.line_break.clone();
+    }
 }
 
 fn merge_value_setter(dst: &mut StyleSetterValue, setter: &StyleSetterValue) {
-    merge_layout_values(&mut dst.layout, &setter.layout);
-    merge_colors_values(&mut dst.colors, &setter.colors);
+    merge_layout_values(
+        &mut dst.layout,
+        &mut dst.layout_important,
+        &setter.layout,
+        &setter.layout_important,
+    );
+    merge_colors_values(
+        &mut dst.colors,
+        &mut dst.colors_important,
+        &setter.colors,
+        &setter.colors_important,
+    );
     merge_text_values(&mut dst.text, &setter.text);
     if setter.font_family.is_some() {
         dst.font_family = setter.font_family.clone();
@@ -1189,6 +2279,9 @@ fn merge_value_setter(dst: &mut StyleSetterValue, setter: &StyleSetterValue) {
     if setter.transition.is_some() {
         dst.transition = setter.transition.clone();
     }
+    if setter.bg_gradient.is_some() {
+        dst.bg_gradient = setter.bg_gradient.clone();
+    }
 }
 
 fn merge_inline_layout_values(dst: &mut LayoutStyleValue, src: &LayoutStyle) {
@@ -1201,6 +2294,9 @@ fn merge_inline_layout_values(dst: &mut LayoutStyleValue, src: &LayoutStyle) {
     if let Some(corner_radius) = src.corner_radius {
         dst.corner_radius = Some(StyleValue::value(corner_radius));
     }
+    if let Some(corner_radii) = src.corner_radii {
+        dst.corner_radii = Some(StyleValue::value(corner_radii));
+    }
     if let Some(border_width) = src.border_width {
         dst.border_width = Some(StyleValue::value(border_width));
     }
@@ -1213,6 +2309,21 @@ fn merge_inline_layout_values(dst: &mut LayoutStyleValue, src: &LayoutStyle) {
     if let Some(scale) = src.scale {
         dst.scale = Some(StyleValue::value(scale));
     }
+    if let Some(margin) = src.margin {
+        dst.margin = Some(StyleValue::value(margin));
+    }
+    if let Some(min_width) = src.min_width {
+        dst.min_width = Some(StyleValue::value(min_width));
+    }
+    if let Some(min_height) = src.min_height {
+        dst.min_height = Some(StyleValue::value(min_height));
+    }
+    if let Some(max_width) = src.max_width {
+        dst.max_width = Some(StyleValue::value(max_width));
+    }
+    if let Some(max_height) = src.max_height {
+        dst.max_height = Some(StyleValue::value(max_height));
+    }
 }
 
 fn merge_inline_color_values(dst: &mut ColorStyleValue, src: &ColorStyle) {
@@ -1243,6 +2354,12 @@ fn merge_inline_color_values(dst: &mut ColorStyleValue, src: &ColorStyle) {
     if let Some(pressed_border) = src.pressed_border {
         dst.pressed_border = Some(StyleValue::value(pressed_border));
     }
+    if let Some(placeholder) = src.placeholder {
+        dst.placeholder = Some(StyleValue::value(placeholder));
+    }
+    if let Some(opacity) = src.opacity {
+        dst.opacity = Some(StyleValue::value(opacity));
+    }
 }
 
 fn merge_inline_text_values(dst: &mut TextStyleValue, src: &TextStyle) {
@@ -1252,6 +2369,15 @@ fn merge_inline_text_values(dst: &mut TextStyleValue, src: &TextStyle) {
     if let Some(text_align) = src.text_align {
         dst.text_align = Some(StyleValue::value(text_align));
     }
+    if let Some(weight) = src.weight {
+        dst.weight = Some(StyleValue::value(weight));
+    }
+    if let Some(italic) = src.italic {
+        dst.italic = Some(StyleValue::value(italic));
+    }
+    if let Some(line_break) = src.line_break {
+        dst.line_break = Some(StyleValue::value(line_break));
+    }
 }
 
 fn component_matches_type(world: &World, entity: Entity, component_id: ComponentId) -> bool {
@@ -1298,6 +2424,10 @@ fn selector_matches_entity(world: &World, entity: Entity, selector: &Selector) -
         Selector::PseudoClass(PseudoClass::Pressed) => world
             .get::<InteractionState>(entity)
             .is_some_and(|state| state.pressed),
+        Selector::PseudoClass(PseudoClass::Selected) => world.get::<Selected>(entity).is_some(),
+        Selector::PseudoClass(
+            pseudo @ (PseudoClass::FirstChild | PseudoClass::LastChild | PseudoClass::NthChild(_)),
+        ) => child_position_matches(world, entity, *pseudo),
         Selector::And(selectors) => selectors
             .iter()
             .all(|selector| selector_matches_entity(world, entity, selector)),
@@ -1308,9 +2438,65 @@ fn selector_matches_entity(world: &World, entity: Entity, selector: &Selector) -
             selector_matches_entity(world, entity, descendant)
                 && entity_has_matching_ancestor(world, entity, ancestor)
         }
+        Selector::Child { parent, child } => {
+            selector_matches_entity(world, entity, child)
+                && world.get::<ChildOf>(entity).is_some_and(|child_of| {
+                    selector_matches_entity(world, child_of.parent(), parent)
+                })
+        }
+        Selector::AdjacentSibling { before, after } => {
+            selector_matches_entity(world, entity, after)
+                && previous_sibling(world, entity)
+                    .is_some_and(|sibling| selector_matches_entity(world, sibling, before))
+        }
+        Selector::Attribute { key, value } => {
+            attributes_match(world.get::<StyleAttributes>(entity), key, value.as_deref())
+        }
+    }
+}
+
+fn sibling_offset(world: &World, entity: Entity, offset: isize) -> Option<Entity> {
+    let parent = world.get::<ChildOf>(entity)?.parent();
+    let siblings = world.get::<Children>(parent)?;
+    let index = siblings.iter().position(|sibling| sibling == entity)?;
+    siblings.get(index.checked_add_signed(offset)?).copied()
+}
+
+fn previous_sibling(world: &World, entity: Entity) -> Option<Entity> {
+    sibling_offset(world, entity, -1)
+}
+
+fn next_sibling(world: &World, entity: Entity) -> Option<Entity> {
+    sibling_offset(world, entity, 1)
+}
+
+/// Match a structural pseudo class against the entity's place in its parent's `Children`.
+///
+/// Entities without a parent never match.
+fn child_position_matches(world: &World, entity: Entity, pseudo: PseudoClass) -> bool {
+    let Some(siblings) = world
+        .get::<ChildOf>(entity)
+        .and_then(|child_of| world.get::<Children>(child_of.parent()))
+    else {
+        return false;
+    };
+    let Some(index) = siblings.iter().position(|sibling| sibling == entity) else {
+        return false;
+    };
+    match pseudo {
+        PseudoClass::FirstChild => index == 0,
+        PseudoClass::LastChild => index + 1 == siblings.len(),
+        PseudoClass::NthChild(kind) => kind.matches(index + 1),
+        PseudoClass::Hovered | PseudoClass::Pressed | PseudoClass::Selected => false,
     }
 }
 
+fn attributes_match(attributes: Option<&StyleAttributes>, key: &str, value: Option<&str>) -> bool {
+    attributes
+        .and_then(|attributes| attributes.0.get(key))
+        .is_some_and(|actual| value.is_none_or(|expected| actual == expected))
+}
+
 fn selector_matches_class_context(
     world: &World,
     entity: Option<Entity>,
@@ -1326,6 +2512,12 @@ fn selector_matches_class_context(
         Selector::PseudoClass(PseudoClass::Pressed) => entity
             .and_then(|entity| world.get::<InteractionState>(entity))
             .is_some_and(|state| state.pressed),
+        Selector::PseudoClass(PseudoClass::Selected) => {
+            entity.is_some_and(|entity| world.get::<Selected>(entity).is_some())
+        }
+        Selector::PseudoClass(
+            pseudo @ (PseudoClass::FirstChild | PseudoClass::LastChild | PseudoClass::NthChild(_)),
+        ) => entity.is_some_and(|entity| child_position_matches(world, entity, *pseudo)),
         Selector::And(selectors) => selectors
             .iter()
             .all(|selector| selector_matches_class_context(world, entity, selector, has_class)),
@@ -1340,26 +2532,141 @@ fn selector_matches_class_context(
             selector_matches_class_context(world, Some(entity), descendant, has_class)
                 && entity_has_matching_ancestor(world, entity, ancestor)
         }
+        Selector::Child { parent, child } => {
+            let Some(entity) = entity else {
+                return false;
+            };
+
+            selector_matches_class_context(world, Some(entity), child, has_class)
+                && world.get::<ChildOf>(entity).is_some_and(|child_of| {
+                    selector_matches_entity(world, child_of.parent(), parent)
+                })
+        }
+        Selector::AdjacentSibling { before, after } => {
+            let Some(entity) = entity else {
+                return false;
+            };
+
+            selector_matches_class_context(world, Some(entity), after, has_class)
+                && previous_sibling(world, entity)
+                    .is_some_and(|sibling| selector_matches_entity(world, sibling, before))
+        }
+        Selector::Attribute { key, value } => entity.is_some_and(|entity| {
+            attributes_match(world.get::<StyleAttributes>(entity), key, value.as_deref())
+        }),
+    }
+}
+
+fn active_locale_overrides(world: &World) -> Option<&StyleSheet> {
+    let locale = &world.get_resource::<AppI18n>()?.active_locale;
+    world.get_resource::<LocaleStyleOverrides>()?.get(locale)
+}
+
+/// Token lookup layering the active locale override tokens over the sheet tokens.
+///
+/// Borrows both maps instead of merging them, so resolving a style never clones tokens.
+#[derive(Debug, Clone, Copy)]
+struct StyleTokens<'a> {
+    sheet: Option<&'a HashMap<String, TokenValue>>,
+    locale: Option<&'a HashMap<String, TokenValue>>,
+}
+
+impl<'a> StyleTokens<'a> {
+    fn sheet(tokens: &'a HashMap<String, TokenValue>) -> Self {
+        Self {
+            sheet: Some(tokens),
+            locale: None,
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&'a TokenValue> {
+        self.locale
+            .and_then(|tokens| tokens.get(name))
+            .or_else(|| self.sheet.and_then(|tokens| tokens.get(name)))
+    }
+}
+
+fn style_tokens(world: &World) -> StyleTokens<'_> {
+    StyleTokens {
+        sheet: world
+            .get_resource::<StyleSheet>()
+            .map(|sheet| &sheet.tokens),
+        locale: active_locale_overrides(world).map(|overrides| &overrides.tokens),
     }
 }
 
+/// Origin of a rule; later layers win regardless of [`Specificity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CascadeLayer {
+    Sheet,
+    LocaleOverride,
+}
+
+/// [`StyleSheet`] rules worth matching against an element with `class_names`, in sheet order.
+///
+/// Uses [`StyleRuleIndex`] when present and built for the current sheet, and every
+/// rule otherwise. Locale override rules are few and always appended unindexed.
+fn candidate_rules<'w>(
+    world: &'w World,
+    class_names: &'w [&'w str],
+) -> impl Iterator<Item = (CascadeLayer, &'w StyleRule)> + 'w {
+    let sheet_rules = world
+        .get_resource::<StyleSheet>()
+        .map(|sheet| sheet.rules.as_slice())
+        .unwrap_or_default();
+    let locale_rules = active_locale_overrides(world)
+        .map(|overrides| overrides.rules.as_slice())
+        .unwrap_or_default();
+
+    let index = world
+        .get_resource::<StyleRuleIndex>()
+        .filter(|index| index.rule_count == sheet_rules.len());
+    let indexed = index.map(|index| {
+        index
+            .candidates(class_names)
+            .map(|rule_index| &sheet_rules[rule_index])
+    });
+    let unindexed = index.is_none().then(|| sheet_rules.iter());
+
+    indexed
+        .into_iter()
+        .flatten()
+        .chain(unindexed.into_iter().flatten())
+        .map(|rule| (CascadeLayer::Sheet, rule))
+        .chain(
+            locale_rules
+                .iter()
+                .map(|rule| (CascadeLayer::LocaleOverride, rule)),
+        )
+}
+
+/// Order matched rules for merging: by layer, then [`Specificity`], then sheet order.
+fn in_cascade_order(mut matched: Vec<(CascadeLayer, &StyleRule)>) -> Vec<&StyleRule> {
+    // `sort_by_key` is stable, so equal specificity keeps declaration order.
+    matched.sort_by_key(|(layer, rule)| (*layer, rule.selector.specificity()));
+    matched.into_iter().map(|(_, rule)| rule).collect()
+}
+
 fn merged_from_class_names<'a>(
     world: &World,
     entity: Option<Entity>,
     class_names: impl IntoIterator<Item = &'a str>,
 ) -> StyleSetterValue {
     let mut merged = StyleSetterValue::default();
-    let Some(sheet) = world.get_resource::<StyleSheet>() else {
+    if !world.contains_resource::<StyleSheet>() {
         return merged;
-    };
+    }
 
-    let class_set = class_names.into_iter().collect::<HashSet<_>>();
-    let has_class = |class_name: &str| class_set.contains(class_name);
+    let class_names = class_names.into_iter().collect::<Vec<_>>();
+    let has_class = |class_name: &str| class_names.contains(&class_name);
 
-    for rule in &sheet.rules {
-        if selector_matches_class_context(world, entity, &rule.selector, &has_class) {
-            merge_value_setter(&mut merged, &rule.setter);
-        }
+    let matched = candidate_rules(world, &class_names)
+        .filter(|(_, rule)| {
+            selector_matches_class_context(world, entity, &rule.selector, &has_class)
+        })
+        .collect();
+    for rule in in_cascade_order(matched) {
+        merge_value_setter(&mut merged, &rule.setter);
     }
 
     merged
@@ -1369,13 +2676,16 @@ fn merged_for_entity(world: &World, entity: Entity) -> (StyleSetterValue, bool)
     let mut merged = StyleSetterValue::default();
     let mut matched_rule = false;
 
-    if let Some(sheet) = world.get_resource::<StyleSheet>() {
-        for rule in &sheet.rules {
-            if selector_matches_entity(world, entity, &rule.selector) {
-                merge_value_setter(&mut merged, &rule.setter);
-                matched_rule = true;
-            }
-        }
+    let class_names = world
+        .get::<StyleClass>(entity)
+        .map(|classes| classes.0.iter().map(String::as_str).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let matched = candidate_rules(world, &class_names)
+        .filter(|(_, rule)| selector_matches_entity(world, entity, &rule.selector))
+        .collect();
+    for rule in in_cascade_order(matched) {
+        merge_value_setter(&mut merged, &rule.setter);
+        matched_rule = true;
     }
 
     if let Some(layout) = world.get::<LayoutStyle>(entity) {
@@ -1414,6 +2724,7 @@ fn target_colors(world: &World, entity: Entity, colors: &ColorStyle) -> Resolved
         bg: colors.bg,
         text: colors.text,
         border: colors.border,
+        placeholder: colors.placeholder,
     };
 
     if hovered {
@@ -1448,17 +2759,30 @@ fn to_resolved_layout(layout: &LayoutStyle) -> ResolvedLayoutStyle {
         padding: layout.padding.unwrap_or(0.0),
         gap: layout.gap.unwrap_or(0.0),
         corner_radius: layout.corner_radius.unwrap_or(0.0),
+        corner_radii: layout.corner_radii,
         border_width: layout.border_width.unwrap_or(0.0),
         justify_content: layout.justify_content.unwrap_or_default(),
         align_items: layout.align_items.unwrap_or_default(),
         scale: layout.scale.unwrap_or(1.0),
+        margin: layout.margin.unwrap_or(0.0),
+        min_width: layout.min_width,
+        min_height: layout.min_height,
+        max_width: layout.max_width,
+        max_height: layout.max_height,
     }
 }
 
-fn to_resolved_text(text: &TextStyle) -> ResolvedTextStyle {
+fn resolved_opacity(colors: &ColorStyle) -> f32 {
+    colors.opacity.unwrap_or(1.0).clamp(0.0, 1.0)
+}
+
+fn to_resolved_text(text: &TextStyle, base_font_size: f32) -> ResolvedTextStyle {
     ResolvedTextStyle {
-        size: text.size.unwrap_or(theme::TEXT_SIZE_NORMAL),
+        size: text.size.unwrap_or(base_font_size),
         text_align: text.text_align.unwrap_or_default(),
+        weight: text.weight.map(|weight| weight.clamp(100, 900)),
+        italic: text.italic.unwrap_or(false),
+        line_break: text.line_break.unwrap_or_default(),
     }
 }
 
@@ -1471,13 +2795,23 @@ fn warn_missing_or_invalid_token(token: &str, field: &str, expected: &str) {
     );
 }
 
+fn warn_relative_length_unsupported(field: &str) {
+    tracing::warn!(
+        field,
+        "rem/em units are only supported on length fields; applying fallback"
+    );
+}
+
 fn resolve_f64_value(
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
     value: &StyleValue<f64>,
+    units: LengthUnits,
     field: &str,
 ) -> f64 {
     match value {
         StyleValue::Value(value) => *value,
+        StyleValue::Rem(multiplier) => multiplier * units.rem,
+        StyleValue::Em(multiplier) => multiplier * units.em,
         StyleValue::Var(token) => match tokens.get(token) {
             Some(TokenValue::Float(value)) => *value,
             _ => {
@@ -1489,12 +2823,15 @@ fn resolve_f64_value(
 }
 
 fn resolve_f32_value(
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
     value: &StyleValue<f32>,
+    units: LengthUnits,
     field: &str,
 ) -> f32 {
     match value {
         StyleValue::Value(value) => *value,
+        StyleValue::Rem(multiplier) => (multiplier * units.rem) as f32,
+        StyleValue::Em(multiplier) => (multiplier * units.em) as f32,
         StyleValue::Var(token) => match tokens.get(token) {
             Some(TokenValue::Float(value)) => *value as f32,
             _ => {
@@ -1505,13 +2842,13 @@ fn resolve_f32_value(
     }
 }
 
-fn resolve_color_value(
-    tokens: &HashMap<String, TokenValue>,
-    value: &StyleValue<Color>,
-    field: &str,
-) -> Color {
+fn resolve_color_value(tokens: &StyleTokens<'_>, value: &StyleValue<Color>, field: &str) -> Color {
     match value {
         StyleValue::Value(value) => *value,
+        StyleValue::Rem(_) | StyleValue::Em(_) => {
+            warn_relative_length_unsupported(field);
+            Color::TRANSPARENT
+        }
         StyleValue::Var(token) => match tokens.get(token) {
             Some(TokenValue::Color(value)) => *value,
             _ => {
@@ -1523,12 +2860,16 @@ fn resolve_color_value(
 }
 
 fn resolve_font_family_value(
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
     value: &StyleValue<Vec<String>>,
     field: &str,
 ) -> Option<Vec<String>> {
     match value {
         StyleValue::Value(value) => Some(value.clone()),
+        StyleValue::Rem(_) | StyleValue::Em(_) => {
+            warn_relative_length_unsupported(field);
+            None
+        }
         StyleValue::Var(token) => match tokens.get(token) {
             Some(TokenValue::FontFamily(value)) => Some(value.clone()),
             _ => {
@@ -1539,13 +2880,34 @@ fn resolve_font_family_value(
     }
 }
 
+fn resolve_gradient_value(value: &StyleValue<Gradient>, field: &str) -> Option<Gradient> {
+    match value {
+        StyleValue::Value(value) => Some(value.clone()),
+        StyleValue::Rem(_) | StyleValue::Em(_) => {
+            warn_relative_length_unsupported(field);
+            None
+        }
+        StyleValue::Var(_token) => {
+            tracing::warn!(
+                field,
+                "gradients currently only support literal values; token reference ignored"
+            );
+            None
+        }
+    }
+}
+
 fn resolve_box_shadow_value(
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
     value: &StyleValue<BoxShadow>,
     field: &str,
 ) -> BoxShadow {
     match value {
         StyleValue::Value(value) => *value,
+        StyleValue::Rem(_) | StyleValue::Em(_) => {
+            warn_relative_length_unsupported(field);
+            BoxShadow::default()
+        }
         StyleValue::Var(token) => match tokens.get(token) {
             Some(TokenValue::BoxShadow(value)) => *value,
             _ => {
@@ -1557,32 +2919,41 @@ fn resolve_box_shadow_value(
 }
 
 fn resolve_transition_value(
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
     value: &StyleValue<StyleTransition>,
     field: &str,
 ) -> StyleTransition {
     match value {
         StyleValue::Value(value) => *value,
+        StyleValue::Rem(_) | StyleValue::Em(_) => {
+            warn_relative_length_unsupported(field);
+            StyleTransition::default()
+        }
         StyleValue::Var(token) => match tokens.get(token) {
             Some(TokenValue::Transition(value)) => *value,
             Some(TokenValue::Float(value)) => StyleTransition {
                 duration: *value as f32,
+                ease: None,
             },
             _ => {
                 warn_missing_or_invalid_token(token, field, "Transition|Float");
-                StyleTransition { duration: 0.0 }
+                StyleTransition::default()
             }
         },
     }
 }
 
 fn resolve_enum_value<T: Copy + Default>(
-    _tokens: &HashMap<String, TokenValue>,
+    _tokens: &StyleTokens<'_>,
     value: &StyleValue<T>,
     _field: &str,
 ) -> T {
     match value {
         StyleValue::Value(value) => *value,
+        StyleValue::Rem(_) | StyleValue::Em(_) => {
+            warn_relative_length_unsupported(_field);
+            T::default()
+        }
         StyleValue::Var(_token) => {
             tracing::warn!(
                 field = _field,
@@ -1595,25 +2966,30 @@ fn resolve_enum_value<T: Copy + Default>(
 
 fn resolve_layout_style(
     layout: &LayoutStyleValue,
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
+    units: LengthUnits,
 ) -> LayoutStyle {
     LayoutStyle {
         padding: layout
             .padding
             .as_ref()
-            .map(|value| resolve_f64_value(tokens, value, "layout.padding")),
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.padding")),
         gap: layout
             .gap
             .as_ref()
-            .map(|value| resolve_f64_value(tokens, value, "layout.gap")),
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.gap")),
         corner_radius: layout
             .corner_radius
             .as_ref()
-            .map(|value| resolve_f64_value(tokens, value, "layout.corner_radius")),
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.corner_radius")),
+        corner_radii: layout
+            .corner_radii
+            .as_ref()
+            .map(|value| resolve_enum_value(tokens, value, "layout.corner_radii")),
         border_width: layout
             .border_width
             .as_ref()
-            .map(|value| resolve_f64_value(tokens, value, "layout.border_width")),
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.border_width")),
         justify_content: layout
             .justify_content
             .as_ref()
@@ -1625,13 +3001,34 @@ fn resolve_layout_style(
         scale: layout
             .scale
             .as_ref()
-            .map(|value| resolve_f64_value(tokens, value, "layout.scale")),
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.scale")),
+        margin: layout
+            .margin
+            .as_ref()
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.margin")),
+        min_width: layout
+            .min_width
+            .as_ref()
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.min_width")),
+        min_height: layout
+            .min_height
+            .as_ref()
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.min_height")),
+        max_width: layout
+            .max_width
+            .as_ref()
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.max_width")),
+        max_height: layout
+            .max_height
+            .as_ref()
+            .map(|value| resolve_f64_value(tokens, value, units, "layout.max_height")),
     }
 }
 
 fn resolve_color_style(
     colors: &ColorStyleValue,
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
+    units: LengthUnits,
 ) -> ColorStyle {
     ColorStyle {
         bg: colors
@@ -1670,30 +3067,64 @@ fn resolve_color_style(
             .pressed_border
             .as_ref()
             .map(|value| resolve_color_value(tokens, value, "colors.pressed_border")),
+        placeholder: colors
+            .placeholder
+            .as_ref()
+            .map(|value| resolve_color_value(tokens, value, "colors.placeholder")),
+        opacity: colors
+            .opacity
+            .as_ref()
+            .map(|value| resolve_f32_value(tokens, value, units, "colors.opacity")),
     }
 }
 
-fn resolve_text_style(text: &TextStyleValue, tokens: &HashMap<String, TokenValue>) -> TextStyle {
+fn resolve_text_style(
+    text: &TextStyleValue,
+    tokens: &StyleTokens<'_>,
+    units: LengthUnits,
+) -> TextStyle {
     TextStyle {
         size: text
             .size
             .as_ref()
-            .map(|value| resolve_f32_value(tokens, value, "text.size")),
+            .map(|value| resolve_f32_value(tokens, value, units, "text.size")),
         text_align: text
             .text_align
             .as_ref()
             .map(|value| resolve_enum_value(tokens, value, "text.text_align")),
+        weight: text
+            .weight
+            .as_ref()
+            .map(|value| resolve_enum_value(tokens, value, "text.weight")),
+        italic: text
+            .italic
+            .as_ref()
+            .map(|value| resolve_enum_value(tokens, value, "text.italic")),
+        line_break: text
+            .line_break
+            .as_ref()
+            .map(|value| resolve_enum_value(tokens, value, "text.line_break")),
     }
 }
 
 fn resolve_setter_values(
     setter: &StyleSetterValue,
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
+    base_font_size: f32,
 ) -> StyleSetter {
+    // `text.size` resolves first (its `Em` is relative to the root size) so layout lengths
+    // can use `Em` against the entity's own font size.
+    let root_units = LengthUnits::from_base(base_font_size);
+    let text = resolve_text_style(&setter.text, tokens, root_units);
+    let layout_units = LengthUnits {
+        em: f64::from(text.size.unwrap_or(base_font_size)),
+        ..root_units
+    };
+
     StyleSetter {
-        layout: resolve_layout_style(&setter.layout, tokens),
-        colors: resolve_color_style(&setter.colors, tokens),
-        text: resolve_text_style(&setter.text, tokens),
+        layout: resolve_layout_style(&setter.layout, tokens, layout_units),
+        colors: resolve_color_style(&setter.colors, tokens, root_units),
+        text,
         font_family: setter
             .font_family
             .as_ref()
@@ -1706,9 +3137,23 @@ fn resolve_setter_values(
             .transition
             .as_ref()
             .map(|value| resolve_transition_value(tokens, value, "transition")),
+        bg_gradient: setter
+            .bg_gradient
+            .as_ref()
+            .and_then(|value| resolve_gradient_value(value, "bg_gradient")),
+        layout_important: setter.layout_important,
+        colors_important: setter.colors_important,
     }
 }
 
+fn base_font_size(world: &World) -> f32 {
+    world
+        .get_resource::<StyleBaseFontSize>()
+        .copied()
+        .unwrap_or_default()
+        .0
+}
+
 fn has_any_style_source(world: &World, entity: Entity, matched_rule: bool) -> bool {
     matched_rule
         || world.get::<StyleClass>(entity).is_some()
@@ -1723,10 +3168,11 @@ fn resolved_from_merged(
     world: &World,
     entity: Entity,
     merged: &StyleSetterValue,
-    tokens: &HashMap<String, TokenValue>,
+    tokens: &StyleTokens<'_>,
     include_current_override: bool,
 ) -> ResolvedStyle {
-    let merged = resolve_setter_values(merged, tokens);
+    let base_font_size = base_font_size(world);
+    let merged = resolve_setter_values(merged, tokens, base_font_size);
     let mut colors = target_colors(world, entity, &merged.colors);
 
     if include_current_override && let Some(current) = world.get::<CurrentColorStyle>(entity) {
@@ -1742,17 +3188,21 @@ fn resolved_from_merged(
     }
 
     let mut layout = to_resolved_layout(&merged.layout);
+    let mut opacity = resolved_opacity(&merged.colors);
     if include_current_override && let Some(current) = world.get::<CurrentColorStyle>(entity) {
         layout.scale = current.scale;
+        opacity = current.opacity;
     }
 
     ResolvedStyle {
         layout,
         colors,
-        text: to_resolved_text(&merged.text),
+        text: to_resolved_text(&merged.text, base_font_size),
         font_family: merged.font_family.clone(),
         box_shadow: merged.box_shadow,
         transition: merged.transition,
+        opacity,
+        bg_gradient: merged.bg_gradient.clone(),
     }
 }
 
@@ -1762,19 +3212,16 @@ fn compute_resolved_style(world: &World, entity: Entity) -> Option<ResolvedStyle
         return None;
     }
 
-    let empty_tokens = HashMap::new();
-    let tokens = world
-        .get_resource::<StyleSheet>()
-        .map(|sheet| &sheet.tokens)
-        .unwrap_or(&empty_tokens);
+    let tokens = style_tokens(world);
 
-    Some(resolved_from_merged(world, entity, &merged, tokens, false))
+    Some(resolved_from_merged(world, entity, &merged, &tokens, false))
 }
 
 /// Resolve final style for an entity.
 ///
 /// Cascading order:
-/// 1. class styles from [`StyleSheet`] and [`StyleClass`]
+/// 1. rules from [`StyleSheet`] matching the entity, ordered by [`Specificity`] and then
+///    declaration order (locale overrides after the sheet)
 /// 2. inline overrides from [`InlineStyle`] (or legacy inline components)
 /// 3. pseudo classes from [`InteractionState`]
 /// 4. animated override from [`CurrentColorStyle`] when present
@@ -1788,6 +3235,8 @@ pub fn resolve_style(world: &World, entity: Entity) -> ResolvedStyle {
             font_family: computed.font_family.clone(),
             box_shadow: computed.box_shadow,
             transition: computed.transition,
+            opacity: computed.opacity,
+            bg_gradient: computed.bg_gradient.clone(),
         };
 
         if let Some(current) = world.get::<CurrentColorStyle>(entity) {
@@ -1801,6 +3250,7 @@ pub fn resolve_style(world: &World, entity: Entity) -> ResolvedStyle {
                 style.colors.border = current.border;
             }
             style.layout.scale = current.scale;
+            style.opacity = current.opacity;
         }
 
         return style;
@@ -1825,12 +3275,9 @@ pub fn resolve_style_for_classes<'a>(
     class_names: impl IntoIterator<Item = &'a str>,
 ) -> ResolvedStyle {
     let merged = merged_from_class_names(world, None, class_names);
-    let empty_tokens = HashMap::new();
-    let tokens = world
-        .get_resource::<StyleSheet>()
-        .map(|sheet| &sheet.tokens)
-        .unwrap_or(&empty_tokens);
-    let merged = resolve_setter_values(&merged, tokens);
+    let tokens = style_tokens(world);
+    let base_font_size = base_font_size(world);
+    let merged = resolve_setter_values(&merged, &tokens, base_font_size);
 
     ResolvedStyle {
         layout: to_resolved_layout(&merged.layout),
@@ -1838,11 +3285,14 @@ pub fn resolve_style_for_classes<'a>(
             bg: merged.colors.bg,
             text: merged.colors.text,
             border: merged.colors.border,
+            placeholder: merged.colors.placeholder,
         },
-        text: to_resolved_text(&merged.text),
+        text: to_resolved_text(&merged.text, base_font_size),
         font_family: merged.font_family,
         box_shadow: merged.box_shadow,
         transition: merged.transition,
+        opacity: resolved_opacity(&merged.colors),
+        bg_gradient: merged.bg_gradient,
     }
 }
 
@@ -1857,12 +3307,8 @@ pub fn resolve_style_for_entity_classes<'a>(
     class_names: impl IntoIterator<Item = &'a str>,
 ) -> ResolvedStyle {
     let merged = merged_from_class_names(world, Some(entity), class_names);
-    let empty_tokens = HashMap::new();
-    let tokens = world
-        .get_resource::<StyleSheet>()
-        .map(|sheet| &sheet.tokens)
-        .unwrap_or(&empty_tokens);
-    resolved_from_merged(world, entity, &merged, tokens, false)
+    let tokens = style_tokens(world);
+    resolved_from_merged(world, entity, &merged, &tokens, false)
 }
 
 /// Map style-level justify-content to Masonry flex main-axis alignment.
@@ -1915,29 +3361,54 @@ where
 }
 
 /// Apply box/layout styling on any widget view.
+///
+/// `margin` adds a transparent box around the styled one. Size bounds wrap the styled box
+/// in [`size_bounds`](crate::size_bounds), which clamps its natural size between `min_*`
+/// and `max_*` rather than pinning it.
 pub fn apply_widget_style<V>(view: V, style: &ResolvedStyle) -> impl WidgetView<(), ()>
 where
     V: WidgetView<(), ()>,
 {
     let scale = style.layout.scale.max(0.01);
-    transformed(
+    let (border_color, background, box_shadow) = style.box_properties();
+    let styled = corner_radii_box(
+        style.corner_radii_paint(),
         sized_box(view)
             .padding(style.layout.padding)
-            .corner_radius(style.layout.corner_radius)
-            .border(
-                style.colors.border.unwrap_or(Color::TRANSPARENT),
-                style.layout.border_width,
-            )
-            .background_color(style.colors.bg.unwrap_or(Color::TRANSPARENT))
-            .box_shadow(style.box_shadow.unwrap_or_default()),
-    )
-    .scale(scale)
-}
-
+            .corner_radius(style.layout.box_corner_radius())
+            .border(border_color, style.layout.border_width)
+            .background(background)
+            .box_shadow(box_shadow),
+    );
+    let layout = &style.layout;
+    let min = Size::new(
+        layout.min_width.unwrap_or(0.0).max(0.0),
+        layout.min_height.unwrap_or(0.0).max(0.0),
+    );
+    let max = Size::new(
+        layout.max_width.unwrap_or(f64::INFINITY).max(0.0),
+        layout.max_height.unwrap_or(f64::INFINITY).max(0.0),
+    );
+    transformed(sized_box(size_bounds(min, max, styled)).padding(layout.margin.max(0.0)))
+        .scale(scale)
+}
+
 /// Apply style directly on the target widget.
 ///
 /// This should be preferred for interactive UI components to ensure visual bounds
-/// and hit-testing bounds remain identical.
+/// and hit-testing bounds remain identical. Widgets that only support a subset of the
+/// box properties can compose the granular helpers instead
+/// ([`apply_padding_style`], [`apply_corner_radius_style`], [`apply_border_style`],
+/// [`apply_background_style`], [`apply_box_shadow_style`]).
+///
+/// Built-in property support:
+///
+/// - `ecs_button`, `ecs_button_with_child`, and the slider drag thumb accept every box
+///   property, so they take this function directly.
+/// - `label` takes text styling from [`apply_label_style`]; [`apply_label_box_style`]
+///   adds the box properties on the label widget itself.
+/// - Any view can be wrapped by [`apply_widget_style`], which styles a `sized_box`
+///   around it instead of the widget itself.
 pub fn apply_direct_widget_style<V>(view: V, style: &ResolvedStyle) -> impl WidgetView<(), ()>
 where
     V: WidgetView<(), ()>,
@@ -1950,17 +3421,94 @@ where
         + HasProperty<BoxShadow>,
 {
     let scale = style.layout.scale.max(0.01);
-    transformed(
-        view.padding(style.layout.padding)
-            .corner_radius(style.layout.corner_radius)
-            .border(
-                style.colors.border.unwrap_or(Color::TRANSPARENT),
-                style.layout.border_width,
-            )
-            .background_color(style.colors.bg.unwrap_or(Color::TRANSPARENT))
-            .box_shadow(style.box_shadow.unwrap_or_default()),
+    let (border_color, background, box_shadow) = style.box_properties();
+    let view = apply_padding_style(view, style);
+    let view = apply_corner_radius_style(view, style)
+        .border(border_color, style.layout.border_width)
+        .background(background)
+        .box_shadow(box_shadow);
+    transformed(corner_radii_box(style.corner_radii_paint(), view)).scale(scale)
+}
+
+/// Apply the resolved padding directly on a widget that supports [`Padding`].
+///
+/// The granular `apply_*_style` helpers keep the widget type, so a custom widget that
+/// implements only some box properties can be styled with exactly those:
+///
+/// ```
+/// use picus_core::{
+///     BuiltinUiAction, ResolvedStyle, apply_background_style, apply_padding_style,
+///     bevy_ecs::world::World, ecs_button,
+/// };
+///
+/// let entity = World::new().spawn_empty().id();
+/// let style = ResolvedStyle::default();
+/// let button = ecs_button(entity, BuiltinUiAction::Clicked, "OK");
+/// let _view = apply_background_style(apply_padding_style(button, &style), &style);
+/// ```
+pub fn apply_padding_style<V>(
+    view: V,
+    style: &ResolvedStyle,
+) -> impl WidgetView<(), (), Widget = V::Widget>
+where
+    V: WidgetView<(), ()>,
+    V::Widget: Sized + HasProperty<Padding>,
+{
+    view.padding(style.layout.padding)
+}
+
+/// Apply the resolved corner radius directly on a widget that supports [`CornerRadius`].
+///
+/// Per-corner radii collapse to the largest one, see
+/// [`ResolvedLayoutStyle::box_corner_radius`].
+pub fn apply_corner_radius_style<V>(
+    view: V,
+    style: &ResolvedStyle,
+) -> impl WidgetView<(), (), Widget = V::Widget>
+where
+    V: WidgetView<(), ()>,
+    V::Widget: Sized + HasProperty<CornerRadius>,
+{
+    view.corner_radius(style.layout.box_corner_radius())
+}
+
+/// Apply the resolved border color and width directly on a widget that supports both.
+pub fn apply_border_style<V>(
+    view: V,
+    style: &ResolvedStyle,
+) -> impl WidgetView<(), (), Widget = V::Widget>
+where
+    V: WidgetView<(), ()>,
+    V::Widget: Sized + HasProperty<BorderColor> + HasProperty<BorderWidth>,
+{
+    view.border(
+        style.faded(style.colors.border.unwrap_or(Color::TRANSPARENT)),
+        style.layout.border_width,
     )
-    .scale(scale)
+}
+
+/// Apply the resolved background color directly on a widget that supports [`Background`].
+pub fn apply_background_style<V>(
+    view: V,
+    style: &ResolvedStyle,
+) -> impl WidgetView<(), (), Widget = V::Widget>
+where
+    V: WidgetView<(), ()>,
+    V::Widget: Sized + HasProperty<Background>,
+{
+    view.background(style.background())
+}
+
+/// Apply the resolved box shadow directly on a widget that supports [`BoxShadow`].
+pub fn apply_box_shadow_style<V>(
+    view: V,
+    style: &ResolvedStyle,
+) -> impl WidgetView<(), (), Widget = V::Widget>
+where
+    V: WidgetView<(), ()>,
+    V::Widget: Sized + HasProperty<BoxShadow>,
+{
+    view.box_shadow(style.box_shadow.unwrap_or_default())
 }
 
 fn to_target_component(style: &ResolvedStyle) -> TargetColorStyle {
@@ -1969,6 +3517,7 @@ fn to_target_component(style: &ResolvedStyle) -> TargetColorStyle {
         text: style.colors.text,
         border: style.colors.border,
         scale: style.layout.scale,
+        opacity: style.opacity,
     }
 }
 
@@ -1978,6 +3527,7 @@ fn to_current_component(colors: TargetColorStyle) -> CurrentColorStyle {
         text: colors.text,
         border: colors.border,
         scale: colors.scale,
+        opacity: colors.opacity,
     }
 }
 
@@ -1994,14 +3544,24 @@ fn spawn_color_style_tween(
     entity: Entity,
     start: CurrentColorStyle,
     end: CurrentColorStyle,
-    duration_secs: f32,
+    transition: StyleTransition,
 ) {
-    let duration = Duration::from_secs_f32(duration_secs.max(0.0));
+    let duration = Duration::from_secs_f32(transition.duration.max(0.0));
+    let ease = transition.ease.map_or_else(
+        || {
+            world
+                .get_resource::<StyleAnimationDefaults>()
+                .copied()
+                .unwrap_or_default()
+                .ease
+        },
+        StyleEase::to_ease_kind,
+    );
 
     world.entity_mut(entity).insert((
         TimeSpan::try_from(Duration::ZERO..duration)
             .expect("style tween duration range should be valid"),
-        EaseKind::QuadraticInOut,
+        ease,
         ComponentTween::new_target(entity, ColorStyleLens { start, end }),
         TimeRunner::new(duration),
         TimeContext::<()>::default(),
@@ -2100,14 +3660,35 @@ pub(crate) fn activate_debounced_hovers(
     }
 }
 
+/// Rebuild [`StyleRuleIndex`] when the [`StyleSheet`] resource was added or changed.
+pub fn sync_style_rule_index(world: &mut World) {
+    let Some(sheet) = world.get_resource::<StyleSheet>() else {
+        world.remove_resource::<StyleRuleIndex>();
+        return;
+    };
+    let stale = world.is_resource_added::<StyleSheet>()
+        || world.is_resource_changed::<StyleSheet>()
+        || world
+            .get_resource::<StyleRuleIndex>()
+            .is_none_or(|index| index.rule_count != sheet.rules.len());
+    if stale {
+        let index = StyleRuleIndex::build(sheet);
+        world.insert_resource(index);
+    }
+}
+
 /// Incremental invalidation: marks entities that need style recomputation.
 pub fn mark_style_dirty(world: &mut World) {
-    let stylesheet_changed =
-        world.is_resource_added::<StyleSheet>() || world.is_resource_changed::<StyleSheet>();
+    let stylesheet_changed = world.is_resource_added::<StyleSheet>()
+        || world.is_resource_changed::<StyleSheet>()
+        || world.is_resource_changed::<StyleBaseFontSize>()
+        || world.is_resource_changed::<LocaleStyleOverrides>()
+        || world.is_resource_changed::<AppI18n>();
 
     let mut dirty = {
         let mut query = world.query_filtered::<Entity, Or<(
             Changed<StyleClass>,
+            Changed<StyleAttributes>,
             Changed<InlineStyle>,
             Changed<LayoutStyle>,
             Changed<ColorStyle>,
@@ -2124,14 +3705,42 @@ pub fn mark_style_dirty(world: &mut World) {
     let has_descendant_selectors = world
         .get_resource::<StyleSheet>()
         .is_some_and(StyleSheet::has_descendant_selectors);
+    let has_structural_selectors = world
+        .get_resource::<StyleSheet>()
+        .is_some_and(StyleSheet::has_structural_selectors);
+    // Like type selectors, structural ones can match entities without any style component.
+    let matches_unstyled = has_type_selectors || has_structural_selectors;
+
+    if has_structural_selectors {
+        // Adding, removing or reordering children moves their siblings' positions.
+        let mut reordered = world.query_filtered::<&Children, Changed<Children>>();
+        let siblings = reordered
+            .iter(world)
+            .flat_map(|children| children.iter())
+            .collect::<Vec<_>>();
+        dirty.extend(siblings);
+    }
+
+    if world
+        .get_resource::<StyleSheet>()
+        .is_some_and(StyleSheet::has_sibling_selectors)
+    {
+        // A change to one sibling can flip whether the sibling right after it matches.
+        let following = dirty
+            .iter()
+            .filter_map(|entity| next_sibling(world, *entity))
+            .collect::<Vec<_>>();
+        dirty.extend(following);
+    }
 
     if stylesheet_changed {
-        if has_type_selectors || has_descendant_selectors {
+        if matches_unstyled || has_descendant_selectors {
             let mut all_entities = world.query::<Entity>();
             dirty.extend(all_entities.iter(world));
         } else {
             let mut candidates = world.query_filtered::<Entity, Or<(
                 With<StyleClass>,
+                With<StyleAttributes>,
                 With<InlineStyle>,
                 With<LayoutStyle>,
                 With<ColorStyle>,
@@ -2159,7 +3768,7 @@ pub fn mark_style_dirty(world: &mut World) {
         dirty.extend(descendants);
     }
 
-    if !has_type_selectors && !has_descendant_selectors {
+    if !matches_unstyled && !has_descendant_selectors {
         let stale = {
             let mut stale_query =
                 world.query_filtered::<Entity, (With<ComputedStyle>, Without<StyleDirty>)>();
@@ -2167,6 +3776,7 @@ pub fn mark_style_dirty(world: &mut World) {
                 .iter(world)
                 .filter(|entity| {
                     world.get::<StyleClass>(*entity).is_none()
+                        && world.get::<StyleAttributes>(*entity).is_none()
                         && world.get::<InlineStyle>(*entity).is_none()
                         && world.get::<LayoutStyle>(*entity).is_none()
                         && world.get::<ColorStyle>(*entity).is_none()
@@ -2186,6 +3796,27 @@ pub fn mark_style_dirty(world: &mut World) {
     }
 }
 
+fn take_style_recompute_batch(world: &mut World, dirty: Vec<Entity>) -> Vec<Entity> {
+    let Some(cap) = world
+        .get_resource::<StyleConfig>()
+        .and_then(|config| config.max_recompute_per_frame)
+    else {
+        return dirty;
+    };
+
+    let mut backlog = world.get_resource_or_insert_with(StyleRecomputeBacklog::default);
+    let dirty_set = dirty.iter().copied().collect::<HashSet<_>>();
+    backlog.0.retain(|entity| dirty_set.contains(entity));
+    let queued = backlog.0.iter().copied().collect::<HashSet<_>>();
+    backlog
+        .0
+        .extend(dirty.into_iter().filter(|entity| !queued.contains(entity)));
+
+    // A zero cap would starve the backlog; always make progress.
+    let take = cap.max(1).min(backlog.0.len());
+    backlog.0.drain(..take).collect()
+}
+
 /// Compute and store target/current style states used by transition animation.
 pub fn sync_style_targets(world: &mut World) {
     let entities = {
@@ -2196,6 +3827,7 @@ pub fn sync_style_targets(world: &mut World) {
     if entities.is_empty() {
         return;
     }
+    let entities = take_style_recompute_batch(world, entities);
 
     let snapshots = {
         let world_ref: &World = world;
@@ -2215,6 +3847,8 @@ pub fn sync_style_targets(world: &mut World) {
                     computed.font_family = resolved.font_family.clone();
                     computed.box_shadow = resolved.box_shadow;
                     computed.transition = resolved.transition;
+                    computed.opacity = resolved.opacity;
+                    computed.bg_gradient = resolved.bg_gradient.clone();
                 } else {
                     world.entity_mut(entity).insert(ComputedStyle {
                         layout: resolved.layout,
@@ -2223,6 +3857,8 @@ pub fn sync_style_targets(world: &mut World) {
                         font_family: resolved.font_family.clone(),
                         box_shadow: resolved.box_shadow,
                         transition: resolved.transition,
+                        opacity: resolved.opacity,
+                        bg_gradient: resolved.bg_gradient.clone(),
                     });
                 }
 
@@ -2255,13 +3891,7 @@ pub fn sync_style_targets(world: &mut World) {
                                 .unwrap_or(end);
 
                             if start != end {
-                                spawn_color_style_tween(
-                                    world,
-                                    entity,
-                                    start,
-                                    end,
-                                    transition.duration,
-                                );
+                                spawn_color_style_tween(world, entity, start, end, transition);
                             } else {
                                 clear_style_managed_tween(world, entity);
                             }
@@ -2347,7 +3977,10 @@ fn map_font_family_name(name: &str) -> FontFamily<'static> {
 }
 
 pub(crate) fn font_stack_from_style(style: &ResolvedStyle) -> Option<FontStack<'static>> {
-    let families = style.font_family.as_ref()?;
+    font_stack_from_families(style.font_family.as_deref()?)
+}
+
+pub(crate) fn font_stack_from_families(families: &[String]) -> Option<FontStack<'static>> {
     if families.is_empty() {
         return None;
     }
@@ -2366,8 +3999,8 @@ pub(crate) fn font_stack_from_style(style: &ResolvedStyle) -> Option<FontStack<'
 
 /// Tween lens for animating computed style fields.
 ///
-/// `font_family` is intentionally non-interpolated and only switches at the
-/// end of the tween.
+/// `font_family` and `bg_gradient` are intentionally non-interpolated and only
+/// switch at the end of the tween.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ComputedStyleLens {
     pub start: ComputedStyle,
@@ -2387,12 +4020,33 @@ impl Interpolator for ComputedStyleLens {
             self.end.layout.corner_radius,
             t,
         );
+        target.layout.corner_radii =
+            match (self.start.layout.corner_radii, self.end.layout.corner_radii) {
+                (None, None) => None,
+                _ => {
+                    let start = self.start.layout.corner_radii();
+                    let end = self.end.layout.corner_radii();
+                    Some(std::array::from_fn(|corner| {
+                        lerp_f64(start[corner], end[corner], t)
+                    }))
+                }
+            };
         target.layout.border_width = lerp_f64(
             self.start.layout.border_width,
             self.end.layout.border_width,
             t,
         );
         target.layout.scale = lerp_f64(self.start.layout.scale, self.end.layout.scale, t);
+        target.layout.margin = lerp_f64(self.start.layout.margin, self.end.layout.margin, t);
+        let size_bounds = if t < 1.0 {
+            self.start.layout
+        } else {
+            self.end.layout
+        };
+        target.layout.min_width = size_bounds.min_width;
+        target.layout.min_height = size_bounds.min_height;
+        target.layout.max_width = size_bounds.max_width;
+        target.layout.max_height = size_bounds.max_height;
         target.layout.justify_content = if t < 1.0 {
             self.start.layout.justify_content
         } else {
@@ -2409,18 +4063,41 @@ impl Interpolator for ComputedStyleLens {
         target.colors.border =
             lerp_optional_color(self.start.colors.border, self.end.colors.border, t);
 
+        target.opacity = lerp_f32(self.start.opacity, self.end.opacity, t).clamp(0.0, 1.0);
+
         target.text.size = lerp_f32(self.start.text.size, self.end.text.size, t);
         target.text.text_align = if t < 1.0 {
             self.start.text.text_align
         } else {
             self.end.text.text_align
         };
+        target.text.weight = if t < 1.0 {
+            self.start.text.weight
+        } else {
+            self.end.text.weight
+        };
+        target.text.italic = if t < 1.0 {
+            self.start.text.italic
+        } else {
+            self.end.text.italic
+        };
+        target.text.line_break = if t < 1.0 {
+            self.start.text.line_break
+        } else {
+            self.end.text.line_break
+        };
         target.transition = if t < 1.0 {
             self.start.transition
         } else {
             self.end.transition
         };
 
+        target.bg_gradient = if t < 1.0 {
+            self.start.bg_gradient.clone()
+        } else {
+            self.end.bg_gradient.clone()
+        };
+
         // font family changes are discrete (non-interpolable)
         target.font_family = if t < 1.0 {
             self.start.font_family.clone()
@@ -2445,6 +4122,7 @@ impl Interpolator for ColorStyleLens {
         target.text = lerp_optional_color(self.start.text, self.end.text, ratio);
         target.border = lerp_optional_color(self.start.border, self.end.border, ratio);
         target.scale = lerp_f64(self.start.scale, self.end.scale, ratio);
+        target.opacity = lerp_f32(self.start.opacity, self.end.opacity, ratio).clamp(0.0, 1.0);
     }
 }
 
@@ -2457,21 +4135,89 @@ pub fn animate_style_transitions(world: &mut World) {
 }
 
 /// Apply text + box styling to a label view.
+///
+/// Lines break according to the resolved [`LineBreakMode`]; `Ellipsis` clips here, and
+/// `UiLabel` projection additionally truncates the text.
 pub fn apply_label_style(view: Label, style: &ResolvedStyle) -> impl WidgetView<(), ()> {
+    apply_label_style_with_overflow(view, style, TextOverflow::Wrap)
+}
+
+/// Apply text + box styling to a label view with an explicit overflow mode.
+///
+/// Masonry has no ellipsis line-breaking mode, so [`TextOverflow::Ellipsis`] expects the
+/// text to be pre-truncated (see [`EllipsizedText`](crate::EllipsizedText)); single-line ellipsis clips like
+/// [`TextOverflow::Clip`] so the untruncated first frame does not wrap.
+pub fn apply_label_style_with_overflow(
+    view: Label,
+    style: &ResolvedStyle,
+    overflow: TextOverflow,
+) -> impl WidgetView<(), ()> {
+    label_font_style(
+        style.text.font_style(),
+        style_label_text(view, style, overflow),
+    )
+}
+
+/// Apply text styling plus box styling (padding, border, background, shadow) on the
+/// label widget itself.
+///
+/// Unlike wrapping [`apply_label_style`] in [`apply_widget_style`], the result is one
+/// styled widget, which suits chip-style text such as tags.
+pub fn apply_label_box_style(view: Label, style: &ResolvedStyle) -> impl WidgetView<(), ()> {
+    apply_direct_widget_style(
+        label_font_style(
+            style.text.font_style(),
+            style_label_text(view, style, TextOverflow::Wrap),
+        ),
+        style,
+    )
+}
+
+/// The overflow a label renders with: an explicit non-`Wrap` `overflow` wins, otherwise
+/// a [`LineBreakMode::Ellipsis`] style opts the label into single-line ellipsis.
+pub(crate) fn effective_label_overflow(
+    overflow: TextOverflow,
+    style: &ResolvedStyle,
+) -> TextOverflow {
+    match (overflow, style.text.line_break) {
+        (TextOverflow::Wrap, LineBreakMode::Ellipsis) => TextOverflow::ellipsis(),
+        _ => overflow,
+    }
+}
+
+fn style_label_text(view: Label, style: &ResolvedStyle, overflow: TextOverflow) -> Label {
+    let line_breaking = match overflow {
+        TextOverflow::Wrap => match style.text.line_break {
+            LineBreakMode::WordWrap => LineBreaking::WordWrap,
+            LineBreakMode::Clip | LineBreakMode::Ellipsis => LineBreaking::Clip,
+            LineBreakMode::Overflow => LineBreaking::Overflow,
+        },
+        TextOverflow::Clip | TextOverflow::Ellipsis { max_lines: 0 | 1 } => LineBreaking::Clip,
+        TextOverflow::Ellipsis { .. } => LineBreaking::WordWrap,
+    };
+
     let mut styled = view
         .text_size(style.text.size)
         .text_alignment(map_text_alignment(style.text.text_align));
     if let Some(font_stack) = font_stack_from_style(style) {
         styled = styled.font(font_stack);
     }
+    if let Some(weight) = style.text.font_weight() {
+        styled = styled.weight(weight);
+    }
 
     styled
-        .color(style.colors.text.unwrap_or(Color::WHITE))
-        .line_break_mode(LineBreaking::WordWrap)
+        .color(style.faded(style.colors.text.unwrap_or(Color::WHITE)))
+        .line_break_mode(line_breaking)
 }
 
-fn placeholder_color_from_style(style: &ResolvedStyle) -> Color {
-    style.colors.text.unwrap_or(Color::WHITE).with_alpha(0.72)
+pub(crate) fn placeholder_color_from_style(style: &ResolvedStyle) -> Color {
+    style.faded(
+        style
+            .colors
+            .placeholder
+            .unwrap_or_else(|| style.colors.text.unwrap_or(Color::WHITE).with_alpha(0.72)),
+    )
 }
 
 /// Apply text + box styling to a text input view.
@@ -2487,7 +4233,7 @@ pub fn apply_text_input_style(
     }
     if let Some(text_color) = style.colors.text {
         return styled
-            .text_color(text_color)
+            .text_color(style.faded(text_color))
             .placeholder_color(placeholder_color_from_style(style));
     }
 
@@ -2509,15 +4255,15 @@ pub fn apply_direct_text_input_style(
     if let Some(text_color) = style.colors.text {
         return transformed(
             styled
-                .text_color(text_color)
+                .text_color(style.faded(text_color))
                 .placeholder_color(placeholder_color_from_style(style))
                 .padding(style.layout.padding)
-                .corner_radius(style.layout.corner_radius)
+                .corner_radius(style.layout.box_corner_radius())
                 .border(
-                    style.colors.border.unwrap_or(Color::TRANSPARENT),
+                    style.faded(style.colors.border.unwrap_or(Color::TRANSPARENT)),
                     style.layout.border_width,
                 )
-                .background_color(style.colors.bg.unwrap_or(Color::TRANSPARENT))
+                .background(style.background())
                 .box_shadow(style.box_shadow.unwrap_or_default()),
         )
         .scale(scale);
@@ -2527,21 +4273,21 @@ pub fn apply_direct_text_input_style(
         styled
             .placeholder_color(placeholder_color_from_style(style))
             .padding(style.layout.padding)
-            .corner_radius(style.layout.corner_radius)
+            .corner_radius(style.layout.box_corner_radius())
             .border(
-                style.colors.border.unwrap_or(Color::TRANSPARENT),
+                style.faded(style.colors.border.unwrap_or(Color::TRANSPARENT)),
                 style.layout.border_width,
             )
-            .background_color(style.colors.bg.unwrap_or(Color::TRANSPARENT))
+            .background(style.background())
             .box_shadow(style.box_shadow.unwrap_or_default()),
     )
     .scale(scale)
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct StyleSheetDef {
-    #[serde(default)]
-    tokens: HashMap<String, TokenDef>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tokens: BTreeMap<String, TokenDef>,
     #[serde(default)]
     rules: Vec<StyleRuleDef>,
 }
@@ -2550,21 +4296,21 @@ struct StyleSheetDef {
 struct StyleSheetVariantsDef {
     default_variant: String,
     #[serde(default)]
-    tokens: HashMap<String, TokenDef>,
+    tokens: BTreeMap<String, TokenDef>,
     #[serde(default)]
     rules: Vec<StyleRuleDef>,
     #[serde(default)]
     variants: HashMap<String, StyleSheetDef>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct StyleRuleDef {
     selector: SelectorDef,
     #[serde(default)]
     setter: StyleSetterDef,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 enum SelectorDef {
     Type(String),
     Class(String),
@@ -2574,42 +4320,91 @@ enum SelectorDef {
         ancestor: Box<SelectorDef>,
         descendant: Box<SelectorDef>,
     },
+    Child {
+        parent: Box<SelectorDef>,
+        child: Box<SelectorDef>,
+    },
+    AdjacentSibling {
+        before: Box<SelectorDef>,
+        after: Box<SelectorDef>,
+    },
+    Attribute {
+        key: String,
+        #[serde(default)]
+        value: Option<String>,
+    },
 }
 
-impl From<SelectorDef> for Selector {
-    fn from(value: SelectorDef) -> Self {
-        match value {
-            SelectorDef::Type(name) => Selector::type_name(name),
-            SelectorDef::Class(name) => Selector::class(name),
-            SelectorDef::PseudoClass(pseudo) => Selector::pseudo(pseudo),
-            SelectorDef::And(selectors) => {
-                Selector::and(selectors.into_iter().map(Into::into).collect::<Vec<_>>())
+impl SelectorDef {
+    fn into_selector(self) -> io::Result<Selector> {
+        let invalid = |reason: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid selector: {reason}"),
+            )
+        };
+
+        Ok(match self {
+            Self::Type(name) if name.trim().is_empty() => {
+                return Err(invalid("`Type` needs a component name".to_string()));
+            }
+            Self::Class(name) if name.trim().is_empty() => {
+                return Err(invalid("`Class` needs a class name".to_string()));
+            }
+            Self::PseudoClass(PseudoClass::NthChild(NthKind::Index(0))) => {
+                return Err(invalid(
+                    "`NthChild(Index(0))` never matches; positions start at 1".to_string(),
+                ));
+            }
+            Self::And(selectors) if selectors.is_empty() => {
+                return Err(invalid("`And` needs at least one selector".to_string()));
             }
-            SelectorDef::Descendant {
+            Self::Attribute { key, .. } if key.trim().is_empty() => {
+                return Err(invalid("`Attribute` needs a key".to_string()));
+            }
+            Self::Type(name) => Selector::type_name(name),
+            Self::Class(name) => Selector::class(name),
+            Self::PseudoClass(pseudo) => Selector::pseudo(pseudo),
+            Self::And(selectors) => Selector::and(
+                selectors
+                    .into_iter()
+                    .map(Self::into_selector)
+                    .collect::<io::Result<Vec<_>>>()?,
+            ),
+            Self::Descendant {
                 ancestor,
                 descendant,
-            } => Selector::descendant((*ancestor).into(), (*descendant).into()),
-        }
+            } => Selector::descendant(ancestor.into_selector()?, descendant.into_selector()?),
+            Self::Child { parent, child } => {
+                Selector::child(parent.into_selector()?, child.into_selector()?)
+            }
+            Self::AdjacentSibling { before, after } => {
+                Selector::adjacent_sibling(before.into_selector()?, after.into_selector()?)
+            }
+            Self::Attribute { key, value } => Selector::Attribute { key, value },
+        })
     }
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 struct StyleSetterDef {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     layout: LayoutStyleDef,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     colors: ColorStyleDef,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     text: TextStyleDef,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     font_family: OptionalStyleValueDef<Vec<String>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     box_shadow: OptionalStyleValueDef<BoxShadowDef>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     transition: OptionalStyleValueDef<StyleTransition>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    bg_gradient: OptionalStyleValueDef<GradientDef>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct OptionalLiteralValueDef<T>(Option<T>);
 
 impl<T> Default for OptionalLiteralValueDef<T> {
@@ -2630,93 +4425,221 @@ where
     }
 }
 
+impl<T: Serialize> Serialize for OptionalLiteralValueDef<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Some(value) => value.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 impl<T> OptionalLiteralValueDef<T> {
     fn into_option(self) -> Option<T> {
         self.0
     }
+
+    /// Fails for anything but a literal, since the field's RON form takes nothing else.
+    fn from_style_value(name: &str, value: Option<&StyleValue<T>>) -> io::Result<Self>
+    where
+        T: Clone,
+    {
+        match value {
+            None => Ok(Self(None)),
+            Some(StyleValue::Value(value)) => Ok(Self(Some(value.clone()))),
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("style field `{name}` only accepts literal values in RON"),
+            )),
+        }
+    }
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
 struct LayoutStyleDef {
-    #[serde(default)]
-    padding: OptionalStyleValueDef<f64>,
-    #[serde(default)]
-    gap: OptionalStyleValueDef<f64>,
-    #[serde(default)]
-    corner_radius: OptionalStyleValueDef<f64>,
-    #[serde(default)]
-    border_width: OptionalStyleValueDef<f64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
+    padding: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    gap: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    corner_radius: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    corner_radii: OptionalStyleValueDef<[f64; 4]>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    border_width: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
     justify_content: OptionalLiteralValueDef<JustifyContent>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     align_items: OptionalLiteralValueDef<AlignItems>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     scale: OptionalStyleValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    margin: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    min_width: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    min_height: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    max_width: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    max_height: OptionalLengthValueDef<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    padding_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    gap_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    corner_radius_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    corner_radii_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    border_width_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    justify_content_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    align_items_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    scale_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    margin_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    min_width_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    min_height_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    max_width_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    max_height_important: bool,
 }
 
 impl LayoutStyleDef {
+    fn important(&self) -> LayoutStyleImportant {
+        LayoutStyleImportant {
+            padding: self.padding_important,
+            gap: self.gap_important,
+            corner_radius: self.corner_radius_important,
+            corner_radii: self.corner_radii_important,
+            border_width: self.border_width_important,
+            justify_content: self.justify_content_important,
+            align_items: self.align_items_important,
+            scale: self.scale_important,
+            margin: self.margin_important,
+            min_width: self.min_width_important,
+            min_height: self.min_height_important,
+            max_width: self.max_width_important,
+            max_height: self.max_height_important,
+        }
+    }
+
     fn into_layout_values(self) -> io::Result<LayoutStyleValue> {
         Ok(LayoutStyleValue {
-            padding: into_style_value(self.padding.into_option(), Ok)?,
-            gap: into_style_value(self.gap.into_option(), Ok)?,
-            corner_radius: into_style_value(self.corner_radius.into_option(), Ok)?,
-            border_width: into_style_value(self.border_width.into_option(), Ok)?,
+            padding: self.padding.into_style_value()?,
+            gap: self.gap.into_style_value()?,
+            corner_radius: self.corner_radius.into_style_value()?,
+            corner_radii: into_style_value(self.corner_radii.into_option(), Ok)?,
+            border_width: self.border_width.into_style_value()?,
             justify_content: self.justify_content.into_option().map(StyleValue::Value),
             align_items: self.align_items.into_option().map(StyleValue::Value),
             scale: into_style_value(self.scale.into_option(), Ok)?,
+            margin: self.margin.into_style_value()?,
+            min_width: self.min_width.into_style_value()?,
+            min_height: self.min_height.into_style_value()?,
+            max_width: self.max_width.into_style_value()?,
+            max_height: self.max_height.into_style_value()?,
         })
     }
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
 struct TextStyleDef {
-    #[serde(default)]
-    size: OptionalStyleValueDef<f32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
+    size: OptionalLengthValueDef<f32>,
+    #[serde(default, skip_serializing_if = "is_default")]
     text_align: OptionalLiteralValueDef<TextAlign>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    weight: OptionalLiteralValueDef<u16>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    italic: OptionalLiteralValueDef<bool>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    line_break: OptionalLiteralValueDef<LineBreakMode>,
 }
 
 impl TextStyleDef {
     fn into_text_values(self) -> io::Result<TextStyleValue> {
         Ok(TextStyleValue {
-            size: into_style_value(self.size.into_option(), Ok)?,
+            size: self.size.into_style_value()?,
             text_align: self.text_align.into_option().map(StyleValue::Value),
+            weight: self.weight.into_option().map(StyleValue::Value),
+            italic: self.italic.into_option().map(StyleValue::Value),
+            line_break: self.line_break.into_option().map(StyleValue::Value),
         })
     }
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
 struct ColorStyleDef {
-    #[serde(default)]
-    bg: OptionalStyleValueDef<ColorDef>,
-    #[serde(default, rename = "text")]
-    text_color: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    border: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    hover_bg: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    hover_text: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    hover_border: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    pressed_bg: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    pressed_text: OptionalStyleValueDef<ColorDef>,
-    #[serde(default)]
-    pressed_border: OptionalStyleValueDef<ColorDef>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
+    #[serde(default, skip_serializing_if = "is_default")]
+    bg: OptionalColorValueDef,
+    #[serde(default, rename = "text", skip_serializing_if = "is_default")]
+    text_color: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    border: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    hover_bg: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    hover_text: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    hover_border: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pressed_bg: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pressed_text: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pressed_border: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    placeholder: OptionalColorValueDef,
+    #[serde(default, skip_serializing_if = "is_default")]
+    opacity: OptionalStyleValueDef<f32>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    bg_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    text_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    border_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    hover_bg_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    hover_text_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    hover_border_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pressed_bg_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pressed_text_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pressed_border_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    placeholder_important: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    opacity_important: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 enum ColorDef {
     Rgb(f32, f32, f32),
     Rgba(f32, f32, f32, f32),
     Rgb8(u8, u8, u8),
     Rgba8(u8, u8, u8, u8),
     Hex(String),
+    /// Hue in degrees, saturation and lightness in `0..=1`.
+    Hsl(f32, f32, f32),
+    Hsla(f32, f32, f32, f32),
+    /// CSS color keyword such as `"rebeccapurple"`.
+    Named(String),
+    /// [`StyleVariables`] entry; only valid in token values.
+    Var(String),
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 enum TokenDef {
     Color(ColorDef),
     Float(f64),
@@ -2726,18 +4649,50 @@ enum TokenDef {
 }
 
 impl TokenDef {
-    fn into_token_value(self) -> io::Result<TokenValue> {
+    /// Converts the token, also returning the [`StyleVariables`] name its color came from.
+    fn into_token_value(
+        self,
+        variables: &StyleVariables,
+    ) -> io::Result<(TokenValue, Option<String>)> {
         match self {
-            Self::Color(color) => Ok(TokenValue::Color(color.into_color()?)),
-            Self::Float(value) => Ok(TokenValue::Float(value)),
-            Self::FontFamily(value) => Ok(TokenValue::FontFamily(value)),
-            Self::BoxShadow(value) => Ok(TokenValue::BoxShadow(value.into_box_shadow()?)),
-            Self::Transition(value) => Ok(TokenValue::Transition(value)),
+            Self::Color(ColorDef::Var(name)) => Ok((
+                TokenValue::Color(resolve_style_variable(&name, variables)?),
+                Some(name),
+            )),
+            Self::Color(color) => Ok((TokenValue::Color(color.into_color()?), None)),
+            Self::Float(value) => Ok((TokenValue::Float(value), None)),
+            Self::FontFamily(value) => Ok((TokenValue::FontFamily(value), None)),
+            Self::BoxShadow(BoxShadowDef {
+                color: ColorDef::Var(name),
+                offset_x,
+                offset_y,
+                blur,
+            }) => Ok((
+                TokenValue::BoxShadow(
+                    BoxShadow::new(
+                        resolve_style_variable(&name, variables)?,
+                        (offset_x, offset_y),
+                    )
+                    .blur(blur),
+                ),
+                Some(name),
+            )),
+            Self::BoxShadow(value) => Ok((TokenValue::BoxShadow(value.into_box_shadow()?), None)),
+            Self::Transition(value) => Ok((TokenValue::Transition(value), None)),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+fn resolve_style_variable(name: &str, variables: &StyleVariables) -> io::Result<Color> {
+    variables.get(name).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unknown style variable `{name}`"),
+        )
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
 enum StyleValueDef<T> {
     Value(T),
     Var(String),
@@ -2893,7 +4848,7 @@ where
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 enum OptionalStyleValueDef<T> {
     Style(StyleValueDef<T>),
     #[default]
@@ -3053,6 +5008,28 @@ where
     }
 }
 
+/// Literals are written bare and token references as `Var(..)`, matching what the
+/// deserializer above accepts.
+impl<T: Serialize> Serialize for StyleValueDef<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Value(value) => value.serialize(serializer),
+            Self::Var(token) => {
+                serializer.serialize_newtype_variant("StyleValueDef", 1, "Var", token)
+            }
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for OptionalStyleValueDef<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Style(value) => value.serialize(serializer),
+            Self::None => serializer.serialize_none(),
+        }
+    }
+}
+
 impl<T> OptionalStyleValueDef<T> {
     fn into_option(self) -> Option<StyleValueDef<T>> {
         match self {
@@ -3060,6 +5037,98 @@ impl<T> OptionalStyleValueDef<T> {
             Self::None => None,
         }
     }
+
+    /// Fails for `Rem`/`Em`, which only length fields accept.
+    fn from_style_value<U>(
+        name: &str,
+        value: Option<&StyleValue<U>>,
+        map: impl FnOnce(&U) -> T,
+    ) -> io::Result<Self> {
+        Ok(match value {
+            None => Self::None,
+            Some(StyleValue::Value(value)) => Self::Style(StyleValueDef::Value(map(value))),
+            Some(StyleValue::Var(token)) => Self::Style(StyleValueDef::Var(token.clone())),
+            Some(StyleValue::Rem(_) | StyleValue::Em(_)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("style field `{name}` does not accept relative lengths"),
+                ));
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+enum RelativeLengthDef {
+    Rem(f64),
+    Em(f64),
+}
+
+/// Length field accepting `Rem(..)` / `Em(..)` on top of regular style values.
+///
+/// Untagged on purpose: RON only exposes the variant name of `Rem(1.0)` through serde's
+/// buffered content, so the plain [`StyleValueDef`] visitor would see an anonymous tuple.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+enum OptionalLengthValueDef<T> {
+    Relative(RelativeLengthDef),
+    Style(OptionalStyleValueDef<T>),
+}
+
+impl<T> Default for OptionalLengthValueDef<T> {
+    fn default() -> Self {
+        Self::Style(OptionalStyleValueDef::None)
+    }
+}
+
+impl<T> OptionalLengthValueDef<T> {
+    fn from_style_value(name: &str, value: Option<&StyleValue<T>>) -> io::Result<Self>
+    where
+        T: Clone,
+    {
+        Ok(match value {
+            Some(StyleValue::Rem(multiplier)) => {
+                Self::Relative(RelativeLengthDef::Rem(*multiplier))
+            }
+            Some(StyleValue::Em(multiplier)) => Self::Relative(RelativeLengthDef::Em(*multiplier)),
+            value => Self::Style(OptionalStyleValueDef::from_style_value(
+                name,
+                value,
+                T::clone,
+            )?),
+        })
+    }
+
+    fn into_style_value(self) -> io::Result<Option<StyleValue<T>>> {
+        match self {
+            Self::Relative(RelativeLengthDef::Rem(multiplier)) => {
+                Ok(Some(StyleValue::Rem(multiplier)))
+            }
+            Self::Relative(RelativeLengthDef::Em(multiplier)) => {
+                Ok(Some(StyleValue::Em(multiplier)))
+            }
+            Self::Style(value) => into_style_value(value.into_option(), Ok),
+        }
+    }
+}
+
+/// Color field accepting every [`ColorDef`] form on top of regular style values.
+///
+/// Untagged for the same reason as [`OptionalLengthValueDef`]: without serde's buffered
+/// content, `Named("rebeccapurple")` would reach the [`StyleValueDef`] visitor as a bare
+/// string and read as a token reference. `Var(..)` parses as [`ColorDef::Var`] here and
+/// stays a token reference.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+enum OptionalColorValueDef {
+    Literal(ColorDef),
+    Style(OptionalStyleValueDef<ColorDef>),
+}
+
+impl Default for OptionalColorValueDef {
+    fn default() -> Self {
+        Self::Style(OptionalStyleValueDef::None)
+    }
 }
 
 fn into_style_value<T, U>(
@@ -3090,11 +5159,20 @@ impl ColorDef {
             Self::Rgb8(r, g, b) => Ok(Color::from_rgb8(r, g, b)),
             Self::Rgba8(r, g, b, a) => Ok(Color::from_rgba8(r, g, b, a)),
             Self::Hex(hex) => parse_hex_color(&hex),
+            Self::Hsl(h, s, l) => Ok(hsl_color(h, s, l, 1.0)),
+            Self::Hsla(h, s, l, a) => Ok(hsl_color(h, s, l, a)),
+            Self::Named(name) => parse_named_color(&name),
+            Self::Var(name) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "style variable `{name}` can only be used in a `Color` or `BoxShadow` token"
+                ),
+            )),
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 struct BoxShadowDef {
     color: ColorDef,
     #[serde(default)]
@@ -3105,6 +5183,27 @@ struct BoxShadowDef {
     blur: f64,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+struct GradientDef {
+    #[serde(default)]
+    angle_deg: f32,
+    stops: Vec<(f32, ColorDef)>,
+}
+
+impl GradientDef {
+    fn into_gradient(self) -> io::Result<Gradient> {
+        let stops = self
+            .stops
+            .into_iter()
+            .map(|(offset, color)| Ok((offset, color.into_color()?)))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Gradient {
+            angle_deg: self.angle_deg,
+            stops,
+        })
+    }
+}
+
 impl BoxShadowDef {
     fn into_box_shadow(self) -> io::Result<BoxShadow> {
         Ok(
@@ -3116,6 +5215,8 @@ impl BoxShadowDef {
 
 impl StyleSetterDef {
     fn into_setter(self) -> io::Result<StyleSetterValue> {
+        let layout_important = self.layout.important();
+        let colors_important = self.colors.important();
         Ok(StyleSetterValue {
             layout: self.layout.into_layout_values()?,
             colors: self.colors.into_color_style_values()?,
@@ -3126,14 +5227,41 @@ impl StyleSetterDef {
                 BoxShadowDef::into_box_shadow,
             )?,
             transition: into_style_value(self.transition.into_option(), Ok)?,
+            bg_gradient: into_style_value(
+                self.bg_gradient.into_option(),
+                GradientDef::into_gradient,
+            )?,
+            layout_important,
+            colors_important,
         })
     }
 }
 
 impl ColorStyleDef {
+    fn important(&self) -> ColorStyleImportant {
+        ColorStyleImportant {
+            bg: self.bg_important,
+            text: self.text_important,
+            border: self.border_important,
+            hover_bg: self.hover_bg_important,
+            hover_text: self.hover_text_important,
+            hover_border: self.hover_border_important,
+            pressed_bg: self.pressed_bg_important,
+            pressed_text: self.pressed_text_important,
+            pressed_border: self.pressed_border_important,
+            placeholder: self.placeholder_important,
+            opacity: self.opacity_important,
+        }
+    }
+
     fn into_color_style_value(
-        value: Option<StyleValueDef<ColorDef>>,
+        value: OptionalColorValueDef,
     ) -> io::Result<Option<StyleValue<Color>>> {
+        let value = match value {
+            OptionalColorValueDef::Literal(ColorDef::Var(name)) => Some(StyleValueDef::Var(name)),
+            OptionalColorValueDef::Literal(color) => Some(StyleValueDef::Value(color)),
+            OptionalColorValueDef::Style(value) => value.into_option(),
+        };
         match value {
             None => Ok(None),
             Some(StyleValueDef::Value(value)) => Ok(Some(StyleValue::Value(value.into_color()?))),
@@ -3155,15 +5283,17 @@ impl ColorStyleDef {
 
     fn into_color_style_values(self) -> io::Result<ColorStyleValue> {
         Ok(ColorStyleValue {
-            bg: Self::into_color_style_value(self.bg.into_option())?,
-            text: Self::into_color_style_value(self.text_color.into_option())?,
-            border: Self::into_color_style_value(self.border.into_option())?,
-            hover_bg: Self::into_color_style_value(self.hover_bg.into_option())?,
-            hover_text: Self::into_color_style_value(self.hover_text.into_option())?,
-            hover_border: Self::into_color_style_value(self.hover_border.into_option())?,
-            pressed_bg: Self::into_color_style_value(self.pressed_bg.into_option())?,
-            pressed_text: Self::into_color_style_value(self.pressed_text.into_option())?,
-            pressed_border: Self::into_color_style_value(self.pressed_border.into_option())?,
+            bg: Self::into_color_style_value(self.bg)?,
+            text: Self::into_color_style_value(self.text_color)?,
+            border: Self::into_color_style_value(self.border)?,
+            hover_bg: Self::into_color_style_value(self.hover_bg)?,
+            hover_text: Self::into_color_style_value(self.hover_text)?,
+            hover_border: Self::into_color_style_value(self.hover_border)?,
+            pressed_bg: Self::into_color_style_value(self.pressed_bg)?,
+            pressed_text: Self::into_color_style_value(self.pressed_text)?,
+            pressed_border: Self::into_color_style_value(self.pressed_border)?,
+            placeholder: Self::into_color_style_value(self.placeholder)?,
+            opacity: into_style_value(self.opacity.into_option(), Ok)?,
         })
     }
 }
@@ -3172,6 +5302,48 @@ fn float_color_component_to_u8(value: f32) -> u8 {
     (value.clamp(0.0, 1.0) * 255.0).round() as u8
 }
 
+/// CSS `hsl()` conversion, quantized to 8 bits per channel like the other color forms.
+fn hsl_color(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Color {
+    let saturation = saturation.clamp(0.0, 1.0);
+    let lightness = lightness.clamp(0.0, 1.0);
+    let chroma = saturation * lightness.min(1.0 - lightness);
+    let channel = |n: f32| {
+        let k = (n + hue / 30.0).rem_euclid(12.0);
+        lightness - chroma * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    Color::from_rgba8(
+        float_color_component_to_u8(channel(0.0)),
+        float_color_component_to_u8(channel(8.0)),
+        float_color_component_to_u8(channel(4.0)),
+        float_color_component_to_u8(alpha),
+    )
+}
+
+fn parse_named_color(name: &str) -> io::Result<Color> {
+    let name = name.trim();
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unknown color name `{name}`; expected a CSS color keyword"),
+        )
+    };
+
+    // `parse_color` also takes functional and hex syntax; only bare keywords are names.
+    if name.is_empty()
+        || !name.chars().all(|c| c.is_ascii_alphabetic())
+        || name.eq_ignore_ascii_case("currentcolor")
+    {
+        return Err(invalid());
+    }
+
+    let color = parse_color(&name.to_ascii_lowercase())
+        .map_err(|_| invalid())?
+        .to_alpha_color::<Srgb>();
+    let (r, g, b, a) = unpack_rgba(color);
+    Ok(Color::from_rgba8(r, g, b, a))
+}
+
 fn parse_hex_color(hex: &str) -> io::Result<Color> {
     let hex = hex.trim();
     let hex = hex.strip_prefix('#').unwrap_or(hex);
@@ -3201,34 +5373,357 @@ fn parse_hex_color(hex: &str) -> io::Result<Color> {
     }
 }
 
-fn stylesheet_from_def(parsed: StyleSheetDef) -> io::Result<StyleSheet> {
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn stylesheet_to_ron(sheet: &StyleSheet) -> io::Result<String> {
+    let def = StyleSheetDef::from_sheet(sheet)?;
+    ron::ser::to_string_pretty(&def, ron::ser::PrettyConfig::default()).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to serialize stylesheet: {error}"),
+        )
+    })
+}
+
+impl StyleSheetDef {
+    /// Inverse of [`stylesheet_from_def`]; tokens bound to a [`StyleVariables`] entry go
+    /// back to their `Var(..)` color.
+    fn from_sheet(sheet: &StyleSheet) -> io::Result<Self> {
+        let tokens = sheet
+            .tokens
+            .iter()
+            .map(|(name, token)| {
+                let variable = sheet.variable_tokens.get(name).map(String::as_str);
+                (name.clone(), TokenDef::from_token(token, variable))
+            })
+            .collect();
+        let rules = sheet
+            .rules
+            .iter()
+            .map(|rule| {
+                Ok(StyleRuleDef {
+                    selector: SelectorDef::from_selector(&rule.selector)?,
+                    setter: StyleSetterDef::from_setter(&rule.setter)?,
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self { tokens, rules })
+    }
+}
+
+impl TokenDef {
+    fn from_token(token: &TokenValue, variable: Option<&str>) -> Self {
+        let color = |color: Color| match variable {
+            Some(name) => ColorDef::Var(name.to_string()),
+            None => ColorDef::from_color(color),
+        };
+        match token {
+            TokenValue::Color(value) => Self::Color(color(*value)),
+            TokenValue::Float(value) => Self::Float(*value),
+            TokenValue::FontFamily(families) => Self::FontFamily(families.clone()),
+            TokenValue::BoxShadow(shadow) => Self::BoxShadow(BoxShadowDef {
+                color: color(shadow.color),
+                ..BoxShadowDef::from_box_shadow(shadow)
+            }),
+            TokenValue::Transition(transition) => Self::Transition(*transition),
+        }
+    }
+}
+
+impl ColorDef {
+    /// `Hex("#RRGGBB")`, or `#RRGGBBAA` when not opaque.
+    fn from_color(color: Color) -> Self {
+        let (r, g, b, a) = unpack_rgba(color);
+        Self::Hex(if a == u8::MAX {
+            format!("#{r:02X}{g:02X}{b:02X}")
+        } else {
+            format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+        })
+    }
+}
+
+impl BoxShadowDef {
+    fn from_box_shadow(shadow: &BoxShadow) -> Self {
+        Self {
+            color: ColorDef::from_color(shadow.color),
+            offset_x: shadow.offset.x,
+            offset_y: shadow.offset.y,
+            blur: shadow.blur_radius,
+        }
+    }
+}
+
+impl GradientDef {
+    fn from_gradient(gradient: &Gradient) -> Self {
+        Self {
+            angle_deg: gradient.angle_deg,
+            stops: gradient
+                .stops
+                .iter()
+                .map(|(offset, color)| (*offset, ColorDef::from_color(*color)))
+                .collect(),
+        }
+    }
+}
+
+impl SelectorDef {
+    fn from_selector(selector: &Selector) -> io::Result<Self> {
+        Ok(match selector {
+            Selector::Type(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "type selectors built from a Rust type have no RON form; use Selector::type_name",
+                ));
+            }
+            Selector::TypeName(name) => Self::Type(name.clone()),
+            Selector::Class(name) => Self::Class(name.clone()),
+            Selector::PseudoClass(pseudo) => Self::PseudoClass(*pseudo),
+            Selector::And(selectors) => Self::And(
+                selectors
+                    .iter()
+                    .map(Self::from_selector)
+                    .collect::<io::Result<Vec<_>>>()?,
+            ),
+            Selector::Descendant {
+                ancestor,
+                descendant,
+            } => Self::Descendant {
+                ancestor: Box::new(Self::from_selector(ancestor)?),
+                descendant: Box::new(Self::from_selector(descendant)?),
+            },
+            Selector::Child { parent, child } => Self::Child {
+                parent: Box::new(Self::from_selector(parent)?),
+                child: Box::new(Self::from_selector(child)?),
+            },
+            Selector::AdjacentSibling { before, after } => Self::AdjacentSibling {
+                before: Box::new(Self::from_selector(before)?),
+                after: Box::new(Self::from_selector(after)?),
+            },
+            Selector::Attribute { key, value } => Self::Attribute {
+                key: key.clone(),
+                value: value.clone(),
+            },
+        })
+    }
+}
+
+impl StyleSetterDef {
+    fn from_setter(setter: &StyleSetterValue) -> io::Result<Self> {
+        Ok(Self {
+            layout: LayoutStyleDef::from_layout(&setter.layout, setter.layout_important)?,
+            colors: ColorStyleDef::from_colors(&setter.colors, setter.colors_important)?,
+            text: TextStyleDef::from_text(&setter.text)?,
+            font_family: OptionalStyleValueDef::from_style_value(
+                "font_family",
+                setter.font_family.as_ref(),
+                Vec::clone,
+            )?,
+            box_shadow: OptionalStyleValueDef::from_style_value(
+                "box_shadow",
+                setter.box_shadow.as_ref(),
+                BoxShadowDef::from_box_shadow,
+            )?,
+            transition: OptionalStyleValueDef::from_style_value(
+                "transition",
+                setter.transition.as_ref(),
+                StyleTransition::clone,
+            )?,
+            bg_gradient: OptionalStyleValueDef::from_style_value(
+                "bg_gradient",
+                setter.bg_gradient.as_ref(),
+                GradientDef::from_gradient,
+            )?,
+        })
+    }
+}
+
+impl LayoutStyleDef {
+    fn from_layout(layout: &LayoutStyleValue, important: LayoutStyleImportant) -> io::Result<Self> {
+        Ok(Self {
+            padding: OptionalLengthValueDef::from_style_value("padding", layout.padding.as_ref())?,
+            gap: OptionalLengthValueDef::from_style_value("gap", layout.gap.as_ref())?,
+            corner_radius: OptionalLengthValueDef::from_style_value(
+                "corner_radius",
+                layout.corner_radius.as_ref(),
+            )?,
+            corner_radii: OptionalStyleValueDef::from_style_value(
+                "corner_radii",
+                layout.corner_radii.as_ref(),
+                |radii| *radii,
+            )?,
+            border_width: OptionalLengthValueDef::from_style_value(
+                "border_width",
+                layout.border_width.as_ref(),
+            )?,
+            justify_content: OptionalLiteralValueDef::from_style_value(
+                "justify_content",
+                layout.justify_content.as_ref(),
+            )?,
+            align_items: OptionalLiteralValueDef::from_style_value(
+                "align_items",
+                layout.align_items.as_ref(),
+            )?,
+            scale: OptionalStyleValueDef::from_style_value(
+                "scale",
+                layout.scale.as_ref(),
+                f64::clone,
+            )?,
+            margin: OptionalLengthValueDef::from_style_value("margin", layout.margin.as_ref())?,
+            min_width: OptionalLengthValueDef::from_style_value(
+                "min_width",
+                layout.min_width.as_ref(),
+            )?,
+            min_height: OptionalLengthValueDef::from_style_value(
+                "min_height",
+                layout.min_height.as_ref(),
+            )?,
+            max_width: OptionalLengthValueDef::from_style_value(
+                "max_width",
+                layout.max_width.as_ref(),
+            )?,
+            max_height: OptionalLengthValueDef::from_style_value(
+                "max_height",
+                layout.max_height.as_ref(),
+            )?,
+            padding_important: important.padding,
+            gap_important: important.gap,
+            corner_radius_important: important.corner_radius,
+            corner_radii_important: important.corner_radii,
+            border_width_important: important.border_width,
+            justify_content_important: important.justify_content,
+            align_items_important: important.align_items,
+            scale_important: important.scale,
+            margin_important: important.margin,
+            min_width_important: important.min_width,
+            min_height_important: important.min_height,
+            max_width_important: important.max_width,
+            max_height_important: important.max_height,
+        })
+    }
+}
+
+impl TextStyleDef {
+    fn from_text(text: &TextStyleValue) -> io::Result<Self> {
+        Ok(Self {
+            size: OptionalLengthValueDef::from_style_value("size", text.size.as_ref())?,
+            text_align: OptionalLiteralValueDef::from_style_value(
+                "text_align",
+                text.text_align.as_ref(),
+            )?,
+            weight: OptionalLiteralValueDef::from_style_value("weight", text.weight.as_ref())?,
+            italic: OptionalLiteralValueDef::from_style_value("italic", text.italic.as_ref())?,
+            line_break: OptionalLiteralValueDef::from_style_value(
+                "line_break",
+                text.line_break.as_ref(),
+            )?,
+        })
+    }
+}
+
+impl ColorStyleDef {
+    fn from_colors(colors: &ColorStyleValue, important: ColorStyleImportant) -> io::Result<Self> {
+        let color = |name: &str, value: &Option<StyleValue<Color>>| {
+            OptionalStyleValueDef::from_style_value(name, value.as_ref(), |color| {
+                ColorDef::from_color(*color)
+            })
+            .map(OptionalColorValueDef::Style)
+        };
+        Ok(Self {
+            bg: color("bg", &colors.bg)?,
+            text_color: color("text", &colors.text)?,
+            border: color("border", &colors.border)?,
+            hover_bg: color("hover_bg", &colors.hover_bg)?,
+            hover_text: color("hover_text", &colors.hover_text)?,
+            hover_border: color("hover_border", &colors.hover_border)?,
+            pressed_bg: color("pressed_bg", &colors.pressed_bg)?,
+            pressed_text: color("pressed_text", &colors.pressed_text)?,
+            pressed_border: color("pressed_border", &colors.pressed_border)?,
+            placeholder: color("placeholder", &colors.placeholder)?,
+            opacity: OptionalStyleValueDef::from_style_value(
+                "opacity",
+                colors.opacity.as_ref(),
+                f32::clone,
+            )?,
+            bg_important: important.bg,
+            text_important: important.text,
+            border_important: important.border,
+            hover_bg_important: important.hover_bg,
+            hover_text_important: important.hover_text,
+            hover_border_important: important.hover_border,
+            pressed_bg_important: important.pressed_bg,
+            pressed_text_important: important.pressed_text,
+            pressed_border_important: important.pressed_border,
+            placeholder_important: important.placeholder,
+            opacity_important: important.opacity,
+        })
+    }
+}
+
+fn stylesheet_from_def(
+    parsed: StyleSheetDef,
+    variables: &StyleVariables,
+) -> io::Result<StyleSheet> {
     let mut sheet = StyleSheet::default();
     for (name, token) in parsed.tokens {
-        sheet.tokens.insert(name, token.into_token_value()?);
+        let (token, variable) = token.into_token_value(variables).map_err(|error| {
+            io::Error::new(error.kind(), format!("stylesheet token `{name}`: {error}"))
+        })?;
+        sheet.insert_token(name, token, variable);
     }
 
-    for rule in parsed.rules {
+    for (index, rule) in parsed.rules.into_iter().enumerate() {
+        let selector = rule.selector.into_selector().map_err(|error| {
+            io::Error::new(error.kind(), format!("stylesheet rule {index}: {error}"))
+        })?;
         sheet.add_rule(StyleRule::new_with_values(
-            rule.selector.into(),
+            selector,
             rule.setter.into_setter()?,
         ));
     }
 
+    bind_color_field_variables(&mut sheet, variables);
+
     Ok(sheet)
 }
 
-fn stylesheet_from_ron_bytes(bytes: &[u8]) -> io::Result<StyleSheet> {
-    let parsed: StyleSheetDef = ron::de::from_bytes(bytes).map_err(|error| {
-        io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!("failed to parse stylesheet RON: {error}"),
-        )
-    })?;
+/// Turn `colors` field `Var(..)` references that name no token of `sheet` but a known
+/// [`StyleVariables`] entry into variable-bound tokens.
+fn bind_color_field_variables(sheet: &mut StyleSheet, variables: &StyleVariables) {
+    let mut bound = Vec::new();
+    for rule in &sheet.rules {
+        let colors = &rule.setter.colors;
+        for value in [
+            &colors.bg,
+            &colors.text,
+            &colors.border,
+            &colors.hover_bg,
+            &colors.hover_text,
+            &colors.hover_border,
+            &colors.pressed_bg,
+            &colors.pressed_text,
+            &colors.pressed_border,
+            &colors.placeholder,
+        ] {
+            if let Some(StyleValue::Var(name)) = value
+                && !sheet.tokens.contains_key(name)
+                && let Some(color) = variables.get(name)
+            {
+                bound.push((name.clone(), color));
+            }
+        }
+    }
 
-    stylesheet_from_def(parsed)
+    for (name, color) in bound {
+        sheet.insert_token(name.clone(), TokenValue::Color(color), Some(name));
+    }
 }
 
-fn stylesheet_variants_from_ron_bytes(bytes: &[u8]) -> io::Result<RegisteredStyleVariants> {
+fn stylesheet_variants_from_ron_bytes(
+    bytes: &[u8],
+    variables: &StyleVariables,
+) -> io::Result<RegisteredStyleVariants> {
     let parsed: StyleSheetVariantsDef = ron::de::from_bytes(bytes).map_err(|error| {
         io::Error::new(
             io::ErrorKind::InvalidData,
@@ -3244,14 +5739,17 @@ fn stylesheet_variants_from_ron_bytes(bytes: &[u8]) -> io::Result<RegisteredStyl
     }
 
     let default_variant = parsed.default_variant;
-    let base_sheet = stylesheet_from_def(StyleSheetDef {
-        tokens: parsed.tokens,
-        rules: parsed.rules,
-    })?;
+    let base_sheet = stylesheet_from_def(
+        StyleSheetDef {
+            tokens: parsed.tokens,
+            rules: parsed.rules,
+        },
+        variables,
+    )?;
 
     let mut raw_variants = HashMap::new();
     for (name, def) in parsed.variants {
-        raw_variants.insert(name, stylesheet_from_def(def)?);
+        raw_variants.insert(name, stylesheet_from_def(def, variables)?);
     }
 
     if !raw_variants.contains_key(&default_variant) {
@@ -3274,11 +5772,6 @@ fn stylesheet_variants_from_ron_bytes(bytes: &[u8]) -> io::Result<RegisteredStyl
     })
 }
 
-#[cfg(test)]
-pub(crate) fn parse_stylesheet_ron_for_tests(ron_text: &str) -> io::Result<StyleSheet> {
-    parse_stylesheet_ron(ron_text)
-}
-
 #[cfg(test)]
 pub(crate) fn parse_stylesheet_variants_ron_for_tests(
     ron_text: &str,
@@ -3287,8 +5780,22 @@ pub(crate) fn parse_stylesheet_variants_ron_for_tests(
 }
 
 /// Asset loader for stylesheet `.ron` files.
-#[derive(Default, TypePath)]
-pub struct StyleSheetRonLoader;
+///
+/// Resolves `Var(..)` colors through the [`StyleVariablesSnapshot`] current at load time.
+#[derive(TypePath)]
+pub struct StyleSheetRonLoader {
+    variables: StyleVariablesSnapshot,
+}
+
+impl FromWorld for StyleSheetRonLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            variables: world
+                .get_resource_or_init::<StyleVariablesSnapshot>()
+                .clone(),
+        }
+    }
+}
 
 impl AssetLoader for StyleSheetRonLoader {
     type Asset = StyleSheet;
@@ -3303,7 +5810,13 @@ impl AssetLoader for StyleSheetRonLoader {
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
-        stylesheet_from_ron_bytes(&bytes)
+        let ron_text = std::str::from_utf8(&bytes).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("stylesheet RON is not valid UTF-8: {error}"),
+            )
+        })?;
+        parse_stylesheet_ron_with_variables(ron_text, &self.variables.get())
     }
 
     fn extensions(&self) -> &[&str] {
@@ -1,18 +1,72 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
 use bevy_ecs::{hierarchy::Children, prelude::*};
+use tracing::{error, warn};
 use xilem_masonry::view::{FlexExt as _, flex_col, label};
 
 use crate::{
-    ecs::{UiOverlayRoot, UiRoot},
-    projection::{UiProjectorRegistry, UiView},
+    AppI18n, LocaleStyleOverrides, StyleBaseFontSize, StyleSheet,
+    ecs::{
+        OffscreenMeasure, UiHidden, UiNodeId, UiOrder, UiOverlayRoot, UiRemountKey, UiRoot,
+        UiRootOrder,
+    },
+    events::UiEventQueue,
+    projection::{
+        UiProjectorRegistry, UiView,
+        elements::{decorate_with_count_badge, decorate_with_overflow, decorate_with_transform},
+    },
     views::entity_scope,
 };
 
-/// Snapshot containing synthesized root views for the current frame.
+/// Double-buffered synthesized root views.
+///
+/// [`synthesize_ui`] writes each pass into the back buffer and swaps it to the
+/// front; the runtime only reads [`Self::front`], which stays stable between swaps.
 #[derive(Resource, Default)]
 pub struct SynthesizedUiViews {
-    pub roots: Vec<UiView>,
+    front: Vec<UiView>,
+    back: Vec<UiView>,
+}
+
+impl SynthesizedUiViews {
+    /// Root views of the latest presented frame.
+    #[must_use]
+    pub fn front(&self) -> &[UiView] {
+        &self.front
+    }
+
+    /// Root views of the previous frame, or of a rejected pass when
+    /// [`SynthesisConfig::keep_last_good_frame`] held the front buffer back.
+    #[must_use]
+    pub fn back(&self) -> &[UiView] {
+        &self.back
+    }
+
+    /// First root view of the presented frame.
+    #[must_use]
+    pub fn first_root(&self) -> Option<&UiView> {
+        self.front.first()
+    }
+
+    /// Write `roots` into the back buffer and swap it to the front.
+    pub fn present(&mut self, roots: Vec<UiView>) {
+        self.back = roots;
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// Write `roots` into the back buffer without presenting them.
+    pub fn stage(&mut self, roots: Vec<UiView>) {
+        self.back = roots;
+    }
 }
 
 /// Snapshot metrics for the latest synthesis pass.
@@ -23,19 +77,572 @@ pub struct UiSynthesisStats {
     pub cycle_count: usize,
     pub missing_entity_count: usize,
     pub unhandled_count: usize,
+    /// Projectors that panicked while [`SynthesisConfig::isolate_panics`] was enabled.
+    pub panicked_count: usize,
+    /// Wall time spent in [`synthesize_ui`] (zero for direct `synthesize_roots*` calls).
+    pub elapsed: Duration,
+    /// Passes [`synthesize_ui`] ran this frame: 1, plus one per honored
+    /// [`ProjectionCtx::request_resynthesis`](crate::ProjectionCtx::request_resynthesis).
+    pub pass_count: usize,
+    /// Deepest nesting reached, counting the root as depth 1.
+    pub max_depth: usize,
+    /// Nodes synthesized under each root, in root order.
+    pub per_root_node_counts: Vec<usize>,
+    /// Subtrees cut off by [`UiSynthesisLimits`].
+    pub limit_hit_count: usize,
+    /// [`UiHidden`] entities skipped with their subtrees; each also counts as one node.
+    pub hidden_count: usize,
+    /// Nodes whose view was built this pass rather than reused from [`UiViewCache`].
+    /// Equal to `node_count` unless [`SynthesisConfig::view_cache`] is enabled.
+    pub rebuilt_view_count: usize,
+}
+
+/// Default number of frames kept by [`UiStatsHistory`].
+pub const DEFAULT_UI_STATS_HISTORY_CAPACITY: usize = 120;
+
+/// Ring buffer of recent [`UiSynthesisStats`], oldest first.
+///
+/// Optional: insert it to start recording. [`record_ui_stats_history`] appends one
+/// sample per frame after synthesis and drops the oldest once `capacity` is reached.
+#[derive(Resource, Debug, Clone)]
+pub struct UiStatsHistory {
+    samples: VecDeque<UiSynthesisStats>,
+    capacity: usize,
+}
+
+impl UiStatsHistory {
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the capacity, discarding the oldest samples if it shrinks.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.samples.len() > self.capacity {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn push(&mut self, stats: UiSynthesisStats) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(stats);
+    }
+
+    /// Samples from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &UiSynthesisStats> + ExactSizeIterator {
+        self.samples.iter()
+    }
+
+    #[must_use]
+    pub fn latest(&self) -> Option<&UiSynthesisStats> {
+        self.samples.back()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+impl Default for UiStatsHistory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_UI_STATS_HISTORY_CAPACITY)
+    }
+}
+
+/// Append the latest [`UiSynthesisStats`] to [`UiStatsHistory`] when it is present.
+pub fn record_ui_stats_history(
+    stats: Res<UiSynthesisStats>,
+    history: Option<ResMut<UiStatsHistory>>,
+) {
+    if let Some(mut history) = history {
+        history.push(stats.clone());
+    }
+}
+
+/// Reverse index from [`UiNodeId`] values to the entities carrying them.
+///
+/// Maintained by [`sync_ui_node_index`]. When two live entities share an id the most
+/// recently added or changed one wins and a warning is logged.
+#[derive(Resource, Debug, Default)]
+pub struct UiNodeIndex {
+    by_id: HashMap<u64, Entity>,
+    by_entity: HashMap<Entity, u64>,
+}
+
+impl UiNodeIndex {
+    #[must_use]
+    pub fn get(&self, id: u64) -> Option<Entity> {
+        self.by_id.get(&id).copied()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+
+    fn insert(&mut self, id: u64, entity: Entity) {
+        self.remove_entity(entity);
+        if let Some(previous) = self.by_id.insert(id, entity)
+            && previous != entity
+        {
+            warn!(
+                id,
+                ?previous,
+                ?entity,
+                "duplicate UiNodeId; index now resolves to the newer entity"
+            );
+            self.by_entity.remove(&previous);
+        }
+        self.by_entity.insert(entity, id);
+    }
+
+    fn remove_entity(&mut self, entity: Entity) {
+        if let Some(id) = self.by_entity.remove(&entity)
+            && self.by_id.get(&id) == Some(&entity)
+        {
+            self.by_id.remove(&id);
+        }
+    }
+}
+
+/// Keep [`UiNodeIndex`] in sync with added, changed, and removed [`UiNodeId`] components.
+pub fn sync_ui_node_index(
+    mut index: ResMut<UiNodeIndex>,
+    changed: Query<(Entity, &UiNodeId), Changed<UiNodeId>>,
+    mut removed: RemovedComponents<UiNodeId>,
+) {
+    for entity in removed.read() {
+        index.remove_entity(entity);
+    }
+    for (entity, id) in &changed {
+        index.insert(id.0, entity);
+    }
+}
+
+/// Builds the fallback view for an entity that cannot be projected normally.
+pub type SynthesisFallbackFn = Arc<dyn Fn(&World, Entity) -> UiView + Send + Sync>;
+
+/// Builds the fallback view for an entity no projector handled, given its synthesized children.
+pub type UnhandledFallbackFn = Arc<dyn Fn(&World, Entity, Vec<UiView>) -> UiView + Send + Sync>;
+
+/// Views produced when synthesis hits a missing child, a hierarchy cycle, an entity
+/// without a matching projector, or (with [`SynthesisConfig::isolate_panics`]) a
+/// panicking projector.
+///
+/// The defaults render debug labels, which is useful during development; production apps
+/// can swap in empty views, styled error cards, or logging.
+#[derive(Resource, Clone)]
+pub struct SynthesisFallbacks {
+    pub missing: SynthesisFallbackFn,
+    pub cycle: SynthesisFallbackFn,
+    pub unhandled: UnhandledFallbackFn,
+    pub panicked: SynthesisFallbackFn,
+}
+
+impl SynthesisFallbacks {
+    #[must_use]
+    pub fn with_missing(
+        mut self,
+        missing: impl Fn(&World, Entity) -> UiView + Send + Sync + 'static,
+    ) -> Self {
+        self.missing = Arc::new(missing);
+        self
+    }
+
+    #[must_use]
+    pub fn with_cycle(
+        mut self,
+        cycle: impl Fn(&World, Entity) -> UiView + Send + Sync + 'static,
+    ) -> Self {
+        self.cycle = Arc::new(cycle);
+        self
+    }
+
+    #[must_use]
+    pub fn with_unhandled(
+        mut self,
+        unhandled: impl Fn(&World, Entity, Vec<UiView>) -> UiView + Send + Sync + 'static,
+    ) -> Self {
+        self.unhandled = Arc::new(unhandled);
+        self
+    }
+
+    #[must_use]
+    pub fn with_panicked(
+        mut self,
+        panicked: impl Fn(&World, Entity) -> UiView + Send + Sync + 'static,
+    ) -> Self {
+        self.panicked = Arc::new(panicked);
+        self
+    }
+}
+
+impl Default for SynthesisFallbacks {
+    fn default() -> Self {
+        Self {
+            missing: Arc::new(|_, entity| Arc::new(label(format!("[missing entity {entity:?}]")))),
+            cycle: Arc::new(|_, entity| Arc::new(label(format!("[cycle at {entity:?}]")))),
+            unhandled: Arc::new(|_, entity, children| {
+                let mut seq = Vec::with_capacity(children.len() + 1);
+                seq.push(label(format!("[unhandled entity {entity:?}]")).into_any_flex());
+                seq.extend(children.into_iter().map(|child| child.into_any_flex()));
+                Arc::new(flex_col(seq))
+            }),
+            panicked: Arc::new(|_, entity| {
+                Arc::new(label(format!("[projector panicked at {entity:?}]")))
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for SynthesisFallbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SynthesisFallbacks").finish_non_exhaustive()
+    }
+}
+
+/// Text of the placeholder rendered where synthesis stopped at a [`UiSynthesisLimits`] bound.
+pub const SYNTHESIS_LIMIT_PLACEHOLDER: &str = "[synthesis limit exceeded]";
+
+/// Upper bounds that stop synthesis from descending into runaway trees.
+///
+/// An entity entered once `max_nodes` nodes are already in the pass, or nested deeper
+/// than `max_depth` (root = 1), is replaced by a [`SYNTHESIS_LIMIT_PLACEHOLDER`] label
+/// without visiting its subtree, and [`UiSynthesisStats::limit_hit_count`] is bumped.
+/// The defaults are far above any real UI.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiSynthesisLimits {
+    pub max_nodes: usize,
+    pub max_depth: usize,
+}
+
+impl UiSynthesisLimits {
+    pub const DEFAULT_MAX_NODES: usize = 100_000;
+    pub const DEFAULT_MAX_DEPTH: usize = 512;
+}
+
+impl Default for UiSynthesisLimits {
+    fn default() -> Self {
+        Self {
+            max_nodes: Self::DEFAULT_MAX_NODES,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+/// Resolves the UI children of an entity, in projection order.
+pub type ChildResolverFn = Arc<dyn Fn(&World, Entity) -> Vec<Entity> + Send + Sync>;
+
+/// Configuration for how synthesis walks the UI hierarchy.
+///
+/// By default synthesis follows Bevy's `Children`. Data-driven UIs can install a
+/// `child_resolver` that derives each node's children from their own data (an ordered
+/// list resource, a custom relationship, ...) instead of maintaining `ChildOf`.
+#[derive(Resource, Clone)]
+pub struct SynthesisConfig {
+    pub child_resolver: Option<ChildResolverFn>,
+    /// Catch projector panics and render [`SynthesisFallbacks::panicked`] for that entity
+    /// instead of aborting the whole pass.
+    ///
+    /// Off by default: a caught panic may leave state a projector was mutating through
+    /// interior mutability half-updated, so projectors must tolerate being unwound.
+    pub isolate_panics: bool,
+    /// Push [`UiNodeMounted`] / [`UiNodeUnmounted`] into [`UiEventQueue`] as entities
+    /// enter and leave the synthesized tree.
+    ///
+    /// Off by default because the queue keeps undrained events, and every node mounts on
+    /// the first frame.
+    pub emit_lifecycle_events: bool,
+    /// Keep the previous [`SynthesizedUiViews::front`] when a pass produced no roots or
+    /// caught a projector panic; the rejected pass is left in the back buffer.
+    pub keep_last_good_frame: bool,
+    /// Extra same-frame passes [`synthesize_ui`] may run for projectors that call
+    /// [`ProjectionCtx::request_resynthesis`](crate::ProjectionCtx::request_resynthesis).
+    ///
+    /// Caps measure-then-place loops; requests past the cap wait for the next frame.
+    pub max_resynthesis_passes: usize,
+    /// Reuse each entity's last view from [`UiViewCache`] while neither its components
+    /// nor its children's views changed.
+    ///
+    /// Off by default: projectors that read other entities or resources than the
+    /// stylesheet and locale (which clear the cache) would keep showing stale views.
+    pub view_cache: bool,
+}
+
+/// Default for [`SynthesisConfig::max_resynthesis_passes`].
+pub const DEFAULT_MAX_RESYNTHESIS_PASSES: usize = 2;
+
+impl Default for SynthesisConfig {
+    fn default() -> Self {
+        Self {
+            child_resolver: None,
+            isolate_panics: false,
+            emit_lifecycle_events: false,
+            keep_last_good_frame: false,
+            max_resynthesis_passes: DEFAULT_MAX_RESYNTHESIS_PASSES,
+            view_cache: false,
+        }
+    }
+}
+
+impl SynthesisConfig {
+    #[must_use]
+    pub fn with_isolate_panics(mut self, isolate_panics: bool) -> Self {
+        self.isolate_panics = isolate_panics;
+        self
+    }
+
+    #[must_use]
+    pub fn with_lifecycle_events(mut self, emit_lifecycle_events: bool) -> Self {
+        self.emit_lifecycle_events = emit_lifecycle_events;
+        self
+    }
+
+    #[must_use]
+    pub fn with_keep_last_good_frame(mut self, keep_last_good_frame: bool) -> Self {
+        self.keep_last_good_frame = keep_last_good_frame;
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_resynthesis_passes(mut self, max_resynthesis_passes: usize) -> Self {
+        self.max_resynthesis_passes = max_resynthesis_passes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_view_cache(mut self, view_cache: bool) -> Self {
+        self.view_cache = view_cache;
+        self
+    }
+
+    #[must_use]
+    pub fn with_child_resolver(
+        mut self,
+        resolver: impl Fn(&World, Entity) -> Vec<Entity> + Send + Sync + 'static,
+    ) -> Self {
+        self.child_resolver = Some(Arc::new(resolver));
+        self
+    }
+}
+
+impl fmt::Debug for SynthesisConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SynthesisConfig")
+            .field("child_resolver", &self.child_resolver.is_some())
+            .field("isolate_panics", &self.isolate_panics)
+            .field("emit_lifecycle_events", &self.emit_lifecycle_events)
+            .field("keep_last_good_frame", &self.keep_last_good_frame)
+            .field("max_resynthesis_passes", &self.max_resynthesis_passes)
+            .field("view_cache", &self.view_cache)
+            .finish()
+    }
+}
+
+/// Set by [`ProjectionCtx::request_resynthesis`](crate::ProjectionCtx::request_resynthesis)
+/// during a pass; [`synthesize_ui`] takes it after each pass.
+#[derive(Resource, Debug, Default)]
+pub struct ResynthesisRequest(AtomicBool);
+
+impl ResynthesisRequest {
+    pub fn request(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Clear the flag, returning whether it was set.
+    pub fn take(&self) -> bool {
+        self.0.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// Last synthesized view per entity, reused when [`SynthesisConfig::view_cache`] is on.
+///
+/// An entry stays valid while none of the entity's components changed (or were
+/// added/removed) since it was stored and every child produced the same view again.
+/// Entities that drop out of the tree are evicted after each pass.
+#[derive(Resource, Default)]
+pub struct UiViewCache {
+    entries: HashMap<Entity, CachedUiView>,
+}
+
+struct CachedUiView {
+    view: UiView,
+    children: Vec<UiView>,
+    component_count: usize,
+    /// World change tick (`Tick::get`) when the view was stored.
+    stored_at: u32,
+}
+
+impl UiViewCache {
+    /// Cached view of `entity`, if any.
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<&UiView> {
+        self.entries.get(&entity).map(|entry| &entry.view)
+    }
+
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entries.contains_key(&entity)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop every entry, forcing the next pass to project everything.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn lookup(&self, world: &World, entity: Entity, children: &[UiView]) -> Option<UiView> {
+        let entry = self.entries.get(&entity)?;
+        let children_unchanged = entry.children.len() == children.len()
+            && entry
+                .children
+                .iter()
+                .zip(children)
+                .all(|(cached, current)| Arc::ptr_eq(cached, current));
+        (children_unchanged && !entity_changed_since(world, entity, entry))
+            .then(|| entry.view.clone())
+    }
+
+    fn store(&mut self, world: &World, entity: Entity, view: UiView, children: Vec<UiView>) {
+        let Ok(entity_ref) = world.get_entity(entity) else {
+            return;
+        };
+        self.entries.insert(
+            entity,
+            CachedUiView {
+                view,
+                children,
+                component_count: entity_ref.archetype().component_count(),
+                stored_at: world.read_change_tick().get(),
+            },
+        );
+    }
+}
+
+impl fmt::Debug for UiViewCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UiViewCache")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+fn entity_changed_since(world: &World, entity: Entity, entry: &CachedUiView) -> bool {
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return true;
+    };
+    let archetype = entity_ref.archetype();
+    if archetype.component_count() != entry.component_count {
+        return true;
+    }
+
+    // Same wrap-around-safe comparison as Bevy's `Tick::is_newer_than`.
+    let now = world.read_change_tick().get();
+    let since_stored = now.wrapping_sub(entry.stored_at);
+    archetype.components().into_iter().any(|component_id| {
+        entity_ref
+            .get_change_ticks_by_id(component_id.to_owned())
+            .is_some_and(|ticks| now.wrapping_sub(ticks.changed.get()) < since_stored)
+    })
+}
+
+/// Children of `entity` in projection order.
+///
+/// With a [`SynthesisConfig::child_resolver`] installed this is what the resolver
+/// returns. Otherwise it is `Children` order, stably sorted by [`UiOrder`] when any child
+/// carries it. [`UiHidden`] children are left out either way. The views in
+/// [`ProjectionCtx::children`](crate::ProjectionCtx::children) follow the same order, so
+/// projectors pairing child entities with views should use this.
+#[must_use]
+pub fn ordered_children(world: &World, entity: Entity) -> Vec<Entity> {
+    let mut child_entities = ordered_children_with_hidden(world, entity);
+    child_entities.retain(|child| !is_hidden(world, *child));
+    child_entities
+}
+
+fn is_hidden(world: &World, entity: Entity) -> bool {
+    world.get::<UiHidden>(entity).is_some()
+}
+
+/// [`ordered_children`] before [`UiHidden`] children are filtered out.
+fn ordered_children_with_hidden(world: &World, entity: Entity) -> Vec<Entity> {
+    if let Some(resolver) = world
+        .get_resource::<SynthesisConfig>()
+        .and_then(|config| config.child_resolver.as_ref())
+    {
+        return resolver(world, entity);
+    }
+
+    let mut child_entities = world
+        .get::<Children>(entity)
+        .map(|children| children.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    if child_entities
+        .iter()
+        .any(|child| world.get::<UiOrder>(*child).is_some())
+    {
+        // `sort_by_key` is stable, so equal orders keep their `Children` position.
+        child_entities
+            .sort_by_key(|child| world.get::<UiOrder>(*child).copied().unwrap_or_default());
+    }
+    child_entities
 }
 
-/// Collect all entities marked with [`UiRoot`].
+/// Collect all entities marked with [`UiRoot`], in z-order.
+///
+/// Roots sort by [`UiRootOrder`] (missing = 0), then entity bits; overlay roots
+/// always come last so they render on top. [`OffscreenMeasure`] and [`UiHidden`] roots
+/// are skipped.
 pub fn gather_ui_roots(world: &mut World) -> Vec<Entity> {
-    let mut query = world.query_filtered::<(Entity, Option<&UiOverlayRoot>), With<UiRoot>>();
+    let mut query = world.query_filtered::<
+        (Entity, Option<&UiOverlayRoot>, Option<&UiRootOrder>),
+        (With<UiRoot>, Without<OffscreenMeasure>, Without<UiHidden>),
+    >();
     let mut roots = query
         .iter(world)
-        .map(|(entity, overlay)| (entity, overlay.is_some()))
+        .map(|(entity, overlay, order)| {
+            (
+                entity,
+                overlay.is_some(),
+                order.copied().unwrap_or_default(),
+            )
+        })
         .collect::<Vec<_>>();
 
-    // Keep deterministic ordering while ensuring overlays are synthesized last.
-    roots.sort_by_key(|(entity, is_overlay)| (*is_overlay, entity.to_bits()));
-    roots.into_iter().map(|(entity, _)| entity).collect()
+    roots.sort_by_key(|(entity, is_overlay, order)| (*is_overlay, *order, entity.to_bits()));
+    roots.into_iter().map(|(entity, _, _)| entity).collect()
 }
 
 /// Synthesize Xilem Masonry views and stats for provided roots.
@@ -44,25 +651,61 @@ pub fn synthesize_roots_with_stats(
     registry: &UiProjectorRegistry,
     roots: impl IntoIterator<Item = Entity>,
 ) -> (Vec<UiView>, UiSynthesisStats) {
+    let (views, stats, _) = synthesize_roots_tracking_mounts(world, registry, roots, None);
+    (views, stats)
+}
+
+/// Like [`synthesize_roots_with_stats`], also returning every entity that was projected.
+fn synthesize_roots_tracking_mounts(
+    world: &World,
+    registry: &UiProjectorRegistry,
+    roots: impl IntoIterator<Item = Entity>,
+    view_cache: Option<&mut UiViewCache>,
+) -> (Vec<UiView>, UiSynthesisStats, HashSet<Entity>) {
     let roots = roots.into_iter().collect::<Vec<_>>();
-    let mut output = Vec::with_capacity(roots.len());
-    let mut stats = UiSynthesisStats {
-        root_count: roots.len(),
-        ..UiSynthesisStats::default()
+    let default_fallbacks;
+    let fallbacks = match world.get_resource::<SynthesisFallbacks>() {
+        Some(fallbacks) => fallbacks,
+        None => {
+            default_fallbacks = SynthesisFallbacks::default();
+            &default_fallbacks
+        }
+    };
+    let mut pass = SynthesisPass {
+        world,
+        registry,
+        fallbacks,
+        isolate_panics: world
+            .get_resource::<SynthesisConfig>()
+            .is_some_and(|config| config.isolate_panics),
+        limits: world
+            .get_resource::<UiSynthesisLimits>()
+            .copied()
+            .unwrap_or_default(),
+        visiting: Vec::new(),
+        stats: UiSynthesisStats {
+            root_count: roots.len(),
+            ..UiSynthesisStats::default()
+        },
+        mounted: HashSet::new(),
+        view_cache,
+        reused_view_count: 0,
     };
-    let mut visiting = Vec::new();
 
-    for root in roots {
-        output.push(synthesize_entity(
-            world,
-            registry,
-            root,
-            &mut visiting,
-            &mut stats,
-        ));
-    }
+    let output = roots
+        .into_iter()
+        .map(|root| {
+            let nodes_before = pass.stats.node_count;
+            let view = pass.synthesize_entity(root);
+            pass.stats
+                .per_root_node_counts
+                .push(pass.stats.node_count - nodes_before);
+            view
+        })
+        .collect::<Vec<_>>();
 
-    (output, stats)
+    pass.stats.rebuilt_view_count = pass.stats.node_count - pass.reused_view_count;
+    (output, pass.stats, pass.mounted)
 }
 
 /// Synthesize Xilem Masonry views for provided roots.
@@ -74,65 +717,307 @@ pub fn synthesize_roots(
     synthesize_roots_with_stats(world, registry, roots).0
 }
 
+/// Synthesize the subtree rooted at `entity` on its own, outside the visible roots.
+pub fn synthesize_subtree(
+    world: &World,
+    registry: &UiProjectorRegistry,
+    entity: Entity,
+) -> (UiView, UiSynthesisStats) {
+    let (mut views, stats) = synthesize_roots_with_stats(world, registry, [entity]);
+    (views.remove(0), stats)
+}
+
 /// Synthesize by auto-discovering all [`UiRoot`] entities.
 pub fn synthesize_world(world: &mut World, registry: &UiProjectorRegistry) -> Vec<UiView> {
     let roots = gather_ui_roots(world);
     synthesize_roots(world, registry, roots)
 }
 
-fn synthesize_entity(
-    world: &World,
-    registry: &UiProjectorRegistry,
-    entity: Entity,
-    visiting: &mut Vec<Entity>,
-    stats: &mut UiSynthesisStats,
-) -> UiView {
-    if world.get_entity(entity).is_err() {
-        stats.node_count += 1;
-        stats.missing_entity_count += 1;
-        return Arc::new(label(format!("[missing entity {entity:?}]")));
+/// State threaded through one synthesis pass.
+struct SynthesisPass<'a> {
+    world: &'a World,
+    registry: &'a UiProjectorRegistry,
+    fallbacks: &'a SynthesisFallbacks,
+    isolate_panics: bool,
+    limits: UiSynthesisLimits,
+    visiting: Vec<Entity>,
+    stats: UiSynthesisStats,
+    mounted: HashSet<Entity>,
+    view_cache: Option<&'a mut UiViewCache>,
+    /// Nodes served from `view_cache` this pass.
+    reused_view_count: usize,
+}
+
+/// One step of the iterative synthesis walk.
+enum SynthesisStep {
+    /// Check the entity and schedule its children.
+    Enter(Entity),
+    /// Project the entity from the last `child_count` synthesized views.
+    Leave { entity: Entity, child_count: usize },
+}
+
+impl SynthesisPass<'_> {
+    /// Synthesize `root` and its descendants.
+    ///
+    /// The walk is iterative: each entity is visited twice through an explicit
+    /// work stack, once on enter (missing/cycle checks, child discovery) and once
+    /// on leave (projection of the already-synthesized children). This keeps
+    /// arbitrarily deep trees off the native call stack while preserving the
+    /// post-order projection, `visiting` path semantics and stats of a plain
+    /// recursive descent.
+    fn synthesize_entity(&mut self, root: Entity) -> UiView {
+        let mut work = vec![SynthesisStep::Enter(root)];
+        let mut views: Vec<UiView> = Vec::new();
+
+        while let Some(step) = work.pop() {
+            match step {
+                SynthesisStep::Enter(entity) => {
+                    if let Some(view) = self.enter_entity(entity, &mut work) {
+                        views.push(view);
+                    }
+                }
+                SynthesisStep::Leave {
+                    entity,
+                    child_count,
+                } => {
+                    let children = views.split_off(views.len() - child_count);
+                    let view = self.leave_entity(entity, children);
+                    views.push(view);
+                }
+            }
+        }
+
+        debug_assert_eq!(views.len(), 1);
+        views
+            .pop()
+            .expect("synthesis work stack always yields one view per root")
     }
 
-    if visiting.contains(&entity) {
-        stats.node_count += 1;
-        stats.cycle_count += 1;
-        return Arc::new(label(format!("[cycle at {entity:?}]")));
+    /// Enter phase: returns a finished view for missing/cyclic entities, or
+    /// schedules the entity's leave step after its children.
+    fn enter_entity(&mut self, entity: Entity, work: &mut Vec<SynthesisStep>) -> Option<UiView> {
+        let world = self.world;
+        let fallbacks = self.fallbacks;
+
+        if world.get_entity(entity).is_err() {
+            self.stats.node_count += 1;
+            self.stats.missing_entity_count += 1;
+            return Some((fallbacks.missing)(world, entity));
+        }
+
+        if self.visiting.contains(&entity) {
+            self.stats.node_count += 1;
+            self.stats.cycle_count += 1;
+            return Some((fallbacks.cycle)(world, entity));
+        }
+
+        // Nodes already in the pass: finished ones plus the ancestors still open.
+        let entered = self.stats.node_count + self.visiting.len();
+        if entered >= self.limits.max_nodes || self.visiting.len() >= self.limits.max_depth {
+            self.stats.node_count += 1;
+            self.stats.limit_hit_count += 1;
+            return Some(Arc::new(label(SYNTHESIS_LIMIT_PLACEHOLDER)));
+        }
+
+        self.visiting.push(entity);
+        self.stats.max_depth = self.stats.max_depth.max(self.visiting.len());
+
+        let mut children = ordered_children_with_hidden(world, entity);
+        children.retain(|child| {
+            let hidden = is_hidden(world, *child);
+            if hidden {
+                self.stats.node_count += 1;
+                self.stats.hidden_count += 1;
+            }
+            !hidden
+        });
+        work.push(SynthesisStep::Leave {
+            entity,
+            child_count: children.len(),
+        });
+        work.extend(children.into_iter().rev().map(SynthesisStep::Enter));
+        None
     }
 
-    visiting.push(entity);
+    /// Leave phase: project `entity` from its synthesized children.
+    fn leave_entity(&mut self, entity: Entity, children: Vec<UiView>) -> UiView {
+        let world = self.world;
+        let fallbacks = self.fallbacks;
 
-    let child_entities = world
-        .get::<Children>(entity)
-        .map(|children| children.iter().collect::<Vec<_>>())
-        .unwrap_or_default();
+        if let Some(view) = self
+            .view_cache
+            .as_deref()
+            .and_then(|cache| cache.lookup(world, entity, &children))
+        {
+            self.stats.node_count += 1;
+            self.reused_view_count += 1;
+            self.mounted.insert(entity);
+            let popped = self.visiting.pop();
+            debug_assert_eq!(popped, Some(entity));
+            return view;
+        }
 
-    let children = child_entities
-        .into_iter()
-        .map(|child| synthesize_entity(world, registry, child, visiting, stats))
-        .collect::<Vec<_>>();
+        let node_id = world
+            .get::<UiNodeId>(entity)
+            .map_or_else(|| entity.to_bits(), |id| id.0);
 
-    let node_id = entity.to_bits();
+        let mut cacheable = false;
+        let base_view: UiView = match self.project(entity, node_id, &children) {
+            Ok(Some(view)) => {
+                cacheable = true;
+                view
+            }
+            Ok(None) => {
+                self.stats.unhandled_count += 1;
+                (fallbacks.unhandled)(world, entity, children.clone())
+            }
+            Err(()) => {
+                self.stats.panicked_count += 1;
+                (fallbacks.panicked)(world, entity)
+            }
+        };
 
-    let projected = registry.project_node(world, entity, node_id, children.clone());
+        let base_view = decorate_with_overflow(world, entity, base_view);
+        let base_view = decorate_with_count_badge(world, entity, base_view);
+        let base_view = decorate_with_transform(world, entity, base_view);
+        let remount_key = world.get::<UiRemountKey>(entity).map(|key| key.0);
+        let view: UiView = Arc::new(entity_scope(entity, base_view).with_remount_key(remount_key));
+        if cacheable && let Some(cache) = self.view_cache.as_deref_mut() {
+            cache.store(world, entity, view.clone(), children);
+        }
+
+        self.stats.node_count += 1;
+        self.mounted.insert(entity);
+
+        let popped = self.visiting.pop();
+        debug_assert_eq!(popped, Some(entity));
 
-    let base_view: UiView = if let Some(view) = projected {
         view
-    } else {
-        stats.unhandled_count += 1;
-        let mut seq = Vec::with_capacity(children.len() + 1);
-        seq.push(label(format!("[unhandled entity {entity:?}]")).into_any_flex());
-        seq.extend(children.into_iter().map(|child| child.into_any_flex()));
-        Arc::new(flex_col(seq))
+    }
+
+    /// Run the registered projectors, catching panics when isolation is enabled.
+    fn project(
+        &self,
+        entity: Entity,
+        node_id: u64,
+        children: &[UiView],
+    ) -> Result<Option<UiView>, ()> {
+        let project = || {
+            self.registry
+                .project_node(self.world, entity, node_id, children.to_vec())
+        };
+        if !self.isolate_panics {
+            return Ok(project());
+        }
+
+        catch_unwind(AssertUnwindSafe(project)).map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| (*message).to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "<non-string panic payload>".to_string());
+            error!(?entity, %message, "UI projector panicked; rendering fallback view");
+        })
+    }
+}
+
+/// Emitted through [`UiEventQueue`] when an entity is projected for the first time
+/// (or again after having been unmounted).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiNodeMounted {
+    pub entity: Entity,
+}
+
+/// Emitted through [`UiEventQueue`] when a previously projected entity is no longer
+/// part of the synthesized tree (despawned, detached, or hidden).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiNodeUnmounted {
+    pub entity: Entity,
+}
+
+/// Entities projected by the latest synthesis pass.
+///
+/// When [`SynthesisConfig::emit_lifecycle_events`] is set, [`synthesize_ui`] diffs this
+/// set across frames to emit [`UiNodeMounted`] and [`UiNodeUnmounted`].
+#[derive(Resource, Debug, Default)]
+pub struct UiMountedNodes {
+    entities: HashSet<Entity>,
+}
+
+impl UiMountedNodes {
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(&entity)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+fn emit_mount_lifecycle_events(world: &mut World, mounted: HashSet<Entity>) {
+    let Some(mut nodes) = world.get_resource_mut::<UiMountedNodes>() else {
+        return;
     };
+    let previous = std::mem::replace(&mut nodes.entities, mounted);
+    if !world
+        .get_resource::<SynthesisConfig>()
+        .is_some_and(|config| config.emit_lifecycle_events)
+    {
+        return;
+    }
+    let current = &world.resource::<UiMountedNodes>().entities;
 
-    let view: UiView = Arc::new(entity_scope(entity, base_view));
+    let mut newly_mounted = current.difference(&previous).copied().collect::<Vec<_>>();
+    let mut unmounted = previous.difference(current).copied().collect::<Vec<_>>();
+    if newly_mounted.is_empty() && unmounted.is_empty() {
+        return;
+    }
+    newly_mounted.sort_by_key(|entity| entity.to_bits());
+    unmounted.sort_by_key(|entity| entity.to_bits());
 
-    stats.node_count += 1;
+    let Some(queue) = world.get_resource::<UiEventQueue>() else {
+        return;
+    };
+    for entity in unmounted {
+        queue.push_typed(entity, UiNodeUnmounted { entity });
+    }
+    for entity in newly_mounted {
+        queue.push_typed(entity, UiNodeMounted { entity });
+    }
+}
 
-    let popped = visiting.pop();
-    debug_assert_eq!(popped, Some(entity));
+/// Remove [`UiViewCache`] from the world for the duration of a pass, if enabled.
+///
+/// The cache is cleared when the stylesheet, locale or synthesis setup changed, since
+/// those affect views without touching the projected entities.
+fn take_view_cache(world: &mut World) -> Option<UiViewCache> {
+    if !world
+        .get_resource::<SynthesisConfig>()
+        .is_some_and(|config| config.view_cache)
+    {
+        world.remove_resource::<UiViewCache>();
+        return None;
+    }
 
-    view
+    let invalidated = world.is_resource_changed::<StyleSheet>()
+        || world.is_resource_changed::<StyleBaseFontSize>()
+        || world.is_resource_changed::<LocaleStyleOverrides>()
+        || world.is_resource_changed::<AppI18n>()
+        || world.is_resource_changed::<UiProjectorRegistry>()
+        || world.is_resource_changed::<SynthesisFallbacks>()
+        || world.is_resource_changed::<SynthesisConfig>();
+    let mut view_cache = world.remove_resource::<UiViewCache>().unwrap_or_default();
+    if invalidated {
+        view_cache.clear();
+    }
+    Some(view_cache)
 }
 
 /// Bevy system that synthesizes all roots and updates [`SynthesizedUiViews`] + [`UiSynthesisStats`].
@@ -145,11 +1030,128 @@ pub fn synthesize_ui(world: &mut World) {
         return;
     }
 
-    let roots = gather_ui_roots(world);
-    let (synthesized, stats) = world.resource_scope(|world, registry: Mut<UiProjectorRegistry>| {
-        synthesize_roots_with_stats(world, &registry, roots)
-    });
+    let started = Instant::now();
+    let max_passes = 1 + world
+        .get_resource::<SynthesisConfig>()
+        .map_or(DEFAULT_MAX_RESYNTHESIS_PASSES, |config| {
+            config.max_resynthesis_passes
+        });
+
+    let mut view_cache = take_view_cache(world);
+
+    let mut pass_count = 0;
+    let (synthesized, mut stats, mounted) = loop {
+        let roots = gather_ui_roots(world);
+        let output = world.resource_scope(|world, registry: Mut<UiProjectorRegistry>| {
+            synthesize_roots_tracking_mounts(world, &registry, roots, view_cache.as_mut())
+        });
+        pass_count += 1;
+
+        let requested = world
+            .get_resource::<ResynthesisRequest>()
+            .is_some_and(ResynthesisRequest::take);
+        if !requested {
+            break output;
+        }
+        if pass_count >= max_passes {
+            warn!(
+                pass_count,
+                "re-synthesis still requested after the pass cap; deferring to next frame"
+            );
+            break output;
+        }
+    };
+
+    if let Some(mut view_cache) = view_cache {
+        view_cache
+            .entries
+            .retain(|entity, _| mounted.contains(entity));
+        world.insert_resource(view_cache);
+    }
 
-    world.resource_mut::<SynthesizedUiViews>().roots = synthesized;
+    stats.elapsed = started.elapsed();
+    stats.pass_count = pass_count;
+    let failed = synthesized.is_empty() || stats.panicked_count > 0;
+    let keep_front = failed
+        && world
+            .get_resource::<SynthesisConfig>()
+            .is_some_and(|config| config.keep_last_good_frame);
+    let mut views = world.resource_mut::<SynthesizedUiViews>();
+    if keep_front {
+        views.stage(synthesized);
+    } else {
+        views.present(synthesized);
+    }
     *world.resource_mut::<UiSynthesisStats>() = stats;
+    emit_mount_lifecycle_events(world, mounted);
+}
+
+/// Entities scheduled for despawn once the current frame has been synthesized.
+///
+/// Despawning mid-frame makes the next synthesis pass fall back to
+/// [`SynthesisFallbacks`] for entities other systems still reference.
+/// [`apply_deferred_despawns`] despawns everything scheduled here at the end of
+/// `PostUpdate`, after synthesis and the retained-tree rebuild.
+#[derive(Resource, Debug, Default)]
+pub struct DeferredDespawn {
+    pending: Vec<Entity>,
+}
+
+impl DeferredDespawn {
+    /// Schedule `entity` (and its descendants) for despawn at the end of the frame.
+    pub fn schedule(&mut self, entity: Entity) {
+        if !self.pending.contains(&entity) {
+            self.pending.push(entity);
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.pending.contains(&entity)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// Action: despawn the event entity at the end of the frame via [`DeferredDespawn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DespawnDeferred;
+
+/// Schedule `entity` on the [`DeferredDespawn`] resource, initializing it if needed.
+pub fn defer_despawn(world: &mut World, entity: Entity) {
+    world
+        .get_resource_or_insert_with(DeferredDespawn::default)
+        .schedule(entity);
+}
+
+/// Bevy system that despawns entities scheduled through [`DeferredDespawn`] or
+/// [`DespawnDeferred`] actions.
+pub fn apply_deferred_despawns(world: &mut World) {
+    let requested = world
+        .get_resource_mut::<UiEventQueue>()
+        .map(|mut queue| queue.drain_actions::<DespawnDeferred>())
+        .unwrap_or_default();
+    let mut pending = world
+        .get_resource_mut::<DeferredDespawn>()
+        .map(|mut deferred| std::mem::take(&mut deferred.pending))
+        .unwrap_or_default();
+    for event in requested {
+        if !pending.contains(&event.entity) {
+            pending.push(event.entity);
+        }
+    }
+
+    for entity in pending {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use std::{
+    collections::HashSet,
     sync::{
-        Arc, Once,
+        Arc, Mutex, Once,
         atomic::{AtomicUsize, Ordering},
     },
     time::Duration,
@@ -18,6 +19,7 @@ use bevy_app::App;
 use bevy_ecs::{hierarchy::ChildOf, prelude::*};
 use bevy_input::{
     ButtonInput, ButtonState,
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey},
     mouse::{MouseButton, MouseButtonInput, MouseScrollUnit, MouseWheel},
 };
 use bevy_math::{Rect, Vec2};
@@ -27,7 +29,7 @@ use bevy_tween::{
     interpolation::EaseKind,
     tween::ComponentTween,
 };
-use bevy_window::{CursorMoved, PrimaryWindow, Window, WindowResized};
+use bevy_window::{CursorMoved, PrimaryWindow, Window, WindowResized, WindowScaleFactorChanged};
 use masonry::core::{Widget, WidgetId, WidgetRef};
 
 #[derive(Component, Debug, Clone, Copy)]
@@ -76,7 +78,7 @@ fn init_test_tracing() {
 #[test]
 fn plugin_wires_synthesis_and_runtime() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .register_projector::<TestRoot>(project_test_root);
 
     app.world_mut().spawn((UiRoot, TestRoot));
@@ -84,7 +86,7 @@ fn plugin_wires_synthesis_and_runtime() {
     app.update();
 
     let synthesized = app.world().resource::<crate::SynthesizedUiViews>();
-    assert_eq!(synthesized.roots.len(), 2);
+    assert_eq!(synthesized.front().len(), 2);
 
     let _runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
 }
@@ -92,7 +94,7 @@ fn plugin_wires_synthesis_and_runtime() {
 #[test]
 fn plugin_auto_registers_builtin_ui_components_without_manual_setup() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     app.world_mut()
         .spawn((UiRoot, crate::UiButton::new("auto-builtins")));
@@ -106,7 +108,7 @@ fn plugin_auto_registers_builtin_ui_components_without_manual_setup() {
 #[test]
 fn plugin_boots_with_embedded_fluent_dark_theme_and_applies_on_first_update() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let active = app.world().resource::<crate::ActiveStyleSheetAsset>();
     assert!(active.path.is_none());
@@ -130,7 +132,7 @@ fn plugin_boots_with_embedded_fluent_dark_theme_and_applies_on_first_update() {
 #[test]
 fn active_style_variant_switches_automatically_without_install_calls() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     crate::set_active_style_variant_by_name(app.world_mut(), "light");
     app.update();
@@ -155,7 +157,7 @@ fn active_style_variant_switches_automatically_without_install_calls() {
 #[test]
 fn active_style_variant_light_overrides_surface_bg_token() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     crate::set_active_style_variant_by_name(app.world_mut(), "light");
     app.update();
@@ -176,7 +178,7 @@ fn active_style_variant_light_overrides_surface_bg_token() {
 #[test]
 fn active_style_variant_high_contrast_overrides_surface_bg_token() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     crate::set_active_style_variant_by_name(app.world_mut(), "high-contrast");
     app.update();
@@ -197,7 +199,7 @@ fn active_style_variant_high_contrast_overrides_surface_bg_token() {
 #[test]
 fn active_style_variant_api_switches_between_dark_light_and_high_contrast() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     crate::set_active_style_variant_by_name(app.world_mut(), "light");
     app.update();
@@ -248,8 +250,9 @@ fn active_style_variant_api_switches_between_dark_light_and_high_contrast() {
 #[test]
 fn load_style_sheet_ron_applies_and_persists_across_variant_switches() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin).load_style_sheet_ron(
-        r##"(
+    app.add_plugins(PicusPlugin::default())
+        .load_style_sheet_ron(
+            r##"(
             rules: [
                 (
                     selector: Class("demo.embedded"),
@@ -261,7 +264,7 @@ fn load_style_sheet_ron_applies_and_persists_across_variant_switches() {
                 ),
             ],
         )"##,
-    );
+        );
 
     let entity = app
         .world_mut()
@@ -408,7 +411,7 @@ fn apply_active_style_variant_applies_selected_registered_variant_to_runtime_she
 #[test]
 fn input_bridge_uses_primary_window_cursor_for_click_and_emits_move_before_down_up() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
     window.resolution.set(800.0, 600.0);
@@ -465,7 +468,7 @@ fn input_bridge_uses_primary_window_cursor_for_click_and_emits_move_before_down_
 #[test]
 fn input_bridge_uses_primary_window_cursor_for_mouse_wheel_events() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
     window.resolution.set(800.0, 600.0);
@@ -511,7 +514,7 @@ fn input_bridge_uses_primary_window_cursor_for_mouse_wheel_events() {
 #[test]
 fn input_bridge_uses_primary_window_logical_size_for_resize_events() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
     window.resolution.set(800.0, 600.0);
@@ -541,16 +544,58 @@ fn input_bridge_uses_primary_window_logical_size_for_resize_events() {
     assert_eq!(runtime.viewport_size(), (1280.0, 720.0));
 }
 
+#[test]
+fn input_bridge_applies_primary_window_scale_factor_changes() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    window.resolution.set_scale_factor(2.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    app.update();
+
+    {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        assert_eq!(runtime.scale_factor(), 2.0);
+    }
+
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut primary_window = query
+            .single_mut(world)
+            .expect("primary window should exist");
+        primary_window.resolution.set_scale_factor(1.5);
+    }
+
+    // Moving between monitors: payload is stale, the bridge should read the Window.
+    app.world_mut().write_message(WindowScaleFactorChanged {
+        window: window_entity,
+        scale_factor: 1.0,
+    });
+
+    app.update();
+
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    assert_eq!(runtime.scale_factor(), 1.5);
+    assert_eq!(runtime.masonry_scale_factors(), (1.5, 1.5));
+}
+
 #[test]
 fn clicking_text_input_enables_window_ime() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
     window.resolution.set(800.0, 600.0);
     let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
     let input = app
         .world_mut()
         .spawn((
@@ -583,7 +628,7 @@ fn clicking_text_input_enables_window_ime() {
 #[test]
 fn ui_event_queue_drains_typed_actions() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .register_projector::<TestRoot>(project_test_root);
 
     let root = app.world_mut().spawn((UiRoot, TestRoot)).id();
@@ -605,10 +650,85 @@ fn ui_event_queue_drains_typed_actions() {
     assert_eq!(actions[0].action, TestAction::Clicked);
 }
 
+#[test]
+fn coalesced_drain_delivers_only_latest_slider_change_per_entity() {
+    let mut world = World::new();
+    let slider = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    let mut queue = UiEventQueue::default();
+    for (entity, value) in [(slider, 0.1), (other, 0.5), (slider, 0.2), (slider, 0.3)] {
+        queue.push_typed(
+            entity,
+            crate::UiSliderChanged {
+                slider: entity,
+                value,
+            },
+        );
+    }
+    queue.push_typed(slider, TestAction::Clicked);
+
+    let changes = queue
+        .drain_actions_coalesced::<crate::UiSliderChanged>()
+        .into_iter()
+        .map(|event| (event.entity, event.action.value))
+        .collect::<Vec<_>>();
+
+    assert_eq!(changes, vec![(other, 0.5), (slider, 0.3)]);
+    assert_eq!(queue.drain_actions::<TestAction>().len(), 1);
+}
+
+#[test]
+fn recorded_click_replays_same_handler_effect() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<TestRoot>(project_test_root);
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    let root = app.world_mut().spawn((UiRoot, TestRoot)).id();
+
+    app.update();
+    app.update();
+
+    app.world()
+        .resource::<UiEventQueue>()
+        .register_recordable::<TestAction>();
+
+    let button_center = widget_center_for_entity(&app, root);
+    send_primary_click(&mut app, window_entity, button_center);
+
+    let recorded = app.world().resource::<UiEventQueue>().snapshot();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].entity, root);
+    assert_eq!(recorded[0].type_name(), std::any::type_name::<TestAction>());
+    assert_eq!(
+        recorded[0].action::<TestAction>(),
+        Some(&TestAction::Clicked)
+    );
+
+    let handle_clicks = |app: &mut App| {
+        app.world_mut()
+            .resource_mut::<UiEventQueue>()
+            .drain_actions::<TestAction>()
+            .into_iter()
+            .map(|event| (event.entity, event.action))
+            .collect::<Vec<_>>()
+    };
+
+    let live = handle_clicks(&mut app);
+    assert_eq!(live, vec![(root, TestAction::Clicked)]);
+    assert!(handle_clicks(&mut app).is_empty());
+
+    app.world().resource::<UiEventQueue>().replay(&recorded);
+    assert_eq!(handle_clicks(&mut app), live);
+}
+
 #[test]
 fn plugin_initializes_app_i18n_resource() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     assert!(app.world().contains_resource::<AppI18n>());
 }
@@ -616,11 +736,12 @@ fn plugin_initializes_app_i18n_resource() {
 #[test]
 fn app_i18n_resolves_showcase_hello_world_for_zh_cn() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin).register_i18n_bundle(
-        "zh-CN",
-        SyncTextSource::String(include_str!("../../../assets/locales/zh-CN/main.ftl")),
-        vec!["Inter", "Noto Sans CJK SC", "sans-serif"],
-    );
+    app.add_plugins(PicusPlugin::default())
+        .register_i18n_bundle(
+            "zh-CN",
+            SyncTextSource::String(include_str!("../../../assets/locales/zh-CN/main.ftl")),
+            vec!["Inter", "Noto Sans CJK SC", "sans-serif"],
+        );
 
     assert_eq!(
         app.world().resource::<AppI18n>().translate("hello_world"),
@@ -631,11 +752,12 @@ fn app_i18n_resolves_showcase_hello_world_for_zh_cn() {
 #[test]
 fn resolve_localized_text_prefers_translation_over_uilabel_fallback() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin).register_i18n_bundle(
-        "zh-CN",
-        SyncTextSource::String(include_str!("../../../assets/locales/zh-CN/main.ftl")),
-        vec!["Inter", "Noto Sans CJK SC", "sans-serif"],
-    );
+    app.add_plugins(PicusPlugin::default())
+        .register_i18n_bundle(
+            "zh-CN",
+            SyncTextSource::String(include_str!("../../../assets/locales/zh-CN/main.ftl")),
+            vec!["Inter", "Noto Sans CJK SC", "sans-serif"],
+        );
 
     let entity = app
         .world_mut()
@@ -653,7 +775,7 @@ fn resolve_localized_text_prefers_translation_over_uilabel_fallback() {
 #[test]
 fn localized_text_updates_after_active_locale_change() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .insert_resource(AppI18n::new(
             "en-US"
                 .parse()
@@ -694,3050 +816,8306 @@ fn localized_text_updates_after_active_locale_change() {
 }
 
 #[test]
-fn synthesis_stats_track_missing_entity() {
-    let mut world = World::new();
-    let mut registry = UiProjectorRegistry::default();
-    register_builtin_projectors(&mut registry);
-
-    let stale_root = world.spawn_empty().id();
-    assert!(world.despawn(stale_root));
-
-    let (_roots, stats) = synthesize_roots_with_stats(&world, &registry, [stale_root]);
-
-    assert_eq!(stats.root_count, 1);
-    assert_eq!(stats.node_count, 1);
-    assert_eq!(stats.missing_entity_count, 1);
-    assert_eq!(stats.cycle_count, 0);
-}
-
-#[test]
-fn builtin_registry_projects_label() {
-    let mut world = World::new();
-    let mut registry = UiProjectorRegistry::default();
-    register_builtin_projectors(&mut registry);
-
-    let root = world.spawn((UiRoot, crate::UiLabel::new("ok"))).id();
-
-    let (roots, stats) = synthesize_roots_with_stats(&world, &registry, [root]);
+fn deferred_despawn_survives_current_synthesis_and_is_gone_next_frame() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.world_mut()
+        .resource_mut::<crate::SynthesisConfig>()
+        .emit_lifecycle_events = true;
 
-    assert_eq!(roots.len(), 1);
-    assert_eq!(stats.unhandled_count, 0);
-    assert_eq!(stats.missing_entity_count, 0);
-}
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let card = app
+        .world_mut()
+        .spawn((crate::UiLabel::new("card"), ChildOf(root)))
+        .id();
+    app.update();
 
-#[test]
-fn resolve_style_for_entity_classes_applies_hover_pseudo_state() {
-    let mut world = World::new();
-    let mut sheet = StyleSheet::default();
-    let base = crate::xilem::Color::from_rgb8(0x11, 0x22, 0x33);
-    let hover = crate::xilem::Color::from_rgb8(0xAA, 0xBB, 0xCC);
+    crate::defer_despawn(app.world_mut(), card);
+    app.update();
 
-    sheet.set_class(
-        "test.button",
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(base),
-                hover_bg: Some(hover),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
+    // This frame's pass still projected the card; it was despawned afterwards.
+    assert!(
+        app.world()
+            .resource::<crate::UiMountedNodes>()
+            .contains(card)
     );
-    world.insert_resource(sheet);
+    assert_eq!(
+        app.world()
+            .resource::<crate::UiSynthesisStats>()
+            .missing_entity_count,
+        0
+    );
+    assert!(app.world().get_entity(card).is_err());
+    assert!(app.world().resource::<crate::DeferredDespawn>().is_empty());
 
-    let entity = world
-        .spawn((InteractionState {
-            hovered: true,
-            pressed: false,
-        },))
-        .id();
-    let resolved = resolve_style_for_entity_classes(&world, entity, ["test.button"]);
+    app.update();
 
-    assert_eq!(resolved.colors.bg, Some(hover));
+    assert!(
+        !app.world()
+            .resource::<crate::UiMountedNodes>()
+            .contains(card)
+    );
+    let unmounted = app
+        .world_mut()
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiNodeUnmounted>();
+    assert!(unmounted.iter().any(|event| event.entity == card));
+    assert_eq!(
+        app.world()
+            .resource::<crate::UiSynthesisStats>()
+            .missing_entity_count,
+        0
+    );
 }
 
 #[test]
-fn resolve_style_without_any_style_source_uses_transparent_text_fallback() {
+fn despawn_deferred_action_despawns_at_end_of_frame() {
     let mut world = World::new();
+    world.init_resource::<UiEventQueue>();
     let entity = world.spawn_empty().id();
 
-    let resolved = resolve_style(&world, entity);
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(entity, crate::DespawnDeferred);
+    assert!(world.get_entity(entity).is_ok());
 
-    assert_eq!(resolved.colors.text, Some(crate::xilem::Color::TRANSPARENT));
+    crate::apply_deferred_despawns(&mut world);
+    assert!(world.get_entity(entity).is_err());
 }
 
 #[test]
-fn selector_and_rule_applies_hover_and_pressed_states() {
+fn ui_roots_synthesize_in_root_order_with_overlay_last() {
     let mut world = World::new();
-    let mut sheet = StyleSheet::default();
+    let overlay = world
+        .spawn((UiRoot, crate::UiOverlayRoot, crate::UiRootOrder(-10)))
+        .id();
+    let top = world
+        .spawn((UiRoot, crate::UiRootOrder(5), crate::UiLabel::new("top")))
+        .id();
+    let bottom = world
+        .spawn((
+            UiRoot,
+            crate::UiRootOrder(-1),
+            crate::UiLabel::new("bottom"),
+        ))
+        .id();
+    let unordered = world.spawn((UiRoot, crate::UiLabel::new("middle"))).id();
 
-    let base = crate::xilem::Color::from_rgb8(0x22, 0x22, 0x22);
-    let hover = crate::xilem::Color::from_rgb8(0x44, 0x44, 0x44);
-    let pressed = crate::xilem::Color::from_rgb8(0x66, 0x66, 0x66);
+    assert_eq!(
+        crate::gather_ui_roots(&mut world),
+        vec![bottom, unordered, top, overlay]
+    );
 
-    sheet.add_rule(StyleRule::new(
-        Selector::class("test.button"),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(base),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
-    sheet.add_rule(StyleRule::new(
-        Selector::and(vec![
-            Selector::class("test.button"),
-            Selector::pseudo(crate::PseudoClass::Hovered),
-        ]),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(hover),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
-    sheet.add_rule(StyleRule::new(
-        Selector::and(vec![
-            Selector::class("test.button"),
-            Selector::pseudo(crate::PseudoClass::Pressed),
-        ]),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(pressed),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
+    world.entity_mut(bottom).insert(crate::UiRootOrder(10));
+    assert_eq!(
+        crate::gather_ui_roots(&mut world),
+        vec![unordered, top, bottom, overlay]
+    );
+}
 
-    world.insert_resource(sheet);
+#[test]
+fn offscreen_measure_root_is_measured_without_joining_visible_roots() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    let entity = world
+    app.world_mut()
+        .spawn((UiRoot, crate::UiLabel::new("visible")));
+    let short = app
+        .world_mut()
+        .spawn((UiRoot, crate::OffscreenMeasure, crate::UiLabel::new("Hi")))
+        .id();
+    let long = app
+        .world_mut()
         .spawn((
-            crate::StyleClass(vec!["test.button".to_string()]),
-            InteractionState {
-                hovered: true,
-                pressed: true,
-            },
+            UiRoot,
+            crate::OffscreenMeasure,
+            crate::UiLabel::new("A considerably longer dialog message"),
         ))
         .id();
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
+    assert_eq!(crate::gather_ui_roots(app.world_mut()).len(), 1);
 
-    let resolved = resolve_style(&world, entity);
-    assert_eq!(resolved.colors.bg, Some(pressed));
+    app.update();
+
+    assert_eq!(
+        app.world()
+            .resource::<crate::SynthesizedUiViews>()
+            .front()
+            .len(),
+        1
+    );
+    assert_eq!(
+        app.world().resource::<crate::UiSynthesisStats>().root_count,
+        1
+    );
+
+    let short_size = *app
+        .world()
+        .get::<crate::OffscreenMeasuredSize>(short)
+        .expect("offscreen root should be measured");
+    let long_size = *app
+        .world()
+        .get::<crate::OffscreenMeasuredSize>(long)
+        .expect("offscreen root should be measured");
+    assert!(short_size.width > 0.0 && short_size.height > 0.0);
+    assert!(long_size.width > short_size.width);
+    assert_eq!(long_size.height, short_size.height);
 }
 
 #[test]
-fn selector_type_rule_matches_component_type() {
-    let mut world = World::new();
-    let mut sheet = StyleSheet::default();
-    let type_color = crate::xilem::Color::from_rgb8(0x12, 0x34, 0x56);
+fn projector_resynthesis_request_runs_one_extra_pass_then_stabilizes() {
+    #[derive(Component, Debug, Clone, Copy)]
+    struct MeasureThenPlace;
 
-    sheet.add_rule(StyleRule::new(
-        Selector::of_type::<TypeStyled>(),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(type_color),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
-    world.insert_resource(sheet);
+    static PROJECTIONS: AtomicUsize = AtomicUsize::new(0);
 
-    let entity = world.spawn((TypeStyled,)).id();
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
+    fn project_measure_then_place(_: &MeasureThenPlace, ctx: ProjectionCtx<'_>) -> UiView {
+        // Only the very first pass asks to be re-run, as a measuring pass would.
+        if PROJECTIONS.fetch_add(1, Ordering::SeqCst) == 0 {
+            ctx.request_resynthesis();
+        }
+        Arc::new(crate::xilem::view::label("placed"))
+    }
 
-    let resolved = resolve_style(&world, entity);
-    assert_eq!(resolved.colors.bg, Some(type_color));
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<MeasureThenPlace>(project_measure_then_place);
+    app.world_mut().spawn((UiRoot, MeasureThenPlace));
+
+    PROJECTIONS.store(0, Ordering::SeqCst);
+    app.update();
+    assert_eq!(PROJECTIONS.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        app.world().resource::<crate::UiSynthesisStats>().pass_count,
+        2
+    );
+
+    app.update();
+    assert_eq!(PROJECTIONS.load(Ordering::SeqCst), 3);
+    assert_eq!(
+        app.world().resource::<crate::UiSynthesisStats>().pass_count,
+        1
+    );
 }
 
 #[test]
-fn ui_root_background_uses_stylesheet_rules_and_class_overrides() {
-    let mut world = World::new();
-    let mut sheet = StyleSheet::default();
+fn resynthesis_requests_are_capped_per_frame() {
+    #[derive(Component, Debug, Clone, Copy)]
+    struct AlwaysUnsettled;
 
-    let base_bg = crate::xilem::Color::from_rgb8(0x22, 0x26, 0x2F);
-    let light_bg = crate::xilem::Color::from_rgb8(0xF4, 0xF7, 0xFF);
+    fn project_always_unsettled(_: &AlwaysUnsettled, ctx: ProjectionCtx<'_>) -> UiView {
+        ctx.request_resynthesis();
+        Arc::new(crate::xilem::view::label("unsettled"))
+    }
 
-    sheet.add_rule(StyleRule::new(
-        Selector::of_type::<UiRoot>(),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(base_bg),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<AlwaysUnsettled>(project_always_unsettled);
+    app.insert_resource(crate::SynthesisConfig::default().with_max_resynthesis_passes(3));
+    app.world_mut().spawn((UiRoot, AlwaysUnsettled));
 
-    sheet.set_class(
-        "theme.light",
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(light_bg),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
+    app.update();
+    assert_eq!(
+        app.world().resource::<crate::UiSynthesisStats>().pass_count,
+        4
     );
+}
 
-    world.insert_resource(sheet);
+#[test]
+fn view_cache_invalidates_only_the_changed_label_path() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default().with_view_cache(true));
+    assert!(app.world().resource::<crate::SynthesisConfig>().view_cache);
 
-    let root = world
-        .spawn((UiRoot, crate::StyleClass(vec!["theme.dark".to_string()])))
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
         .id();
+    let mut columns = Vec::new();
+    let mut labels = Vec::new();
+    for column_index in 0..5 {
+        let column = app
+            .world_mut()
+            .spawn((crate::UiFlexColumn::default(), ChildOf(root)))
+            .id();
+        columns.push(column);
+        labels.push(
+            (0..5)
+                .map(|label_index| {
+                    app.world_mut()
+                        .spawn((
+                            crate::UiLabel::new(format!("cell {column_index}.{label_index}")),
+                            ChildOf(column),
+                        ))
+                        .id()
+                })
+                .collect::<Vec<_>>(),
+        );
+    }
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
-    assert_eq!(resolve_style(&world, root).colors.bg, Some(base_bg));
+    for _ in 0..3 {
+        app.update();
+    }
 
-    world.clear_trackers();
-    world
-        .entity_mut(root)
-        .insert(crate::StyleClass(vec!["theme.light".to_string()]));
+    let snapshot = |app: &App, entity: Entity| {
+        app.world()
+            .resource::<crate::UiViewCache>()
+            .get(entity)
+            .cloned()
+            .expect("mounted entity should be cached")
+    };
+    let root_before = snapshot(&app, root);
+    let columns_before = columns
+        .iter()
+        .map(|column| snapshot(&app, *column))
+        .collect::<Vec<_>>();
+    let siblings_before = labels[2]
+        .iter()
+        .map(|label| snapshot(&app, *label))
+        .collect::<Vec<_>>();
+    assert_eq!(app.world().resource::<crate::UiViewCache>().len(), 31);
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
-    assert_eq!(resolve_style(&world, root).colors.bg, Some(light_bg));
+    // Nothing changed: the whole tree is reused.
+    app.update();
+    assert!(Arc::ptr_eq(&snapshot(&app, root), &root_before));
+
+    app.world_mut()
+        .get_mut::<crate::UiLabel>(labels[2][3])
+        .expect("label should exist")
+        .text = "edited".to_string();
+    app.update();
+
+    assert!(!Arc::ptr_eq(&snapshot(&app, root), &root_before));
+    for (index, column) in columns.iter().enumerate() {
+        let reused = Arc::ptr_eq(&snapshot(&app, *column), &columns_before[index]);
+        assert_eq!(reused, index != 2, "column {index}");
+    }
+    for (index, label) in labels[2].iter().enumerate() {
+        let reused = Arc::ptr_eq(&snapshot(&app, *label), &siblings_before[index]);
+        assert_eq!(reused, index != 3, "label {index}");
+    }
 }
 
 #[test]
-fn selector_descendant_rule_matches_nested_entity_and_updates_on_ancestor_change() {
+fn synthesis_stats_report_max_depth_and_per_root_node_counts() {
     let mut world = World::new();
-    let mut sheet = StyleSheet::default();
+    let mut registry = UiProjectorRegistry::default();
+    register_builtin_projectors(&mut registry);
 
-    let dark_bg = crate::xilem::Color::from_rgb8(0x20, 0x2A, 0x44);
-    let light_bg = crate::xilem::Color::from_rgb8(0xE8, 0xEE, 0xFF);
+    let root = world.spawn((UiRoot, crate::UiFlexColumn::default())).id();
+    let mut parent = root;
+    for _ in 0..2 {
+        parent = world
+            .spawn((crate::UiFlexColumn::default(), ChildOf(parent)))
+            .id();
+    }
+    world.spawn((crate::UiLabel::new("leaf"), ChildOf(parent)));
 
-    sheet.add_rule(StyleRule::new(
-        Selector::descendant(
-            Selector::class("theme.dark"),
-            Selector::class("gallery.target"),
-        ),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(dark_bg),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
+    let (_roots, stats) = synthesize_roots_with_stats(&world, &registry, [root]);
 
-    sheet.add_rule(StyleRule::new(
-        Selector::descendant(
-            Selector::class("theme.light"),
-            Selector::class("gallery.target"),
-        ),
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(light_bg),
-                ..ColorStyle::default()
-            },
-            ..StyleSetter::default()
-        },
-    ));
+    assert_eq!(stats.node_count, 4);
+    assert_eq!(stats.max_depth, 4);
+    assert_eq!(stats.per_root_node_counts, vec![stats.node_count]);
+}
 
-    world.insert_resource(sheet);
+#[test]
+fn synthesis_handles_very_deep_trees_without_native_recursion() {
+    const DEPTH: usize = 10_000;
+    // Far too small for one native frame per level, so recursive synthesis overflows.
+    const SYNTHESIS_STACK: usize = 256 * 1024;
+    // Dropping the nested Xilem views recurses inside Xilem's own drop glue, which is
+    // outside what this test covers, so the teardown gets a generous stack instead.
+    const TEARDOWN_STACK: usize = 512 * 1024 * 1024;
+
+    let (roots, stats) = std::thread::Builder::new()
+        .name("deep-synthesis".into())
+        .stack_size(SYNTHESIS_STACK)
+        .spawn(|| {
+            let mut world = World::new();
+            world.insert_resource(crate::UiSynthesisLimits {
+                max_depth: DEPTH,
+                ..crate::UiSynthesisLimits::default()
+            });
+            let mut registry = UiProjectorRegistry::default();
+            register_builtin_projectors(&mut registry);
+
+            let root = world.spawn((UiRoot, crate::UiFlexColumn::default())).id();
+            let mut parent = root;
+            for _ in 1..DEPTH {
+                parent = world
+                    .spawn((crate::UiFlexColumn::default(), ChildOf(parent)))
+                    .id();
+            }
 
-    let root = world
-        .spawn((crate::StyleClass(vec!["theme.dark".to_string()]),))
+            synthesize_roots_with_stats(&world, &registry, [root])
+        })
+        .expect("synthesis thread should spawn")
+        .join()
+        .expect("synthesis should not overflow a small stack");
+
+    assert_eq!(stats.node_count, DEPTH);
+    assert_eq!(stats.max_depth, DEPTH);
+    assert_eq!(stats.cycle_count, 0);
+    assert_eq!(stats.missing_entity_count, 0);
+    assert_eq!(roots.len(), 1);
+
+    std::thread::Builder::new()
+        .name("deep-teardown".into())
+        .stack_size(TEARDOWN_STACK)
+        .spawn(move || drop(roots))
+        .expect("teardown thread should spawn")
+        .join()
+        .expect("deep view tree should drop");
+}
+
+#[test]
+fn synthesis_depth_limit_renders_placeholder_and_counts_hit() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(crate::UiSynthesisLimits {
+        max_depth: 2,
+        ..crate::UiSynthesisLimits::default()
+    });
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
         .id();
-    let child = world
-        .spawn((
-            crate::StyleClass(vec!["gallery.target".to_string()]),
-            ChildOf(root),
-        ))
+    let column = app
+        .world_mut()
+        .spawn((crate::UiFlexColumn::default(), ChildOf(root)))
         .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("too deep"), ChildOf(column)));
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
-    assert_eq!(resolve_style(&world, child).colors.bg, Some(dark_bg));
+    app.update();
+    app.update();
 
-    world.clear_trackers();
-    world
-        .entity_mut(root)
-        .insert(crate::StyleClass(vec!["theme.light".to_string()]));
+    let stats = app.world().resource::<UiSynthesisStats>().clone();
+    assert_eq!(stats.limit_hit_count, 1);
+    assert_eq!(stats.max_depth, 2);
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
-    assert_eq!(resolve_style(&world, child).colors.bg, Some(light_bg));
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer = || runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(layer(), crate::SYNTHESIS_LIMIT_PLACEHOLDER).is_some());
+    assert!(find_widget_id_by_debug_text(layer(), "too deep").is_none());
 }
 
 #[test]
-fn sync_style_targets_restarts_tween_when_current_differs_but_target_unchanged() {
+fn synthesis_node_limit_stops_descending_once_budget_is_spent() {
     let mut world = World::new();
-    let mut sheet = StyleSheet::default();
+    world.insert_resource(crate::UiSynthesisLimits {
+        max_nodes: 3,
+        ..crate::UiSynthesisLimits::default()
+    });
+    let mut registry = UiProjectorRegistry::default();
+    register_builtin_projectors(&mut registry);
 
-    let base = crate::xilem::Color::from_rgb8(0x20, 0x2A, 0x44);
-    let mid = crate::xilem::Color::from_rgb8(0x90, 0x99, 0xB3);
+    let root = world.spawn((UiRoot, crate::UiFlexColumn::default())).id();
+    for index in 0..4 {
+        world.spawn((crate::UiLabel::new(format!("item {index}")), ChildOf(root)));
+    }
 
-    sheet.set_class(
-        "test.animated",
-        StyleSetter {
-            colors: ColorStyle {
-                bg: Some(base),
-                ..ColorStyle::default()
-            },
-            transition: Some(crate::StyleTransition { duration: 0.2 }),
-            ..StyleSetter::default()
-        },
-    );
+    let (_roots, stats) = synthesize_roots_with_stats(&world, &registry, [root]);
 
-    world.insert_resource(sheet);
+    // The root plus two labels fit the budget; the last two labels are cut off.
+    assert_eq!(stats.limit_hit_count, 2);
+    assert_eq!(stats.node_count, 5);
+    assert_eq!(stats.unhandled_count, 0);
+}
 
-    let entity = world
-        .spawn((crate::StyleClass(vec!["test.animated".to_string()]),))
+#[test]
+fn default_synthesis_limits_leave_ordinary_trees_untouched() {
+    let limits = crate::UiSynthesisLimits::default();
+    assert_eq!(limits.max_nodes, 100_000);
+    assert_eq!(limits.max_depth, 512);
+
+    let mut world = World::new();
+    let mut registry = UiProjectorRegistry::default();
+    register_builtin_projectors(&mut registry);
+    let root = world.spawn((UiRoot, crate::UiFlexColumn::default())).id();
+    world.spawn((crate::UiLabel::new("leaf"), ChildOf(root)));
+
+    let (_roots, stats) = synthesize_roots_with_stats(&world, &registry, [root]);
+    assert_eq!(stats.limit_hit_count, 0);
+}
+
+#[test]
+fn hidden_subtree_is_skipped_and_reappears_when_unhidden() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("visible"), ChildOf(root)));
+    let panel = app
+        .world_mut()
+        .spawn((
+            crate::UiFlexColumn::default(),
+            crate::UiHidden,
+            ChildOf(root),
+        ))
         .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("collapsed"), ChildOf(panel)));
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
+    app.update();
+    app.update();
 
-    world.entity_mut(entity).insert(crate::CurrentColorStyle {
-        bg: Some(mid),
-        text: None,
-        border: None,
-        scale: 1.0,
-    });
-    world.entity_mut(entity).insert(crate::TargetColorStyle {
-        bg: Some(base),
-        text: None,
-        border: None,
-        scale: 1.0,
-    });
-    world.entity_mut(entity).insert(crate::StyleDirty);
+    {
+        let stats = app.world().resource::<UiSynthesisStats>();
+        assert_eq!(stats.hidden_count, 1);
+        assert_eq!(stats.node_count, 3);
+        assert_eq!(stats.unhandled_count, 0);
 
-    crate::sync_style_targets(&mut world);
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let layer = || runtime.render_root.get_layer_root(0);
+        assert!(find_widget_id_by_debug_text(layer(), "visible").is_some());
+        assert!(find_widget_id_by_debug_text(layer(), "collapsed").is_none());
+    }
+    assert_eq!(crate::ordered_children(app.world(), root).len(), 1);
 
-    assert_eq!(
-        world
-            .get::<crate::TargetColorStyle>(entity)
-            .and_then(|target| target.bg),
-        Some(base)
-    );
-    assert!(world.get::<TimeRunner>(entity).is_some());
+    app.world_mut()
+        .entity_mut(panel)
+        .remove::<crate::UiHidden>();
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<UiSynthesisStats>().hidden_count, 0);
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
     assert!(
-        world
-            .get::<ComponentTween<crate::ColorStyleLens>>(entity)
-            .is_some()
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), "collapsed").is_some()
     );
 }
 
 #[test]
-fn pointer_left_does_not_clear_pressed_marker() {
-    let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
-    world.insert_resource(bevy_time::Time::<()>::default());
+fn rebuilt_view_count_matches_node_count_without_cache_and_drops_to_zero_with_it() {
+    let spawn_tree = |app: &mut App| {
+        let root = app
+            .world_mut()
+            .spawn((UiRoot, crate::UiFlexColumn::default()))
+            .id();
+        for text in ["a", "b", "c"] {
+            app.world_mut()
+                .spawn((crate::UiLabel::new(text), ChildOf(root)));
+        }
+    };
 
-    let entity = world
-        .spawn((crate::InteractionState {
-            hovered: true,
-            pressed: true,
-        },))
-        .id();
-
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(entity, crate::UiInteractionEvent::PointerLeft);
-
-    crate::sync_ui_interaction_markers(&mut world);
-
-    let state = world
-        .get::<crate::InteractionState>(entity)
-        .expect("interaction state should exist");
-    assert!(!state.hovered);
-    assert!(state.pressed);
-}
+    let mut uncached = App::new();
+    uncached.add_plugins(PicusPlugin::default());
+    spawn_tree(&mut uncached);
+    for _ in 0..3 {
+        uncached.update();
+    }
+    let stats = uncached.world().resource::<UiSynthesisStats>();
+    assert_eq!(stats.node_count, 4);
+    assert_eq!(stats.rebuilt_view_count, stats.node_count);
+
+    let mut cached = App::new();
+    cached.add_plugins(PicusPlugin::default());
+    cached.insert_resource(crate::SynthesisConfig::default().with_view_cache(true));
+    spawn_tree(&mut cached);
+    for _ in 0..3 {
+        cached.update();
+    }
+    cached.update();
+    let stats = cached.world().resource::<UiSynthesisStats>();
+    assert_eq!(stats.node_count, 4);
+    assert_eq!(stats.rebuilt_view_count, 0);
+}
 
 #[test]
-fn debounced_hover_waits_before_setting_hovered_state() {
+fn synthesis_stats_track_missing_entity() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
-    world.insert_resource(bevy_time::Time::<()>::default());
+    let mut registry = UiProjectorRegistry::default();
+    register_builtin_projectors(&mut registry);
 
-    let entity = world
-        .spawn((crate::styling::HoverDebounce {
-            enter_delay_secs: 0.05,
-        },))
-        .id();
+    let stale_root = world.spawn_empty().id();
+    assert!(world.despawn(stale_root));
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(entity, crate::UiInteractionEvent::PointerEntered);
+    let (_roots, stats) = synthesize_roots_with_stats(&world, &registry, [stale_root]);
 
-    crate::sync_ui_interaction_markers(&mut world);
+    assert_eq!(stats.root_count, 1);
+    assert_eq!(stats.node_count, 1);
+    assert_eq!(stats.missing_entity_count, 1);
+    assert_eq!(stats.cycle_count, 0);
+}
 
-    assert!(world.get::<crate::InteractionState>(entity).is_none());
+#[test]
+fn builtin_registry_projects_label() {
+    let mut world = World::new();
+    let mut registry = UiProjectorRegistry::default();
+    register_builtin_projectors(&mut registry);
 
-    world
-        .resource_mut::<bevy_time::Time<()>>()
-        .advance_by(Duration::from_millis(60));
+    let root = world.spawn((UiRoot, crate::UiLabel::new("ok"))).id();
 
-    let mut schedule = Schedule::default();
-    schedule.add_systems(crate::styling::activate_debounced_hovers);
-    schedule.run(&mut world);
+    let (roots, stats) = synthesize_roots_with_stats(&world, &registry, [root]);
 
-    let state = world
-        .get::<crate::InteractionState>(entity)
-        .expect("interaction state should exist after debounce elapses");
-    assert!(state.hovered);
+    assert_eq!(roots.len(), 1);
+    assert_eq!(stats.unhandled_count, 0);
+    assert_eq!(stats.missing_entity_count, 0);
 }
 
 #[test]
-fn direct_slider_action_updates_slider_state() {
+fn resolve_style_for_entity_classes_applies_hover_pseudo_state() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
-
-    let slider = world
-        .spawn((crate::UiSlider::new(0.0, 100.0, 10.0).with_step(5.0),))
-        .id();
+    let mut sheet = StyleSheet::default();
+    let base = crate::xilem::Color::from_rgb8(0x11, 0x22, 0x33);
+    let hover = crate::xilem::Color::from_rgb8(0xAA, 0xBB, 0xCC);
 
-    world.resource::<UiEventQueue>().push_typed(
-        slider,
-        crate::WidgetUiAction::SetSliderValue {
-            slider,
-            value: 42.0,
+    sheet.set_class(
+        "test.button",
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(base),
+                hover_bg: Some(hover),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
         },
     );
+    world.insert_resource(sheet);
 
-    crate::handle_widget_actions(&mut world);
-
-    let slider_state = world
-        .get::<crate::UiSlider>(slider)
-        .expect("slider should exist");
-    assert_eq!(slider_state.value, 40.0);
+    let entity = world
+        .spawn((InteractionState {
+            hovered: true,
+            pressed: false,
+        },))
+        .id();
+    let resolved = resolve_style_for_entity_classes(&world, entity, ["test.button"]);
 
-    let changed = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiSliderChanged>();
-    assert_eq!(changed.len(), 1);
-    assert_eq!(changed[0].action.value, 40.0);
+    assert_eq!(resolved.colors.bg, Some(hover));
 }
 
 #[test]
-fn direct_checkbox_action_sets_checkbox_state() {
+fn resolve_style_without_any_style_source_uses_transparent_text_fallback() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
-
-    let checkbox = world.spawn((crate::UiCheckbox::new("demo", false),)).id();
-
-    world.resource::<UiEventQueue>().push_typed(
-        checkbox,
-        crate::WidgetUiAction::SetCheckbox {
-            checkbox,
-            checked: true,
-        },
-    );
-
-    crate::handle_widget_actions(&mut world);
+    let entity = world.spawn_empty().id();
 
-    let checkbox_state = world
-        .get::<crate::UiCheckbox>(checkbox)
-        .expect("checkbox should exist");
-    assert!(checkbox_state.checked);
+    let resolved = resolve_style(&world, entity);
 
-    let changed = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiCheckboxChanged>();
-    assert_eq!(changed.len(), 1);
-    assert!(changed[0].action.checked);
+    assert_eq!(resolved.colors.text, Some(crate::xilem::Color::TRANSPARENT));
 }
 
 #[test]
-fn sync_style_targets_keeps_unmanaged_tween_anim() {
+fn selector_and_rule_applies_hover_and_pressed_states() {
     let mut world = World::new();
+    let mut sheet = StyleSheet::default();
 
-    let duration = Duration::from_secs(1);
-    let entity = world.spawn_empty().id();
-    world.entity_mut(entity).insert((
-        TimeSpan::try_from(Duration::ZERO..duration)
-            .expect("test tween duration range should be valid"),
-        EaseKind::Linear,
-        ComponentTween::new_target(
-            entity,
-            crate::ColorStyleLens {
-                start: crate::CurrentColorStyle {
-                    bg: Some(crate::xilem::Color::from_rgb8(0x10, 0x20, 0x30)),
-                    text: None,
-                    border: None,
-                    scale: 1.0,
-                },
-                end: crate::CurrentColorStyle {
-                    bg: Some(crate::xilem::Color::from_rgb8(0x40, 0x50, 0x60)),
-                    text: None,
-                    border: None,
-                    scale: 1.0,
-                },
+    let base = crate::xilem::Color::from_rgb8(0x22, 0x22, 0x22);
+    let hover = crate::xilem::Color::from_rgb8(0x44, 0x44, 0x44);
+    let pressed = crate::xilem::Color::from_rgb8(0x66, 0x66, 0x66);
+
+    sheet.add_rule(StyleRule::new(
+        Selector::class("test.button"),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(base),
+                ..ColorStyle::default()
             },
-        ),
-        TimeRunner::new(duration),
-        TimeContext::<()>::default(),
+            ..StyleSetter::default()
+        },
     ));
-    world.entity_mut(entity).insert(crate::StyleDirty);
+    sheet.add_rule(StyleRule::new(
+        Selector::and(vec![
+            Selector::class("test.button"),
+            Selector::pseudo(crate::PseudoClass::Hovered),
+        ]),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(hover),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    ));
+    sheet.add_rule(StyleRule::new(
+        Selector::and(vec![
+            Selector::class("test.button"),
+            Selector::pseudo(crate::PseudoClass::Pressed),
+        ]),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(pressed),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    ));
+
+    world.insert_resource(sheet);
+
+    let entity = world
+        .spawn((
+            crate::StyleClass(vec!["test.button".to_string()]),
+            InteractionState {
+                hovered: true,
+                pressed: true,
+            },
+        ))
+        .id();
 
+    crate::mark_style_dirty(&mut world);
     crate::sync_style_targets(&mut world);
 
-    assert!(world.get::<TimeRunner>(entity).is_some());
-    assert!(
-        world
-            .get::<ComponentTween<crate::ColorStyleLens>>(entity)
-            .is_some()
-    );
+    let resolved = resolve_style(&world, entity);
+    assert_eq!(resolved.colors.bg, Some(pressed));
 }
 
 #[test]
-fn resolve_style_for_classes_applies_font_family() {
+fn selector_type_rule_matches_component_type() {
     let mut world = World::new();
     let mut sheet = StyleSheet::default();
+    let type_color = crate::xilem::Color::from_rgb8(0x12, 0x34, 0x56);
 
-    sheet.set_class(
-        "cjk-text",
+    sheet.add_rule(StyleRule::new(
+        Selector::of_type::<TypeStyled>(),
         StyleSetter {
-            font_family: Some(vec![
-                "Primary Family".to_string(),
-                "Fallback Family".to_string(),
-            ]),
+            colors: ColorStyle {
+                bg: Some(type_color),
+                ..ColorStyle::default()
+            },
             ..StyleSetter::default()
         },
-    );
+    ));
     world.insert_resource(sheet);
 
-    let resolved = crate::resolve_style_for_classes(&world, ["cjk-text"]);
-    assert_eq!(
-        resolved.font_family,
-        Some(vec![
-            "Primary Family".to_string(),
-            "Fallback Family".to_string()
-        ])
-    );
+    let entity = world.spawn((TypeStyled,)).id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = resolve_style(&world, entity);
+    assert_eq!(resolved.colors.bg, Some(type_color));
 }
 
 #[test]
-fn computed_style_lens_keeps_font_family_until_completion() {
+fn ui_root_background_uses_stylesheet_rules_and_class_overrides() {
     let mut world = World::new();
+    let mut sheet = StyleSheet::default();
 
-    let start = crate::ComputedStyle {
-        font_family: Some(vec!["Family A".to_string()]),
-        ..crate::ComputedStyle::default()
-    };
-    let end = crate::ComputedStyle {
-        font_family: Some(vec!["Family B".to_string()]),
-        ..crate::ComputedStyle::default()
-    };
-
-    let entity = world.spawn((start.clone(),)).id();
-    let lens = crate::ComputedStyleLens {
-        start: start.clone(),
-        end: end.clone(),
-    };
-
-    {
-        let target = world
-            .get_mut::<crate::ComputedStyle>(entity)
-            .expect("computed style should exist");
-        lens.interpolate(target.into_inner(), 0.5, 0.0);
-    }
-
-    assert_eq!(
-        world
-            .get::<crate::ComputedStyle>(entity)
-            .and_then(|style| style.font_family.clone()),
-        Some(vec!["Family A".to_string()])
-    );
+    let base_bg = crate::xilem::Color::from_rgb8(0x22, 0x26, 0x2F);
+    let light_bg = crate::xilem::Color::from_rgb8(0xF4, 0xF7, 0xFF);
 
-    {
-        let target = world
-            .get_mut::<crate::ComputedStyle>(entity)
-            .expect("computed style should exist");
-        lens.interpolate(target.into_inner(), 1.0, 0.0);
-    }
+    sheet.add_rule(StyleRule::new(
+        Selector::of_type::<UiRoot>(),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(base_bg),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    ));
 
-    assert_eq!(
-        world
-            .get::<crate::ComputedStyle>(entity)
-            .and_then(|style| style.font_family.clone()),
-        Some(vec!["Family B".to_string()])
+    sheet.set_class(
+        "theme.light",
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(light_bg),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
     );
-}
-
-#[test]
-fn xilem_font_bridge_deduplicates_same_font_bytes() {
-    let mut bridge = crate::XilemFontBridge::default();
-    assert!(bridge.register_font_bytes(b"font-data"));
-    assert!(!bridge.register_font_bytes(b"font-data"));
-}
 
-#[test]
-fn lucide_font_family_matches_upstream_identifier() {
-    assert_eq!(crate::LUCIDE_FONT_FAMILY, "lucide");
-}
+    world.insert_resource(sheet);
 
-#[test]
-fn register_i18n_bundle_stores_locale_font_stacks_in_app_i18n() {
-    let mut app = App::new();
-    app.add_plugins(PicusPlugin)
-        .register_i18n_bundle(
-            "en-US",
-            SyncTextSource::String(include_str!("../../../assets/locales/en-US/main.ftl")),
-            vec!["Inter", "sans-serif"],
-        )
-        .register_i18n_bundle(
-            "zh-CN",
-            SyncTextSource::String(include_str!("../../../assets/locales/zh-CN/main.ftl")),
-            vec!["Inter", "Noto Sans CJK SC", "sans-serif"],
-        );
+    let root = world
+        .spawn((UiRoot, crate::StyleClass(vec!["theme.dark".to_string()])))
+        .id();
 
-    {
-        let i18n = app.world().resource::<AppI18n>();
-        assert_eq!(
-            i18n.get_font_stack(),
-            vec!["Inter".to_string(), "sans-serif".to_string()]
-        );
-    }
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, root).colors.bg, Some(base_bg));
 
-    app.world_mut().resource_mut::<AppI18n>().set_active_locale(
-        "zh-CN"
-            .parse()
-            .expect("zh-CN locale identifier should parse"),
-    );
-    {
-        let i18n = app.world().resource::<AppI18n>();
-        assert_eq!(
-            i18n.get_font_stack(),
-            vec![
-                "Inter".to_string(),
-                "Noto Sans CJK SC".to_string(),
-                "sans-serif".to_string()
-            ]
-        );
-    }
+    world.clear_trackers();
+    world
+        .entity_mut(root)
+        .insert(crate::StyleClass(vec!["theme.light".to_string()]));
 
-    app.world_mut().resource_mut::<AppI18n>().set_active_locale(
-        "ja-JP"
-            .parse()
-            .expect("ja-JP locale identifier should parse"),
-    );
-    assert_eq!(
-        app.world().resource::<AppI18n>().get_font_stack(),
-        vec!["Inter".to_string(), "sans-serif".to_string()]
-    );
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, root).colors.bg, Some(light_bg));
 }
 
 #[test]
-fn resolve_localized_text_falls_back_when_cache_is_missing() {
+fn selector_attribute_rule_matches_presence_and_value() {
     let mut world = World::new();
-    let entity = world.spawn((crate::LocalizeText::new("hello_world"),)).id();
-
-    let with_fallback = crate::resolve_localized_text(&world, entity, "Fallback");
-    let without_fallback = crate::resolve_localized_text(&world, entity, "");
+    let present = crate::xilem::Color::from_rgb8(0x10, 0x20, 0x30);
+    let danger = crate::xilem::Color::from_rgb8(0xC0, 0x10, 0x10);
 
-    assert_eq!(with_fallback, "Fallback");
-    assert_eq!(without_fallback, "hello_world");
-}
+    let ron = r##"(
+  rules: [
+    (
+      selector: Attribute(key: "badge"),
+      setter: (colors: (bg: Hex("#102030"))),
+    ),
+    (
+      selector: Attribute(key: "tone", value: Some("danger")),
+      setter: (colors: (bg: Hex("#c01010"))),
+    ),
+  ],
+)"##;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    assert_eq!(sheet.rules[0].selector, Selector::attribute("badge"));
+    assert_eq!(
+        sheet.rules[1].selector,
+        Selector::attribute_eq("tone", "danger")
+    );
+    world.insert_resource(sheet);
 
-#[test]
-fn ensure_overlay_root_spawns_once() {
-    let mut world = World::new();
-    world.spawn((UiRoot,));
+    let badge_only = world
+        .spawn(crate::StyleAttributes::default().with("badge", "new"))
+        .id();
+    let danger_tone = world
+        .spawn(crate::StyleAttributes::default().with("tone", "danger"))
+        .id();
+    let calm_tone = world
+        .spawn(crate::StyleAttributes::default().with("tone", "calm"))
+        .id();
 
-    ensure_overlay_root(&mut world);
-    ensure_overlay_root(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
 
-    let mut overlay_query = world.query_filtered::<Entity, With<crate::UiOverlayRoot>>();
-    let overlays = overlay_query.iter(&world).collect::<Vec<_>>();
+    assert_eq!(resolve_style(&world, badge_only).colors.bg, Some(present));
+    assert_eq!(resolve_style(&world, danger_tone).colors.bg, Some(danger));
+    assert_eq!(resolve_style(&world, calm_tone).colors.bg, None);
 
-    assert_eq!(overlays.len(), 1);
-    assert!(world.get::<UiRoot>(overlays[0]).is_some());
+    // Class-only resolution has no entity to read attributes from.
+    let by_classes = crate::resolve_style_for_classes(&world, ["unrelated"]);
+    assert_eq!(by_classes.colors.bg, None);
 }
 
 #[test]
-fn overlay_actions_toggle_and_select_combo_box() {
+fn structural_pseudo_classes_stripe_sibling_rows() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
-
-    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
-    let mut combo_box = crate::UiComboBox::new(vec![
-        crate::UiComboOption::new("one", "One"),
-        crate::UiComboOption::new("two", "Two"),
-    ]);
-    combo_box.selected = 0;
-    let combo = world.spawn((combo_box,)).id();
-
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(combo, crate::OverlayUiAction::ToggleCombo);
-
-    handle_overlay_actions(&mut world);
-
-    let mut dropdown_query = world.query::<(Entity, &crate::AnchoredTo, &crate::UiDropdownMenu)>();
-    let dropdowns = dropdown_query
-        .iter(&world)
-        .filter_map(|(entity, anchored_to, _)| (anchored_to.0 == combo).then_some(entity))
-        .collect::<Vec<_>>();
+    let odd = crate::xilem::Color::from_rgb8(0x11, 0x11, 0x11);
+    let even = crate::xilem::Color::from_rgb8(0x22, 0x22, 0x22);
 
-    assert_eq!(dropdowns.len(), 1);
-    let dropdown = dropdowns[0];
-    let mut item_query = world.query::<(Entity, &crate::UiDropdownItem, &crate::StyleClass)>();
-    let items = item_query
-        .iter(&world)
-        .filter(|(_, item, _)| item.dropdown == dropdown)
-        .map(|(entity, item, classes)| (entity, *item, classes.clone()))
+    let ron = r##"(
+  rules: [
+    (
+      selector: And([Class("row"), PseudoClass(NthChild(Odd))]),
+      setter: (colors: (bg: Hex("#111111"))),
+    ),
+    (
+      selector: And([Class("row"), PseudoClass(NthChild(Even))]),
+      setter: (colors: (bg: Hex("#222222"))),
+    ),
+    (
+      selector: And([Class("row"), PseudoClass(FirstChild)]),
+      setter: (layout: (corner_radius: 4.0)),
+    ),
+    (
+      selector: And([Class("row"), PseudoClass(LastChild)]),
+      setter: (layout: (corner_radius: 8.0)),
+    ),
+    (
+      selector: And([Class("row"), PseudoClass(NthChild(Index(3)))]),
+      setter: (layout: (padding: 3.0)),
+    ),
+  ],
+)"##;
+    world.insert_resource(crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse"));
+
+    let table = world.spawn_empty().id();
+    let rows = (0..5)
+        .map(|_| {
+            world
+                .spawn((crate::StyleClass(vec!["row".to_string()]), ChildOf(table)))
+                .id()
+        })
         .collect::<Vec<_>>();
 
-    assert_eq!(items.len(), 2);
-    assert!(items.iter().any(|(_, item, classes)| {
-        item.index == 0
-            && classes
-                .0
-                .iter()
-                .any(|class_name| class_name == "overlay.dropdown.item.selected")
-    }));
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
 
-    let second_item = items
+    let backgrounds = rows
         .iter()
-        .find_map(|(entity, item, _)| (item.index == 1).then_some(*entity))
-        .expect("second dropdown item should exist");
-    assert!(
-        world
-            .get::<bevy_ecs::hierarchy::ChildOf>(dropdown)
-            .is_some()
-    );
+        .map(|row| resolve_style(&world, *row).colors.bg)
+        .collect::<Vec<_>>();
     assert_eq!(
-        world
-            .get::<bevy_ecs::hierarchy::ChildOf>(dropdown)
-            .expect("dropdown should be parented")
-            .parent(),
-        overlay_root
-    );
-    assert!(
-        world
-            .get::<crate::UiComboBox>(combo)
-            .expect("combo should exist")
-            .is_open
-    );
-
-    world.resource::<UiEventQueue>().push_typed(
-        second_item,
-        crate::OverlayUiAction::SelectComboItem { dropdown, index: 1 },
+        backgrounds,
+        vec![Some(odd), Some(even), Some(odd), Some(even), Some(odd)]
     );
+    let radii = rows
+        .iter()
+        .map(|row| resolve_style(&world, *row).layout.corner_radius)
+        .collect::<Vec<_>>();
+    assert_eq!(radii[0], 4.0);
+    assert_eq!(radii[4], 8.0);
+    assert_eq!(resolve_style(&world, rows[2]).layout.padding, 3.0);
+    assert_ne!(resolve_style(&world, rows[1]).layout.padding, 3.0);
 
-    handle_overlay_actions(&mut world);
-
-    let combo_after = world
-        .get::<crate::UiComboBox>(combo)
-        .expect("combo should exist");
-    assert_eq!(combo_after.selected, 1);
-    assert!(!combo_after.is_open);
-    assert!(world.get_entity(dropdown).is_err());
-    assert!(world.get_entity(second_item).is_err());
+    // Removing the first row shifts every remaining row's stripe.
+    world.entity_mut(rows[0]).despawn();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, rows[1]).colors.bg, Some(odd));
+    assert_eq!(resolve_style(&world, rows[1]).layout.corner_radius, 4.0);
 }
 
 #[test]
-fn overlay_actions_toggle_and_select_theme_picker() {
+fn selector_descendant_rule_matches_nested_entity_and_updates_on_ancestor_change() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
-
-    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
-    let picker = world.spawn((crate::UiThemePicker::fluent(),)).id();
+    let mut sheet = StyleSheet::default();
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(picker, crate::OverlayUiAction::ToggleThemePicker);
-
-    handle_overlay_actions(&mut world);
+    let dark_bg = crate::xilem::Color::from_rgb8(0x20, 0x2A, 0x44);
+    let light_bg = crate::xilem::Color::from_rgb8(0xE8, 0xEE, 0xFF);
 
-    let mut panel_query = world.query::<(Entity, &crate::UiThemePickerMenu)>();
-    let panels = panel_query
-        .iter(&world)
-        .filter_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
-        .collect::<Vec<_>>();
+    sheet.add_rule(StyleRule::new(
+        Selector::descendant(
+            Selector::class("theme.dark"),
+            Selector::class("gallery.target"),
+        ),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(dark_bg),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    ));
 
-    assert_eq!(panels.len(), 1);
-    let panel = panels[0];
-    assert_eq!(
-        world
-            .get::<bevy_ecs::hierarchy::ChildOf>(panel)
-            .expect("theme picker panel should be parented")
-            .parent(),
-        overlay_root
-    );
-    assert!(
-        world
-            .get::<crate::UiThemePicker>(picker)
-            .expect("theme picker should exist")
-            .is_open
-    );
+    sheet.add_rule(StyleRule::new(
+        Selector::descendant(
+            Selector::class("theme.light"),
+            Selector::class("gallery.target"),
+        ),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(light_bg),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    ));
 
-    world.resource::<UiEventQueue>().push_typed(
-        panel,
-        crate::OverlayUiAction::SelectThemePickerItem { index: 1 },
-    );
+    world.insert_resource(sheet);
 
-    handle_overlay_actions(&mut world);
+    let root = world
+        .spawn((crate::StyleClass(vec!["theme.dark".to_string()]),))
+        .id();
+    let child = world
+        .spawn((
+            crate::StyleClass(vec!["gallery.target".to_string()]),
+            ChildOf(root),
+        ))
+        .id();
 
-    let picker_after = world
-        .get::<crate::UiThemePicker>(picker)
-        .expect("theme picker should exist");
-    assert_eq!(picker_after.selected, 1);
-    assert!(!picker_after.is_open);
-    assert!(world.get_entity(panel).is_err());
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, child).colors.bg, Some(dark_bg));
 
-    let active_variant = world.resource::<crate::ActiveStyleVariant>();
-    assert_eq!(active_variant.0.as_deref(), Some("light"));
+    world.clear_trackers();
+    world
+        .entity_mut(root)
+        .insert(crate::StyleClass(vec!["theme.light".to_string()]));
 
-    let changed = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiThemePickerChanged>();
-    assert_eq!(changed.len(), 1);
-    assert_eq!(changed[0].entity, picker);
-    assert_eq!(changed[0].action.selected, 1);
-    assert_eq!(changed[0].action.variant, "light");
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, child).colors.bg, Some(light_bg));
 }
 
 #[test]
-fn overlay_actions_toggle_and_select_color_picker() {
+fn selector_child_rule_matches_direct_child_but_not_grandchild() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+    let item_bg = crate::xilem::Color::from_rgb8(0x30, 0x40, 0x50);
 
-    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
-    let picker = world.spawn((crate::UiColorPicker::new(12, 34, 56),)).id();
+    let ron = r##"(
+  rules: [
+    (
+      selector: Child(parent: Class("menu"), child: Class("item")),
+      setter: (colors: (bg: Hex("#304050"))),
+    ),
+  ],
+)"##;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    assert_eq!(
+        sheet.rules[0].selector,
+        Selector::child(Selector::class("menu"), Selector::class("item"))
+    );
+    world.insert_resource(sheet);
+
+    let menu = world
+        .spawn(crate::StyleClass(vec!["menu".to_string()]))
+        .id();
+    let item = world
+        .spawn((crate::StyleClass(vec!["item".to_string()]), ChildOf(menu)))
+        .id();
+    let nested_item = world
+        .spawn((crate::StyleClass(vec!["item".to_string()]), ChildOf(item)))
+        .id();
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    assert_eq!(resolve_style(&world, item).colors.bg, Some(item_bg));
+    assert_eq!(resolve_style(&world, nested_item).colors.bg, None);
 
+    world.clear_trackers();
     world
-        .resource::<UiEventQueue>()
-        .push_typed(picker, crate::OverlayUiAction::ToggleColorPicker);
+        .entity_mut(menu)
+        .insert(crate::StyleClass(vec!["toolbar".to_string()]));
 
-    handle_overlay_actions(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, item).colors.bg, None);
+}
 
-    let mut panel_query = world.query::<(Entity, &crate::UiColorPickerPanel)>();
-    let panels = panel_query
-        .iter(&world)
-        .filter_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
-        .collect::<Vec<_>>();
+#[test]
+fn selector_adjacent_sibling_rule_styles_only_the_next_sibling() {
+    let mut world = World::new();
+    let caption_bg = crate::xilem::Color::from_rgb8(0x50, 0x60, 0x70);
 
-    assert_eq!(panels.len(), 1);
-    let panel = panels[0];
+    let ron = r##"(
+  rules: [
+    (
+      selector: AdjacentSibling(before: Class("check"), after: Class("caption")),
+      setter: (colors: (bg: Hex("#506070"))),
+    ),
+  ],
+)"##;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
     assert_eq!(
-        world
-            .get::<bevy_ecs::hierarchy::ChildOf>(panel)
-            .expect("color picker panel should be parented")
-            .parent(),
-        overlay_root
-    );
-    assert!(
-        world
-            .get::<crate::UiColorPicker>(picker)
-            .expect("color picker should exist")
-            .is_open
+        sheet.rules[0].selector,
+        Selector::adjacent_sibling(Selector::class("check"), Selector::class("caption"))
     );
+    world.insert_resource(sheet);
 
-    world.resource::<UiEventQueue>().push_typed(
-        panel,
-        crate::OverlayUiAction::SelectColorSwatch {
-            r: 200,
-            g: 100,
-            b: 50,
-        },
-    );
+    let row = world.spawn_empty().id();
+    let check = world
+        .spawn((crate::StyleClass(vec!["check".to_string()]), ChildOf(row)))
+        .id();
+    let first_caption = world
+        .spawn((crate::StyleClass(vec!["caption".to_string()]), ChildOf(row)))
+        .id();
+    let second_caption = world
+        .spawn((crate::StyleClass(vec!["caption".to_string()]), ChildOf(row)))
+        .id();
 
-    handle_overlay_actions(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
 
-    let picker_after = world
-        .get::<crate::UiColorPicker>(picker)
-        .expect("color picker should exist");
+    assert_eq!(resolve_style(&world, check).colors.bg, None);
     assert_eq!(
-        (picker_after.r, picker_after.g, picker_after.b),
-        (200, 100, 50)
+        resolve_style(&world, first_caption).colors.bg,
+        Some(caption_bg)
     );
-    assert!(!picker_after.is_open);
-    assert!(world.get_entity(panel).is_err());
+    assert_eq!(resolve_style(&world, second_caption).colors.bg, None);
 
-    let changed = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiColorPickerChanged>();
-    assert_eq!(changed.len(), 1);
-    assert_eq!(changed[0].entity, picker);
-    assert_eq!(
-        (
-            changed[0].action.r,
-            changed[0].action.g,
-            changed[0].action.b
-        ),
-        (200, 100, 50)
-    );
+    // Changing only the preceding sibling re-dirties the caption after it.
+    world.clear_trackers();
+    world
+        .entity_mut(check)
+        .insert(crate::StyleClass(vec!["radio".to_string()]));
+
+    crate::mark_style_dirty(&mut world);
+    assert!(world.get::<crate::StyleDirty>(first_caption).is_some());
+    crate::sync_style_targets(&mut world);
+    assert_eq!(resolve_style(&world, first_caption).colors.bg, None);
 }
 
 #[test]
-fn overlay_actions_toggle_and_select_date_picker() {
+fn specific_rule_wins_over_later_less_specific_rule() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+    let mut sheet = StyleSheet::default();
+    let hover = crate::xilem::Color::from_rgb8(0x44, 0x44, 0x44);
+    let broad = crate::xilem::Color::from_rgb8(0x99, 0x99, 0x99);
+    let later_class = crate::xilem::Color::from_rgb8(0x12, 0x12, 0x12);
 
-    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
-    let picker = world.spawn((crate::UiDatePicker::new(2026, 3, 17),)).id();
+    let bg = |color| StyleSetter {
+        colors: ColorStyle {
+            bg: Some(color),
+            ..ColorStyle::default()
+        },
+        ..StyleSetter::default()
+    };
+    sheet.add_rule(StyleRule::new(
+        Selector::and(vec![
+            Selector::class("test.button"),
+            Selector::pseudo(crate::PseudoClass::Hovered),
+        ]),
+        bg(hover),
+    ));
+    sheet.add_rule(StyleRule::new(Selector::of_type::<TypeStyled>(), bg(broad)));
+    world.insert_resource(sheet);
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(picker, crate::OverlayUiAction::ToggleDatePicker);
+    let hovered = world
+        .spawn((
+            TypeStyled,
+            crate::StyleClass(vec!["test.button".to_string()]),
+            InteractionState {
+                hovered: true,
+                pressed: false,
+            },
+        ))
+        .id();
+    let idle = world
+        .spawn((
+            TypeStyled,
+            crate::StyleClass(vec!["test.button".to_string()]),
+        ))
+        .id();
 
-    handle_overlay_actions(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
 
-    let mut panel_query = world.query::<(Entity, &crate::UiDatePickerPanel)>();
-    let panels = panel_query
-        .iter(&world)
-        .filter_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
-        .collect::<Vec<_>>();
+    assert_eq!(resolve_style(&world, hovered).colors.bg, Some(hover));
+    assert_eq!(resolve_style(&world, idle).colors.bg, Some(broad));
 
-    assert_eq!(panels.len(), 1);
-    let panel = panels[0];
+    // Equal specificity still falls back to declaration order.
+    world.resource_mut::<StyleSheet>().add_rule(StyleRule::new(
+        Selector::class("test.button"),
+        bg(later_class),
+    ));
+    world.resource_mut::<StyleSheet>().add_rule(StyleRule::new(
+        Selector::and(vec![
+            Selector::pseudo(crate::PseudoClass::Hovered),
+            Selector::class("test.button"),
+        ]),
+        bg(later_class),
+    ));
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    assert_eq!(resolve_style(&world, hovered).colors.bg, Some(later_class));
+    assert_eq!(resolve_style(&world, idle).colors.bg, Some(later_class));
+    assert!(Selector::class("a").specificity() > Selector::of_type::<TypeStyled>().specificity());
+}
+
+#[test]
+fn important_bg_survives_later_more_specific_rule() {
+    let mut world = World::new();
+    let forced = crate::xilem::Color::from_rgb8(0xAA, 0x00, 0x00);
+    let hover_border = crate::xilem::Color::from_rgb8(0x00, 0x00, 0xAA);
+
+    let ron = r##"(
+  rules: [
+    (
+      selector: Class("card"),
+      setter: (colors: (bg: Hex("#aa0000"), bg_important: true)),
+    ),
+    (
+      selector: And([Class("card"), PseudoClass(Hovered)]),
+      setter: (colors: (bg: Hex("#00aa00"), border: Hex("#0000aa"))),
+    ),
+  ],
+)"##;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    assert!(sheet.rules[0].setter.colors_important.bg);
+    assert!(!sheet.rules[0].setter.colors_important.border);
     assert_eq!(
-        world
-            .get::<bevy_ecs::hierarchy::ChildOf>(panel)
-            .expect("date picker panel should be parented")
-            .parent(),
-        overlay_root
-    );
-    let panel_state = world
-        .get::<crate::UiDatePickerPanel>(panel)
-        .expect("date picker panel should exist");
-    assert_eq!(panel_state.view_year, 2026);
-    assert_eq!(panel_state.view_month, 3);
-    assert!(
-        world
-            .get::<crate::UiDatePicker>(picker)
-            .expect("date picker should exist")
-            .is_open
+        sheet.rules[1].setter.colors_important,
+        crate::ColorStyleImportant::default()
     );
+    world.insert_resource(sheet);
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(panel, crate::OverlayUiAction::SelectDateDay { day: 29 });
+    let card = world
+        .spawn((
+            crate::StyleClass(vec!["card".to_string()]),
+            InteractionState {
+                hovered: true,
+                pressed: false,
+            },
+        ))
+        .id();
 
-    handle_overlay_actions(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
 
-    let picker_after = world
-        .get::<crate::UiDatePicker>(picker)
-        .expect("date picker should exist");
-    assert_eq!(picker_after.year, 2026);
-    assert_eq!(picker_after.month, 3);
-    assert_eq!(picker_after.day, 29);
-    assert!(!picker_after.is_open);
-    assert!(world.get_entity(panel).is_err());
+    let resolved = resolve_style(&world, card);
+    assert_eq!(resolved.colors.bg, Some(forced));
+    assert_eq!(resolved.colors.border, Some(hover_border));
+}
 
-    let changed = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiDatePickerChanged>();
-    assert_eq!(changed.len(), 1);
-    assert_eq!(changed[0].entity, picker);
-    assert_eq!(changed[0].action.year, 2026);
-    assert_eq!(changed[0].action.month, 3);
-    assert_eq!(changed[0].action.day, 29);
+#[test]
+fn layout_margin_and_size_bounds_parse_from_ron_into_resolved_layout() {
+    let mut world = World::new();
+
+    let ron = r#"(
+  rules: [
+    (
+      selector: Class("panel"),
+      setter: (layout: (margin: 6.0, min_width: 120.0, max_width: 320.0, max_height: 200.0)),
+    ),
+  ],
+)"#;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    world.insert_resource(sheet);
+
+    let panel = world
+        .spawn(crate::StyleClass(vec!["panel".to_string()]))
+        .id();
+    let plain = world.spawn_empty().id();
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = resolve_style(&world, panel).layout;
+    assert_eq!(resolved.margin, 6.0);
+    assert_eq!(resolved.min_width, Some(120.0));
+    assert_eq!(resolved.max_width, Some(320.0));
+    assert_eq!(resolved.min_height, None);
+    assert_eq!(resolved.max_height, Some(200.0));
+
+    let unstyled = resolve_style(&world, plain).layout;
+    assert_eq!(unstyled.margin, 0.0);
+    assert_eq!(unstyled.min_width, None);
+    assert_eq!(unstyled.max_width, None);
 }
 
 #[test]
-/// On HiDPI displays, `Window::cursor_position` (logical) must still resolve to an
-/// inside-overlay retained hit after conversion to physical coordinates.
-fn overlay_click_inside_computed_overlay_position_not_dismissed_on_hidpi() {
+fn size_bounds_clamp_natural_size_instead_of_pinning_it() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
+    app.add_plugins(PicusPlugin::default())
+        .load_style_sheet_ron(
+            r#"(
+            rules: [
+                (selector: Class("capped"), setter: (layout: (max_width: 400.0))),
+                (selector: Class("floored"), setter: (layout: (min_width: 160.0))),
+                (selector: Class("squeezed"), setter: (layout: (max_width: 40.0))),
+            ],
+        )"#,
+        );
     let mut window = Window::default();
-    window.resolution.set(400.0, 300.0);
-    window.resolution.set_scale_factor_override(Some(2.0));
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+    let root = app.world_mut().spawn(UiRoot).id();
+    let row = app
+        .world_mut()
+        .spawn((crate::UiFlexRow::default(), ChildOf(root)))
+        .id();
+    for (class, text) in [
+        ("capped", "capped"),
+        ("floored", "floored"),
+        ("squeezed", "a label much wider than forty pixels"),
+    ] {
+        let column = app
+            .world_mut()
+            .spawn((
+                crate::UiFlexColumn::default(),
+                crate::StyleClass(vec![class.to_string()]),
+                ChildOf(row),
+            ))
+            .id();
+        app.world_mut()
+            .spawn((crate::UiLabel::new(text), ChildOf(column)));
+    }
 
-    app.update();
     app.update();
 
-    let opaque_debug = format!("opaque_hitbox_entity={}", dialog.to_bits());
-    let opaque_widget_id = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        find_widget_id_by_debug_text(root, &opaque_debug)
-            .expect("dialog should project an entity-tagged OpaqueHitboxWidget")
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer = runtime.render_root.get_layer_root(0);
+    let mut bounded = Vec::new();
+    collect_widget_bounds_by_short_name(layer, "SizeBoundsWidget", &mut bounded);
+    let width_around = |text: &str| {
+        let label_id = find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+            .expect("label should be rendered");
+        let label = runtime
+            .render_root
+            .get_widget(label_id)
+            .expect("label widget should resolve");
+        let origin = label.ctx().window_origin();
+        let label_width = label.ctx().border_box_size().width as f32;
+        let inside = Vec2::new(origin.x as f32 + 1.0, origin.y as f32 + 1.0);
+        let bounds = bounded
+            .iter()
+            .filter(|rect| rect.contains(inside))
+            .min_by(|a, b| a.width().total_cmp(&b.width()))
+            .expect("label should sit inside a size-bounded box");
+        (label_width, bounds.width())
     };
 
-    let runtime_center = widget_center_for_widget_id(&app, opaque_widget_id);
-    let window_scale_factor = app
-        .world()
-        .get::<Window>(window_entity)
-        .expect("primary window should exist")
-        .scale_factor();
-    let click_position = runtime_center / window_scale_factor.max(f32::EPSILON);
+    let (label_width, capped) = width_around("capped");
+    assert!(
+        capped < 400.0,
+        "capped box should keep its natural width, got {capped}"
+    );
+    assert!(capped >= label_width);
 
-    run_global_overlay_click(&mut app, window_entity, click_position);
+    let (_, floored) = width_around("floored");
+    assert!(
+        (floored - 160.0).abs() < 0.5,
+        "floored box should grow to 160, got {floored}"
+    );
 
-    assert!(app.world().get_entity(dialog).is_ok());
+    let (_, squeezed) = width_around("a label much wider than forty pixels");
+    assert!(
+        squeezed <= 40.5,
+        "squeezed box should shrink to 40, got {squeezed}"
+    );
 }
 
 #[test]
-fn spawn_in_overlay_root_parents_entity_under_overlay_root() {
+fn layout_corner_radii_parse_from_ron_and_override_uniform_radius() {
     let mut world = World::new();
-    world.spawn((UiRoot,));
-
-    let dialog = spawn_in_overlay_root(&mut world, (crate::UiDialog::new("title", "body"),));
-
-    let overlay_root = ensure_overlay_root_entity(&mut world);
-    let parent = world
-        .get::<bevy_ecs::hierarchy::ChildOf>(dialog)
-        .expect("dialog should be parented")
-        .parent();
 
-    assert_eq!(parent, overlay_root);
-    assert!(world.get::<crate::UiOverlayRoot>(overlay_root).is_some());
-}
+    let ron = r#"(
+  rules: [
+    (
+      selector: Class("feed-card"),
+      setter: (layout: (corner_radius: 4.0, corner_radii: (12.0, 12.0, 0.0, 0.0))),
+    ),
+    (
+      selector: Class("chip"),
+      setter: (layout: (corner_radius: 6.0)),
+    ),
+  ],
+)"#;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    world.insert_resource(sheet);
 
-#[test]
-fn reparent_overlay_entities_moves_dialog_to_overlay_root() {
-    let mut world = World::new();
-    let app_root = world.spawn((UiRoot,)).id();
-    let dialog = world
-        .spawn((crate::UiDialog::new("title", "body"), ChildOf(app_root)))
+    let card = world
+        .spawn(crate::StyleClass(vec!["feed-card".to_string()]))
+        .id();
+    let chip = world
+        .spawn(crate::StyleClass(vec!["chip".to_string()]))
         .id();
 
-    reparent_overlay_entities(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
 
-    let mut overlays = world.query_filtered::<Entity, With<crate::UiOverlayRoot>>();
-    let overlay_root = overlays
-        .iter(&world)
-        .next()
-        .expect("overlay root should exist");
+    let card_layout = resolve_style(&world, card).layout;
+    assert_eq!(card_layout.corner_radii, Some([12.0, 12.0, 0.0, 0.0]));
+    assert_eq!(card_layout.corner_radii(), [12.0, 12.0, 0.0, 0.0]);
+    assert_eq!(card_layout.box_corner_radius(), 12.0);
 
-    let parent = world
-        .get::<bevy_ecs::hierarchy::ChildOf>(dialog)
-        .expect("dialog should be parented")
-        .parent();
-    assert_eq!(parent, overlay_root);
+    let chip_layout = resolve_style(&world, chip).layout;
+    assert_eq!(chip_layout.corner_radii, None);
+    assert_eq!(chip_layout.corner_radii(), [6.0; 4]);
+    assert_eq!(chip_layout.box_corner_radius(), 6.0);
 }
 
 #[test]
-fn reparent_overlay_entities_moves_toast_and_tooltip_to_overlay_root_and_tracks_stack() {
-    let mut world = World::new();
-    world.insert_resource(crate::OverlayStack::default());
+fn stylesheet_to_ron_string_round_trips_through_parser() {
+    let ron = r##"(
+    tokens: {
+        "accent": Color(Hex("#3366CC")),
+        "radius": Float(6.0),
+    },
+    rules: [
+        (
+            selector: Class("card"),
+            setter: (
+                layout: (padding: Rem(1.5), corner_radius: Var("radius"), padding_important: true),
+                colors: (bg: Hex("#10203080"), text: Var("accent")),
+                transition: (duration: 0.25),
+            ),
+        ),
+        (
+            selector: And([Class("card"), PseudoClass(Hovered)]),
+            setter: (colors: (bg: Hex("#203040"), bg_important: true)),
+        ),
+        (
+            selector: Descendant(ancestor: Class("sidebar"), descendant: Type("Label")),
+            setter: (text: (size: 13.0, line_break: Clip)),
+        ),
+    ],
+)"##;
 
-    let app_root = world.spawn((UiRoot,)).id();
-    let anchor = world.spawn((ChildOf(app_root),)).id();
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    let serialized = sheet
+        .to_ron_string()
+        .expect("parsed stylesheet should serialize");
+    let reparsed =
+        crate::parse_stylesheet_ron(&serialized).expect("serialized stylesheet should parse");
 
-    let toast = world
-        .spawn((
-            crate::UiToast::new("Saved"),
-            crate::OverlayState {
-                is_modal: false,
-                anchor: None,
-            },
-            ChildOf(app_root),
-        ))
-        .id();
+    assert!(serialized.contains(r#"Hex("#10203080")"#));
+    assert_eq!(reparsed, sheet);
+}
 
-    let tooltip = world
-        .spawn((
-            crate::UiTooltip {
-                text: "Helpful tip".to_string(),
-                anchor,
-            },
-            crate::OverlayState {
-                is_modal: false,
-                anchor: Some(anchor),
-            },
-            ChildOf(app_root),
-        ))
-        .id();
+#[test]
+fn stylesheet_to_ron_string_keeps_variable_bound_tokens_as_var() {
+    let ron = r##"(
+    tokens: {
+        "accent": Color(Var("--accent")),
+        "glow": BoxShadow((color: Var("--accent"), blur: 4.0)),
+    },
+    rules: [(selector: Class("card"), setter: (colors: (bg: Var("accent"))))],
+)"##;
+    let variables = crate::StyleVariables::default()
+        .with("--accent", crate::xilem::Color::from_rgb8(0, 120, 215));
+
+    let sheet = crate::parse_stylesheet_ron_with_variables(ron, &variables)
+        .expect("stylesheet ron should parse");
+    let serialized = sheet
+        .to_ron_string()
+        .expect("parsed stylesheet should serialize");
+    let reparsed = crate::parse_stylesheet_ron_with_variables(&serialized, &variables)
+        .expect("serialized stylesheet should parse");
+
+    assert!(serialized.contains(r#"Color(Var("--accent"))"#));
+    assert!(!serialized.contains("#0078D7"));
+    assert_eq!(reparsed, sheet);
+}
 
-    reparent_overlay_entities(&mut world);
+#[test]
+fn public_parse_stylesheet_ron_parses_multi_rule_sheet() {
+    let ron = r##"(
+    rules: [
+        (selector: Class("primary"), setter: (colors: (bg: Hex("#0055AA")))),
+        (selector: Type("UiButton"), setter: (layout: (padding: 6.0))),
+        (
+            selector: Child(parent: Class("toolbar"), child: Class("primary")),
+            setter: (text: (size: 12.0)),
+        ),
+    ],
+)"##;
 
-    let mut overlays = world.query_filtered::<Entity, With<crate::UiOverlayRoot>>();
-    let overlay_root = overlays
-        .iter(&world)
-        .next()
-        .expect("overlay root should exist");
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
 
-    let toast_parent = world
-        .get::<bevy_ecs::hierarchy::ChildOf>(toast)
-        .expect("toast should be parented")
-        .parent();
-    let tooltip_parent = world
-        .get::<bevy_ecs::hierarchy::ChildOf>(tooltip)
-        .expect("tooltip should be parented")
-        .parent();
+    assert_eq!(sheet.rules.len(), 3);
+    assert_eq!(sheet.rules[0].selector, crate::Selector::class("primary"));
+    assert_eq!(
+        sheet.rules[0].setter.colors.bg,
+        Some(crate::StyleValue::Value(crate::xilem::Color::from_rgb8(
+            0x00, 0x55, 0xAA
+        )))
+    );
+    assert_eq!(
+        sheet.rules[1].selector,
+        crate::Selector::type_name("UiButton")
+    );
+    assert_eq!(
+        sheet.rules[2].selector,
+        crate::Selector::child(
+            crate::Selector::class("toolbar"),
+            crate::Selector::class("primary")
+        )
+    );
+}
 
-    assert_eq!(toast_parent, overlay_root);
-    assert_eq!(tooltip_parent, overlay_root);
+#[test]
+fn public_parse_stylesheet_ron_reports_invalid_hex_color_and_selector() {
+    let bad_color = r##"(
+    rules: [(selector: Class("primary"), setter: (colors: (bg: Hex("#12345G"))))],
+)"##;
+    let error = crate::parse_stylesheet_ron(bad_color).expect_err("bad hex color should fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(
+        error.to_string().contains("invalid hex color `12345G`"),
+        "unexpected error: {error}"
+    );
 
-    let stack = world.resource::<crate::OverlayStack>();
-    assert!(stack.active_overlays.contains(&toast));
-    assert!(stack.active_overlays.contains(&tooltip));
+    let bad_selector = r##"(
+    rules: [
+        (selector: Class("ok"), setter: ()),
+        (selector: And([]), setter: ()),
+    ],
+)"##;
+    let error = crate::parse_stylesheet_ron(bad_selector).expect_err("empty And should fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(
+        error
+            .to_string()
+            .contains("stylesheet rule 1: invalid selector: `And` needs at least one selector"),
+        "unexpected error: {error}"
+    );
 }
 
 #[test]
-fn ensure_overlay_defaults_assigns_built_in_overlay_metadata() {
-    let mut world = World::new();
-    let combo = world
-        .spawn((crate::UiComboBox::new(vec![crate::UiComboOption::new(
-            "v", "V",
-        )]),))
-        .id();
-    let dialog = world.spawn((crate::UiDialog::new("t", "b"),)).id();
-    let dropdown = world
-        .spawn((crate::UiDropdownMenu, crate::AnchoredTo(combo)))
-        .id();
-    let menu_item = world
-        .spawn((crate::UiMenuBarItem::new(
-            "File",
-            [crate::UiMenuItem::new("Open", "file.open")],
-        ),))
-        .id();
-    let menu_panel = world
-        .spawn((crate::UiMenuItemPanel { anchor: menu_item },))
-        .id();
-    let theme_picker = world.spawn((crate::UiThemePicker::fluent(),)).id();
-    let theme_panel = world
-        .spawn((crate::UiThemePickerMenu {
-            anchor: theme_picker,
-        },))
-        .id();
-    let color_picker = world.spawn((crate::UiColorPicker::new(12, 34, 56),)).id();
-    let color_panel = world
-        .spawn((crate::UiColorPickerPanel {
-            anchor: color_picker,
-        },))
-        .id();
-    let date_picker = world.spawn((crate::UiDatePicker::new(2026, 3, 17),)).id();
-    let date_panel = world
-        .spawn((crate::UiDatePickerPanel {
-            anchor: date_picker,
-            view_year: 2026,
-            view_month: 3,
-        },))
-        .id();
-    let tooltip_anchor = world.spawn_empty().id();
-    let tooltip = world
-        .spawn((crate::UiTooltip {
-            text: "Helpful tip".to_string(),
-            anchor: tooltip_anchor,
-        },))
-        .id();
-    let toast = world
-        .spawn((crate::UiToast::new("Saved").with_duration(1.25),))
-        .id();
-    let custom_toast = world
-        .spawn((crate::UiToast::new("Pinned top")
-            .with_placement(crate::OverlayPlacement::TopEnd)
-            .with_auto_flip_placement(true)
-            .with_duration(0.0),))
-        .id();
-    let persistent_toast = world
-        .spawn((
-            crate::UiToast::new("Pinned").with_duration(0.0),
-            crate::AutoDismiss::from_seconds(2.0),
-        ))
-        .id();
+fn hsl_and_named_colors_parse_from_ron() {
+    let ron = r##"(
+    tokens: {
+        "steel": Color(Hsl(210.0, 0.5, 0.4)),
+        "steel-translucent": Color(Hsla(210.0, 0.5, 0.4, 0.5)),
+        "royal": Color(Named("RebeccaPurple")),
+    },
+    rules: [
+        (
+            selector: Class("royal"),
+            setter: (colors: (bg: Named("rebeccapurple"), border: Hsl(210.0, 0.5, 0.4), text: Var("steel"))),
+        ),
+    ],
+)"##;
 
-    ensure_overlay_defaults(&mut world);
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
 
-    assert_overlay_defaults_for_entity(
-        &world,
-        dialog,
-        "dialog",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::Center,
-            anchor: None,
-            auto_flip: false,
-        },
-        crate::OverlayState {
-            is_modal: true,
-            anchor: None,
-        },
-        false,
+    assert_eq!(
+        sheet.tokens.get("steel"),
+        Some(&crate::TokenValue::Color(crate::xilem::Color::from_rgb8(
+            51, 102, 153
+        )))
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        dropdown,
-        "dropdown",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomStart,
-            anchor: Some(combo),
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: Some(combo),
-        },
-        true,
+    assert_eq!(
+        sheet.tokens.get("steel-translucent"),
+        Some(&crate::TokenValue::Color(crate::xilem::Color::from_rgba8(
+            51, 102, 153, 128
+        )))
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        menu_panel,
-        "menu panel",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomStart,
-            anchor: Some(menu_item),
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: Some(menu_item),
-        },
-        true,
+    assert_eq!(
+        sheet.tokens.get("royal"),
+        Some(&crate::TokenValue::Color(crate::xilem::Color::from_rgb8(
+            0x66, 0x33, 0x99
+        )))
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        theme_panel,
-        "theme picker panel",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomEnd,
-            anchor: Some(theme_picker),
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: Some(theme_picker),
-        },
-        true,
+
+    let colors = &sheet.rules[0].setter.colors;
+    assert_eq!(
+        colors.bg,
+        Some(crate::StyleValue::Value(crate::xilem::Color::from_rgb8(
+            0x66, 0x33, 0x99
+        )))
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        color_panel,
-        "color picker panel",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomStart,
-            anchor: Some(color_picker),
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: Some(color_picker),
-        },
-        true,
+    assert_eq!(
+        colors.border,
+        Some(crate::StyleValue::Value(crate::xilem::Color::from_rgb8(
+            51, 102, 153
+        )))
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        date_panel,
-        "date picker panel",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomStart,
-            anchor: Some(date_picker),
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: Some(date_picker),
-        },
-        true,
+    assert_eq!(
+        colors.text,
+        Some(crate::StyleValue::Var("steel".to_string()))
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        tooltip,
-        "tooltip",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::Top,
-            anchor: Some(tooltip_anchor),
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: Some(tooltip_anchor),
-        },
-        true,
+}
+
+#[test]
+fn unknown_named_color_is_invalid_data() {
+    let ron = r##"(
+    tokens: {
+        "royal": Color(Named("notacolor")),
+    },
+)"##;
+
+    let error = crate::parse_stylesheet_ron(ron).expect_err("unknown color name should fail");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(
+        error.to_string().contains("unknown color name `notacolor`"),
+        "unexpected error: {error}"
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        toast,
-        "toast",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomEnd,
-            anchor: None,
-            auto_flip: false,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: None,
-        },
-        false,
+
+    let rule_ron = r##"(
+    rules: [
+        (selector: Class("royal"), setter: (colors: (bg: Named("notacolor")))),
+    ],
+)"##;
+    let error = crate::parse_stylesheet_ron(rule_ron)
+        .expect_err("unknown color name in a rule should fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(
+        error.to_string().contains("unknown color name `notacolor`"),
+        "unexpected error: {error}"
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        custom_toast,
-        "custom toast",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::TopEnd,
-            anchor: None,
-            auto_flip: true,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: None,
-        },
-        false,
+}
+
+#[test]
+fn style_variables_resolve_var_colors_when_sheet_is_parsed() {
+    let accent = crate::xilem::Color::from_rgb8(0x22, 0x88, 0xEE);
+    let variables = crate::StyleVariables::default().with("--accent", accent);
+    let ron = r##"(
+    tokens: {
+        "card-border": Color(Var("accent")),
+    },
+    rules: [
+        (
+            selector: Class("card"),
+            setter: (colors: (bg: Var("accent"), border: Var("card-border"))),
+        ),
+    ],
+)"##;
+
+    let sheet = crate::parse_stylesheet_ron_with_variables(ron, &variables)
+        .expect("stylesheet ron should parse");
+    assert_eq!(
+        sheet.variable_tokens.get("card-border").map(String::as_str),
+        Some("accent")
     );
-    assert_overlay_defaults_for_entity(
-        &world,
-        persistent_toast,
-        "persistent toast",
-        crate::OverlayConfig {
-            placement: crate::OverlayPlacement::BottomEnd,
-            anchor: None,
-            auto_flip: false,
-        },
-        crate::OverlayState {
-            is_modal: false,
-            anchor: None,
-        },
-        false,
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+    let entity = world
+        .spawn((crate::StyleClass(vec!["card".to_string()]),))
+        .id();
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = crate::resolve_style(&world, entity);
+    assert_eq!(resolved.colors.bg, Some(accent));
+    assert_eq!(resolved.colors.border, Some(accent));
+}
+
+#[test]
+fn changing_style_variable_restyles_bound_entities() {
+    let blue = crate::xilem::Color::from_rgb8(0x22, 0x88, 0xEE);
+    let orange = crate::xilem::Color::from_rgb8(0xEE, 0x88, 0x22);
+    let ron = r##"(
+    rules: [
+        (selector: Class("card"), setter: (colors: (bg: Var("accent")))),
+    ],
+)"##;
+
+    let mut world = World::new();
+    world.insert_resource(crate::StyleVariables::default().with("--accent", blue));
+    crate::apply_active_stylesheet_ron(&mut world, ron).expect("stylesheet ron should apply");
+    let entity = world
+        .spawn((crate::StyleClass(vec!["card".to_string()]),))
+        .id();
+
+    crate::sync_style_variables(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    assert_eq!(
+        world
+            .get::<crate::ComputedStyle>(entity)
+            .and_then(|computed| computed.colors.bg),
+        Some(blue)
     );
 
-    let dismiss = world
-        .get::<crate::AutoDismiss>(toast)
-        .expect("toast should receive auto-dismiss timer");
-    assert_eq!(dismiss.timer.duration(), Duration::from_secs_f32(1.25));
+    world.clear_trackers();
+    world
+        .resource_mut::<crate::StyleVariables>()
+        .insert("--accent", orange);
+    crate::sync_style_variables(&mut world);
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    assert_eq!(
+        world
+            .get::<crate::ComputedStyle>(entity)
+            .and_then(|computed| computed.colors.bg),
+        Some(orange)
+    );
+    assert_eq!(
+        world
+            .resource::<crate::ActiveStyleSheet>()
+            .0
+            .tokens
+            .get("accent"),
+        Some(&crate::TokenValue::Color(orange))
+    );
+}
+
+#[test]
+fn unknown_style_variable_in_token_is_invalid_data() {
+    let ron = r##"(
+    tokens: {
+        "card-border": Color(Var("missing")),
+    },
+)"##;
+
+    let error = crate::parse_stylesheet_ron_with_variables(ron, &crate::StyleVariables::default())
+        .expect_err("unknown variable should fail");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(
+        error
+            .to_string()
+            .contains("unknown style variable `missing`"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn switching_locale_applies_locale_style_override_layer() {
+    let mut world = World::new();
+    let mut sheet = StyleSheet::default();
+    sheet.set_class(
+        "test.body",
+        StyleSetter {
+            layout: crate::LayoutStyle {
+                padding: Some(4.0),
+                ..crate::LayoutStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    );
+    world.insert_resource(sheet);
+    world.insert_resource(AppI18n::new("en-US".parse().expect("locale should parse")));
+
+    let mut cjk_sheet = StyleSheet::default();
+    cjk_sheet.set_class(
+        "test.body",
+        StyleSetter {
+            layout: crate::LayoutStyle {
+                padding: Some(10.0),
+                ..crate::LayoutStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    );
+    let mut overrides = crate::LocaleStyleOverrides::default();
+    overrides.insert("zh-CN".parse().expect("locale should parse"), cjk_sheet);
+    world.insert_resource(overrides);
+
+    let entity = world
+        .spawn(crate::StyleClass(vec!["test.body".to_string()]))
+        .id();
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    let computed = world
+        .get::<crate::ComputedStyle>(entity)
+        .expect("styled entity should have a computed style");
+    assert_eq!(computed.layout.padding, 4.0);
+
+    world.clear_trackers();
+    world
+        .resource_mut::<AppI18n>()
+        .set_active_locale("zh-CN".parse().expect("locale should parse"));
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+    let computed = world
+        .get::<crate::ComputedStyle>(entity)
+        .expect("styled entity should have a computed style");
+    assert_eq!(computed.layout.padding, 10.0);
+}
+
+#[test]
+fn locale_override_tokens_shadow_sheet_tokens_without_replacing_them() {
+    let mut world = World::new();
+    let sheet = crate::parse_stylesheet_ron(
+        r##"(
+    tokens: {
+        "body-pad": Float(4.0),
+        "body-gap": Float(2.0),
+    },
+    rules: [
+        (
+            selector: Class("test.body"),
+            setter: (
+                layout: (
+                    padding: Var("body-pad"),
+                    gap: Var("body-gap"),
+                ),
+            ),
+        ),
+    ],
+)"##,
+    )
+    .expect("sheet should parse");
+    world.insert_resource(sheet);
+    world.insert_resource(AppI18n::new("zh-CN".parse().expect("locale should parse")));
+
+    let cjk_sheet = crate::parse_stylesheet_ron(
+        r##"(
+    tokens: {
+        "body-pad": Float(10.0),
+    },
+)"##,
+    )
+    .expect("override sheet should parse");
+    let mut overrides = crate::LocaleStyleOverrides::default();
+    overrides.insert("zh-CN".parse().expect("locale should parse"), cjk_sheet);
+    world.insert_resource(overrides);
+
+    let style = crate::resolve_style_for_classes(&world, ["test.body"]);
+    assert_eq!(style.layout.padding, 10.0);
+    assert_eq!(style.layout.gap, 2.0);
+    assert_eq!(
+        world.resource::<StyleSheet>().tokens.get("body-pad"),
+        Some(&crate::TokenValue::Float(4.0))
+    );
+}
+
+#[test]
+fn capped_style_recompute_spreads_dirty_entities_across_updates() {
+    let mut world = World::new();
+    let mut sheet = StyleSheet::default();
+    sheet.set_class(
+        "test.cell",
+        StyleSetter {
+            layout: crate::LayoutStyle {
+                padding: Some(6.0),
+                ..crate::LayoutStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    );
+    world.insert_resource(sheet);
+    world.insert_resource(crate::StyleConfig {
+        max_recompute_per_frame: Some(2),
+    });
+
+    let cells = (0..5)
+        .map(|_| {
+            world
+                .spawn(crate::StyleClass(vec!["test.cell".to_string()]))
+                .id()
+        })
+        .collect::<Vec<_>>();
+
+    crate::mark_style_dirty(&mut world);
+
+    let mut computed_counts = Vec::new();
+    for _ in 0..3 {
+        crate::sync_style_targets(&mut world);
+        computed_counts.push(
+            cells
+                .iter()
+                .filter(|cell| world.get::<crate::ComputedStyle>(**cell).is_some())
+                .count(),
+        );
+    }
+
+    assert_eq!(computed_counts, vec![2, 4, 5]);
+    assert!(
+        cells
+            .iter()
+            .all(|cell| world.get::<crate::StyleDirty>(*cell).is_none())
+    );
+    assert!(cells.iter().all(|cell| {
+        world
+            .get::<crate::ComputedStyle>(*cell)
+            .is_some_and(|computed| computed.layout.padding == 6.0)
+    }));
+}
+
+#[test]
+fn sync_style_targets_restarts_tween_when_current_differs_but_target_unchanged() {
+    let mut world = World::new();
+    let mut sheet = StyleSheet::default();
+
+    let base = crate::xilem::Color::from_rgb8(0x20, 0x2A, 0x44);
+    let mid = crate::xilem::Color::from_rgb8(0x90, 0x99, 0xB3);
+
+    sheet.set_class(
+        "test.animated",
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(base),
+                ..ColorStyle::default()
+            },
+            transition: Some(crate::StyleTransition {
+                duration: 0.2,
+                ease: None,
+            }),
+            ..StyleSetter::default()
+        },
+    );
+
+    world.insert_resource(sheet);
+
+    let entity = world
+        .spawn((crate::StyleClass(vec!["test.animated".to_string()]),))
+        .id();
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    world.entity_mut(entity).insert(crate::CurrentColorStyle {
+        bg: Some(mid),
+        text: None,
+        border: None,
+        scale: 1.0,
+        opacity: 1.0,
+    });
+    world.entity_mut(entity).insert(crate::TargetColorStyle {
+        bg: Some(base),
+        text: None,
+        border: None,
+        scale: 1.0,
+        opacity: 1.0,
+    });
+    world.entity_mut(entity).insert(crate::StyleDirty);
+
+    crate::sync_style_targets(&mut world);
+
+    assert_eq!(
+        world
+            .get::<crate::TargetColorStyle>(entity)
+            .and_then(|target| target.bg),
+        Some(base)
+    );
+    assert!(world.get::<TimeRunner>(entity).is_some());
+    assert!(
+        world
+            .get::<ComponentTween<crate::ColorStyleLens>>(entity)
+            .is_some()
+    );
+}
+
+fn spawn_transitioning_entity(world: &mut World, ease: Option<crate::StyleEase>) -> Entity {
+    let mut sheet = StyleSheet::default();
+    sheet.set_class(
+        "test.eased",
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(crate::xilem::Color::from_rgb8(0x20, 0x2A, 0x44)),
+                ..ColorStyle::default()
+            },
+            transition: Some(crate::StyleTransition {
+                duration: 0.2,
+                ease,
+            }),
+            ..StyleSetter::default()
+        },
+    );
+    world.insert_resource(sheet);
+
+    let entity = world
+        .spawn((
+            crate::StyleClass(vec!["test.eased".to_string()]),
+            crate::CurrentColorStyle {
+                bg: Some(crate::xilem::Color::from_rgb8(0x90, 0x99, 0xB3)),
+                ..crate::CurrentColorStyle::default()
+            },
+        ))
+        .id();
+    crate::mark_style_dirty(world);
+    entity
+}
+
+#[test]
+fn style_animation_defaults_ease_drives_transition_tween() {
+    let mut world = World::new();
+    let entity = spawn_transitioning_entity(&mut world, None);
+
+    crate::sync_style_targets(&mut world);
+    assert!(matches!(
+        world.get::<EaseKind>(entity),
+        Some(EaseKind::QuadraticInOut)
+    ));
+
+    world.insert_resource(crate::StyleAnimationDefaults {
+        ease: EaseKind::Linear,
+    });
+    world.entity_mut(entity).insert(crate::CurrentColorStyle {
+        bg: Some(crate::xilem::Color::from_rgb8(0x90, 0x99, 0xB3)),
+        ..crate::CurrentColorStyle::default()
+    });
+    world.entity_mut(entity).insert(crate::StyleDirty);
+
+    crate::sync_style_targets(&mut world);
+    assert!(matches!(
+        world.get::<EaseKind>(entity),
+        Some(EaseKind::Linear)
+    ));
+}
+
+#[test]
+fn named_transition_ease_overrides_animation_defaults() {
+    let mut world = World::new();
+    world.insert_resource(crate::StyleAnimationDefaults {
+        ease: EaseKind::Linear,
+    });
+    let entity = spawn_transitioning_entity(&mut world, Some(crate::StyleEase::BackOut));
+
+    crate::sync_style_targets(&mut world);
+    assert!(matches!(
+        world.get::<EaseKind>(entity),
+        Some(EaseKind::BackOut)
+    ));
+}
+
+#[test]
+fn elastic_style_ease_maps_to_elastic_tween_curve() {
+    let mut world = World::new();
+    let entity = spawn_transitioning_entity(&mut world, Some(crate::StyleEase::ElasticOut));
+
+    crate::sync_style_targets(&mut world);
+    assert!(matches!(
+        world.get::<EaseKind>(entity),
+        Some(EaseKind::ElasticOut)
+    ));
+    assert!(matches!(
+        crate::StyleEase::ElasticIn.to_ease_kind(),
+        EaseKind::ElasticIn
+    ));
+    assert!(matches!(
+        crate::StyleEase::ElasticInOut.to_ease_kind(),
+        EaseKind::ElasticInOut
+    ));
+}
+
+#[test]
+fn pointer_left_does_not_clear_pressed_marker() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+    world.insert_resource(bevy_time::Time::<()>::default());
+
+    let entity = world
+        .spawn((crate::InteractionState {
+            hovered: true,
+            pressed: true,
+        },))
+        .id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(entity, crate::UiInteractionEvent::PointerLeft);
+
+    crate::sync_ui_interaction_markers(&mut world);
+
+    let state = world
+        .get::<crate::InteractionState>(entity)
+        .expect("interaction state should exist");
+    assert!(!state.hovered);
+    assert!(state.pressed);
+}
+
+#[test]
+fn debounced_hover_waits_before_setting_hovered_state() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+    world.insert_resource(bevy_time::Time::<()>::default());
+
+    let entity = world
+        .spawn((crate::styling::HoverDebounce {
+            enter_delay_secs: 0.05,
+        },))
+        .id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(entity, crate::UiInteractionEvent::PointerEntered);
+
+    crate::sync_ui_interaction_markers(&mut world);
+
+    assert!(world.get::<crate::InteractionState>(entity).is_none());
+
+    world
+        .resource_mut::<bevy_time::Time<()>>()
+        .advance_by(Duration::from_millis(60));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(crate::styling::activate_debounced_hovers);
+    schedule.run(&mut world);
+
+    let state = world
+        .get::<crate::InteractionState>(entity)
+        .expect("interaction state should exist after debounce elapses");
+    assert!(state.hovered);
+}
+
+#[test]
+fn direct_slider_action_updates_slider_state() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let slider = world
+        .spawn((crate::UiSlider::new(0.0, 100.0, 10.0).with_step(5.0),))
+        .id();
+
+    world.resource::<UiEventQueue>().push_typed(
+        slider,
+        crate::WidgetUiAction::SetSliderValue {
+            slider,
+            value: 42.0,
+        },
+    );
+
+    crate::handle_widget_actions(&mut world);
+
+    let slider_state = world
+        .get::<crate::UiSlider>(slider)
+        .expect("slider should exist");
+    assert_eq!(slider_state.value, 40.0);
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiSliderChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].action.value, 40.0);
+}
+
+#[test]
+fn text_input_numeric_filter_rejects_letters_before_change_event() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let input = world
+        .spawn((crate::UiTextInput::new("12").with_filter(crate::InputFilter::Numeric),))
+        .id();
+
+    world.resource::<UiEventQueue>().push_typed(
+        input,
+        crate::WidgetUiAction::SetTextInput {
+            input,
+            value: "12a3b".to_string(),
+        },
+    );
+
+    crate::handle_widget_actions(&mut world);
+
+    assert_eq!(
+        world
+            .get::<crate::UiTextInput>(input)
+            .expect("text input should exist")
+            .value,
+        "123"
+    );
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiTextInputChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].action.value, "123");
+}
+
+#[test]
+fn masked_text_input_renders_dots_but_reports_real_value() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let input = app
+        .world_mut()
+        .spawn((
+            crate::UiTextInput::new("hunter2").with_mask('•'),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let layer = || runtime.render_root.get_layer_root(0);
+        assert!(find_widget_id_by_debug_text(layer(), "•••••••").is_some());
+        assert!(find_widget_id_by_debug_text(layer(), "hunter2").is_none());
+    }
+
+    app.world().resource::<UiEventQueue>().push_typed(
+        input,
+        crate::WidgetUiAction::SetTextInput {
+            input,
+            value: "hunter22".to_string(),
+        },
+    );
+    app.update();
+
+    let changed = app
+        .world_mut()
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiTextInputChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].action.value, "hunter22");
+}
+
+#[test]
+fn text_input_keeps_widget_id_when_only_sibling_is_reprojected() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let status = app
+        .world_mut()
+        .spawn((crate::UiLabel::new("status: idle"), ChildOf(root)))
+        .id();
+    // No remount key needed: the entity scope keeps the input's slot stable.
+    app.world_mut()
+        .spawn((crate::UiTextInput::new("stable-input"), ChildOf(root)));
+
+    app.update();
+    app.update();
+
+    let input_widget_id = |app: &App| {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), "stable-input")
+            .expect("text input widget should exist")
+    };
+    let before = input_widget_id(&app);
+
+    app.world_mut()
+        .entity_mut(status)
+        .insert(crate::UiLabel::new("status: busy"));
+    app.update();
+    app.update();
+
+    assert_eq!(input_widget_id(&app), before);
+}
+
+#[test]
+fn every_builtin_control_projects_entity_tagged_debug_text() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let controls = [
+        app.world_mut()
+            .spawn((crate::UiButton::new("button"), ChildOf(root)))
+            .id(),
+        app.world_mut()
+            .spawn((crate::UiCheckbox::new("checkbox", false), ChildOf(root)))
+            .id(),
+        app.world_mut()
+            .spawn((crate::UiSlider::new(0.0, 1.0, 0.5), ChildOf(root)))
+            .id(),
+        app.world_mut()
+            .spawn((crate::UiSwitch::new(true), ChildOf(root)))
+            .id(),
+        app.world_mut()
+            .spawn((crate::UiTextInput::new("text"), ChildOf(root)))
+            .id(),
+        app.world_mut()
+            .spawn((crate::UiComboBox::from_values(["a", "b"]), ChildOf(root)))
+            .id(),
+    ];
+
+    app.update();
+    app.update();
+
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let mut tagged = HashSet::new();
+    collect_entity_debug_bits(runtime.render_root.get_layer_root(0), &mut tagged);
+
+    for entity in controls {
+        assert!(
+            tagged.contains(&entity.to_bits()),
+            "control {entity:?} should project an entity-tagged widget"
+        );
+        let scope_debug = crate::widgets::entity_debug_text(
+            Some(crate::widgets::ENTITY_SCOPE_DEBUG_KIND),
+            entity,
+        );
+        assert!(
+            find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), &scope_debug)
+                .is_some()
+        );
+    }
+}
+
+#[test]
+fn changing_remount_key_rebuilds_text_input_widget() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let input = app
+        .world_mut()
+        .spawn((
+            crate::UiTextInput::new("keyed-input"),
+            crate::UiRemountKey(1),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    let input_widget_id = |app: &App| {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), "keyed-input")
+            .expect("text input widget should exist")
+    };
+    let before = input_widget_id(&app);
+
+    // Unrelated changes keep the key, so the widget is rebuilt in place.
+    app.world_mut()
+        .entity_mut(input)
+        .insert(crate::UiTextInput::new("keyed-input").with_placeholder("edit"));
+    app.update();
+    app.update();
+    assert_eq!(input_widget_id(&app), before);
+
+    app.world_mut()
+        .entity_mut(input)
+        .insert(crate::UiRemountKey(2));
+    app.update();
+    app.update();
+
+    assert_ne!(input_widget_id(&app), before);
+}
+
+#[derive(Resource, Default)]
+struct ContentReady(bool);
+
+#[test]
+fn suspense_shows_fallback_until_readiness_flag_flips() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.init_resource::<ContentReady>();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let suspense = app.world_mut().spawn(ChildOf(root)).id();
+    let fallback = app
+        .world_mut()
+        .spawn((crate::UiLabel::new("thumbnail loading…"), ChildOf(suspense)))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("thumbnail ready"), ChildOf(suspense)));
+    app.world_mut().entity_mut(suspense).insert(
+        crate::UiSuspense::new(fallback).with_ready(|world, _| world.resource::<ContentReady>().0),
+    );
+
+    app.update();
+    app.update();
+
+    let visible = |app: &App, text: &str| {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text).is_some()
+    };
+    assert!(visible(&app, "thumbnail loading…"));
+    assert!(!visible(&app, "thumbnail ready"));
+
+    app.world_mut().resource_mut::<ContentReady>().0 = true;
+    app.update();
+    app.update();
+
+    assert!(!visible(&app, "thumbnail loading…"));
+    assert!(visible(&app, "thumbnail ready"));
+}
+
+#[derive(Clone, Default)]
+struct MockClipboard {
+    contents: Arc<Mutex<Option<String>>>,
+}
+
+impl crate::ClipboardAccess for MockClipboard {
+    fn set_text(&mut self, text: &str) -> Result<(), String> {
+        *self.contents.lock().expect("mock clipboard lock") = Some(text.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn copy_to_clipboard_action_sets_text_and_reports_success() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+    let clipboard = MockClipboard::default();
+    world.insert_resource(crate::UiClipboard::new(clipboard.clone()));
+
+    let button = world.spawn_empty().id();
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(button, crate::CopyToClipboard("copied body".to_string()));
+
+    crate::handle_clipboard_actions(&mut world);
+
+    assert_eq!(
+        clipboard
+            .contents
+            .lock()
+            .expect("mock clipboard lock")
+            .as_deref(),
+        Some("copied body")
+    );
+    let copied = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::ClipboardCopied>();
+    assert_eq!(copied.len(), 1);
+    assert_eq!(copied[0].entity, button);
+    assert_eq!(copied[0].action.text, "copied body");
+}
+
+#[derive(Clone, Default)]
+struct MockUrlOpener {
+    opened: Arc<Mutex<Vec<String>>>,
+}
+
+impl crate::UrlOpener for MockUrlOpener {
+    fn open(&mut self, url: &str) -> Result<(), String> {
+        self.opened
+            .lock()
+            .expect("mock opener lock")
+            .push(url.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn open_url_action_invokes_opener_with_url() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+    let opener = MockUrlOpener::default();
+    world.insert_resource(crate::UiUrlOpener::new(opener.clone()));
+
+    let link = world.spawn_empty().id();
+    world.resource::<UiEventQueue>().push_typed(
+        link,
+        crate::OpenUrl("https://example.com/login".to_string()),
+    );
+
+    crate::handle_open_url_actions(&mut world);
+
+    assert_eq!(
+        *opener.opened.lock().expect("mock opener lock"),
+        vec!["https://example.com/login".to_string()]
+    );
+    let opened = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UrlOpened>();
+    assert_eq!(opened.len(), 1);
+    assert_eq!(opened[0].entity, link);
+    assert!(
+        world
+            .resource_mut::<UiEventQueue>()
+            .drain_actions::<crate::OpenUrlFailed>()
+            .is_empty()
+    );
+}
+
+#[test]
+fn url_launcher_passes_shell_metacharacters_as_one_argument() {
+    let url = "https://example.com/search?q=1&calc";
+    let (program, args) = crate::platform::browser_launcher(url)
+        .expect("https URLs should have a launcher on this platform");
+
+    assert_ne!(program, "cmd");
+    assert_eq!(args.last().map(String::as_str), Some(url));
+}
+
+#[test]
+fn system_url_opener_refuses_non_web_schemes() {
+    let mut opener = crate::SystemUrlOpener;
+    for url in [
+        "file:///C:/Windows/System32/calc.exe",
+        "javascript:alert(1)&calc",
+        "calc.exe&calc",
+    ] {
+        let error =
+            crate::UrlOpener::open(&mut opener, url).expect_err("non-web URL should be refused");
+        assert!(error.contains("only http, https and mailto"), "{error}");
+    }
+}
+
+#[test]
+fn title_bar_drag_moves_floating_panel_within_window() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    world.spawn((window, PrimaryWindow));
+
+    let panel = world
+        .spawn(
+            crate::UiFloatingPanel::new("Inspector")
+                .with_position(40.0, 40.0)
+                .with_size(200.0, 150.0),
+        )
+        .id();
+    assert!(world.get::<crate::Teleport>(panel).is_some());
+
+    world.resource::<UiEventQueue>().push_typed(
+        panel,
+        crate::WidgetUiAction::DragFloatingPanel {
+            panel,
+            handle: crate::FloatingPanelHandle::Move,
+            delta: (25.0, -10.0),
+        },
+    );
+    crate::handle_widget_actions(&mut world);
+
+    let floating = world
+        .get::<crate::UiFloatingPanel>(panel)
+        .expect("panel should exist");
+    assert_eq!(floating.position, (65.0, 30.0));
+    assert_eq!(floating.size, (200.0, 150.0));
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiFloatingPanelChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].action.position, (65.0, 30.0));
+
+    world.resource::<UiEventQueue>().push_typed(
+        panel,
+        crate::WidgetUiAction::DragFloatingPanel {
+            panel,
+            handle: crate::FloatingPanelHandle::Move,
+            delta: (1000.0, 1000.0),
+        },
+    );
+    crate::handle_widget_actions(&mut world);
+
+    let floating = world
+        .get::<crate::UiFloatingPanel>(panel)
+        .expect("panel should exist");
+    assert_eq!(floating.position, (600.0, 450.0));
+}
+
+#[test]
+fn direct_checkbox_action_sets_checkbox_state() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let checkbox = world.spawn((crate::UiCheckbox::new("demo", false),)).id();
+
+    world.resource::<UiEventQueue>().push_typed(
+        checkbox,
+        crate::WidgetUiAction::SetCheckbox {
+            checkbox,
+            checked: true,
+        },
+    );
+
+    crate::handle_widget_actions(&mut world);
+
+    let checkbox_state = world
+        .get::<crate::UiCheckbox>(checkbox)
+        .expect("checkbox should exist");
+    assert!(checkbox_state.checked);
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiCheckboxChanged>();
+    assert_eq!(changed.len(), 1);
+    assert!(changed[0].action.checked);
+}
+
+#[test]
+fn sync_style_targets_keeps_unmanaged_tween_anim() {
+    let mut world = World::new();
+
+    let duration = Duration::from_secs(1);
+    let entity = world.spawn_empty().id();
+    world.entity_mut(entity).insert((
+        TimeSpan::try_from(Duration::ZERO..duration)
+            .expect("test tween duration range should be valid"),
+        EaseKind::Linear,
+        ComponentTween::new_target(
+            entity,
+            crate::ColorStyleLens {
+                start: crate::CurrentColorStyle {
+                    bg: Some(crate::xilem::Color::from_rgb8(0x10, 0x20, 0x30)),
+                    text: None,
+                    border: None,
+                    scale: 1.0,
+                    opacity: 1.0,
+                },
+                end: crate::CurrentColorStyle {
+                    bg: Some(crate::xilem::Color::from_rgb8(0x40, 0x50, 0x60)),
+                    text: None,
+                    border: None,
+                    scale: 1.0,
+                    opacity: 1.0,
+                },
+            },
+        ),
+        TimeRunner::new(duration),
+        TimeContext::<()>::default(),
+    ));
+    world.entity_mut(entity).insert(crate::StyleDirty);
+
+    crate::sync_style_targets(&mut world);
+
+    assert!(world.get::<TimeRunner>(entity).is_some());
+    assert!(
+        world
+            .get::<ComponentTween<crate::ColorStyleLens>>(entity)
+            .is_some()
+    );
+}
+
+#[test]
+fn resolve_style_for_classes_applies_font_family() {
+    let mut world = World::new();
+    let mut sheet = StyleSheet::default();
+
+    sheet.set_class(
+        "cjk-text",
+        StyleSetter {
+            font_family: Some(vec![
+                "Primary Family".to_string(),
+                "Fallback Family".to_string(),
+            ]),
+            ..StyleSetter::default()
+        },
+    );
+    world.insert_resource(sheet);
+
+    let resolved = crate::resolve_style_for_classes(&world, ["cjk-text"]);
+    assert_eq!(
+        resolved.font_family,
+        Some(vec![
+            "Primary Family".to_string(),
+            "Fallback Family".to_string()
+        ])
+    );
+}
+
+#[test]
+fn computed_style_lens_keeps_font_family_until_completion() {
+    let mut world = World::new();
+
+    let start = crate::ComputedStyle {
+        font_family: Some(vec!["Family A".to_string()]),
+        ..crate::ComputedStyle::default()
+    };
+    let end = crate::ComputedStyle {
+        font_family: Some(vec!["Family B".to_string()]),
+        ..crate::ComputedStyle::default()
+    };
+
+    let entity = world.spawn((start.clone(),)).id();
+    let lens = crate::ComputedStyleLens {
+        start: start.clone(),
+        end: end.clone(),
+    };
+
+    {
+        let target = world
+            .get_mut::<crate::ComputedStyle>(entity)
+            .expect("computed style should exist");
+        lens.interpolate(target.into_inner(), 0.5, 0.0);
+    }
+
+    assert_eq!(
+        world
+            .get::<crate::ComputedStyle>(entity)
+            .and_then(|style| style.font_family.clone()),
+        Some(vec!["Family A".to_string()])
+    );
+
+    {
+        let target = world
+            .get_mut::<crate::ComputedStyle>(entity)
+            .expect("computed style should exist");
+        lens.interpolate(target.into_inner(), 1.0, 0.0);
+    }
+
+    assert_eq!(
+        world
+            .get::<crate::ComputedStyle>(entity)
+            .and_then(|style| style.font_family.clone()),
+        Some(vec!["Family B".to_string()])
+    );
+}
+
+#[test]
+fn opacity_transition_interpolates_computed_style_opacity() {
+    let mut world = World::new();
+
+    let ron = r#"(
+  rules: [
+    (
+      selector: Class("toast"),
+      setter: (colors: (opacity: 0.0), transition: (duration: 0.3)),
+    ),
+    (
+      selector: Class("toast.visible"),
+      setter: (colors: (opacity: 1.0)),
+    ),
+  ],
+)"#;
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    world.insert_resource(sheet);
+
+    let toast = world
+        .spawn(crate::StyleClass(vec!["toast".to_string()]))
+        .id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let hidden = world
+        .get::<crate::ComputedStyle>(toast)
+        .cloned()
+        .expect("computed style should exist");
+    assert_eq!(hidden.opacity, 0.0);
+    assert_eq!(resolve_style(&world, toast).opacity, 0.0);
+
+    world.entity_mut(toast).insert(crate::StyleClass(vec![
+        "toast".to_string(),
+        "toast.visible".to_string(),
+    ]));
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let shown = world
+        .get::<crate::ComputedStyle>(toast)
+        .cloned()
+        .expect("computed style should exist");
+    assert_eq!(shown.opacity, 1.0);
+    assert_eq!(
+        world
+            .get::<crate::TargetColorStyle>(toast)
+            .map(|target| target.opacity),
+        Some(1.0)
+    );
+    assert!(
+        world
+            .get::<ComponentTween<crate::ColorStyleLens>>(toast)
+            .is_some()
+    );
+
+    let lens = crate::ComputedStyleLens {
+        start: hidden.clone(),
+        end: shown,
+    };
+    let mut target = hidden;
+    lens.interpolate(&mut target, 0.5, 0.0);
+    assert!((target.opacity - 0.5).abs() < f32::EPSILON);
+
+    let style = crate::ResolvedStyle {
+        opacity: target.opacity,
+        ..crate::ResolvedStyle::default()
+    };
+    assert_eq!(
+        style.faded(crate::xilem::Color::from_rgba8(0x10, 0x20, 0x30, 0xFF)),
+        crate::xilem::Color::from_rgba8(0x10, 0x20, 0x30, 0xFF).multiply_alpha(0.5)
+    );
+}
+
+#[test]
+fn xilem_font_bridge_deduplicates_same_font_bytes() {
+    let mut bridge = crate::XilemFontBridge::default();
+    assert!(bridge.register_font_bytes(b"font-data"));
+    assert!(!bridge.register_font_bytes(b"font-data"));
+}
+
+#[test]
+fn lucide_font_family_matches_upstream_identifier() {
+    assert_eq!(crate::LUCIDE_FONT_FAMILY, "lucide");
+}
+
+#[test]
+fn register_i18n_bundle_stores_locale_font_stacks_in_app_i18n() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_i18n_bundle(
+            "en-US",
+            SyncTextSource::String(include_str!("../../../assets/locales/en-US/main.ftl")),
+            vec!["Inter", "sans-serif"],
+        )
+        .register_i18n_bundle(
+            "zh-CN",
+            SyncTextSource::String(include_str!("../../../assets/locales/zh-CN/main.ftl")),
+            vec!["Inter", "Noto Sans CJK SC", "sans-serif"],
+        );
+
+    {
+        let i18n = app.world().resource::<AppI18n>();
+        assert_eq!(
+            i18n.get_font_stack(),
+            vec!["Inter".to_string(), "sans-serif".to_string()]
+        );
+    }
+
+    app.world_mut().resource_mut::<AppI18n>().set_active_locale(
+        "zh-CN"
+            .parse()
+            .expect("zh-CN locale identifier should parse"),
+    );
+    {
+        let i18n = app.world().resource::<AppI18n>();
+        assert_eq!(
+            i18n.get_font_stack(),
+            vec![
+                "Inter".to_string(),
+                "Noto Sans CJK SC".to_string(),
+                "sans-serif".to_string()
+            ]
+        );
+    }
+
+    app.world_mut().resource_mut::<AppI18n>().set_active_locale(
+        "ja-JP"
+            .parse()
+            .expect("ja-JP locale identifier should parse"),
+    );
+    assert_eq!(
+        app.world().resource::<AppI18n>().get_font_stack(),
+        vec!["Inter".to_string(), "sans-serif".to_string()]
+    );
+}
+
+#[test]
+fn resolve_localized_text_falls_back_when_cache_is_missing() {
+    let mut world = World::new();
+    let entity = world.spawn((crate::LocalizeText::new("hello_world"),)).id();
+
+    let with_fallback = crate::resolve_localized_text(&world, entity, "Fallback");
+    let without_fallback = crate::resolve_localized_text(&world, entity, "");
+
+    assert_eq!(with_fallback, "Fallback");
+    assert_eq!(without_fallback, "hello_world");
+}
+
+#[test]
+fn ensure_overlay_root_spawns_once() {
+    let mut world = World::new();
+    world.spawn((UiRoot,));
+
+    ensure_overlay_root(&mut world);
+    ensure_overlay_root(&mut world);
+
+    let mut overlay_query = world.query_filtered::<Entity, With<crate::UiOverlayRoot>>();
+    let overlays = overlay_query.iter(&world).collect::<Vec<_>>();
+
+    assert_eq!(overlays.len(), 1);
+    assert!(world.get::<UiRoot>(overlays[0]).is_some());
+}
+
+#[test]
+fn overlay_actions_toggle_and_select_combo_box() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
+    let mut combo_box = crate::UiComboBox::new(vec![
+        crate::UiComboOption::new("one", "One"),
+        crate::UiComboOption::new("two", "Two"),
+    ]);
+    combo_box.selected = 0;
+    let combo = world.spawn((combo_box,)).id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(combo, crate::OverlayUiAction::ToggleCombo);
+
+    handle_overlay_actions(&mut world);
+
+    let mut dropdown_query = world.query::<(Entity, &crate::AnchoredTo, &crate::UiDropdownMenu)>();
+    let dropdowns = dropdown_query
+        .iter(&world)
+        .filter_map(|(entity, anchored_to, _)| (anchored_to.0 == combo).then_some(entity))
+        .collect::<Vec<_>>();
+
+    assert_eq!(dropdowns.len(), 1);
+    let dropdown = dropdowns[0];
+    let mut item_query = world.query::<(Entity, &crate::UiDropdownItem, &crate::StyleClass)>();
+    let items = item_query
+        .iter(&world)
+        .filter(|(_, item, _)| item.dropdown == dropdown)
+        .map(|(entity, item, classes)| (entity, *item, classes.clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(items.len(), 2);
+    assert!(items.iter().any(|(_, item, classes)| {
+        item.index == 0
+            && classes
+                .0
+                .iter()
+                .any(|class_name| class_name == "overlay.dropdown.item.selected")
+    }));
+
+    let second_item = items
+        .iter()
+        .find_map(|(entity, item, _)| (item.index == 1).then_some(*entity))
+        .expect("second dropdown item should exist");
+    assert!(
+        world
+            .get::<bevy_ecs::hierarchy::ChildOf>(dropdown)
+            .is_some()
+    );
+    assert_eq!(
+        world
+            .get::<bevy_ecs::hierarchy::ChildOf>(dropdown)
+            .expect("dropdown should be parented")
+            .parent(),
+        overlay_root
+    );
+    assert!(
+        world
+            .get::<crate::UiComboBox>(combo)
+            .expect("combo should exist")
+            .is_open
+    );
+
+    world.resource::<UiEventQueue>().push_typed(
+        second_item,
+        crate::OverlayUiAction::SelectComboItem { dropdown, index: 1 },
+    );
+
+    handle_overlay_actions(&mut world);
+
+    let combo_after = world
+        .get::<crate::UiComboBox>(combo)
+        .expect("combo should exist");
+    assert_eq!(combo_after.selected, 1);
+    assert!(!combo_after.is_open);
+    assert!(world.get_entity(dropdown).is_err());
+    assert!(world.get_entity(second_item).is_err());
+}
+
+#[test]
+fn overlay_actions_toggle_and_select_theme_picker() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
+    let picker = world.spawn((crate::UiThemePicker::fluent(),)).id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(picker, crate::OverlayUiAction::ToggleThemePicker);
+
+    handle_overlay_actions(&mut world);
+
+    let mut panel_query = world.query::<(Entity, &crate::UiThemePickerMenu)>();
+    let panels = panel_query
+        .iter(&world)
+        .filter_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
+        .collect::<Vec<_>>();
+
+    assert_eq!(panels.len(), 1);
+    let panel = panels[0];
+    assert_eq!(
+        world
+            .get::<bevy_ecs::hierarchy::ChildOf>(panel)
+            .expect("theme picker panel should be parented")
+            .parent(),
+        overlay_root
+    );
+    assert!(
+        world
+            .get::<crate::UiThemePicker>(picker)
+            .expect("theme picker should exist")
+            .is_open
+    );
+
+    world.resource::<UiEventQueue>().push_typed(
+        panel,
+        crate::OverlayUiAction::SelectThemePickerItem { index: 1 },
+    );
+
+    handle_overlay_actions(&mut world);
+
+    let picker_after = world
+        .get::<crate::UiThemePicker>(picker)
+        .expect("theme picker should exist");
+    assert_eq!(picker_after.selected, 1);
+    assert!(!picker_after.is_open);
+    assert!(world.get_entity(panel).is_err());
+
+    let active_variant = world.resource::<crate::ActiveStyleVariant>();
+    assert_eq!(active_variant.0.as_deref(), Some("light"));
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiThemePickerChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].entity, picker);
+    assert_eq!(changed[0].action.selected, 1);
+    assert_eq!(changed[0].action.variant, "light");
+}
+
+#[test]
+fn overlay_actions_toggle_and_select_color_picker() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
+    let picker = world.spawn((crate::UiColorPicker::new(12, 34, 56),)).id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(picker, crate::OverlayUiAction::ToggleColorPicker);
+
+    handle_overlay_actions(&mut world);
+
+    let mut panel_query = world.query::<(Entity, &crate::UiColorPickerPanel)>();
+    let panels = panel_query
+        .iter(&world)
+        .filter_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
+        .collect::<Vec<_>>();
+
+    assert_eq!(panels.len(), 1);
+    let panel = panels[0];
+    assert_eq!(
+        world
+            .get::<bevy_ecs::hierarchy::ChildOf>(panel)
+            .expect("color picker panel should be parented")
+            .parent(),
+        overlay_root
+    );
+    assert!(
+        world
+            .get::<crate::UiColorPicker>(picker)
+            .expect("color picker should exist")
+            .is_open
+    );
+
+    world.resource::<UiEventQueue>().push_typed(
+        panel,
+        crate::OverlayUiAction::SelectColorSwatch {
+            r: 200,
+            g: 100,
+            b: 50,
+        },
+    );
+
+    handle_overlay_actions(&mut world);
+
+    let picker_after = world
+        .get::<crate::UiColorPicker>(picker)
+        .expect("color picker should exist");
+    assert_eq!(
+        (picker_after.r, picker_after.g, picker_after.b),
+        (200, 100, 50)
+    );
+    assert!(!picker_after.is_open);
+    assert!(world.get_entity(panel).is_err());
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiColorPickerChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].entity, picker);
+    assert_eq!(
+        (
+            changed[0].action.r,
+            changed[0].action.g,
+            changed[0].action.b
+        ),
+        (200, 100, 50)
+    );
+}
+
+#[test]
+fn overlay_actions_toggle_and_select_date_picker() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let overlay_root = world.spawn((UiRoot, crate::UiOverlayRoot)).id();
+    let picker = world.spawn((crate::UiDatePicker::new(2026, 3, 17),)).id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(picker, crate::OverlayUiAction::ToggleDatePicker);
+
+    handle_overlay_actions(&mut world);
+
+    let mut panel_query = world.query::<(Entity, &crate::UiDatePickerPanel)>();
+    let panels = panel_query
+        .iter(&world)
+        .filter_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
+        .collect::<Vec<_>>();
+
+    assert_eq!(panels.len(), 1);
+    let panel = panels[0];
+    assert_eq!(
+        world
+            .get::<bevy_ecs::hierarchy::ChildOf>(panel)
+            .expect("date picker panel should be parented")
+            .parent(),
+        overlay_root
+    );
+    let panel_state = world
+        .get::<crate::UiDatePickerPanel>(panel)
+        .expect("date picker panel should exist");
+    assert_eq!(panel_state.view_year, 2026);
+    assert_eq!(panel_state.view_month, 3);
+    assert!(
+        world
+            .get::<crate::UiDatePicker>(picker)
+            .expect("date picker should exist")
+            .is_open
+    );
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(panel, crate::OverlayUiAction::SelectDateDay { day: 29 });
+
+    handle_overlay_actions(&mut world);
+
+    let picker_after = world
+        .get::<crate::UiDatePicker>(picker)
+        .expect("date picker should exist");
+    assert_eq!(picker_after.year, 2026);
+    assert_eq!(picker_after.month, 3);
+    assert_eq!(picker_after.day, 29);
+    assert!(!picker_after.is_open);
+    assert!(world.get_entity(panel).is_err());
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiDatePickerChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].entity, picker);
+    assert_eq!(changed[0].action.year, 2026);
+    assert_eq!(changed[0].action.month, 3);
+    assert_eq!(changed[0].action.day, 29);
+}
+
+#[test]
+/// On HiDPI displays, `Window::cursor_position` (logical) must still resolve to an
+/// inside-overlay retained hit after conversion to physical coordinates.
+fn overlay_click_inside_computed_overlay_position_not_dismissed_on_hidpi() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(400.0, 300.0);
+    window.resolution.set_scale_factor_override(Some(2.0));
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+
+    app.update();
+    app.update();
+
+    let opaque_debug = format!("opaque_hitbox entity={}", dialog.to_bits());
+    let opaque_widget_id = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        find_widget_id_by_debug_text(root, &opaque_debug)
+            .expect("dialog should project an entity-tagged OpaqueHitboxWidget")
+    };
+
+    let runtime_center = widget_center_for_widget_id(&app, opaque_widget_id);
+    let window_scale_factor = app
+        .world()
+        .get::<Window>(window_entity)
+        .expect("primary window should exist")
+        .scale_factor();
+    let click_position = runtime_center / window_scale_factor.max(f32::EPSILON);
+
+    run_global_overlay_click(&mut app, window_entity, click_position);
+
+    assert!(app.world().get_entity(dialog).is_ok());
+}
+
+#[test]
+fn spawn_in_overlay_root_parents_entity_under_overlay_root() {
+    let mut world = World::new();
+    world.spawn((UiRoot,));
+
+    let dialog = spawn_in_overlay_root(&mut world, (crate::UiDialog::new("title", "body"),));
+
+    let overlay_root = ensure_overlay_root_entity(&mut world);
+    let parent = world
+        .get::<bevy_ecs::hierarchy::ChildOf>(dialog)
+        .expect("dialog should be parented")
+        .parent();
+
+    assert_eq!(parent, overlay_root);
+    assert!(world.get::<crate::UiOverlayRoot>(overlay_root).is_some());
+}
+
+#[test]
+fn reparent_overlay_entities_moves_dialog_to_overlay_root() {
+    let mut world = World::new();
+    let app_root = world.spawn((UiRoot,)).id();
+    let dialog = world
+        .spawn((crate::UiDialog::new("title", "body"), ChildOf(app_root)))
+        .id();
+
+    reparent_overlay_entities(&mut world);
+
+    let mut overlays = world.query_filtered::<Entity, With<crate::UiOverlayRoot>>();
+    let overlay_root = overlays
+        .iter(&world)
+        .next()
+        .expect("overlay root should exist");
+
+    let parent = world
+        .get::<bevy_ecs::hierarchy::ChildOf>(dialog)
+        .expect("dialog should be parented")
+        .parent();
+    assert_eq!(parent, overlay_root);
+}
+
+#[test]
+fn reparent_overlay_entities_moves_toast_and_tooltip_to_overlay_root_and_tracks_stack() {
+    let mut world = World::new();
+    world.insert_resource(crate::OverlayStack::default());
+
+    let app_root = world.spawn((UiRoot,)).id();
+    let anchor = world.spawn((ChildOf(app_root),)).id();
+
+    let toast = world
+        .spawn((
+            crate::UiToast::new("Saved"),
+            crate::OverlayState {
+                is_modal: false,
+                anchor: None,
+            },
+            ChildOf(app_root),
+        ))
+        .id();
+
+    let tooltip = world
+        .spawn((
+            crate::UiTooltip {
+                text: "Helpful tip".to_string(),
+                anchor,
+            },
+            crate::OverlayState {
+                is_modal: false,
+                anchor: Some(anchor),
+            },
+            ChildOf(app_root),
+        ))
+        .id();
+
+    reparent_overlay_entities(&mut world);
+
+    let mut overlays = world.query_filtered::<Entity, With<crate::UiOverlayRoot>>();
+    let overlay_root = overlays
+        .iter(&world)
+        .next()
+        .expect("overlay root should exist");
+
+    let toast_parent = world
+        .get::<bevy_ecs::hierarchy::ChildOf>(toast)
+        .expect("toast should be parented")
+        .parent();
+    let tooltip_parent = world
+        .get::<bevy_ecs::hierarchy::ChildOf>(tooltip)
+        .expect("tooltip should be parented")
+        .parent();
+
+    assert_eq!(toast_parent, overlay_root);
+    assert_eq!(tooltip_parent, overlay_root);
+
+    let stack = world.resource::<crate::OverlayStack>();
+    assert!(stack.active_overlays.contains(&toast));
+    assert!(stack.active_overlays.contains(&tooltip));
+}
+
+#[test]
+fn ensure_overlay_defaults_assigns_built_in_overlay_metadata() {
+    let mut world = World::new();
+    let combo = world
+        .spawn((crate::UiComboBox::new(vec![crate::UiComboOption::new(
+            "v", "V",
+        )]),))
+        .id();
+    let dialog = world.spawn((crate::UiDialog::new("t", "b"),)).id();
+    let dropdown = world
+        .spawn((crate::UiDropdownMenu, crate::AnchoredTo(combo)))
+        .id();
+    let menu_item = world
+        .spawn((crate::UiMenuBarItem::new(
+            "File",
+            [crate::UiMenuItem::new("Open", "file.open")],
+        ),))
+        .id();
+    let menu_panel = world
+        .spawn((crate::UiMenuItemPanel { anchor: menu_item },))
+        .id();
+    let theme_picker = world.spawn((crate::UiThemePicker::fluent(),)).id();
+    let theme_panel = world
+        .spawn((crate::UiThemePickerMenu {
+            anchor: theme_picker,
+        },))
+        .id();
+    let color_picker = world.spawn((crate::UiColorPicker::new(12, 34, 56),)).id();
+    let color_panel = world
+        .spawn((crate::UiColorPickerPanel {
+            anchor: color_picker,
+        },))
+        .id();
+    let date_picker = world.spawn((crate::UiDatePicker::new(2026, 3, 17),)).id();
+    let date_panel = world
+        .spawn((crate::UiDatePickerPanel {
+            anchor: date_picker,
+            view_year: 2026,
+            view_month: 3,
+        },))
+        .id();
+    let tooltip_anchor = world.spawn_empty().id();
+    let tooltip = world
+        .spawn((crate::UiTooltip {
+            text: "Helpful tip".to_string(),
+            anchor: tooltip_anchor,
+        },))
+        .id();
+    let toast = world
+        .spawn((crate::UiToast::new("Saved").with_duration(1.25),))
+        .id();
+    let custom_toast = world
+        .spawn((crate::UiToast::new("Pinned top")
+            .with_placement(crate::OverlayPlacement::TopEnd)
+            .with_auto_flip_placement(true)
+            .with_duration(0.0),))
+        .id();
+    let persistent_toast = world
+        .spawn((
+            crate::UiToast::new("Pinned").with_duration(0.0),
+            crate::AutoDismiss::from_seconds(2.0),
+        ))
+        .id();
+
+    ensure_overlay_defaults(&mut world);
+
+    assert_overlay_defaults_for_entity(
+        &world,
+        dialog,
+        "dialog",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::Center,
+            anchor: None,
+            auto_flip: false,
+        },
+        crate::OverlayState {
+            is_modal: true,
+            anchor: None,
+        },
+        false,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        dropdown,
+        "dropdown",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomStart,
+            anchor: Some(combo),
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: Some(combo),
+        },
+        true,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        menu_panel,
+        "menu panel",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomStart,
+            anchor: Some(menu_item),
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: Some(menu_item),
+        },
+        true,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        theme_panel,
+        "theme picker panel",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomEnd,
+            anchor: Some(theme_picker),
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: Some(theme_picker),
+        },
+        true,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        color_panel,
+        "color picker panel",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomStart,
+            anchor: Some(color_picker),
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: Some(color_picker),
+        },
+        true,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        date_panel,
+        "date picker panel",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomStart,
+            anchor: Some(date_picker),
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: Some(date_picker),
+        },
+        true,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        tooltip,
+        "tooltip",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::Top,
+            anchor: Some(tooltip_anchor),
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: Some(tooltip_anchor),
+        },
+        true,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        toast,
+        "toast",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomEnd,
+            anchor: None,
+            auto_flip: false,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: None,
+        },
+        false,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        custom_toast,
+        "custom toast",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::TopEnd,
+            anchor: None,
+            auto_flip: true,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: None,
+        },
+        false,
+    );
+    assert_overlay_defaults_for_entity(
+        &world,
+        persistent_toast,
+        "persistent toast",
+        crate::OverlayConfig {
+            placement: crate::OverlayPlacement::BottomEnd,
+            anchor: None,
+            auto_flip: false,
+        },
+        crate::OverlayState {
+            is_modal: false,
+            anchor: None,
+        },
+        false,
+    );
+
+    let dismiss = world
+        .get::<crate::AutoDismiss>(toast)
+        .expect("toast should receive auto-dismiss timer");
+    assert_eq!(dismiss.timer.duration(), Duration::from_secs_f32(1.25));
+
+    assert!(world.get::<crate::AutoDismiss>(custom_toast).is_none());
+
+    assert!(world.get::<crate::AutoDismiss>(persistent_toast).is_none());
+}
+
+#[test]
+fn sync_overlay_positions_uses_dynamic_primary_window_size() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(1024.0, 768.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let dialog = app
+        .world_mut()
+        .spawn((crate::UiDialog::new("title", "body"),))
+        .id();
+
+    app.update();
+
+    let initial = *app
+        .world()
+        .get::<crate::OverlayComputedPosition>(dialog)
+        .expect("dialog should have computed position");
+    assert!(initial.is_positioned);
+
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut primary_window = query
+            .single_mut(world)
+            .expect("primary window should exist");
+        primary_window.resolution.set(1600.0, 900.0);
+    }
+
+    app.update();
+
+    let resized = *app
+        .world()
+        .get::<crate::OverlayComputedPosition>(dialog)
+        .expect("dialog should still have computed position");
+
+    assert!(resized.x > initial.x);
+    assert_eq!(initial.width, resized.width);
+    assert_eq!(initial.height, resized.height);
+    assert!(resized.is_positioned);
+    assert!(resized.x + resized.width <= 1600.0 + f64::EPSILON);
+    assert!(resized.y + resized.height <= 900.0 + f64::EPSILON);
+}
+
+#[test]
+fn sync_overlay_positions_leaves_idle_overlay_position_untouched() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(1024.0, 768.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let dialog = app
+        .world_mut()
+        .spawn((crate::UiDialog::new("title", "body"),))
+        .id();
+
+    app.update();
+    app.update();
+
+    let last_changed = |app: &App| {
+        app.world()
+            .entity(dialog)
+            .get_ref::<crate::OverlayComputedPosition>()
+            .expect("dialog should have computed position")
+            .last_changed()
+    };
+    let settled = last_changed(&app);
+
+    app.update();
+    app.update();
+
+    assert_eq!(last_changed(&app), settled);
+}
+
+#[test]
+fn sync_overlay_positions_works_without_primary_window_marker() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(1280.0, 720.0);
+    app.world_mut().spawn((window,));
+
+    let dialog = app
+        .world_mut()
+        .spawn((crate::UiDialog::new("title", "body"),))
+        .id();
+
+    app.update();
+
+    let computed = *app
+        .world()
+        .get::<crate::OverlayComputedPosition>(dialog)
+        .expect("dialog should have computed position without PrimaryWindow marker");
+
+    assert!(computed.width > 1.0);
+    assert!(computed.height > 1.0);
+    assert!(computed.x > 0.0);
+    assert!(computed.y > 0.0);
+    assert!(computed.is_positioned);
+}
+
+fn send_primary_click(app: &mut App, window_entity: Entity, position: Vec2) {
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut primary_window = query
+            .single_mut(world)
+            .expect("primary window should exist");
+        primary_window.set_cursor_position(Some(position));
+    }
+
+    app.world_mut().write_message(MouseButtonInput {
+        button: MouseButton::Left,
+        state: ButtonState::Pressed,
+        window: window_entity,
+    });
+    app.world_mut().write_message(MouseButtonInput {
+        button: MouseButton::Left,
+        state: ButtonState::Released,
+        window: window_entity,
+    });
+
+    app.update();
+}
+
+fn set_window_cursor_position(app: &mut App, window_entity: Entity, position: Vec2) {
+    let world = app.world_mut();
+    let mut window = world
+        .get_mut::<Window>(window_entity)
+        .expect("window should exist");
+    window.set_cursor_position(Some(position));
+}
+
+fn run_global_overlay_click(app: &mut App, window_entity: Entity, position: Vec2) {
+    set_window_cursor_position(app, window_entity, position);
+
+    if !app.world().contains_resource::<ButtonInput<MouseButton>>() {
+        app.world_mut()
+            .insert_resource(ButtonInput::<MouseButton>::default());
+    }
+
+    {
+        let mut input = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        input.release(MouseButton::Left);
+        input.clear();
+        input.press(MouseButton::Left);
+    }
+
+    app.update();
+
+    let mut input = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+    input.release(MouseButton::Left);
+    input.clear();
+}
+
+fn hit_path_for_position(app: &mut App, window_entity: Entity, position: Vec2) -> Vec<WidgetId> {
+    set_window_cursor_position(app, window_entity, position);
+
+    let mut runtime = app
+        .world_mut()
+        .non_send_resource_mut::<crate::MasonryRuntime>();
+    let _ = runtime.render_root.redraw();
+    runtime.get_hit_path((position.x as f64, position.y as f64).into())
+}
+
+fn collect_entity_debug_bits(widget: WidgetRef<'_, dyn Widget>, out: &mut HashSet<u64>) {
+    for child in widget.children() {
+        collect_entity_debug_bits(child, out);
+    }
+
+    if let Some((_, bits)) = widget
+        .get_debug_text()
+        .as_deref()
+        .and_then(crate::widgets::parse_entity_debug_text)
+    {
+        out.insert(bits);
+    }
+}
+
+fn find_widget_id_by_debug_text(
+    widget: WidgetRef<'_, dyn Widget>,
+    expected_debug_text: &str,
+) -> Option<WidgetId> {
+    for child in widget.children() {
+        if let Some(id) = find_widget_id_by_debug_text(child, expected_debug_text) {
+            return Some(id);
+        }
+    }
+
+    (widget.get_debug_text().as_deref() == Some(expected_debug_text)).then_some(widget.id())
+}
+
+fn widget_center_for_widget_id(app: &App, widget_id: WidgetId) -> Vec2 {
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let widget = runtime
+        .render_root
+        .get_widget(widget_id)
+        .expect("widget id should resolve in render tree");
+
+    let ctx = widget.ctx();
+    let origin = ctx.window_origin();
+    let size = ctx.border_box_size();
+    Vec2::new(
+        (origin.x + size.width * 0.5) as f32,
+        (origin.y + size.height * 0.5) as f32,
+    )
+}
+
+fn widget_inset_point_for_widget_id(app: &App, widget_id: WidgetId, inset: f64) -> Vec2 {
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let widget = runtime
+        .render_root
+        .get_widget(widget_id)
+        .expect("widget id should resolve in render tree");
+
+    let ctx = widget.ctx();
+    let origin = ctx.window_origin();
+    Vec2::new((origin.x + inset) as f32, (origin.y + inset) as f32)
+}
+
+fn widget_center_for_entity(app: &App, entity: Entity) -> Vec2 {
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let widget_id = runtime
+        .find_widget_id_for_entity_bits(entity.to_bits(), true)
+        .or_else(|| runtime.find_widget_id_for_entity_bits(entity.to_bits(), false))
+        .expect("entity should resolve to a Masonry widget");
+    widget_center_for_widget_id(app, widget_id)
+}
+
+fn open_combo_dropdown(app: &mut App, combo: Entity) -> Entity {
+    app.world()
+        .resource::<UiEventQueue>()
+        .push_typed(combo, crate::OverlayUiAction::ToggleCombo);
+
+    app.update();
+
+    let mut query = app.world_mut().query::<(Entity, &crate::AnchoredTo)>();
+    query
+        .iter(app.world())
+        .find_map(|(entity, anchored_to)| {
+            app.world()
+                .get::<crate::UiDropdownMenu>(entity)
+                .is_some_and(|_| anchored_to.0 == combo)
+                .then_some(entity)
+        })
+        .expect("combo toggle should create dropdown")
+}
+
+fn assert_overlay_defaults_for_entity(
+    world: &World,
+    entity: Entity,
+    label: &str,
+    expected_config: crate::OverlayConfig,
+    expected_state: crate::OverlayState,
+    expect_anchor_rect: bool,
+) {
+    let config = world
+        .get::<crate::OverlayConfig>(entity)
+        .unwrap_or_else(|| panic!("{label} should receive overlay config"));
+    assert_eq!(*config, expected_config);
+
+    let state = world
+        .get::<crate::OverlayState>(entity)
+        .unwrap_or_else(|| panic!("{label} should receive overlay state"));
+    assert_eq!(*state, expected_state);
+
+    let position = world
+        .get::<crate::OverlayComputedPosition>(entity)
+        .unwrap_or_else(|| panic!("{label} should receive computed position"));
+    assert_eq!(*position, crate::OverlayComputedPosition::default());
+
+    if expect_anchor_rect {
+        let anchor_rect = world
+            .get::<crate::OverlayAnchorRect>(entity)
+            .unwrap_or_else(|| panic!("{label} should receive overlay anchor rect"));
+        assert_eq!(*anchor_rect, crate::OverlayAnchorRect::default());
+    } else {
+        assert!(
+            world.get::<crate::OverlayAnchorRect>(entity).is_none(),
+            "{label} should not receive overlay anchor rect"
+        );
+    }
+}
+
+fn collect_widget_bounds_by_short_name(
+    widget: WidgetRef<'_, dyn Widget>,
+    short_type_name: &str,
+    bounds: &mut Vec<Rect>,
+) {
+    for child in widget.children() {
+        collect_widget_bounds_by_short_name(child, short_type_name, bounds);
+    }
+
+    if widget.short_type_name() == short_type_name {
+        let ctx = widget.ctx();
+        let origin = ctx.window_origin();
+        let size = ctx.border_box_size();
+        bounds.push(Rect::from_corners(
+            Vec2::new(origin.x as f32, origin.y as f32),
+            Vec2::new(
+                (origin.x + size.width) as f32,
+                (origin.y + size.height) as f32,
+            ),
+        ));
+    }
+}
+
+#[test]
+fn dialog_body_click_does_not_dismiss_overlay() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    window.set_cursor_position(Some(Vec2::new(0.0, 0.0)));
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+
+    app.update();
+    app.update();
+
+    let computed = app
+        .world()
+        .get::<crate::OverlayComputedPosition>(dialog)
+        .expect("dialog should have computed position");
+
+    let click_position = Vec2::new(
+        (computed.x + computed.width * 0.5) as f32,
+        (computed.y + 24.0) as f32,
+    );
+
+    send_primary_click(&mut app, window_entity, click_position);
+
+    assert!(app.world().get_entity(dialog).is_ok());
+}
+
+#[test]
+fn dialog_padding_click_is_in_overlay_hit_path_and_does_not_dismiss() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    window.set_cursor_position(Some(Vec2::new(0.0, 0.0)));
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+
+    app.update();
+
+    let opaque_debug = format!("opaque_hitbox entity={}", dialog.to_bits());
+    let opaque_widget_id = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        find_widget_id_by_debug_text(root, &opaque_debug)
+            .expect("dialog should project an entity-tagged OpaqueHitboxWidget")
+    };
+
+    // Deliberately target a stable inset point inside the opaque panel surface.
+    let click_position = widget_inset_point_for_widget_id(&app, opaque_widget_id, 14.0);
+    let hit_path = hit_path_for_position(&mut app, window_entity, click_position);
+    assert!(hit_path.contains(&opaque_widget_id));
+
+    run_global_overlay_click(&mut app, window_entity, click_position);
+
+    assert!(app.world().get_entity(dialog).is_ok());
+}
+
+#[test]
+fn dialog_dismiss_button_targets_dialog_entity() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    window.set_cursor_position(Some(Vec2::new(0.0, 0.0)));
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+
+    app.update();
+
+    let computed = app
+        .world()
+        .get::<crate::OverlayComputedPosition>(dialog)
+        .expect("dialog should have computed position");
+    let content_rect = Rect::from_corners(
+        Vec2::new(computed.x as f32, computed.y as f32),
+        Vec2::new(
+            (computed.x + computed.width) as f32,
+            (computed.y + computed.height) as f32,
+        ),
+    );
+
+    let button_rect = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        let mut button_rects = Vec::new();
+        collect_widget_bounds_by_short_name(root, "EcsButtonWithChildWidget", &mut button_rects);
+
+        button_rects
+            .into_iter()
+            .filter(|rect| {
+                let width = rect.max.x - rect.min.x;
+                let height = rect.max.y - rect.min.y;
+                width < (content_rect.max.x - content_rect.min.x)
+                    && height < (content_rect.max.y - content_rect.min.y)
+            })
+            .min_by(|a, b| {
+                let area_a = (a.max.x - a.min.x) * (a.max.y - a.min.y);
+                let area_b = (b.max.x - b.min.x) * (b.max.y - b.min.y);
+                area_a.total_cmp(&area_b)
+            })
+            .expect("dialog should project a dedicated dismiss button")
+    };
+
+    let click_position = Vec2::new(
+        (button_rect.min.x + button_rect.max.x) * 0.5,
+        (button_rect.min.y + button_rect.max.y) * 0.5,
+    );
+
+    let (hit_widget, hit_debug_text) = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        root.find_widget_under_pointer((click_position.x as f64, click_position.y as f64).into())
+            .map(|widget| {
+                (
+                    widget.short_type_name().to_string(),
+                    widget.get_debug_text().unwrap_or_default(),
+                )
+            })
+            .unwrap_or_default()
+    };
+
+    assert_eq!(hit_widget.as_str(), "EcsButtonWithChildWidget");
+    assert_eq!(hit_debug_text, format!("entity={}", dialog.to_bits()));
+
+    let content_width = content_rect.max.x - content_rect.min.x;
+    let content_height = content_rect.max.y - content_rect.min.y;
+    let button_top = button_rect.min.y;
+    let button_right = button_rect.max.x;
+
+    assert!(
+        button_right > content_width * 0.82,
+        "dismiss button should align against the right side of the dialog header"
+    );
+    assert!(
+        button_top < content_height * 0.22,
+        "dismiss button should sit in the top portion of the dialog header"
+    );
+}
+
+#[test]
+fn dialog_projects_single_dismiss_button_without_fullscreen_backdrop_button() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+
+    app.update();
+
+    let computed = app
+        .world()
+        .get::<crate::OverlayComputedPosition>(dialog)
+        .expect("dialog should have computed position");
+    let content_rect = Rect::from_corners(
+        Vec2::new(computed.x as f32, computed.y as f32),
+        Vec2::new(
+            (computed.x + computed.width) as f32,
+            (computed.y + computed.height) as f32,
+        ),
+    );
+
+    let button_rects = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        let mut button_rects = Vec::new();
+        collect_widget_bounds_by_short_name(root, "EcsButtonWithChildWidget", &mut button_rects);
+        button_rects
+    };
+
+    assert_eq!(
+        button_rects.len(),
+        1,
+        "dialog projector should only emit the dismiss button, not a structural backdrop button"
+    );
+
+    let only_button = button_rects[0];
+    let button_area = (only_button.max.x - only_button.min.x).max(0.0)
+        * (only_button.max.y - only_button.min.y).max(0.0);
+    let content_area = (content_rect.max.x - content_rect.min.x).max(0.0)
+        * (content_rect.max.y - content_rect.min.y).max(0.0);
+
+    assert!(button_area < content_area * 0.8);
+}
+
+#[test]
+fn overlay_action_dismiss_dialog_despawns_dialog() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let dialog = world.spawn((crate::UiDialog::new("title", "body"),)).id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(dialog, crate::OverlayUiAction::DismissDialog);
+
+    handle_overlay_actions(&mut world);
+
+    assert!(world.get_entity(dialog).is_err());
+}
+
+#[test]
+fn overlay_action_dismiss_dialog_emits_optional_close_hook_before_despawn() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let target = world.spawn_empty().id();
+    let dialog = world
+        .spawn((
+            crate::UiDialog::new("title", "body"),
+            crate::UiDialogCloseAction::new(target, DialogCloseTestAction::Closed),
+        ))
+        .id();
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(dialog, crate::OverlayUiAction::DismissDialog);
+
+    handle_overlay_actions(&mut world);
+
+    assert!(world.get_entity(dialog).is_err());
+
+    let events = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<DialogCloseTestAction>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity, target);
+    assert_eq!(events[0].action, DialogCloseTestAction::Closed);
+}
+
+#[test]
+fn handle_global_overlay_clicks_closes_when_clicking_anchor_and_suppresses_pointer() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    {
+        let mut combo_state = app
+            .world_mut()
+            .get_mut::<crate::UiComboBox>(combo)
+            .expect("combo should exist");
+        combo_state.selected = usize::MAX;
+    }
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+    app.update();
+    let anchor_center = widget_center_for_entity(&app, combo);
+
+    run_global_overlay_click(&mut app, window_entity, anchor_center);
+
+    assert!(app.world().get_entity(dropdown).is_err());
+
+    let mut routing = app
+        .world_mut()
+        .resource_mut::<crate::OverlayPointerRoutingState>();
+    assert!(routing.take_suppressed_press(window_entity, MouseButton::Left));
+    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+}
+
+#[test]
+fn handle_global_overlay_clicks_closes_menu_panel_anchor_and_resets_open_state() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let menu_bar = app
+        .world_mut()
+        .spawn((crate::UiMenuBar, ChildOf(root)))
+        .id();
+    let menu_item = app
+        .world_mut()
+        .spawn((
+            crate::UiMenuBarItem::new(
+                "File",
+                [
+                    crate::UiMenuItem::new("Open", "file.open"),
+                    crate::UiMenuItem::new("Save", "file.save"),
+                ],
+            ),
+            ChildOf(menu_bar),
+        ))
+        .id();
+
+    app.update();
+
+    app.world()
+        .resource::<UiEventQueue>()
+        .push_typed(menu_item, crate::OverlayUiAction::ToggleMenuBarItem);
+    app.update();
+
+    let panel = {
+        let mut query = app.world_mut().query::<(Entity, &crate::UiMenuItemPanel)>();
+        query
+            .iter(app.world())
+            .find_map(|(entity, panel)| (panel.anchor == menu_item).then_some(entity))
+            .expect("menu toggle should spawn menu panel")
+    };
+
+    assert!(
+        app.world()
+            .get::<crate::UiMenuBarItem>(menu_item)
+            .expect("menu item should exist")
+            .is_open
+    );
+
+    let anchor_center = widget_center_for_entity(&app, menu_item);
+    run_global_overlay_click(&mut app, window_entity, anchor_center);
+
+    assert!(app.world().get_entity(panel).is_err());
+    assert!(
+        !app.world()
+            .get::<crate::UiMenuBarItem>(menu_item)
+            .expect("menu item should remain")
+            .is_open
+    );
+}
+
+#[test]
+fn handle_global_overlay_clicks_closes_theme_picker_anchor_and_resets_open_state() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let picker = app
+        .world_mut()
+        .spawn((crate::UiThemePicker::fluent(), ChildOf(root)))
+        .id();
+
+    app.update();
+
+    app.world()
+        .resource::<UiEventQueue>()
+        .push_typed(picker, crate::OverlayUiAction::ToggleThemePicker);
+    app.update();
+
+    let panel = {
+        let mut query = app
+            .world_mut()
+            .query::<(Entity, &crate::UiThemePickerMenu)>();
+        query
+            .iter(app.world())
+            .find_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
+            .expect("theme picker toggle should spawn menu panel")
+    };
+
+    assert!(
+        app.world()
+            .get::<crate::UiThemePicker>(picker)
+            .expect("theme picker should exist")
+            .is_open
+    );
+
+    let anchor_center = widget_center_for_entity(&app, picker);
+    run_global_overlay_click(&mut app, window_entity, anchor_center);
+
+    assert!(app.world().get_entity(panel).is_err());
+    assert!(
+        !app.world()
+            .get::<crate::UiThemePicker>(picker)
+            .expect("theme picker should remain")
+            .is_open
+    );
+}
+
+#[test]
+fn ui_button_projects_to_ecs_button_with_child_widget() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let button = app
+        .world_mut()
+        .spawn((crate::UiButton::new("Action"), ChildOf(root)))
+        .id();
+
+    app.update();
+
+    let debug = format!("entity={}", button.to_bits());
+    let widget_id = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        find_widget_id_by_debug_text(root, &debug)
+            .expect("UiButton should project an entity-tagged action button widget")
+    };
+
+    let short_type = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        runtime
+            .render_root
+            .get_widget(widget_id)
+            .map(|widget| widget.short_type_name().to_string())
+            .unwrap_or_default()
+    };
+
+    assert_eq!(short_type, "EcsButtonWithChildWidget");
+}
+
+const GRADIENT_BUTTON_RON: &str = r##"(
+  rules: [
+    (
+      selector: Class("cta"),
+      setter: (
+        colors: (bg: Hex("#202020")),
+        bg_gradient: (
+          angle_deg: 90.0,
+          stops: [(0.0, Hex("#ff0000")), (1.0, Hex("#0000ff"))],
+        ),
+      ),
+    ),
+  ],
+)"##;
+
+#[test]
+fn bg_gradient_parses_from_ron_and_overrides_flat_bg() {
+    let sheet =
+        crate::parse_stylesheet_ron(GRADIENT_BUTTON_RON).expect("stylesheet ron should parse");
+    let gradient = crate::Gradient {
+        angle_deg: 90.0,
+        stops: vec![
+            (0.0, crate::xilem::Color::from_rgb8(0xFF, 0x00, 0x00)),
+            (1.0, crate::xilem::Color::from_rgb8(0x00, 0x00, 0xFF)),
+        ],
+    };
+    assert_eq!(
+        sheet.rules[0].setter.bg_gradient,
+        Some(crate::StyleValue::Value(gradient.clone()))
+    );
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+    let button = world.spawn(crate::StyleClass(vec!["cta".to_string()])).id();
+    let plain = world
+        .spawn(crate::StyleClass(vec!["other".to_string()]))
+        .id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = resolve_style(&world, button);
+    assert_eq!(resolved.bg_gradient, Some(gradient));
+    assert_eq!(
+        resolved.colors.bg,
+        Some(crate::xilem::Color::from_rgb8(0x20, 0x20, 0x20))
+    );
+    assert!(matches!(
+        resolved.background(),
+        crate::xilem_masonry::masonry::properties::Background::Gradient(_)
+    ));
+    assert!(matches!(
+        resolve_style(&world, plain).background(),
+        crate::xilem_masonry::masonry::properties::Background::Color(_)
+    ));
+}
+
+#[test]
+fn gradient_backed_button_projects_without_panicking() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(
+        crate::parse_stylesheet_ron(GRADIENT_BUTTON_RON).expect("stylesheet ron should parse"),
+    );
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let button = app
+        .world_mut()
+        .spawn((
+            crate::UiButton::new("Gradient"),
+            crate::StyleClass(vec!["cta".to_string()]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    assert!(resolve_style(app.world(), button).bg_gradient.is_some());
+    let debug = format!("entity={}", button.to_bits());
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    assert!(find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), &debug).is_some());
+}
+
+const HEADING_TEXT_RON: &str = r#"(
+  rules: [
+    (
+      selector: Class("heading"),
+      setter: (text: (size: 24.0, weight: 700, italic: true)),
+    ),
+  ],
+)"#;
+
+#[test]
+fn text_weight_and_italic_parse_from_ron_into_setter() {
+    let sheet = crate::parse_stylesheet_ron(HEADING_TEXT_RON).expect("stylesheet ron should parse");
+    let text = &sheet.rules[0].setter.text;
+    assert_eq!(text.weight, Some(crate::StyleValue::Value(700)));
+    assert_eq!(text.italic, Some(crate::StyleValue::Value(true)));
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+    let heading = world
+        .spawn(crate::StyleClass(vec!["heading".to_string()]))
+        .id();
+    let body = world
+        .spawn(crate::StyleClass(vec!["body".to_string()]))
+        .id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let heading_text = resolve_style(&world, heading).text;
+    assert_eq!(heading_text.weight, Some(700));
+    assert!(heading_text.italic);
+    assert_eq!(
+        heading_text.font_weight(),
+        Some(crate::xilem_masonry::masonry::parley::style::FontWeight::BOLD)
+    );
+    assert_eq!(
+        heading_text.font_style(),
+        crate::xilem_masonry::masonry::parley::style::FontStyle::Italic
+    );
+
+    let body_text = resolve_style(&world, body).text;
+    assert_eq!(body_text.weight, None);
+    assert!(!body_text.italic);
+    assert_eq!(body_text.font_weight(), None);
+}
+
+#[test]
+fn italic_text_style_reaches_built_label_and_text_input_widgets() {
+    use crate::xilem_masonry::masonry::{
+        parley::{StyleProperty, style::FontStyle},
+        widgets,
+    };
+
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .load_style_sheet_ron(HEADING_TEXT_RON);
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app.world_mut().spawn(UiRoot).id();
+    let heading = crate::StyleClass(vec!["heading".to_string()]);
+    app.world_mut().spawn((
+        crate::UiLabel::new("Italic heading"),
+        heading.clone(),
+        ChildOf(root),
+    ));
+    app.world_mut().spawn((
+        crate::UiTextInput::new("Italic input"),
+        heading,
+        ChildOf(root),
+    ));
+    app.world_mut()
+        .spawn((crate::UiLabel::new("Upright body"), ChildOf(root)));
+
+    app.update();
+
+    let mut runtime = app
+        .world_mut()
+        .non_send_resource_mut::<crate::MasonryRuntime>();
+    let widget_id = |text: &str| {
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+            .unwrap_or_else(|| panic!("`{text}` should be rendered"))
+    };
+    let heading_id = widget_id("Italic heading");
+    let input_id = widget_id("Italic input");
+    let body_id = widget_id("Upright body");
+
+    // `insert_style` hands back the style the widget was built with.
+    let heading_style = runtime.render_root.edit_widget(heading_id, |mut widget| {
+        let mut label = widget.downcast::<widgets::Label>();
+        widgets::Label::insert_style(&mut label, StyleProperty::FontStyle(FontStyle::Normal))
+    });
+    assert!(matches!(
+        heading_style,
+        Some(StyleProperty::FontStyle(FontStyle::Italic))
+    ));
+
+    let input_style = runtime.render_root.edit_widget(input_id, |mut widget| {
+        let mut text_area = widget.downcast::<widgets::TextArea<true>>();
+        widgets::TextArea::insert_style(&mut text_area, StyleProperty::FontStyle(FontStyle::Normal))
+    });
+    assert!(matches!(
+        input_style,
+        Some(StyleProperty::FontStyle(FontStyle::Italic))
+    ));
+
+    let body_style = runtime.render_root.edit_widget(body_id, |mut widget| {
+        let mut label = widget.downcast::<widgets::Label>();
+        widgets::Label::insert_style(&mut label, StyleProperty::FontStyle(FontStyle::Italic))
+    });
+    assert!(matches!(
+        body_style,
+        Some(StyleProperty::FontStyle(FontStyle::Normal))
+    ));
+}
+
+#[test]
+fn weighted_italic_label_projects() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(
+        crate::parse_stylesheet_ron(HEADING_TEXT_RON).expect("stylesheet ron should parse"),
+    );
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.world_mut().spawn((
+        crate::UiLabel::new("Section title"),
+        crate::StyleClass(vec!["heading".to_string()]),
+        ChildOf(root),
+    ));
+
+    app.update();
+
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    assert!(
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), "Section title")
+            .is_some()
+    );
+}
+
+const STATUS_BAR_TEXT_RON: &str = r#"(
+  rules: [
+    (
+      selector: Class("status"),
+      setter: (text: (line_break: Clip)),
+    ),
+    (
+      selector: Class("cell"),
+      setter: (text: (line_break: Ellipsis)),
+    ),
+  ],
+)"#;
+
+#[test]
+fn text_line_break_parses_from_ron_and_defaults_to_word_wrap() {
+    let sheet =
+        crate::parse_stylesheet_ron(STATUS_BAR_TEXT_RON).expect("stylesheet ron should parse");
+    assert_eq!(
+        sheet.rules[0].setter.text.line_break,
+        Some(crate::StyleValue::Value(crate::LineBreakMode::Clip))
+    );
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+    let status = world
+        .spawn(crate::StyleClass(vec!["status".to_string()]))
+        .id();
+    let cell = world
+        .spawn(crate::StyleClass(vec!["cell".to_string()]))
+        .id();
+    let plain = world
+        .spawn(crate::StyleClass(vec!["plain".to_string()]))
+        .id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let status_style = resolve_style(&world, status);
+    assert_eq!(status_style.text.line_break, crate::LineBreakMode::Clip);
+    assert_eq!(
+        crate::styling::effective_label_overflow(crate::TextOverflow::Wrap, &status_style),
+        crate::TextOverflow::Wrap
+    );
+
+    let cell_style = resolve_style(&world, cell);
+    assert_eq!(
+        crate::styling::effective_label_overflow(crate::TextOverflow::Wrap, &cell_style),
+        crate::TextOverflow::ellipsis()
+    );
+    assert_eq!(
+        crate::styling::effective_label_overflow(
+            crate::TextOverflow::Ellipsis { max_lines: 3 },
+            &cell_style
+        ),
+        crate::TextOverflow::Ellipsis { max_lines: 3 }
+    );
+
+    assert_eq!(
+        resolve_style(&world, plain).text.line_break,
+        crate::LineBreakMode::WordWrap
+    );
+}
+
+#[test]
+fn clipped_label_projects() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(
+        crate::parse_stylesheet_ron(STATUS_BAR_TEXT_RON).expect("stylesheet ron should parse"),
+    );
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let status = app
+        .world_mut()
+        .spawn((
+            crate::UiLabel::new("Connected to 3 peers"),
+            crate::StyleClass(vec!["status".to_string()]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        resolve_style(app.world(), status).text.line_break,
+        crate::LineBreakMode::Clip
+    );
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    assert!(
+        find_widget_id_by_debug_text(
+            runtime.render_root.get_layer_root(0),
+            "Connected to 3 peers"
+        )
+        .is_some()
+    );
+}
+
+#[test]
+fn overlay_pointer_routing_suppress_click_only_suppresses_press() {
+    let mut routing = crate::OverlayPointerRoutingState::default();
+    let window = Entity::from_raw_u32(7).expect("test entity index should be valid");
+
+    routing.suppress_click(window, MouseButton::Left);
+
+    assert!(routing.take_suppressed_press(window, MouseButton::Left));
+    assert!(!routing.take_suppressed_release(window, MouseButton::Left));
+}
+
+#[test]
+fn handle_global_overlay_clicks_keeps_overlay_open_when_clicking_inside_overlay() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+    let dropdown_center = widget_center_for_entity(&app, dropdown);
+
+    run_global_overlay_click(&mut app, window_entity, dropdown_center);
+
+    assert!(app.world().get_entity(dropdown).is_ok());
+
+    let mut routing = app
+        .world_mut()
+        .resource_mut::<crate::OverlayPointerRoutingState>();
+    assert!(!routing.take_suppressed_press(window_entity, MouseButton::Left));
+    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+}
+
+#[test]
+fn dropdown_padding_click_is_in_overlay_hit_path_and_does_not_dismiss() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+
+    let opaque_debug = format!("opaque_hitbox entity={}", dropdown.to_bits());
+    let opaque_widget_id = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        find_widget_id_by_debug_text(root, &opaque_debug)
+            .expect("dropdown should project an entity-tagged OpaqueHitboxWidget")
+    };
+
+    // Deliberately target menu padding, not option label text.
+    let click_position = widget_inset_point_for_widget_id(&app, opaque_widget_id, 6.0);
+    let hit_path = hit_path_for_position(&mut app, window_entity, click_position);
+    assert!(hit_path.contains(&opaque_widget_id));
+
+    run_global_overlay_click(&mut app, window_entity, click_position);
+
+    assert!(app.world().get_entity(dropdown).is_ok());
+}
+
+#[test]
+fn dropdown_item_text_region_hits_button_entity_instead_of_child_subwidget() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Longer option label"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+    app.update();
+
+    let item_entity = {
+        let mut query = app.world_mut().query::<(Entity, &crate::UiDropdownItem)>();
+        query
+            .iter(app.world())
+            .find_map(|(entity, item)| {
+                (item.dropdown == dropdown && item.index == 1).then_some(entity)
+            })
+            .expect("second dropdown item should exist")
+    };
+
+    let hit_position = {
+        let debug = format!("entity={}", item_entity.to_bits());
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        let widget_id = find_widget_id_by_debug_text(root, &debug)
+            .expect("dropdown item button should expose an entity-tagged widget");
+        widget_center_for_widget_id(&app, widget_id)
+    };
+    let (hit_widget, hit_debug_text) = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        root.find_widget_under_pointer((hit_position.x as f64, hit_position.y as f64).into())
+            .map(|widget| {
+                (
+                    widget.short_type_name().to_string(),
+                    widget.get_debug_text().unwrap_or_default(),
+                )
+            })
+            .unwrap_or_default()
+    };
+
+    assert_eq!(hit_widget.as_str(), "EcsButtonWithChildWidget");
+    assert_eq!(hit_debug_text, format!("entity={}", item_entity.to_bits()));
+}
+
+#[test]
+fn plugin_auto_registers_badge_and_progress_bar_components() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiBadge::new("Beta"), ChildOf(root)));
+    app.world_mut()
+        .spawn((crate::UiProgressBar::determinate(0.5), ChildOf(root)));
+
+    app.update();
+
+    let stats = app.world().resource::<crate::UiSynthesisStats>();
+    assert_eq!(stats.unhandled_count, 0);
+}
+
+#[test]
+fn handle_global_overlay_clicks_closes_overlay_on_outside_click_without_suppression() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+
+    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
+
+    assert!(app.world().get_entity(dropdown).is_err());
+
+    let mut routing = app
+        .world_mut()
+        .resource_mut::<crate::OverlayPointerRoutingState>();
+    assert!(!routing.take_suppressed_press(window_entity, MouseButton::Left));
+    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+}
+
+#[test]
+fn handle_global_overlay_clicks_outside_dialog_emits_same_optional_close_hook() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let target = app.world_mut().spawn_empty().id();
+    let dialog = spawn_in_overlay_root(
+        app.world_mut(),
+        (
+            crate::UiDialog::new("title", "body"),
+            crate::UiDialogCloseAction::new(target, DialogCloseTestAction::Closed),
+        ),
+    );
+
+    app.update();
+    app.update();
+
+    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
+
+    assert!(app.world().get_entity(dialog).is_err());
+
+    let events = app
+        .world_mut()
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<DialogCloseTestAction>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity, target);
+    assert_eq!(events[0].action, DialogCloseTestAction::Closed);
+}
+
+#[test]
+fn handle_global_overlay_clicks_outside_dialog_without_hook_keeps_existing_behavior() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("title", "body"),));
+
+    app.update();
+    app.update();
+
+    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
+
+    assert!(app.world().get_entity(dialog).is_err());
+    assert!(
+        app.world_mut()
+            .resource_mut::<UiEventQueue>()
+            .drain_actions::<DialogCloseTestAction>()
+            .is_empty()
+    );
+}
+
+#[test]
+fn handle_global_overlay_clicks_works_without_primary_window_marker() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window,)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+
+    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
+
+    assert!(app.world().get_entity(dropdown).is_err());
+}
+
+#[test]
+fn close_all_overlays_dismisses_every_stacked_overlay() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    app.world_mut().spawn((window, PrimaryWindow));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+    let dialog = spawn_in_overlay_root(app.world_mut(), crate::UiDialog::new("title", "body"));
+
+    app.update();
+
+    {
+        let stack = app.world().resource::<crate::OverlayStack>();
+        assert_eq!(stack.len(), 2);
+        assert!(stack.contains(dropdown));
+        assert_eq!(stack.topmost(), Some(dialog));
+    }
+
+    app.world()
+        .resource::<UiEventQueue>()
+        .push_typed(root, crate::CloseAllOverlays);
+    app.update();
+
+    let stack = app.world().resource::<crate::OverlayStack>();
+    assert!(stack.is_empty());
+    assert_eq!(stack.topmost(), None);
+    assert!(app.world().get_entity(dropdown).is_err());
+    assert!(app.world().get_entity(dialog).is_err());
+    assert!(!app.world().get::<crate::UiComboBox>(combo).unwrap().is_open);
+}
+
+#[test]
+fn toast_in_overlay_root_is_isolated_from_dropdown_overlay_stack_dismissal() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<ToastProbe>(project_toast_probe);
+
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::new(vec![
+                crate::UiComboOption::new("one", "One"),
+                crate::UiComboOption::new("two", "Two"),
+            ]),
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let dropdown = open_combo_dropdown(&mut app, combo);
+    let toast = spawn_in_overlay_root(app.world_mut(), (ToastProbe,));
+
+    app.update();
+
+    assert!(app.world().get::<crate::OverlayState>(toast).is_none());
+    {
+        let stack = app.world().resource::<crate::OverlayStack>();
+        assert_eq!(stack.active_overlays, vec![dropdown]);
+    }
+
+    let toast_center = widget_center_for_entity(&app, toast);
+    run_global_overlay_click(&mut app, window_entity, toast_center);
+
+    assert!(app.world().get_entity(dropdown).is_err());
+    assert!(app.world().get_entity(toast).is_ok());
+    assert!(
+        app.world()
+            .resource::<crate::OverlayStack>()
+            .active_overlays
+            .is_empty()
+    );
+
+    let mut routing = app
+        .world_mut()
+        .resource_mut::<crate::OverlayPointerRoutingState>();
+    assert!(!routing.take_suppressed_press(window_entity, MouseButton::Left));
+    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+}
+
+#[test]
+fn handle_global_overlay_clicks_logs_when_window_missing() {
+    init_test_tracing();
+
+    let mut world = World::new();
+    world.insert_resource(ButtonInput::<MouseButton>::default());
+
+    {
+        let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+        input.press(MouseButton::Left);
+    }
+
+    let dialog = world
+        .spawn((
+            crate::UiDialog::new("title", "body"),
+            crate::OverlayState {
+                is_modal: true,
+                anchor: None,
+            },
+        ))
+        .id();
+
+    crate::handle_global_overlay_clicks(&mut world);
+
+    assert!(world.get_entity(dialog).is_ok());
+}
+
+#[test]
+fn pointer_hits_bubble_to_parent_until_consumed() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let root = world.spawn_empty().id();
+    let parent = world
+        .spawn((ChildOf(root), crate::StopUiPointerPropagation))
+        .id();
+    let child = world.spawn((ChildOf(parent),)).id();
+
+    world.resource::<UiEventQueue>().push_typed(
+        child,
+        crate::UiPointerHitEvent {
+            target: child,
+            position: (12.0, 24.0),
+            button: MouseButton::Left,
+            phase: crate::UiPointerPhase::Pressed,
+        },
+    );
+
+    bubble_ui_pointer_events(&mut world);
+
+    let bubbled = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiPointerEvent>();
+
+    assert_eq!(bubbled.len(), 2);
+    assert_eq!(bubbled[0].entity, child);
+    assert_eq!(bubbled[0].action.current_target, child);
+    assert!(!bubbled[0].action.consumed);
+
+    assert_eq!(bubbled[1].entity, parent);
+    assert_eq!(bubbled[1].action.current_target, parent);
+    assert!(bubbled[1].action.consumed);
+}
+
+#[test]
+fn projector_registry_last_registered_component_projector_wins() {
+    #[derive(Component, Debug, Clone, Copy)]
+    struct OverrideProbe;
+
+    static LAST_PROJECTOR: AtomicUsize = AtomicUsize::new(0);
+
+    fn project_first(_: &OverrideProbe, _ctx: ProjectionCtx<'_>) -> UiView {
+        LAST_PROJECTOR.store(1, Ordering::SeqCst);
+        Arc::new(crate::xilem::view::label("first"))
+    }
+
+    fn project_second(_: &OverrideProbe, _ctx: ProjectionCtx<'_>) -> UiView {
+        LAST_PROJECTOR.store(2, Ordering::SeqCst);
+        Arc::new(crate::xilem::view::label("second"))
+    }
+
+    let mut world = World::new();
+    let entity = world.spawn((OverrideProbe,)).id();
+
+    let mut registry = UiProjectorRegistry::default();
+    registry
+        .register_component::<OverrideProbe>(project_first)
+        .register_component::<OverrideProbe>(project_second);
+
+    LAST_PROJECTOR.store(0, Ordering::SeqCst);
+    let projected = registry.project_node(&world, entity, entity.to_bits(), Vec::new());
+    assert!(projected.is_some());
+    assert_eq!(LAST_PROJECTOR.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn projector_reads_parent_ancestor_and_sibling_components_through_ctx() {
+    #[derive(Component, Debug, Clone, Copy)]
+    struct Theme(usize);
+
+    #[derive(Component, Debug, Clone, Copy)]
+    struct Density(usize);
+
+    #[derive(Component, Debug, Clone, Copy)]
+    struct Card(usize);
+
+    static PARENT_DENSITY: AtomicUsize = AtomicUsize::new(0);
+    static ANCESTOR_THEME: AtomicUsize = AtomicUsize::new(0);
+    static SIBLING_SUM: AtomicUsize = AtomicUsize::new(0);
+
+    fn project_card(_: &Card, ctx: ProjectionCtx<'_>) -> UiView {
+        if let Some(Density(density)) = ctx.parent_component::<Density>() {
+            PARENT_DENSITY.store(*density, Ordering::SeqCst);
+        }
+        if let Some(Theme(theme)) = ctx.ancestor_component::<Theme>() {
+            ANCESTOR_THEME.store(*theme, Ordering::SeqCst);
+        }
+        let siblings = ctx.sibling_components::<Card>();
+        SIBLING_SUM.store(
+            siblings.iter().map(|(_, Card(value))| value).sum(),
+            Ordering::SeqCst,
+        );
+        Arc::new(crate::xilem::view::label("card"))
+    }
+
+    let mut world = World::new();
+    let root = world.spawn((Theme(3),)).id();
+    let list = world.spawn((Density(7), ChildOf(root))).id();
+    let card = world.spawn((Card(1), ChildOf(list))).id();
+    world.spawn((Card(10), ChildOf(list)));
+    world.spawn((Card(100), ChildOf(list)));
+
+    let mut registry = UiProjectorRegistry::default();
+    registry.register_component::<Card>(project_card);
+
+    let projected = registry.project_node(&world, card, card.to_bits(), Vec::new());
+    assert!(projected.is_some());
+    assert_eq!(PARENT_DENSITY.load(Ordering::SeqCst), 7);
+    assert_eq!(ANCESTOR_THEME.load(Ordering::SeqCst), 3);
+    assert_eq!(SIBLING_SUM.load(Ordering::SeqCst), 110);
+}
+
+#[test]
+fn projector_reads_resource_and_own_props_through_ctx() {
+    #[derive(Resource)]
+    struct Accent(usize);
+
+    #[derive(Component, Debug, Clone, Copy)]
+    struct Weight(usize);
+
+    #[derive(Component, Debug, Clone, Copy)]
+    struct Tag;
+
+    static ACCENT: AtomicUsize = AtomicUsize::new(0);
+    static WEIGHT: AtomicUsize = AtomicUsize::new(0);
+
+    fn project_tag(_: &Tag, ctx: ProjectionCtx<'_>) -> UiView {
+        if let Some(Accent(accent)) = ctx.get_resource::<Accent>() {
+            ACCENT.store(*accent, Ordering::SeqCst);
+        }
+        if let Some(Weight(weight)) = ctx.props::<Weight>() {
+            WEIGHT.store(*weight, Ordering::SeqCst);
+        }
+        Arc::new(crate::xilem::view::label("tag"))
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Accent(5));
+    let tag = world.spawn((Tag, Weight(9))).id();
+
+    let mut registry = UiProjectorRegistry::default();
+    registry.register_component::<Tag>(project_tag);
+
+    let projected = registry.project_node(&world, tag, tag.to_bits(), Vec::new());
+    assert!(projected.is_some());
+    assert_eq!(ACCENT.load(Ordering::SeqCst), 5);
+    assert_eq!(WEIGHT.load(Ordering::SeqCst), 9);
+}
+
+#[test]
+fn projector_registry_lists_component_registrations_in_order() {
+    #[derive(Component, Debug, Clone, Copy)]
+    struct IntrospectProbe;
+
+    fn project_probe(_: &IntrospectProbe, _ctx: ProjectionCtx<'_>) -> UiView {
+        Arc::new(crate::xilem::view::label("probe"))
+    }
+
+    struct RawProjector;
+
+    impl crate::UiProjector for RawProjector {
+        fn project(&self, _ctx: ProjectionCtx<'_>) -> Option<UiView> {
+            None
+        }
+    }
+
+    let mut registry = UiProjectorRegistry::default();
+    assert!(registry.is_empty());
+
+    registry
+        .register_component::<IntrospectProbe>(project_probe)
+        .register_component::<TestRoot>(project_test_root)
+        .register_projector(RawProjector)
+        .register_component::<IntrospectProbe>(project_probe);
+
+    assert_eq!(registry.len(), 4);
+    let probe = std::any::type_name::<IntrospectProbe>();
+    assert_eq!(
+        registry.registered_component_types(),
+        vec![probe, std::any::type_name::<TestRoot>(), probe]
+    );
+}
+
+#[test]
+fn stylesheet_ron_parser_supports_tokens_and_var_values() {
+    let ron = r##"(
+    tokens: {
+        "demo-bg": Color(Hex("#112233")),
+        "radius": Float(6.0),
+    },
+  rules: [
+    (
+      selector: Class("demo.button"),
+      setter: (
+                                layout: (
+                                        padding: 10.0,
+                                        corner_radius: Var("radius"),
+                                        justify_content: Start,
+                                        align_items: Center,
+                                        scale: 0.97,
+                                ),
+                                text: (text_align: Center),
+                                colors: (bg: Var("demo-bg"), text: Hex("#f0f0f0")),
+      ),
+    ),
+    (
+      selector: And([Class("demo.button"), PseudoClass(Hovered)]),
+      setter: (
+                colors: (hover_bg: Hex("#112233ff")),
+      ),
+    ),
+  ],
+)"##;
+
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+    assert_eq!(sheet.rules.len(), 2);
+    assert_eq!(sheet.tokens.len(), 2);
+
+    assert!(matches!(
+        &sheet.rules[0].selector,
+        crate::Selector::Class(name) if name == "demo.button"
+    ));
+    assert!(sheet.rules[0].setter.layout.padding.is_some());
+    assert!(sheet.rules[0].setter.colors.bg.is_some());
+    assert!(sheet.rules[0].setter.layout.justify_content.is_some());
+    assert!(sheet.rules[0].setter.layout.align_items.is_some());
+    assert!(sheet.rules[0].setter.layout.scale.is_some());
+    assert!(sheet.rules[0].setter.text.text_align.is_some());
+    assert!(matches!(
+        sheet.rules[0].setter.colors.bg.as_ref(),
+        Some(crate::StyleValue::Var(token)) if token == "demo-bg"
+    ));
+    assert!(matches!(
+        sheet.rules[0].setter.colors.text.as_ref(),
+        Some(crate::StyleValue::Value(color))
+            if *color == crate::xilem::Color::from_rgb8(0xF0, 0xF0, 0xF0)
+    ));
+
+    assert!(matches!(&sheet.rules[1].selector, crate::Selector::And(parts) if !parts.is_empty()));
+    assert!(matches!(
+        sheet.rules[1].setter.colors.hover_bg.as_ref(),
+        Some(crate::StyleValue::Value(color))
+            if *color == crate::xilem::Color::from_rgba8(0x11, 0x22, 0x33, 0xFF)
+    ));
+}
+
+#[test]
+fn indexed_style_resolution_matches_naive_scan_with_fewer_rule_checks() {
+    use crate::{LayoutStyle, Selector, StyleRule, StyleSetter};
+
+    let layout = |layout: LayoutStyle| StyleSetter {
+        layout,
+        ..StyleSetter::default()
+    };
+    let mut rules = (0..96)
+        .map(|i| {
+            StyleRule::class(
+                format!("c{i}"),
+                layout(LayoutStyle {
+                    padding: Some(f64::from(i)),
+                    ..LayoutStyle::default()
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    rules.push(StyleRule::new(
+        Selector::of_type::<crate::UiButton>(),
+        layout(LayoutStyle {
+            gap: Some(3.0),
+            ..LayoutStyle::default()
+        }),
+    ));
+    rules.push(StyleRule::new(
+        Selector::pseudo(crate::PseudoClass::Hovered),
+        layout(LayoutStyle {
+            corner_radius: Some(5.0),
+            ..LayoutStyle::default()
+        }),
+    ));
+    rules.push(StyleRule::new(
+        Selector::descendant(Selector::class("panel"), Selector::class("c10")),
+        layout(LayoutStyle {
+            border_width: Some(2.0),
+            ..LayoutStyle::default()
+        }),
+    ));
+    rules.push(StyleRule::new(
+        Selector::and([
+            Selector::class("c42"),
+            Selector::pseudo(crate::PseudoClass::Hovered),
+        ]),
+        layout(LayoutStyle {
+            scale: Some(1.5),
+            ..LayoutStyle::default()
+        }),
+    ));
+    assert_eq!(rules.len(), 100);
+
+    let mut world = World::new();
+    world.insert_resource(crate::StyleSheet {
+        rules,
+        ..crate::StyleSheet::default()
+    });
+    let panel = world
+        .spawn(crate::StyleClass(vec!["panel".to_string()]))
+        .id();
+    let button = world
+        .spawn((
+            crate::UiButton::new("indexed"),
+            crate::StyleClass(vec!["c10".to_string(), "c42".to_string()]),
+            crate::InteractionState {
+                hovered: true,
+                ..crate::InteractionState::default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+
+    let resolve_all = |world: &World| {
+        (
+            crate::resolve_style(world, button),
+            crate::resolve_style_for_entity_classes(world, button, ["c10", "c42"]),
+            crate::resolve_style_for_classes(world, ["c5", "c95"]),
+        )
+    };
+    let naive = resolve_all(&world);
+
+    crate::sync_style_rule_index(&mut world);
+    let index = world.resource::<crate::StyleRuleIndex>();
+    assert_eq!(index.rule_count(), 100);
+    // Naive resolution checks all 100 rules; the index leaves the two class-less
+    // rules plus the two rules filed under each of the element's classes.
+    assert_eq!(
+        index.candidates(&["c10", "c42"]).collect::<Vec<_>>(),
+        vec![10, 42, 96, 97, 98, 99]
+    );
+    assert_eq!(
+        index.candidates(&["c5", "c95"]).collect::<Vec<_>>(),
+        vec![5, 95, 96, 97]
+    );
+    // Repeated class names merge into one pass over their bucket.
+    assert_eq!(
+        index.candidates(&["c5", "c5"]).collect::<Vec<_>>(),
+        index.candidates(&["c5"]).collect::<Vec<_>>()
+    );
+
+    let indexed = resolve_all(&world);
+    assert_eq!(indexed, naive);
+    assert_eq!(indexed.0.layout.padding, 42.0);
+    assert_eq!(indexed.0.layout.gap, 3.0);
+    assert_eq!(indexed.0.layout.corner_radius, 5.0);
+    assert_eq!(indexed.0.layout.border_width, 2.0);
+    assert_eq!(indexed.0.layout.scale, 1.5);
+
+    // A sheet edit the index has not caught up with falls back to the full scan.
+    world
+        .resource_mut::<crate::StyleSheet>()
+        .rules
+        .push(StyleRule::class(
+            "c10",
+            layout(LayoutStyle {
+                padding: Some(99.0),
+                ..LayoutStyle::default()
+            }),
+        ));
+    assert_eq!(crate::resolve_style(&world, button).layout.padding, 99.0);
+}
+
+#[test]
+fn placeholder_color_resolves_from_class_for_text_input_style() {
+    let ron = r##"(
+    rules: [
+        (
+            selector: Class("demo.input"),
+            setter: (
+                colors: (text: Hex("#F0F0F0"), placeholder: Hex("#80808099")),
+            ),
+        ),
+        (
+            selector: Class("demo.plain-input"),
+            setter: (
+                colors: (text: Hex("#F0F0F0")),
+            ),
+        ),
+    ],
+)"##;
+    let mut world = World::new();
+    world.insert_resource(crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse"));
+
+    let placeholder = crate::xilem::Color::from_rgba8(0x80, 0x80, 0x80, 0x99);
+    let resolved = crate::resolve_style_for_classes(&world, ["demo.input"]);
+    assert_eq!(resolved.colors.placeholder, Some(placeholder));
+    assert_eq!(
+        crate::styling::placeholder_color_from_style(&resolved),
+        placeholder
+    );
+
+    // Without a placeholder color, inputs keep the dimmed text color.
+    let plain = crate::resolve_style_for_classes(&world, ["demo.plain-input"]);
+    assert_eq!(plain.colors.placeholder, None);
+    assert_eq!(
+        crate::styling::placeholder_color_from_style(&plain),
+        crate::xilem::Color::from_rgb8(0xF0, 0xF0, 0xF0).with_alpha(0.72)
+    );
+}
+
+#[test]
+fn stylesheet_hex_literal_for_bg_is_not_treated_as_token_var() {
+    let ron = r##"(
+    rules: [
+        (
+            selector: Class("demo.hex"),
+            setter: (
+                colors: (bg: Hex("#FFFFFF14")),
+            ),
+        ),
+    ],
+)"##;
+
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+
+    assert!(matches!(
+        sheet.rules[0].setter.colors.bg.as_ref(),
+        Some(crate::StyleValue::Value(color))
+            if *color == crate::xilem::Color::from_rgba8(0xFF, 0xFF, 0xFF, 0x14)
+    ));
+}
+
+#[test]
+fn embedded_fluent_theme_color_fields_do_not_parse_hex_literals_as_var_tokens() {
+    let assert_not_hex_var =
+        |value: &Option<crate::StyleValue<crate::xilem::Color>>, field: &str, selector: &str| {
+            if let Some(crate::StyleValue::Var(token)) = value {
+                assert!(
+                    !token.trim().starts_with('#'),
+                    "{selector} {field} parsed as Var token `{token}` but should be a literal color"
+                );
+            }
+        };
+
+    let variants = crate::styling::parse_stylesheet_variants_ron_for_tests(
+        crate::styling::BUILTIN_FLUENT_THEME_RON,
+    )
+    .expect("embedded fluent theme bundle should parse");
+
+    for (variant_name, sheet) in &variants.variants {
+        for rule in &sheet.rules {
+            let selector = format!("{variant_name}::{:?}", rule.selector);
+            let colors = &rule.setter.colors;
+            assert_not_hex_var(&colors.bg, "bg", &selector);
+            assert_not_hex_var(&colors.text, "text", &selector);
+            assert_not_hex_var(&colors.border, "border", &selector);
+            assert_not_hex_var(&colors.hover_bg, "hover_bg", &selector);
+            assert_not_hex_var(&colors.hover_text, "hover_text", &selector);
+            assert_not_hex_var(&colors.hover_border, "hover_border", &selector);
+            assert_not_hex_var(&colors.pressed_bg, "pressed_bg", &selector);
+            assert_not_hex_var(&colors.pressed_text, "pressed_text", &selector);
+            assert_not_hex_var(&colors.pressed_border, "pressed_border", &selector);
+        }
+    }
+}
+
+#[test]
+fn stylesheet_var_missing_token_falls_back_to_transparent_or_zero() {
+    let ron = r##"(
+    rules: [
+        (
+            selector: Class("demo.button"),
+            setter: (
+                layout: (padding: Var("missing-padding")),
+                colors: (bg: Var("missing-bg")),
+            ),
+        ),
+    ],
+)"##;
+
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+
+    let entity = world
+        .spawn((crate::StyleClass(vec!["demo.button".to_string()]),))
+        .id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = crate::resolve_style(&world, entity);
+    assert_eq!(resolved.layout.padding, 0.0);
+    assert_eq!(resolved.colors.bg, Some(crate::xilem::Color::TRANSPARENT));
+}
+
+#[test]
+fn rem_and_em_lengths_scale_with_base_font_size() {
+    let ron = r##"(
+    rules: [
+        (
+            selector: Class("demo.relative"),
+            setter: (
+                layout: (padding: Rem(1.0), gap: Em(0.5), corner_radius: 4.0),
+                text: (size: Rem(1.25)),
+            ),
+        ),
+    ],
+)"##;
+
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+    world.insert_resource(crate::StyleBaseFontSize(16.0));
+
+    let entity = world
+        .spawn((crate::StyleClass(vec!["demo.relative".to_string()]),))
+        .id();
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = crate::resolve_style(&world, entity);
+    assert_eq!(resolved.layout.padding, 16.0);
+    assert_eq!(resolved.text.size, 20.0);
+    assert_eq!(resolved.layout.gap, 10.0);
+    assert_eq!(resolved.layout.corner_radius, 4.0);
+
+    world.clear_trackers();
+    world.resource_mut::<crate::StyleBaseFontSize>().0 = 20.0;
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = crate::resolve_style(&world, entity);
+    assert_eq!(resolved.layout.padding, 20.0);
+    assert_eq!(resolved.text.size, 25.0);
+    assert_eq!(resolved.layout.gap, 12.5);
+    assert_eq!(resolved.layout.corner_radius, 4.0);
+}
+
+#[test]
+fn stylesheet_box_shadow_token_parses_and_resolves() {
+    let ron = r##"(
+    tokens: {
+        "flyout-shadow": BoxShadow((
+            color: Rgba(0.0, 0.0, 0.0, 0.35),
+            offset_x: 0.0,
+            offset_y: 12.0,
+            blur: 24.0,
+        )),
+    },
+    rules: [
+        (
+            selector: Class("shadowed"),
+            setter: (
+                box_shadow: Var("flyout-shadow"),
+            ),
+        ),
+    ],
+)"##;
+
+    let sheet = crate::parse_stylesheet_ron(ron).expect("stylesheet ron should parse");
+
+    let mut world = World::new();
+    world.insert_resource(sheet);
+    let entity = world
+        .spawn((crate::StyleClass(vec!["shadowed".to_string()]),))
+        .id();
+
+    crate::mark_style_dirty(&mut world);
+    crate::sync_style_targets(&mut world);
+
+    let resolved = crate::resolve_style(&world, entity);
+    let expected = crate::xilem::style::BoxShadow::new(
+        crate::xilem::Color::from_rgba8(0, 0, 0, 89),
+        (0.0, 12.0),
+    )
+    .blur(24.0);
+
+    assert_eq!(resolved.box_shadow, Some(expected));
+}
+
+#[test]
+fn template_expansion_and_widget_actions_update_checkbox_state() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let checkbox = world
+        .spawn((crate::UiCheckbox::new("Receive updates", false),))
+        .id();
+
+    crate::expand_builtin_ui_component_templates(&mut world);
+
+    let indicator = crate::find_template_part::<crate::PartCheckboxIndicator>(&world, checkbox)
+        .expect("checkbox indicator part should be expanded");
+    let label = crate::find_template_part::<crate::PartCheckboxLabel>(&world, checkbox)
+        .expect("checkbox label part should be expanded");
+
+    assert_eq!(
+        world
+            .get::<crate::UiLabel>(indicator)
+            .expect("indicator label should exist")
+            .text,
+        "☐"
+    );
+    assert_eq!(
+        world
+            .get::<crate::UiLabel>(label)
+            .expect("label part should have text")
+            .text,
+        "Receive updates"
+    );
+
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(checkbox, crate::WidgetUiAction::ToggleCheckbox { checkbox });
+    crate::handle_widget_actions(&mut world);
+    crate::expand_builtin_ui_component_templates(&mut world);
+
+    assert!(
+        world
+            .get::<crate::UiCheckbox>(checkbox)
+            .expect("checkbox should exist")
+            .checked
+    );
+    assert_eq!(
+        world
+            .resource_mut::<UiEventQueue>()
+            .drain_actions::<crate::UiCheckboxChanged>()
+            .len(),
+        1
+    );
+    assert_eq!(
+        world
+            .get::<crate::UiLabel>(indicator)
+            .expect("indicator label should exist")
+            .text,
+        "☑"
+    );
+}
+
+#[test]
+fn widget_actions_update_radio_group_selection() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let group = world
+        .spawn((crate::UiRadioGroup::new(["Apple", "Banana", "Cherry"]),))
+        .id();
+
+    world.resource::<UiEventQueue>().push_typed(
+        group,
+        crate::WidgetUiAction::SelectRadioItem { group, index: 2 },
+    );
+
+    crate::handle_widget_actions(&mut world);
+
+    assert_eq!(
+        world
+            .get::<crate::UiRadioGroup>(group)
+            .expect("radio group should exist")
+            .selected,
+        2
+    );
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiRadioGroupChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].entity, group);
+    assert_eq!(changed[0].action.selected, 2);
+}
+
+#[test]
+fn third_party_ui_component_can_register_via_trait_api() {
+    #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+    struct UiKnob;
+
+    #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+    struct PartKnobIndicator;
+
+    impl crate::UiComponentTemplate for UiKnob {
+        fn expand(world: &mut World, entity: Entity) {
+            let _ = crate::ensure_template_part::<PartKnobIndicator, _>(world, entity, || {
+                (
+                    crate::UiLabel::new("○"),
+                    crate::StyleClass(vec!["template.knob.indicator".to_string()]),
+                )
+            });
+        }
+
+        fn project(_: &Self, _ctx: crate::ProjectionCtx<'_>) -> crate::UiView {
+            Arc::new(crate::xilem::view::label("knob"))
+        }
+    }
+
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_ui_component::<UiKnob>();
+
+    let knob = app.world_mut().spawn((UiRoot, UiKnob)).id();
+    app.update();
+
+    assert!(
+        app.world()
+            .resource::<crate::StyleTypeRegistry>()
+            .resolve("UiKnob")
+            .is_some()
+    );
+
+    assert!(crate::find_template_part::<PartKnobIndicator>(app.world(), knob).is_some());
+}
+
+#[test]
+fn scroll_view_template_expands_required_parts() {
+    let mut world = World::new();
+
+    let scroll_view = world.spawn((crate::UiScrollView::default(),)).id();
+    crate::expand_builtin_ui_component_templates(&mut world);
+
+    assert!(crate::find_template_part::<crate::PartScrollViewport>(&world, scroll_view).is_some());
+    assert!(
+        crate::find_template_part::<crate::PartScrollBarVertical>(&world, scroll_view).is_some()
+    );
+    assert!(
+        crate::find_template_part::<crate::PartScrollThumbVertical>(&world, scroll_view).is_some()
+    );
+    assert!(
+        crate::find_template_part::<crate::PartScrollBarHorizontal>(&world, scroll_view).is_some()
+    );
+    assert!(
+        crate::find_template_part::<crate::PartScrollThumbHorizontal>(&world, scroll_view)
+            .is_some()
+    );
+}
+
+#[test]
+fn drag_scroll_thumb_action_updates_scroll_view_offset() {
+    let mut world = World::new();
+    world.insert_resource(UiEventQueue::default());
+
+    let scroll_view = world
+        .spawn((crate::UiScrollView {
+            scroll_offset: bevy_math::Vec2::ZERO,
+            content_size: bevy_math::Vec2::new(400.0, 1200.0),
+            viewport_size: bevy_math::Vec2::new(300.0, 200.0),
+            show_horizontal_scrollbar: false,
+            show_vertical_scrollbar: true,
+        },))
+        .id();
+
+    crate::expand_builtin_ui_component_templates(&mut world);
+
+    let thumb = crate::find_template_part::<crate::PartScrollThumbVertical>(&world, scroll_view)
+        .expect("vertical thumb part should exist");
+
+    world.resource::<UiEventQueue>().push_typed(
+        thumb,
+        crate::WidgetUiAction::DragScrollThumb {
+            thumb,
+            axis: crate::ScrollAxis::Vertical,
+            delta_pixels: 18.0,
+        },
+    );
+
+    crate::handle_widget_actions(&mut world);
+
+    let offset = world
+        .get::<crate::UiScrollView>(scroll_view)
+        .expect("scroll view should exist")
+        .scroll_offset;
+    assert!(offset.y > 0.0);
+
+    let changed = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiScrollViewChanged>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].entity, scroll_view);
+}
+
+#[test]
+fn tooltip_hover_spawns_and_despawns_overlay_entity() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let source = app
+        .world_mut()
+        .spawn((
+            crate::UiButton::new("Hover me"),
+            crate::HasTooltip::new("Tooltip text"),
+            crate::InteractionState {
+                hovered: true,
+                pressed: false,
+            },
+            ChildOf(root),
+        ))
+        .id();
+
+    app.update();
+
+    let mut tooltip_query = app.world_mut().query::<(
+        Entity,
+        &crate::UiTooltip,
+        &crate::OverlayState,
+        &crate::OverlayConfig,
+    )>();
+    let spawned_tooltips = tooltip_query
+        .iter(app.world())
+        .filter_map(|(entity, tooltip, state, config)| {
+            (tooltip.anchor == source
+                && state.anchor == Some(source)
+                && config.anchor == Some(source)
+                && config.placement == crate::OverlayPlacement::Top)
+                .then_some(entity)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(spawned_tooltips.len(), 1);
 
-    assert!(world.get::<crate::AutoDismiss>(custom_toast).is_none());
+    app.world_mut()
+        .entity_mut(source)
+        .insert(crate::InteractionState {
+            hovered: false,
+            pressed: false,
+        });
+    app.update();
 
-    assert!(world.get::<crate::AutoDismiss>(persistent_toast).is_none());
+    let mut tooltip_query = app.world_mut().query::<&crate::UiTooltip>();
+    assert!(
+        tooltip_query
+            .iter(app.world())
+            .all(|tooltip| tooltip.anchor != source)
+    );
 }
 
 #[test]
-fn sync_overlay_positions_uses_dynamic_primary_window_size() {
+fn scroll_view_geometry_sync_clamps_out_of_bounds_offset() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(1024.0, 768.0);
+    window.resolution.set(900.0, 640.0);
     app.world_mut().spawn((window, PrimaryWindow));
 
-    let dialog = app
+    let root = app
         .world_mut()
-        .spawn((crate::UiDialog::new("title", "body"),))
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let scroll_view = app
+        .world_mut()
+        .spawn((
+            crate::UiScrollView {
+                scroll_offset: bevy_math::Vec2::new(4_000.0, 4_000.0),
+                content_size: bevy_math::Vec2::new(320.0, 10_000.0),
+                viewport_size: bevy_math::Vec2::new(320.0, 220.0),
+                show_horizontal_scrollbar: false,
+                show_vertical_scrollbar: true,
+            },
+            ChildOf(root),
+        ))
         .id();
 
-    app.update();
-
-    let initial = *app
-        .world()
-        .get::<crate::OverlayComputedPosition>(dialog)
-        .expect("dialog should have computed position");
-    assert!(initial.is_positioned);
-
-    {
-        let world = app.world_mut();
-        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
-        let mut primary_window = query
-            .single_mut(world)
-            .expect("primary window should exist");
-        primary_window.resolution.set(1600.0, 900.0);
-    }
+    app.world_mut().spawn((
+        crate::UiLabel::new("Only one line of content"),
+        ChildOf(scroll_view),
+    ));
 
+    app.update();
     app.update();
 
-    let resized = *app
+    let scroll = app
         .world()
-        .get::<crate::OverlayComputedPosition>(dialog)
-        .expect("dialog should still have computed position");
+        .get::<crate::UiScrollView>(scroll_view)
+        .expect("scroll view should exist");
 
-    assert!(resized.x > initial.x);
-    assert_eq!(initial.width, resized.width);
-    assert_eq!(initial.height, resized.height);
-    assert!(resized.is_positioned);
-    assert!(resized.x + resized.width <= 1600.0 + f64::EPSILON);
-    assert!(resized.y + resized.height <= 900.0 + f64::EPSILON);
+    let max_y = (scroll.content_size.y - scroll.viewport_size.y).max(0.0);
+    assert!(scroll.scroll_offset.x.abs() <= f32::EPSILON);
+    assert!(scroll.content_size.y < 2_000.0);
+    assert!(scroll.scroll_offset.y <= max_y + f32::EPSILON);
 }
 
 #[test]
-fn sync_overlay_positions_works_without_primary_window_marker() {
+fn scroll_view_geometry_sync_expands_viewport_width_to_parent_width() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(1280.0, 720.0);
-    app.world_mut().spawn((window,));
+    window.resolution.set(900.0, 640.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let dialog = app
+    let root = app
         .world_mut()
-        .spawn((crate::UiDialog::new("title", "body"),))
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let scroll_view = app
+        .world_mut()
+        .spawn((
+            crate::UiScrollView {
+                scroll_offset: bevy_math::Vec2::ZERO,
+                content_size: bevy_math::Vec2::new(200.0, 1_200.0),
+                viewport_size: bevy_math::Vec2::new(200.0, 220.0),
+                show_horizontal_scrollbar: false,
+                show_vertical_scrollbar: true,
+            },
+            ChildOf(root),
+        ))
         .id();
 
+    app.world_mut().spawn((
+        crate::UiLabel::new("A short row that should not dictate viewport width"),
+        ChildOf(scroll_view),
+    ));
+
+    app.update();
     app.update();
 
-    let computed = *app
+    let scroll = app
         .world()
-        .get::<crate::OverlayComputedPosition>(dialog)
-        .expect("dialog should have computed position without PrimaryWindow marker");
-
-    assert!(computed.width > 1.0);
-    assert!(computed.height > 1.0);
-    assert!(computed.x > 0.0);
-    assert!(computed.y > 0.0);
-    assert!(computed.is_positioned);
-}
-
-fn send_primary_click(app: &mut App, window_entity: Entity, position: Vec2) {
-    {
-        let world = app.world_mut();
-        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
-        let mut primary_window = query
-            .single_mut(world)
-            .expect("primary window should exist");
-        primary_window.set_cursor_position(Some(position));
-    }
-
-    app.world_mut().write_message(MouseButtonInput {
-        button: MouseButton::Left,
-        state: ButtonState::Pressed,
-        window: window_entity,
-    });
-    app.world_mut().write_message(MouseButtonInput {
-        button: MouseButton::Left,
-        state: ButtonState::Released,
-        window: window_entity,
-    });
-
-    app.update();
-}
+        .get::<crate::UiScrollView>(scroll_view)
+        .expect("scroll view should exist");
 
-fn set_window_cursor_position(app: &mut App, window_entity: Entity, position: Vec2) {
-    let world = app.world_mut();
-    let mut window = world
-        .get_mut::<Window>(window_entity)
-        .expect("window should exist");
-    window.set_cursor_position(Some(position));
+    assert!(
+        scroll.viewport_size.x > 400.0,
+        "viewport width should stretch beyond the initial seed width, got {}",
+        scroll.viewport_size.x
+    );
+    assert_eq!(scroll.viewport_size.y, 220.0);
 }
 
-fn run_global_overlay_click(app: &mut App, window_entity: Entity, position: Vec2) {
-    set_window_cursor_position(app, window_entity, position);
-
-    if !app.world().contains_resource::<ButtonInput<MouseButton>>() {
-        app.world_mut()
-            .insert_resource(ButtonInput::<MouseButton>::default());
-    }
-
-    {
-        let mut input = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
-        input.release(MouseButton::Left);
-        input.clear();
-        input.press(MouseButton::Left);
-    }
-
-    app.update();
-
-    let mut input = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
-    input.release(MouseButton::Left);
-    input.clear();
-}
+#[test]
+fn scroll_view_left_aligns_narrow_content_after_viewport_stretch() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-fn hit_path_for_position(app: &mut App, window_entity: Entity, position: Vec2) -> Vec<WidgetId> {
-    set_window_cursor_position(app, window_entity, position);
+    let mut window = Window::default();
+    window.resolution.set(900.0, 640.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let mut runtime = app
+    let root = app
         .world_mut()
-        .non_send_resource_mut::<crate::MasonryRuntime>();
-    let _ = runtime.render_root.redraw();
-    runtime.get_hit_path((position.x as f64, position.y as f64).into())
-}
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let scroll_view = app
+        .world_mut()
+        .spawn((
+            crate::UiScrollView {
+                scroll_offset: bevy_math::Vec2::ZERO,
+                content_size: bevy_math::Vec2::new(200.0, 600.0),
+                viewport_size: bevy_math::Vec2::new(200.0, 220.0),
+                show_horizontal_scrollbar: false,
+                show_vertical_scrollbar: true,
+            },
+            ChildOf(root),
+        ))
+        .id();
 
-fn find_widget_id_by_debug_text(
-    widget: WidgetRef<'_, dyn Widget>,
-    expected_debug_text: &str,
-) -> Option<WidgetId> {
-    for child in widget.children() {
-        if let Some(id) = find_widget_id_by_debug_text(child, expected_debug_text) {
-            return Some(id);
-        }
-    }
+    app.world_mut().spawn((
+        crate::UiLabel::new("Left aligned scroll content"),
+        ChildOf(scroll_view),
+    ));
 
-    (widget.get_debug_text().as_deref() == Some(expected_debug_text)).then_some(widget.id())
-}
+    app.update();
+    app.update();
 
-fn widget_center_for_widget_id(app: &App, widget_id: WidgetId) -> Vec2 {
     let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-    let widget = runtime
-        .render_root
-        .get_widget(widget_id)
-        .expect("widget id should resolve in render tree");
-
-    let ctx = widget.ctx();
-    let origin = ctx.window_origin();
-    let size = ctx.border_box_size();
-    Vec2::new(
-        (origin.x + size.width * 0.5) as f32,
-        (origin.y + size.height * 0.5) as f32,
+    let scroll_widget_id = runtime
+        .find_widget_id_for_entity_bits(scroll_view.to_bits(), true)
+        .or_else(|| runtime.find_widget_id_for_entity_bits(scroll_view.to_bits(), false))
+        .expect("scroll view should resolve to a Masonry widget");
+    let label_widget_id = find_widget_id_by_debug_text(
+        runtime.render_root.get_layer_root(0),
+        "Left aligned scroll content",
     )
-}
+    .expect("label widget should exist in render tree");
 
-fn widget_inset_point_for_widget_id(app: &App, widget_id: WidgetId, inset: f64) -> Vec2 {
-    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-    let widget = runtime
+    let scroll_widget = runtime
         .render_root
-        .get_widget(widget_id)
-        .expect("widget id should resolve in render tree");
+        .get_widget(scroll_widget_id)
+        .expect("scroll widget id should resolve");
+    let label_widget = runtime
+        .render_root
+        .get_widget(label_widget_id)
+        .expect("label widget id should resolve");
 
-    let ctx = widget.ctx();
-    let origin = ctx.window_origin();
-    Vec2::new((origin.x + inset) as f32, (origin.y + inset) as f32)
-}
+    let scroll_x = scroll_widget.ctx().window_origin().x;
+    let label_x = label_widget.ctx().window_origin().x;
 
-fn widget_center_for_entity(app: &App, entity: Entity) -> Vec2 {
-    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-    let widget_id = runtime
-        .find_widget_id_for_entity_bits(entity.to_bits(), true)
-        .or_else(|| runtime.find_widget_id_for_entity_bits(entity.to_bits(), false))
-        .expect("entity should resolve to a Masonry widget");
-    widget_center_for_widget_id(app, widget_id)
+    assert!(
+        (label_x - scroll_x).abs() <= 4.0,
+        "scroll content should start at the viewport left edge, got scroll_x={scroll_x}, label_x={label_x}"
+    );
 }
 
-fn open_combo_dropdown(app: &mut App, combo: Entity) -> Entity {
-    app.world()
-        .resource::<UiEventQueue>()
-        .push_typed(combo, crate::OverlayUiAction::ToggleCombo);
+#[test]
+fn determinate_spinner_at_quarter_progress_draws_quarter_arc() {
+    let spinner = crate::UiSpinner::determinate(0.25)
+        .with_size(32.0)
+        .with_thickness(4.0);
+
+    assert!(spinner.is_determinate());
+    assert!((spinner.arc_sweep() - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert_eq!(crate::UiSpinner::determinate(4.0).progress, Some(1.0));
+
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    let entity = app.world_mut().spawn((UiRoot, spinner)).id();
 
+    app.update();
     app.update();
 
-    let mut query = app.world_mut().query::<(Entity, &crate::AnchoredTo)>();
-    query
-        .iter(app.world())
-        .find_map(|(entity, anchored_to)| {
-            app.world()
-                .get::<crate::UiDropdownMenu>(entity)
-                .is_some_and(|_| anchored_to.0 == combo)
-                .then_some(entity)
-        })
-        .expect("combo toggle should create dropdown")
+    assert!(
+        app.world()
+            .get::<crate::UiSpinnerRotation>(entity)
+            .is_none()
+    );
+    assert_eq!(
+        app.world()
+            .resource::<crate::UiSynthesisStats>()
+            .unhandled_count,
+        0
+    );
 }
 
-fn assert_overlay_defaults_for_entity(
-    world: &World,
-    entity: Entity,
-    label: &str,
-    expected_config: crate::OverlayConfig,
-    expected_state: crate::OverlayState,
-    expect_anchor_rect: bool,
-) {
-    let config = world
-        .get::<crate::OverlayConfig>(entity)
-        .unwrap_or_else(|| panic!("{label} should receive overlay config"));
-    assert_eq!(*config, expected_config);
-
-    let state = world
-        .get::<crate::OverlayState>(entity)
-        .unwrap_or_else(|| panic!("{label} should receive overlay state"));
-    assert_eq!(*state, expected_state);
+#[test]
+fn indeterminate_spinner_rotation_advances_with_speed() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(bevy_time::TimeUpdateStrategy::ManualDuration(
+        Duration::from_millis(100),
+    ));
 
-    let position = world
-        .get::<crate::OverlayComputedPosition>(entity)
-        .unwrap_or_else(|| panic!("{label} should receive computed position"));
-    assert_eq!(*position, crate::OverlayComputedPosition::default());
+    let entity = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiSpinner::new().with_speed(0.5)))
+        .id();
 
-    if expect_anchor_rect {
-        let anchor_rect = world
-            .get::<crate::OverlayAnchorRect>(entity)
-            .unwrap_or_else(|| panic!("{label} should receive overlay anchor rect"));
-        assert_eq!(*anchor_rect, crate::OverlayAnchorRect::default());
-    } else {
-        assert!(
-            world.get::<crate::OverlayAnchorRect>(entity).is_none(),
-            "{label} should not receive overlay anchor rect"
-        );
-    }
-}
+    app.update();
+    let initial = app
+        .world()
+        .get::<crate::UiSpinnerRotation>(entity)
+        .copied()
+        .expect("indeterminate spinner should receive a rotation component");
 
-fn collect_widget_bounds_by_short_name(
-    widget: WidgetRef<'_, dyn Widget>,
-    short_type_name: &str,
-    bounds: &mut Vec<Rect>,
-) {
-    for child in widget.children() {
-        collect_widget_bounds_by_short_name(child, short_type_name, bounds);
-    }
+    app.update();
+    app.update();
 
-    if widget.short_type_name() == short_type_name {
-        let ctx = widget.ctx();
-        let origin = ctx.window_origin();
-        let size = ctx.border_box_size();
-        bounds.push(Rect::from_corners(
-            Vec2::new(origin.x as f32, origin.y as f32),
-            Vec2::new(
-                (origin.x + size.width) as f32,
-                (origin.y + size.height) as f32,
-            ),
-        ));
-    }
+    let advanced = app
+        .world()
+        .get::<crate::UiSpinnerRotation>(entity)
+        .copied()
+        .expect("rotation should persist");
+    assert!(
+        advanced.angle > initial.angle,
+        "rotation should advance, got {} -> {}",
+        initial.angle,
+        advanced.angle
+    );
+    // Two frames of 100ms at half a turn per second; the tween may start a frame late.
+    let per_frame = 0.5 * std::f32::consts::TAU * 0.1;
+    let expected = 2.0 * per_frame;
+    assert!((advanced.angle - initial.angle - expected).abs() <= per_frame + 1e-3);
 }
 
 #[test]
-fn dialog_body_click_does_not_dismiss_overlay() {
+fn spinner_rotation_tween_loops_and_is_despawned_with_spinner() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(bevy_time::TimeUpdateStrategy::ManualDuration(
+        Duration::from_millis(100),
+    ));
 
-    let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    window.set_cursor_position(Some(Vec2::new(0.0, 0.0)));
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    let entity = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiSpinner::new().with_speed(1.0)))
+        .id();
+    let count_tweens = |app: &mut App| {
+        let mut query = app.world_mut().query_filtered::<Entity, With<
+            bevy_tween::tween::ComponentTween<crate::UiSpinnerRotationLens>,
+        >>();
+        query.iter(app.world()).count()
+    };
 
-    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+    // Run past one full turn: a one-shot tween would have stopped at its end.
+    let mut angles = Vec::new();
+    for _ in 0..15 {
+        app.update();
+        if let Some(rotation) = app.world().get::<crate::UiSpinnerRotation>(entity) {
+            angles.push(rotation.angle);
+        }
+    }
+    assert_eq!(count_tweens(&mut app), 1);
+    assert!(
+        angles
+            .iter()
+            .all(|angle| (0.0..std::f32::consts::TAU).contains(angle))
+    );
+    assert!(
+        angles.windows(2).any(|pair| pair[1] < pair[0]),
+        "rotation should wrap past a full turn, got {angles:?}"
+    );
+    let last = *angles.last().unwrap();
+    app.update();
+    assert_ne!(
+        app.world()
+            .get::<crate::UiSpinnerRotation>(entity)
+            .unwrap()
+            .angle,
+        last,
+        "rotation should keep advancing after the first turn"
+    );
 
+    app.world_mut().entity_mut(entity).despawn();
     app.update();
     app.update();
+    assert_eq!(count_tweens(&mut app), 0);
+}
 
-    let computed = app
-        .world()
-        .get::<crate::OverlayComputedPosition>(dialog)
-        .expect("dialog should have computed position");
+#[test]
+fn spinner_with_unrepresentable_speed_stands_still_without_tween() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    let click_position = Vec2::new(
-        (computed.x + computed.width * 0.5) as f32,
-        (computed.y + 24.0) as f32,
-    );
+    assert_eq!(crate::UiSpinner::new().with_speed(f32::NAN).speed, 0.0);
+    assert_eq!(crate::UiSpinner::new().with_speed(f32::INFINITY).speed, 0.0);
 
-    send_primary_click(&mut app, window_entity, click_position);
+    let spinners = [f32::NAN, f32::INFINITY, 1.0e-40, -1.0e-40].map(|speed| {
+        app.world_mut()
+            .spawn((
+                UiRoot,
+                crate::UiSpinner {
+                    speed,
+                    ..crate::UiSpinner::new()
+                },
+            ))
+            .id()
+    });
 
-    assert!(app.world().get_entity(dialog).is_ok());
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let mut query = app.world_mut().query_filtered::<Entity, With<
+        bevy_tween::tween::ComponentTween<crate::UiSpinnerRotationLens>,
+    >>();
+    assert_eq!(query.iter(app.world()).count(), 0);
+    for spinner in spinners {
+        assert_eq!(
+            app.world().get::<crate::UiSpinnerRotation>(spinner),
+            Some(&crate::UiSpinnerRotation::default())
+        );
+    }
 }
 
 #[test]
-fn dialog_padding_click_is_in_overlay_hit_path_and_does_not_dismiss() {
+fn animated_group_box_expand_grows_height_toward_full_height() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
-    let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    window.set_cursor_position(Some(Vec2::new(0.0, 0.0)));
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(bevy_time::TimeUpdateStrategy::ManualDuration(
+        Duration::from_millis(50),
+    ));
 
-    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+    let group_box = app
+        .world_mut()
+        .spawn((
+            UiRoot,
+            crate::UiGroupBox::new("Settings").collapsed(),
+            crate::AnimatedCollapse { duration: 0.3 },
+            crate::UiCollapseHeight {
+                full: 120.0,
+                ..crate::UiCollapseHeight::default()
+            },
+        ))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("body"), ChildOf(group_box)));
 
     app.update();
-
-    let opaque_debug = format!("opaque_hitbox_entity={}", dialog.to_bits());
-    let opaque_widget_id = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        find_widget_id_by_debug_text(root, &opaque_debug)
-            .expect("dialog should project an entity-tagged OpaqueHitboxWidget")
+    let height = |app: &App| {
+        *app.world()
+            .get::<crate::UiCollapseHeight>(group_box)
+            .expect("animated group box should track its height")
     };
+    assert_eq!(height(&app).current, 0.0);
 
-    // Deliberately target a stable inset point inside the opaque panel surface.
-    let click_position = widget_inset_point_for_widget_id(&app, opaque_widget_id, 14.0);
-    let hit_path = hit_path_for_position(&mut app, window_entity, click_position);
-    assert!(hit_path.contains(&opaque_widget_id));
+    app.world_mut()
+        .get_mut::<crate::UiGroupBox>(group_box)
+        .expect("group box should exist")
+        .collapsed = false;
+    app.update();
+    assert_eq!(height(&app).target, 120.0);
 
-    run_global_overlay_click(&mut app, window_entity, click_position);
+    let mut previous = height(&app).current;
+    for _ in 0..3 {
+        app.update();
+        let current = height(&app);
+        assert!(current.is_animating());
+        assert!(
+            current.current > previous && current.current < 120.0,
+            "height should grow toward 120, got {previous} -> {}",
+            current.current
+        );
+        previous = current.current;
+    }
 
-    assert!(app.world().get_entity(dialog).is_ok());
+    for _ in 0..8 {
+        app.update();
+    }
+    assert!(!height(&app).is_animating());
 }
 
 #[test]
-fn dialog_dismiss_button_targets_dialog_entity() {
+fn collapsible_group_box_skips_body_projection_when_collapsed() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
-    let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    window.set_cursor_position(Some(Vec2::new(0.0, 0.0)));
-    app.world_mut().spawn((window, PrimaryWindow));
+    let group_box = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiGroupBox::new("Settings").collapsible()))
+        .id();
+    app.world_mut().spawn((
+        crate::UiButton::new("Help"),
+        crate::PartGroupBoxTitleAction,
+        ChildOf(group_box),
+    ));
+    app.world_mut()
+        .spawn((crate::UiLabel::new("Group body"), ChildOf(group_box)));
 
-    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+    app.update();
+
+    {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let root = runtime.render_root.get_layer_root(0);
+        assert!(find_widget_id_by_debug_text(root, "Group body").is_some());
+    }
 
+    app.world().resource::<UiEventQueue>().push_typed(
+        group_box,
+        crate::WidgetUiAction::ToggleGroupBox { group_box },
+    );
     app.update();
 
-    let computed = app
+    let group = app
         .world()
-        .get::<crate::OverlayComputedPosition>(dialog)
-        .expect("dialog should have computed position");
-    let content_rect = Rect::from_corners(
-        Vec2::new(computed.x as f32, computed.y as f32),
-        Vec2::new(
-            (computed.x + computed.width) as f32,
-            (computed.y + computed.height) as f32,
-        ),
-    );
+        .get::<crate::UiGroupBox>(group_box)
+        .expect("group box should exist");
+    assert!(group.collapsed);
+    assert!(!group.is_body_visible());
 
-    let button_rect = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        let mut button_rects = Vec::new();
-        collect_widget_bounds_by_short_name(root, "EcsButtonWithChildWidget", &mut button_rects);
+    let toggled = app
+        .world_mut()
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiGroupBoxToggled>();
+    assert_eq!(toggled.len(), 1);
+    assert!(toggled[0].action.collapsed);
 
-        button_rects
-            .into_iter()
-            .filter(|rect| {
-                let width = rect.max.x - rect.min.x;
-                let height = rect.max.y - rect.min.y;
-                width < (content_rect.max.x - content_rect.min.x)
-                    && height < (content_rect.max.y - content_rect.min.y)
-            })
-            .min_by(|a, b| {
-                let area_a = (a.max.x - a.min.x) * (a.max.y - a.min.y);
-                let area_b = (b.max.x - b.min.x) * (b.max.y - b.min.y);
-                area_a.total_cmp(&area_b)
-            })
-            .expect("dialog should project a dedicated dismiss button")
-    };
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let root = runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(root, "Group body").is_none());
+    assert!(
+        runtime
+            .find_widget_id_for_entity_bits(group_box.to_bits(), false)
+            .is_some()
+    );
+}
 
-    let click_position = Vec2::new(
-        (button_rect.min.x + button_rect.max.x) * 0.5,
-        (button_rect.min.y + button_rect.max.y) * 0.5,
+#[test]
+fn sticky_header_offset_pins_within_section_and_yields_to_next_header() {
+    // Section spans 100..300 with a 24px header.
+    assert_eq!(
+        crate::sticky_header_offset(0.0, 100.0, 24.0, Some(300.0)),
+        0.0
+    );
+    assert_eq!(
+        crate::sticky_header_offset(100.0, 100.0, 24.0, Some(300.0)),
+        0.0
+    );
+    assert_eq!(
+        crate::sticky_header_offset(180.0, 100.0, 24.0, Some(300.0)),
+        80.0
+    );
+    assert_eq!(
+        crate::sticky_header_offset(290.0, 100.0, 24.0, Some(300.0)),
+        176.0
     );
+    assert_eq!(crate::sticky_header_offset(900.0, 100.0, 24.0, None), 800.0);
+}
+
+#[test]
+fn sticky_header_stays_at_viewport_top_while_its_section_scrolls() {
+    let mut world = World::new();
+
+    let scroll_view = world
+        .spawn(crate::UiScrollView::new(
+            Vec2::new(320.0, 200.0),
+            Vec2::new(320.0, 1200.0),
+        ))
+        .id();
+    let first_header = world
+        .spawn((
+            crate::StickyHeader,
+            crate::StickyHeaderLayout {
+                section_top: 0.0,
+                header_height: 30.0,
+                pinned_offset: 0.0,
+            },
+            ChildOf(scroll_view),
+        ))
+        .id();
+    world.spawn((crate::UiLabel::new("row"), ChildOf(scroll_view)));
+    let second_header = world
+        .spawn((
+            crate::StickyHeader,
+            crate::StickyHeaderLayout {
+                section_top: 400.0,
+                header_height: 30.0,
+                pinned_offset: 0.0,
+            },
+            ChildOf(scroll_view),
+        ))
+        .id();
 
-    let (hit_widget, hit_debug_text) = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        root.find_widget_under_pointer((click_position.x as f64, click_position.y as f64).into())
-            .map(|widget| {
-                (
-                    widget.short_type_name().to_string(),
-                    widget.get_debug_text().unwrap_or_default(),
-                )
-            })
-            .unwrap_or_default()
-    };
+    let mut schedule = Schedule::default();
+    schedule.add_systems(crate::update_sticky_header_offsets);
 
-    assert_eq!(hit_widget.as_str(), "EcsButtonWithChildWidget");
-    assert_eq!(hit_debug_text, format!("entity={}", dialog.to_bits()));
+    for scroll_y in [0.0_f32, 120.0, 300.0, 369.0] {
+        world
+            .get_mut::<crate::UiScrollView>(scroll_view)
+            .expect("scroll view should exist")
+            .scroll_offset
+            .y = scroll_y;
+        schedule.run(&mut world);
+
+        let layout = world
+            .get::<crate::StickyHeaderLayout>(first_header)
+            .copied()
+            .expect("header layout should exist");
+        assert_eq!(
+            layout.section_top + layout.pinned_offset,
+            scroll_y,
+            "first header should sit at the viewport top at scroll_y={scroll_y}"
+        );
+        assert_eq!(
+            world
+                .get::<crate::StickyHeaderLayout>(second_header)
+                .map(|layout| layout.pinned_offset),
+            Some(0.0)
+        );
+    }
 
-    let content_width = content_rect.max.x - content_rect.min.x;
-    let content_height = content_rect.max.y - content_rect.min.y;
-    let button_top = button_rect.min.y;
-    let button_right = button_rect.max.x;
+    world
+        .get_mut::<crate::UiScrollView>(scroll_view)
+        .expect("scroll view should exist")
+        .scroll_offset
+        .y = 390.0;
+    schedule.run(&mut world);
 
-    assert!(
-        button_right > content_width * 0.82,
-        "dismiss button should align against the right side of the dialog header"
-    );
-    assert!(
-        button_top < content_height * 0.22,
-        "dismiss button should sit in the top portion of the dialog header"
+    let first = world
+        .get::<crate::StickyHeaderLayout>(first_header)
+        .copied()
+        .expect("header layout should exist");
+    assert_eq!(
+        first.pinned_offset, 370.0,
+        "next header pushes the first one up"
     );
 }
 
 #[test]
-fn dialog_projects_single_dismiss_button_without_fullscreen_backdrop_button() {
+fn virtual_grid_column_count_shrinks_with_viewport_width() {
+    let grid = crate::UiVirtualGrid::new(40)
+        .with_min_item_width(200.0)
+        .with_gap(10.0);
+    assert_eq!(grid.column_count(1280.0), 6);
+    assert_eq!(grid.column_count(500.0), 2);
+    assert_eq!(grid.column_count(50.0), 1);
+    assert_eq!(grid.row_count(6), 7);
+
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    app.world_mut().spawn((window, PrimaryWindow));
+    window.resolution.set(1280.0, 720.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
 
-    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("t", "b"),));
+    let grid_entity = app.world_mut().spawn((UiRoot, grid)).id();
+    for index in 0..40 {
+        app.world_mut().spawn((
+            crate::UiLabel::new(format!("cell {index}")),
+            ChildOf(grid_entity),
+        ));
+    }
 
+    app.update();
+    app.update();
     app.update();
 
-    let computed = app
+    let wide_columns = app
         .world()
-        .get::<crate::OverlayComputedPosition>(dialog)
-        .expect("dialog should have computed position");
-    let content_rect = Rect::from_corners(
-        Vec2::new(computed.x as f32, computed.y as f32),
-        Vec2::new(
-            (computed.x + computed.width) as f32,
-            (computed.y + computed.height) as f32,
-        ),
-    );
+        .get::<crate::UiVirtualGridLayout>(grid_entity)
+        .expect("grid layout should be synchronized")
+        .columns;
 
-    let button_rects = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        let mut button_rects = Vec::new();
-        collect_widget_bounds_by_short_name(root, "EcsButtonWithChildWidget", &mut button_rects);
-        button_rects
-    };
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut primary_window = query
+            .single_mut(world)
+            .expect("primary window should exist");
+        primary_window.resolution.set(500.0, 720.0);
+    }
+    app.world_mut().write_message(WindowResized {
+        window: window_entity,
+        width: 500.0,
+        height: 720.0,
+    });
 
-    assert_eq!(
-        button_rects.len(),
-        1,
-        "dialog projector should only emit the dismiss button, not a structural backdrop button"
+    app.update();
+    app.update();
+    app.update();
+
+    let narrow_columns = app
+        .world()
+        .get::<crate::UiVirtualGridLayout>(grid_entity)
+        .expect("grid layout should be synchronized")
+        .columns;
+
+    assert!(
+        narrow_columns < wide_columns,
+        "narrowing the viewport should reduce columns, got {wide_columns} -> {narrow_columns}"
     );
+}
 
-    let only_button = button_rects[0];
-    let button_area = (only_button.max.x - only_button.min.x).max(0.0)
-        * (only_button.max.y - only_button.min.y).max(0.0);
-    let content_area = (content_rect.max.x - content_rect.min.x).max(0.0)
-        * (content_rect.max.y - content_rect.min.y).max(0.0);
+fn spawn_selection_list(world: &mut World, selection: crate::Selection) -> (Entity, Vec<Entity>) {
+    world.insert_resource(UiEventQueue::default());
+    let list = world.spawn(selection).id();
+    let items = (0..5)
+        .map(|index| {
+            world
+                .spawn((crate::UiLabel::new(format!("item {index}")), ChildOf(list)))
+                .id()
+        })
+        .collect::<Vec<_>>();
+    (list, items)
+}
 
-    assert!(button_area < content_area * 0.8);
+fn click_selection_item(
+    world: &mut World,
+    list: Entity,
+    item: Entity,
+    modifiers: crate::SelectionModifiers,
+) -> Vec<Entity> {
+    world.resource::<UiEventQueue>().push_typed(
+        list,
+        crate::WidgetUiAction::SelectItem {
+            list,
+            item,
+            modifiers,
+        },
+    );
+    crate::handle_widget_actions(world);
+
+    world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::SelectionChanged>()
+        .pop()
+        .map(|event| event.action.selected)
+        .unwrap_or_default()
 }
 
 #[test]
-fn overlay_action_dismiss_dialog_despawns_dialog() {
+fn selection_single_click_replaces_previous_item() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+    let (list, items) = spawn_selection_list(&mut world, crate::Selection::multi());
 
-    let dialog = world.spawn((crate::UiDialog::new("title", "body"),)).id();
+    let selected =
+        click_selection_item(&mut world, list, items[1], crate::SelectionModifiers::NONE);
+    assert_eq!(selected, vec![items[1]]);
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(dialog, crate::OverlayUiAction::DismissDialog);
+    let selected =
+        click_selection_item(&mut world, list, items[3], crate::SelectionModifiers::NONE);
+    assert_eq!(selected, vec![items[3]]);
+    assert!(world.get::<crate::Selected>(items[1]).is_none());
+    assert!(world.get::<crate::Selected>(items[3]).is_some());
+}
 
-    handle_overlay_actions(&mut world);
+#[test]
+fn selection_ctrl_click_toggles_items() {
+    let mut world = World::new();
+    let (list, items) = spawn_selection_list(&mut world, crate::Selection::multi());
 
-    assert!(world.get_entity(dialog).is_err());
+    click_selection_item(&mut world, list, items[0], crate::SelectionModifiers::NONE);
+    let selected =
+        click_selection_item(&mut world, list, items[2], crate::SelectionModifiers::CTRL);
+    assert_eq!(selected, vec![items[0], items[2]]);
+
+    let selected =
+        click_selection_item(&mut world, list, items[0], crate::SelectionModifiers::CTRL);
+    assert_eq!(selected, vec![items[2]]);
+    assert!(world.get::<crate::Selected>(items[0]).is_none());
 }
 
 #[test]
-fn overlay_action_dismiss_dialog_emits_optional_close_hook_before_despawn() {
+fn selection_shift_click_extends_range_in_child_order() {
     let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+    let (list, items) = spawn_selection_list(&mut world, crate::Selection::multi());
 
-    let target = world.spawn_empty().id();
-    let dialog = world
-        .spawn((
-            crate::UiDialog::new("title", "body"),
-            crate::UiDialogCloseAction::new(target, DialogCloseTestAction::Closed),
-        ))
-        .id();
+    click_selection_item(&mut world, list, items[3], crate::SelectionModifiers::NONE);
+    let selected =
+        click_selection_item(&mut world, list, items[1], crate::SelectionModifiers::SHIFT);
+    assert_eq!(selected, vec![items[1], items[2], items[3]]);
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(dialog, crate::OverlayUiAction::DismissDialog);
+    // The anchor stays put, so a second Shift-click re-extends from item 3.
+    let selected =
+        click_selection_item(&mut world, list, items[4], crate::SelectionModifiers::SHIFT);
+    assert_eq!(selected, vec![items[3], items[4]]);
+}
 
-    handle_overlay_actions(&mut world);
+#[test]
+fn selection_single_mode_ignores_modifiers() {
+    let mut world = World::new();
+    let (list, items) = spawn_selection_list(&mut world, crate::Selection::single());
 
-    assert!(world.get_entity(dialog).is_err());
+    click_selection_item(&mut world, list, items[0], crate::SelectionModifiers::NONE);
+    let selected =
+        click_selection_item(&mut world, list, items[2], crate::SelectionModifiers::CTRL);
+    assert_eq!(selected, vec![items[2]]);
+}
 
-    let events = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<DialogCloseTestAction>();
-    assert_eq!(events.len(), 1);
-    assert_eq!(events[0].entity, target);
-    assert_eq!(events[0].action, DialogCloseTestAction::Closed);
+#[test]
+fn selected_pseudo_class_applies_to_selected_items() {
+    let mut world = World::new();
+    let mut sheet = StyleSheet::default();
+    sheet.add_rule(StyleRule::new(
+        Selector::and(vec![
+            Selector::class("list.item"),
+            Selector::pseudo(crate::PseudoClass::Selected),
+        ]),
+        StyleSetter {
+            colors: ColorStyle {
+                bg: Some(crate::xilem::Color::from_rgb8(0x00, 0x78, 0xD4)),
+                ..ColorStyle::default()
+            },
+            ..StyleSetter::default()
+        },
+    ));
+    world.insert_resource(sheet);
+
+    let item = world
+        .spawn(crate::StyleClass(vec!["list.item".to_string()]))
+        .id();
+    assert_eq!(resolve_style(&world, item).colors.bg, None);
+
+    world.entity_mut(item).insert(crate::Selected);
+    assert_eq!(
+        resolve_style(&world, item).colors.bg,
+        Some(crate::xilem::Color::from_rgb8(0x00, 0x78, 0xD4))
+    );
 }
 
 #[test]
-fn handle_global_overlay_clicks_closes_when_clicking_anchor_and_suppresses_pointer() {
+fn empty_container_with_empty_state_projects_its_message() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
-    let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    app.add_plugins(PicusPlugin::default());
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let root = app.world_mut().spawn(UiRoot).id();
+    let list = app
         .world_mut()
         .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Two"),
-            ]),
+            crate::UiFlexColumn::default(),
+            crate::EmptyState::new("No data yet").with_icon(crate::PicusIcon::Inbox),
             ChildOf(root),
         ))
         .id();
 
+    app.update();
+
     {
-        let mut combo_state = app
-            .world_mut()
-            .get_mut::<crate::UiComboBox>(combo)
-            .expect("combo should exist");
-        combo_state.selected = usize::MAX;
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let layer_root = runtime.render_root.get_layer_root(0);
+        assert!(find_widget_id_by_debug_text(layer_root, "No data yet").is_some());
     }
 
+    app.world_mut()
+        .spawn((crate::UiLabel::new("First row"), ChildOf(list)));
     app.update();
 
-    let dropdown = open_combo_dropdown(&mut app, combo);
-    app.update();
-    let anchor_center = widget_center_for_entity(&app, combo);
-
-    run_global_overlay_click(&mut app, window_entity, anchor_center);
-
-    assert!(app.world().get_entity(dropdown).is_err());
-
-    let mut routing = app
-        .world_mut()
-        .resource_mut::<crate::OverlayPointerRoutingState>();
-    assert!(routing.take_suppressed_press(window_entity, MouseButton::Left));
-    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer_root = runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(layer_root, "No data yet").is_none());
+    assert!(find_widget_id_by_debug_text(layer_root, "First row").is_some());
 }
 
 #[test]
-fn handle_global_overlay_clicks_closes_menu_panel_anchor_and_resets_open_state() {
+fn count_badge_past_max_display_renders_capped_text() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
-    let mut window = Window::default();
-    window.resolution.set(900.0, 680.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
-
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let menu_bar = app
+    let root = app
         .world_mut()
-        .spawn((crate::UiMenuBar, ChildOf(root)))
+        .spawn((UiRoot, crate::UiFlexRow::default()))
         .id();
-    let menu_item = app
+    let tab = app
         .world_mut()
         .spawn((
-            crate::UiMenuBarItem::new(
-                "File",
-                [
-                    crate::UiMenuItem::new("Open", "file.open"),
-                    crate::UiMenuItem::new("Save", "file.save"),
-                ],
-            ),
-            ChildOf(menu_bar),
+            crate::UiButton::new("Inbox"),
+            crate::Badge::new(150).with_max_display(99),
+            ChildOf(root),
         ))
         .id();
 
     app.update();
 
-    app.world()
-        .resource::<UiEventQueue>()
-        .push_typed(menu_item, crate::OverlayUiAction::ToggleMenuBarItem);
-    app.update();
-
-    let panel = {
-        let mut query = app.world_mut().query::<(Entity, &crate::UiMenuItemPanel)>();
-        query
-            .iter(app.world())
-            .find_map(|(entity, panel)| (panel.anchor == menu_item).then_some(entity))
-            .expect("menu toggle should spawn menu panel")
-    };
-
-    assert!(
-        app.world()
-            .get::<crate::UiMenuBarItem>(menu_item)
-            .expect("menu item should exist")
-            .is_open
-    );
+    {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let layer_root = runtime.render_root.get_layer_root(0);
+        assert!(find_widget_id_by_debug_text(layer_root, "99+").is_some());
+        assert!(find_widget_id_by_debug_text(layer_root, "150").is_none());
+    }
 
-    let anchor_center = widget_center_for_entity(&app, menu_item);
-    run_global_overlay_click(&mut app, window_entity, anchor_center);
+    app.world_mut()
+        .entity_mut(tab)
+        .insert(crate::Badge::new(0).with_max_display(99));
+    app.update();
 
-    assert!(app.world().get_entity(panel).is_err());
-    assert!(
-        !app.world()
-            .get::<crate::UiMenuBarItem>(menu_item)
-            .expect("menu item should remain")
-            .is_open
-    );
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer_root = runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(layer_root, "99+").is_none());
+    assert!(find_widget_id_by_debug_text(layer_root, "0").is_none());
 }
 
 #[test]
-fn handle_global_overlay_clicks_closes_theme_picker_anchor_and_resets_open_state() {
-    let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+fn custom_unhandled_fallback_replaces_debug_placeholder() {
+    #[derive(Component)]
+    struct Unprojected;
 
-    let mut window = Window::default();
-    window.resolution.set(900.0, 680.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+    app.insert_resource(
+        crate::SynthesisFallbacks::default()
+            .with_unhandled(|_, _, _| Arc::new(crate::xilem::view::label("custom unhandled"))),
+    );
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let picker = app
+    let root = app
         .world_mut()
-        .spawn((crate::UiThemePicker::fluent(), ChildOf(root)))
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
         .id();
+    let orphan = app.world_mut().spawn((Unprojected, ChildOf(root))).id();
 
     app.update();
 
-    app.world()
-        .resource::<UiEventQueue>()
-        .push_typed(picker, crate::OverlayUiAction::ToggleThemePicker);
-    app.update();
-
-    let panel = {
-        let mut query = app
-            .world_mut()
-            .query::<(Entity, &crate::UiThemePickerMenu)>();
-        query
-            .iter(app.world())
-            .find_map(|(entity, panel)| (panel.anchor == picker).then_some(entity))
-            .expect("theme picker toggle should spawn menu panel")
-    };
-
-    assert!(
+    assert_eq!(
         app.world()
-            .get::<crate::UiThemePicker>(picker)
-            .expect("theme picker should exist")
-            .is_open
+            .resource::<crate::UiSynthesisStats>()
+            .unhandled_count,
+        1
     );
-
-    let anchor_center = widget_center_for_entity(&app, picker);
-    run_global_overlay_click(&mut app, window_entity, anchor_center);
-
-    assert!(app.world().get_entity(panel).is_err());
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer_root = runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(layer_root, "custom unhandled").is_some());
     assert!(
-        !app.world()
-            .get::<crate::UiThemePicker>(picker)
-            .expect("theme picker should remain")
-            .is_open
+        find_widget_id_by_debug_text(layer_root, &format!("[unhandled entity {orphan:?}]"))
+            .is_none()
     );
 }
 
 #[test]
-fn ui_button_projects_to_ecs_button_with_child_widget() {
+fn ui_order_sorts_siblings_before_projection() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
     window.resolution.set(800.0, 600.0);
     app.world_mut().spawn((window, PrimaryWindow));
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let button = app
+    let root = app
         .world_mut()
-        .spawn((crate::UiButton::new("Action"), ChildOf(root)))
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
         .id();
+    for (text, order) in [
+        ("third", 2),
+        ("first", -1),
+        ("second-a", 0),
+        ("second-b", 0),
+    ] {
+        app.world_mut().spawn((
+            crate::UiLabel::new(text),
+            crate::UiOrder(order),
+            ChildOf(root),
+        ));
+    }
+    // Unordered children count as `UiOrder(0)` and keep their `Children` position.
+    app.world_mut()
+        .spawn((crate::UiLabel::new("second-c"), ChildOf(root)));
 
     app.update();
+    app.update();
 
-    let debug = format!("entity={}", button.to_bits());
-    let widget_id = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        find_widget_id_by_debug_text(root, &debug)
-            .expect("UiButton should project an entity-tagged action button widget")
-    };
-
-    let short_type = {
+    let ids = {
         let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        runtime
-            .render_root
-            .get_widget(widget_id)
-            .map(|widget| widget.short_type_name().to_string())
-            .unwrap_or_default()
+        ["first", "second-a", "second-b", "second-c", "third"].map(|text| {
+            find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+                .expect("label should render")
+        })
     };
 
-    assert_eq!(short_type, "EcsButtonWithChildWidget");
+    let ys = ids.map(|id| widget_center_for_widget_id(&app, id).y);
+    assert!(
+        ys.windows(2).all(|pair| pair[0] < pair[1]),
+        "labels should be laid out in UiOrder: {ys:?}"
+    );
 }
 
 #[test]
-fn overlay_pointer_routing_suppress_click_only_suppresses_press() {
-    let mut routing = crate::OverlayPointerRoutingState::default();
-    let window = Entity::from_raw_u32(7).expect("test entity index should be valid");
+fn measure_text_scales_with_font_size_for_registered_font_stack() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    routing.suppress_click(window, MouseButton::Left);
+    let font_bytes = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../assets/fonts/NotoSans-Regular.ttf"
+    ))
+    .expect("bundled Noto Sans font should be readable");
 
-    assert!(routing.take_suppressed_press(window, MouseButton::Left));
-    assert!(!routing.take_suppressed_release(window, MouseButton::Left));
+    let mut runtime = app
+        .world_mut()
+        .non_send_resource_mut::<crate::MasonryRuntime>();
+    runtime.register_fonts(font_bytes);
+
+    let font_stack = ["Noto Sans".to_string()];
+    let small = runtime.measure_text("Hello, world", &font_stack, 16.0);
+    let large = runtime.measure_text("Hello, world", &font_stack, 32.0);
+
+    assert!(small.width > 40.0 && small.width < 200.0, "{small:?}");
+    assert!(small.height > 0.0);
+    assert!(
+        (large.width / small.width - 2.0).abs() < 0.1,
+        "width should scale with size: {small:?} vs {large:?}"
+    );
+    assert_eq!(runtime.measure_text("", &font_stack, 16.0).width, 0.0);
 }
 
 #[test]
-fn handle_global_overlay_clicks_keeps_overlay_open_when_clicking_inside_overlay() {
+fn ellipsis_label_truncates_to_a_single_line_within_its_box() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    window.resolution.set(320.0, 240.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let title = "An exceptionally long illustration title that cannot fit on one card line";
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let label = app
         .world_mut()
         .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Two"),
-            ]),
+            crate::UiLabel::new(title).with_overflow(crate::TextOverflow::ellipsis()),
             ChildOf(root),
         ))
         .id();
 
     app.update();
+    app.update();
+    app.update();
 
-    let dropdown = open_combo_dropdown(&mut app, combo);
-    let dropdown_center = widget_center_for_entity(&app, dropdown);
-
-    run_global_overlay_click(&mut app, window_entity, dropdown_center);
-
-    assert!(app.world().get_entity(dropdown).is_ok());
+    let ellipsized = app
+        .world()
+        .get::<crate::EllipsizedText>(label)
+        .expect("overflowing label should be truncated")
+        .0
+        .clone();
+    assert!(ellipsized.ends_with('…'), "{ellipsized}");
+    assert!(ellipsized.chars().count() < title.chars().count());
+    assert!(title.starts_with(ellipsized.trim_end_matches('…')));
 
-    let mut routing = app
+    let mut runtime = app
         .world_mut()
-        .resource_mut::<crate::OverlayPointerRoutingState>();
-    assert!(!routing.take_suppressed_press(window_entity, MouseButton::Left));
-    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+        .non_send_resource_mut::<crate::MasonryRuntime>();
+    let line_height = runtime
+        .measure_text("Ag", &[], masonry::theme::TEXT_SIZE_NORMAL)
+        .height;
+    let widget_id =
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), &ellipsized)
+            .expect("truncated text should be rendered");
+    let widget = runtime
+        .render_root
+        .get_widget(widget_id)
+        .expect("label widget should exist");
+    assert!(widget.ctx().border_box_size().height < line_height * 1.5);
+    assert!(find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), title).is_none());
 }
 
 #[test]
-fn dropdown_padding_click_is_in_overlay_hit_path_and_does_not_dismiss() {
+fn ellipsis_labels_are_only_retruncated_when_inputs_or_width_change() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
+    window.resolution.set(320.0, 240.0);
     let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let title = "An exceptionally long illustration title that cannot fit on one card line";
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let label = app
         .world_mut()
         .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Two"),
-            ]),
+            crate::UiLabel::new(title).with_overflow(crate::TextOverflow::ellipsis()),
             ChildOf(root),
         ))
         .id();
 
     app.update();
+    app.update();
+    app.update();
+    let narrow = app
+        .world()
+        .get::<crate::EllipsizedText>(label)
+        .expect("overflowing label should be truncated")
+        .0
+        .clone();
 
-    let dropdown = open_combo_dropdown(&mut app, combo);
+    // Nothing the truncation depends on changes, so idle frames leave the label alone.
+    app.world_mut()
+        .entity_mut(label)
+        .remove::<crate::EllipsizedText>();
+    app.update();
+    app.update();
+    assert!(app.world().get::<crate::EllipsizedText>(label).is_none());
 
-    let opaque_debug = format!("opaque_hitbox_entity={}", dropdown.to_bits());
-    let opaque_widget_id = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        find_widget_id_by_debug_text(root, &opaque_debug)
-            .expect("dropdown should project an entity-tagged OpaqueHitboxWidget")
-    };
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut primary_window = query
+            .single_mut(world)
+            .expect("primary window should exist");
+        primary_window.resolution.set(480.0, 240.0);
+    }
+    app.world_mut().write_message(WindowResized {
+        window: window_entity,
+        width: 480.0,
+        height: 240.0,
+    });
+    app.update();
+    app.update();
+    app.update();
 
-    // Deliberately target menu padding, not option label text.
-    let click_position = widget_inset_point_for_widget_id(&app, opaque_widget_id, 6.0);
-    let hit_path = hit_path_for_position(&mut app, window_entity, click_position);
-    assert!(hit_path.contains(&opaque_widget_id));
+    let wide = app
+        .world()
+        .get::<crate::EllipsizedText>(label)
+        .expect("a new layout width should truncate the label again")
+        .0
+        .clone();
+    assert!(
+        wide.chars().count() > narrow.chars().count(),
+        "{narrow} -> {wide}"
+    );
 
-    run_global_overlay_click(&mut app, window_entity, click_position);
+    app.world_mut()
+        .get_mut::<crate::UiLabel>(label)
+        .expect("label should exist")
+        .text = "Short".to_string();
+    app.update();
+    app.update();
+    assert!(app.world().get::<crate::EllipsizedText>(label).is_none());
+}
 
-    assert!(app.world().get_entity(dropdown).is_ok());
+#[derive(Component, Debug, Clone, Copy)]
+struct LabelChipProbe {
+    boxed: bool,
+}
+
+fn label_chip_style() -> crate::ResolvedStyle {
+    let mut style = crate::ResolvedStyle::default();
+    style.layout.padding = 6.0;
+    style.layout.border_width = 2.0;
+    style.layout.scale = 1.0;
+    style.colors.bg = Some(crate::xilem::Color::from_rgb8(0x22, 0x44, 0x66));
+    style.colors.border = Some(crate::xilem::Color::from_rgb8(0x88, 0xAA, 0xCC));
+    style
+}
+
+fn project_label_chip_probe(probe: &LabelChipProbe, _ctx: ProjectionCtx<'_>) -> UiView {
+    let style = label_chip_style();
+    let text = if probe.boxed {
+        "boxed-chip"
+    } else {
+        "wrapped-chip"
+    };
+    if probe.boxed {
+        Arc::new(crate::apply_label_box_style(
+            crate::xilem::view::label(text),
+            &style,
+        ))
+    } else {
+        Arc::new(crate::apply_widget_style(
+            crate::apply_label_style(crate::xilem::view::label(text), &style),
+            &style,
+        ))
+    }
 }
 
 #[test]
-fn dropdown_item_text_region_hits_button_entity_instead_of_child_subwidget() {
+fn label_box_style_applies_border_and_padding_on_the_label_widget() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<LabelChipProbe>(project_label_chip_probe);
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
+    window.resolution.set(320.0, 240.0);
     app.world_mut().spawn((window, PrimaryWindow));
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let root = app
         .world_mut()
-        .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Longer option label"),
-            ]),
-            ChildOf(root),
-        ))
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
         .id();
+    app.world_mut()
+        .spawn((LabelChipProbe { boxed: true }, ChildOf(root)));
+    app.world_mut()
+        .spawn((LabelChipProbe { boxed: false }, ChildOf(root)));
 
     app.update();
-
-    let dropdown = open_combo_dropdown(&mut app, combo);
     app.update();
 
-    let item_entity = {
-        let mut query = app.world_mut().query::<(Entity, &crate::UiDropdownItem)>();
-        query
-            .iter(app.world())
-            .find_map(|(entity, item)| {
-                (item.dropdown == dropdown && item.index == 1).then_some(entity)
-            })
-            .expect("second dropdown item should exist")
-    };
-
-    let hit_position = {
-        let debug = format!("entity={}", item_entity.to_bits());
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        let widget_id = find_widget_id_by_debug_text(root, &debug)
-            .expect("dropdown item button should expose an entity-tagged widget");
-        widget_center_for_widget_id(&app, widget_id)
-    };
-    let (hit_widget, hit_debug_text) = {
-        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-        let root = runtime.render_root.get_layer_root(0);
-        root.find_widget_under_pointer((hit_position.x as f64, hit_position.y as f64).into())
-            .map(|widget| {
-                (
-                    widget.short_type_name().to_string(),
-                    widget.get_debug_text().unwrap_or_default(),
-                )
-            })
-            .unwrap_or_default()
+    let style = label_chip_style();
+    let inset = 2.0 * (style.layout.padding + style.layout.border_width);
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let height_of = |text: &str| {
+        let widget_id = find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+            .expect("chip label should be rendered");
+        runtime
+            .render_root
+            .get_widget(widget_id)
+            .expect("chip widget should exist")
+            .ctx()
+            .border_box_size()
+            .height
     };
 
-    assert_eq!(hit_widget.as_str(), "EcsButtonWithChildWidget");
-    assert_eq!(hit_debug_text, format!("entity={}", item_entity.to_bits()));
+    let boxed = height_of("boxed-chip");
+    let wrapped = height_of("wrapped-chip");
+    // The wrapped pair keeps padding/border on a surrounding `sized_box`; the boxed
+    // chip carries them on the label widget itself.
+    assert!(
+        (boxed - wrapped - inset).abs() < 0.5,
+        "boxed={boxed} wrapped={wrapped} inset={inset}"
+    );
 }
 
 #[test]
-fn plugin_auto_registers_badge_and_progress_bar_components() {
+fn perf_overlay_projects_current_node_count_when_enabled() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
     app.world_mut()
-        .spawn((crate::UiBadge::new("Beta"), ChildOf(root)));
+        .spawn((crate::UiLabel::new("perf-probe"), ChildOf(root)));
+    app.world_mut().spawn((crate::UiPerfOverlay, ChildOf(root)));
+
+    app.update();
+    app.update();
+    {
+        let node_count = app.world().resource::<crate::UiSynthesisStats>().node_count;
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        assert!(
+            find_widget_id_by_debug_text(
+                runtime.render_root.get_layer_root(0),
+                &format!("nodes: {node_count}")
+            )
+            .is_none(),
+            "perf overlay should stay hidden until enabled"
+        );
+    }
+
     app.world_mut()
-        .spawn((crate::UiProgressBar::determinate(0.5), ChildOf(root)));
+        .resource_mut::<crate::UiPerfOverlaySettings>()
+        .enabled = true;
+    app.update();
+    app.update();
+
+    let node_count = app.world().resource::<crate::UiSynthesisStats>().node_count;
+    assert!(node_count > 0);
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    assert!(
+        find_widget_id_by_debug_text(
+            runtime.render_root.get_layer_root(0),
+            &format!("nodes: {node_count}")
+        )
+        .is_some(),
+        "perf overlay should show nodes: {node_count}"
+    );
+}
+
+#[test]
+fn ui_node_index_tracks_spawn_despawn_and_duplicate_ids() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let first = app.world_mut().spawn(crate::UiNodeId(7)).id();
+    let other = app.world_mut().spawn(crate::UiNodeId(9)).id();
+    app.update();
+    {
+        let index = app.world().resource::<crate::UiNodeIndex>();
+        assert_eq!(index.get(7), Some(first));
+        assert_eq!(index.get(9), Some(other));
+        assert_eq!(index.len(), 2);
+    }
 
+    let duplicate = app.world_mut().spawn(crate::UiNodeId(7)).id();
     app.update();
+    assert_eq!(
+        app.world().resource::<crate::UiNodeIndex>().get(7),
+        Some(duplicate),
+        "last entity registered for a duplicate id wins"
+    );
 
-    let stats = app.world().resource::<crate::UiSynthesisStats>();
-    assert_eq!(stats.unhandled_count, 0);
+    app.world_mut().entity_mut(first).despawn();
+    app.update();
+    assert_eq!(
+        app.world().resource::<crate::UiNodeIndex>().get(7),
+        Some(duplicate),
+        "despawning the shadowed entity keeps the winning mapping"
+    );
+
+    app.world_mut().entity_mut(duplicate).despawn();
+    app.world_mut().entity_mut(other).despawn();
+    app.update();
+    let index = app.world().resource::<crate::UiNodeIndex>();
+    assert_eq!(index.get(7), None);
+    assert_eq!(index.get(9), None);
+    assert!(index.is_empty());
 }
 
 #[test]
-fn handle_global_overlay_clicks_closes_overlay_on_outside_click_without_suppression() {
+fn flex_column_component_gap_and_cross_alignment_override_style() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    window.resolution.set(320.0, 240.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexRow::default()))
+        .id();
+    let spaced = app
         .world_mut()
         .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Two"),
-            ]),
+            crate::UiFlexColumn::default()
+                .with_gap(8.0)
+                .with_cross_align(crate::AlignItems::Center),
             ChildOf(root),
         ))
         .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("gap-a"), ChildOf(spaced)));
+    app.world_mut()
+        .spawn((crate::UiLabel::new("gap-wide-label"), ChildOf(spaced)));
+    let packed = app
+        .world_mut()
+        .spawn((crate::UiFlexColumn::default().with_gap(0.0), ChildOf(root)))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("pack-a"), ChildOf(packed)));
+    app.world_mut()
+        .spawn((crate::UiLabel::new("pack-b"), ChildOf(packed)));
 
     app.update();
+    app.update();
 
-    let dropdown = open_combo_dropdown(&mut app, combo);
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let bounds_of = |text: &str| {
+        let widget_id = find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+            .expect("column label should be rendered");
+        let widget = runtime
+            .render_root
+            .get_widget(widget_id)
+            .expect("label widget should exist");
+        let origin = widget.ctx().window_origin();
+        let size = widget.ctx().border_box_size();
+        (origin.x, origin.y, size.width, size.height)
+    };
 
-    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
+    let (ax, ay, aw, ah) = bounds_of("gap-a");
+    let (bx, by, bw, _) = bounds_of("gap-wide-label");
+    assert!(bw > aw, "labels should differ in width: {aw} vs {bw}");
+    assert!(
+        ((ax + aw / 2.0) - (bx + bw / 2.0)).abs() < 0.5,
+        "center cross alignment should share a horizontal center"
+    );
 
-    assert!(app.world().get_entity(dropdown).is_err());
+    let (_, pay, _, pah) = bounds_of("pack-a");
+    let (_, pby, _, _) = bounds_of("pack-b");
+    let spaced_gap = by - (ay + ah);
+    let packed_gap = pby - (pay + pah);
+    assert!(
+        (spaced_gap - packed_gap - 8.0).abs() < 0.5,
+        "spaced={spaced_gap} packed={packed_gap}"
+    );
+}
 
-    let mut routing = app
-        .world_mut()
-        .resource_mut::<crate::OverlayPointerRoutingState>();
-    assert!(!routing.take_suppressed_press(window_entity, MouseButton::Left));
-    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+fn label_bounds(app: &App, text: &str) -> (f64, f64, f64, f64) {
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let widget_id = find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+        .expect("label should be rendered");
+    let widget = runtime
+        .render_root
+        .get_widget(widget_id)
+        .expect("label widget should exist");
+    let origin = widget.ctx().window_origin();
+    let size = widget.ctx().border_box_size();
+    (origin.x, origin.y, size.width, size.height)
 }
 
 #[test]
-fn handle_global_overlay_clicks_outside_dialog_emits_same_optional_close_hook() {
+fn scale_transform_projects_transformed_view() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    window.resolution.set(320.0, 240.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let target = app.world_mut().spawn_empty().id();
-    let dialog = spawn_in_overlay_root(
-        app.world_mut(),
-        (
-            crate::UiDialog::new("title", "body"),
-            crate::UiDialogCloseAction::new(target, DialogCloseTestAction::Closed),
-        ),
-    );
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("plain"), ChildOf(root)));
+    app.world_mut().spawn((
+        crate::UiLabel::new("scaled"),
+        crate::UiTransform::from_scale(2.0),
+        ChildOf(root),
+    ));
 
     app.update();
     app.update();
 
-    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
+    let window_scale = |text: &str| {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let widget_id = find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text)
+            .expect("label should be rendered");
+        let widget = runtime
+            .render_root
+            .get_widget(widget_id)
+            .expect("label widget should exist");
+        widget.ctx().window_transform().as_coeffs()[0]
+    };
 
-    assert!(app.world().get_entity(dialog).is_err());
+    assert!((window_scale("plain") - 1.0).abs() < 1e-6);
+    assert!((window_scale("scaled") - 2.0).abs() < 1e-6);
+}
 
-    let events = app
-        .world_mut()
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<DialogCloseTestAction>();
-    assert_eq!(events.len(), 1);
-    assert_eq!(events[0].entity, target);
-    assert_eq!(events[0].action, DialogCloseTestAction::Closed);
+#[test]
+fn transform_lens_interpolates_each_component() {
+    use bevy_tween::interpolate::Interpolator as _;
+
+    let lens = crate::UiTransformLens {
+        start: crate::UiTransform::IDENTITY,
+        end: crate::UiTransform::from_translation(10.0, -20.0).with_rotation(1.0),
+    };
+    let mut value = crate::UiTransform::IDENTITY;
+    lens.interpolate(&mut value, 0.5, 0.0);
+
+    assert_eq!(value.scale, 1.0);
+    assert_eq!(value.translate, (5.0, -10.0));
+    assert_eq!(value.rotate, 0.5);
 }
 
 #[test]
-fn handle_global_overlay_clicks_outside_dialog_without_hook_keeps_existing_behavior() {
+fn styled_box_with_corner_radii_paints_through_corner_radii_widget() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
-    let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    app.add_plugins(PicusPlugin::default());
 
-    let dialog = spawn_in_overlay_root(app.world_mut(), (crate::UiDialog::new("title", "body"),));
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let card = app
+        .world_mut()
+        .spawn((
+            crate::UiFlexColumn::default(),
+            crate::LayoutStyle {
+                corner_radius: Some(4.0),
+                corner_radii: Some([12.0, 12.0, 0.0, 0.0]),
+                ..crate::LayoutStyle::default()
+            },
+            crate::ColorStyle {
+                bg: Some(crate::xilem::Color::from_rgb8(0x20, 0x30, 0x40)),
+                ..crate::ColorStyle::default()
+            },
+            ChildOf(root),
+        ))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("card body"), ChildOf(card)));
 
     app.update();
     app.update();
 
-    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
-
-    assert!(app.world().get_entity(dialog).is_err());
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer = || runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(layer(), "corner_radii 12,12,0,0").is_some());
+    assert!(find_widget_id_by_debug_text(layer(), "card body").is_some());
+
+    let paint = resolve_style(app.world(), card)
+        .corner_radii_paint()
+        .expect("corner radii should produce a per-corner paint");
+    assert_eq!(paint.radii, [12.0, 12.0, 0.0, 0.0]);
     assert!(
-        app.world_mut()
-            .resource_mut::<UiEventQueue>()
-            .drain_actions::<DialogCloseTestAction>()
-            .is_empty()
+        resolve_style(app.world(), root)
+            .corner_radii_paint()
+            .is_none()
     );
 }
 
 #[test]
-fn handle_global_overlay_clicks_works_without_primary_window_marker() {
+fn clipped_container_projects_clip_box_with_style_corner_radius() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
-    let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window,)).id();
+    app.add_plugins(PicusPlugin::default());
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let card = app
         .world_mut()
         .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Two"),
-            ]),
+            crate::UiFlexColumn::default(),
+            crate::Overflow::Clip,
+            crate::LayoutStyle {
+                corner_radius: Some(12.0),
+                ..crate::LayoutStyle::default()
+            },
             ChildOf(root),
         ))
         .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("card body"), ChildOf(card)));
 
     app.update();
+    app.update();
 
-    let dropdown = open_combo_dropdown(&mut app, combo);
-
-    run_global_overlay_click(&mut app, window_entity, Vec2::new(790.0, 590.0));
-
-    assert!(app.world().get_entity(dropdown).is_err());
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let layer = || runtime.render_root.get_layer_root(0);
+    assert!(find_widget_id_by_debug_text(layer(), "clip_box radius=12").is_some());
+    assert!(find_widget_id_by_debug_text(layer(), "card body").is_some());
 }
 
 #[test]
-fn toast_in_overlay_root_is_isolated_from_dropdown_overlay_stack_dismissal() {
+fn spacer_with_unit_flex_stretches_to_fill_row() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
-        .register_projector::<ToastProbe>(project_toast_probe);
+    app.add_plugins(PicusPlugin::default());
 
     let mut window = Window::default();
-    window.resolution.set(800.0, 600.0);
-    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    window.resolution.set(320.0, 240.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let combo = app
+    let root = app.world_mut().spawn(UiRoot).id();
+    let row = app
         .world_mut()
-        .spawn((
-            crate::UiComboBox::new(vec![
-                crate::UiComboOption::new("one", "One"),
-                crate::UiComboOption::new("two", "Two"),
-            ]),
-            ChildOf(root),
-        ))
+        .spawn((crate::UiFlexRow::default().with_gap(0.0), ChildOf(root)))
         .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("L"), ChildOf(row)));
+    app.world_mut()
+        .spawn((crate::UiSpacer::new(1.0), ChildOf(row)));
+    app.world_mut()
+        .spawn((crate::UiLabel::new("R"), ChildOf(row)));
 
     app.update();
-
-    let dropdown = open_combo_dropdown(&mut app, combo);
-    let toast = spawn_in_overlay_root(app.world_mut(), (ToastProbe,));
-
     app.update();
 
-    assert!(app.world().get::<crate::OverlayState>(toast).is_none());
-    {
-        let stack = app.world().resource::<crate::OverlayStack>();
-        assert_eq!(stack.active_overlays, vec![dropdown]);
-    }
-
-    let toast_center = widget_center_for_entity(&app, toast);
-    run_global_overlay_click(&mut app, window_entity, toast_center);
-
-    assert!(app.world().get_entity(dropdown).is_err());
-    assert!(app.world().get_entity(toast).is_ok());
+    let (lx, _, lw, _) = label_bounds(&app, "L");
+    let (rx, _, rw, _) = label_bounds(&app, "R");
+    assert!(lx < 10.0, "leading label should stay at the start: {lx}");
     assert!(
-        app.world()
-            .resource::<crate::OverlayStack>()
-            .active_overlays
-            .is_empty()
+        rx + rw > 310.0,
+        "spacer should push the trailing label to the row end: {}",
+        rx + rw
     );
-
-    let mut routing = app
-        .world_mut()
-        .resource_mut::<crate::OverlayPointerRoutingState>();
-    assert!(!routing.take_suppressed_press(window_entity, MouseButton::Left));
-    assert!(!routing.take_suppressed_release(window_entity, MouseButton::Left));
+    assert!(rx - (lx + lw) > 250.0);
 }
 
 #[test]
-fn handle_global_overlay_clicks_logs_when_window_missing() {
-    init_test_tracing();
-
-    let mut world = World::new();
-    world.insert_resource(ButtonInput::<MouseButton>::default());
+fn horizontal_divider_projects_one_pixel_line_by_default() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    {
-        let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
-        input.press(MouseButton::Left);
-    }
+    let mut window = Window::default();
+    window.resolution.set(320.0, 240.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    let dialog = world
-        .spawn((
-            crate::UiDialog::new("title", "body"),
-            crate::OverlayState {
-                is_modal: true,
-                anchor: None,
-            },
-        ))
+    let root = app.world_mut().spawn(UiRoot).id();
+    let column = app
+        .world_mut()
+        .spawn((crate::UiFlexColumn::default().with_gap(0.0), ChildOf(root)))
         .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("above"), ChildOf(column)));
+    app.world_mut()
+        .spawn((crate::UiDivider::default(), ChildOf(column)));
+    app.world_mut()
+        .spawn((crate::UiLabel::new("below"), ChildOf(column)));
 
-    crate::handle_global_overlay_clicks(&mut world);
+    app.update();
+    app.update();
 
-    assert!(world.get_entity(dialog).is_ok());
+    let (_, above_y, _, above_h) = label_bounds(&app, "above");
+    let (_, below_y, _, _) = label_bounds(&app, "below");
+    let line = below_y - (above_y + above_h);
+    assert!(
+        (line - 1.0).abs() < 0.01,
+        "divider should occupy exactly 1px: {line}"
+    );
+}
+
+#[derive(Resource)]
+struct FeedOrderProbe {
+    root: Entity,
+    items: Vec<Entity>,
 }
 
 #[test]
-fn pointer_hits_bubble_to_parent_until_consumed() {
-    let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+fn synthesis_config_child_resolver_drives_hierarchy_from_resource_order() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    let root = world.spawn_empty().id();
-    let parent = world
-        .spawn((ChildOf(root), crate::StopUiPointerPropagation))
-        .id();
-    let child = world.spawn((ChildOf(parent),)).id();
+    let mut window = Window::default();
+    window.resolution.set(320.0, 240.0);
+    app.world_mut().spawn((window, PrimaryWindow));
 
-    world.resource::<UiEventQueue>().push_typed(
-        child,
-        crate::UiPointerHitEvent {
-            target: child,
-            position: (12.0, 24.0),
-            button: MouseButton::Left,
-            phase: crate::UiPointerPhase::Pressed,
-        },
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default().with_gap(0.0)))
+        .id();
+    // No `ChildOf`: the resolver alone links these labels to the root.
+    let first = app
+        .world_mut()
+        .spawn(crate::UiLabel::new("feed-first"))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn(crate::UiLabel::new("feed-second"))
+        .id();
+    let third = app
+        .world_mut()
+        .spawn(crate::UiLabel::new("feed-third"))
+        .id();
+    app.insert_resource(FeedOrderProbe {
+        root,
+        items: vec![third, first, second],
+    });
+    app.insert_resource(
+        crate::SynthesisConfig::default().with_child_resolver(|world, entity| {
+            let order = world.resource::<FeedOrderProbe>();
+            if entity == order.root {
+                order.items.clone()
+            } else {
+                Vec::new()
+            }
+        }),
     );
 
-    bubble_ui_pointer_events(&mut world);
-
-    let bubbled = world
-        .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiPointerEvent>();
+    app.update();
+    app.update();
 
-    assert_eq!(bubbled.len(), 2);
-    assert_eq!(bubbled[0].entity, child);
-    assert_eq!(bubbled[0].action.current_target, child);
-    assert!(!bubbled[0].action.consumed);
+    assert_eq!(
+        crate::ordered_children(app.world(), root),
+        vec![third, first, second]
+    );
+    // Labels have no entries of their own, so the resolver yields no children for them.
+    assert!(crate::ordered_children(app.world(), first).is_empty());
 
-    assert_eq!(bubbled[1].entity, parent);
-    assert_eq!(bubbled[1].action.current_target, parent);
-    assert!(bubbled[1].action.consumed);
+    let (_, third_y, _, _) = label_bounds(&app, "feed-third");
+    let (_, first_y, _, _) = label_bounds(&app, "feed-first");
+    let (_, second_y, _, _) = label_bounds(&app, "feed-second");
+    assert!(third_y < first_y && first_y < second_y);
 }
 
-#[test]
-fn projector_registry_last_registered_component_projector_wins() {
-    #[derive(Component, Debug, Clone, Copy)]
-    struct OverrideProbe;
-
-    static LAST_PROJECTOR: AtomicUsize = AtomicUsize::new(0);
-
-    fn project_first(_: &OverrideProbe, _ctx: ProjectionCtx<'_>) -> UiView {
-        LAST_PROJECTOR.store(1, Ordering::SeqCst);
-        Arc::new(crate::xilem::view::label("first"))
-    }
-
-    fn project_second(_: &OverrideProbe, _ctx: ProjectionCtx<'_>) -> UiView {
-        LAST_PROJECTOR.store(2, Ordering::SeqCst);
-        Arc::new(crate::xilem::view::label("second"))
-    }
-
-    let mut world = World::new();
-    let entity = world.spawn((OverrideProbe,)).id();
+#[derive(Component, Debug, Clone, PartialEq)]
+struct FeedItem {
+    id: u32,
+    title: &'static str,
+}
 
-    let mut registry = UiProjectorRegistry::default();
-    registry
-        .register_component::<OverrideProbe>(project_first)
-        .register_component::<OverrideProbe>(project_second);
+impl crate::Keyed for FeedItem {
+    type Key = u32;
 
-    LAST_PROJECTOR.store(0, Ordering::SeqCst);
-    let projected = registry.project_node(&world, entity, entity.to_bits(), Vec::new());
-    assert!(projected.is_some());
-    assert_eq!(LAST_PROJECTOR.load(Ordering::SeqCst), 2);
+    fn key(&self) -> u32 {
+        self.id
+    }
 }
 
 #[test]
-fn stylesheet_ron_parser_supports_tokens_and_var_values() {
-    let ron = r##"(
-    tokens: {
-        "demo-bg": Color(Hex("#112233")),
-        "radius": Float(6.0),
-    },
-  rules: [
-    (
-      selector: Class("demo.button"),
-      setter: (
-                                layout: (
-                                        padding: 10.0,
-                                        corner_radius: Var("radius"),
-                                        justify_content: Start,
-                                        align_items: Center,
-                                        scale: 0.97,
-                                ),
-                                text: (text_align: Center),
-                                colors: (bg: Var("demo-bg"), text: Hex("#f0f0f0")),
-      ),
-    ),
-    (
-      selector: And([Class("demo.button"), PseudoClass(Hovered)]),
-      setter: (
-                colors: (hover_bg: Hex("#112233ff")),
-      ),
-    ),
-  ],
-)"##;
+fn reconcile_children_reuses_matching_entities_by_key() {
+    let mut world = World::new();
+    let parent = world.spawn_empty().id();
+    let item = |id, title| FeedItem { id, title };
 
-    let sheet =
-        crate::styling::parse_stylesheet_ron_for_tests(ron).expect("stylesheet ron should parse");
-    assert_eq!(sheet.rules.len(), 2);
-    assert_eq!(sheet.tokens.len(), 2);
+    let first = crate::reconcile_children(
+        &mut world,
+        parent,
+        &[item(1, "A"), item(2, "B"), item(3, "C")],
+    );
+    let [a, b, c] = first[..] else {
+        panic!("three children should be spawned");
+    };
 
-    assert!(matches!(
-        &sheet.rules[0].selector,
-        crate::Selector::Class(name) if name == "demo.button"
-    ));
-    assert!(sheet.rules[0].setter.layout.padding.is_some());
-    assert!(sheet.rules[0].setter.colors.bg.is_some());
-    assert!(sheet.rules[0].setter.layout.justify_content.is_some());
-    assert!(sheet.rules[0].setter.layout.align_items.is_some());
-    assert!(sheet.rules[0].setter.layout.scale.is_some());
-    assert!(sheet.rules[0].setter.text.text_align.is_some());
-    assert!(matches!(
-        sheet.rules[0].setter.colors.bg.as_ref(),
-        Some(crate::StyleValue::Var(token)) if token == "demo-bg"
-    ));
-    assert!(matches!(
-        sheet.rules[0].setter.colors.text.as_ref(),
-        Some(crate::StyleValue::Value(color))
-            if *color == crate::xilem::Color::from_rgb8(0xF0, 0xF0, 0xF0)
-    ));
+    let second = crate::reconcile_children(
+        &mut world,
+        parent,
+        &[item(1, "A"), item(3, "C2"), item(4, "D")],
+    );
 
-    assert!(matches!(&sheet.rules[1].selector, crate::Selector::And(parts) if !parts.is_empty()));
-    assert!(matches!(
-        sheet.rules[1].setter.colors.hover_bg.as_ref(),
-        Some(crate::StyleValue::Value(color))
-            if *color == crate::xilem::Color::from_rgba8(0x11, 0x22, 0x33, 0xFF)
-    ));
+    assert_eq!(second[0], a);
+    assert_eq!(second[1], c);
+    let d = second[2];
+    assert!(![a, b, c].contains(&d));
+    assert!(world.get_entity(b).is_err());
+    assert_eq!(world.get::<FeedItem>(c), Some(&item(3, "C2")));
+    assert_eq!(world.get::<FeedItem>(d), Some(&item(4, "D")));
+    assert_eq!(
+        world
+            .get::<Children>(parent)
+            .expect("parent should keep children")
+            .to_vec(),
+        vec![a, c, d]
+    );
 }
 
-#[test]
-fn stylesheet_hex_literal_for_bg_is_not_treated_as_token_var() {
-    let ron = r##"(
-    rules: [
-        (
-            selector: Class("demo.hex"),
-            setter: (
-                colors: (bg: Hex("#FFFFFF14")),
-            ),
-        ),
-    ],
-)"##;
-
-    let sheet =
-        crate::styling::parse_stylesheet_ron_for_tests(ron).expect("stylesheet ron should parse");
+#[derive(Component)]
+struct PanickingProbe;
 
-    assert!(matches!(
-        sheet.rules[0].setter.colors.bg.as_ref(),
-        Some(crate::StyleValue::Value(color))
-            if *color == crate::xilem::Color::from_rgba8(0xFF, 0xFF, 0xFF, 0x14)
-    ));
+fn project_panicking_probe(_: &PanickingProbe, _ctx: ProjectionCtx<'_>) -> UiView {
+    panic!("projector failure under test");
 }
 
 #[test]
-fn embedded_fluent_theme_color_fields_do_not_parse_hex_literals_as_var_tokens() {
-    let assert_not_hex_var =
-        |value: &Option<crate::StyleValue<crate::xilem::Color>>, field: &str, selector: &str| {
-            if let Some(crate::StyleValue::Var(token)) = value {
-                assert!(
-                    !token.trim().starts_with('#'),
-                    "{selector} {field} parsed as Var token `{token}` but should be a literal color"
-                );
-            }
-        };
+fn failed_synthesis_keeps_last_good_front_buffer_when_configured() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<PanickingProbe>(project_panicking_probe)
+        .insert_resource(
+            crate::SynthesisConfig::default()
+                .with_isolate_panics(true)
+                .with_keep_last_good_frame(true),
+        );
 
-    let variants = crate::styling::parse_stylesheet_variants_ron_for_tests(
-        crate::styling::BUILTIN_FLUENT_THEME_RON,
-    )
-    .expect("embedded fluent theme bundle should parse");
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("last-good"), ChildOf(root)));
 
-    for (variant_name, sheet) in &variants.variants {
-        for rule in &sheet.rules {
-            let selector = format!("{variant_name}::{:?}", rule.selector);
-            let colors = &rule.setter.colors;
-            assert_not_hex_var(&colors.bg, "bg", &selector);
-            assert_not_hex_var(&colors.text, "text", &selector);
-            assert_not_hex_var(&colors.border, "border", &selector);
-            assert_not_hex_var(&colors.hover_bg, "hover_bg", &selector);
-            assert_not_hex_var(&colors.hover_text, "hover_text", &selector);
-            assert_not_hex_var(&colors.hover_border, "hover_border", &selector);
-            assert_not_hex_var(&colors.pressed_bg, "pressed_bg", &selector);
-            assert_not_hex_var(&colors.pressed_text, "pressed_text", &selector);
-            assert_not_hex_var(&colors.pressed_border, "pressed_border", &selector);
-        }
-    }
+    app.update();
+
+    let good_front = app
+        .world()
+        .resource::<crate::SynthesizedUiViews>()
+        .front()
+        .to_vec();
+    assert!(!good_front.is_empty());
+
+    app.world_mut().spawn((PanickingProbe, ChildOf(root)));
+    app.update();
+
+    assert_eq!(
+        app.world()
+            .resource::<crate::UiSynthesisStats>()
+            .panicked_count,
+        1
+    );
+    let views = app.world().resource::<crate::SynthesizedUiViews>();
+    assert_eq!(views.front().len(), good_front.len());
+    assert!(
+        views
+            .front()
+            .iter()
+            .zip(&good_front)
+            .all(|(front, good)| Arc::ptr_eq(front, good))
+    );
+    assert_eq!(views.back().len(), good_front.len());
+    assert!(!Arc::ptr_eq(&views.back()[0], &good_front[0]));
 }
 
 #[test]
-fn stylesheet_var_missing_token_falls_back_to_transparent_or_zero() {
-    let ron = r##"(
-    rules: [
-        (
-            selector: Class("demo.button"),
-            setter: (
-                layout: (padding: Var("missing-padding")),
-                colors: (bg: Var("missing-bg")),
-            ),
-        ),
-    ],
-)"##;
+fn isolated_projector_panic_renders_fallback_and_keeps_siblings() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .register_projector::<PanickingProbe>(project_panicking_probe)
+        .insert_resource(crate::SynthesisConfig::default().with_isolate_panics(true));
 
-    let sheet =
-        crate::styling::parse_stylesheet_ron_for_tests(ron).expect("stylesheet ron should parse");
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("before-panic"), ChildOf(root)));
+    let panicking = app.world_mut().spawn((PanickingProbe, ChildOf(root))).id();
+    app.world_mut()
+        .spawn((crate::UiLabel::new("after-panic"), ChildOf(root)));
 
-    let mut world = World::new();
-    world.insert_resource(sheet);
+    app.update();
+    app.update();
 
-    let entity = world
-        .spawn((crate::StyleClass(vec!["demo.button".to_string()]),))
-        .id();
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
+    let stats = app.world().resource::<crate::UiSynthesisStats>();
+    assert_eq!(stats.panicked_count, 1);
+    assert_eq!(stats.unhandled_count, 0);
 
-    let resolved = crate::resolve_style(&world, entity);
-    assert_eq!(resolved.layout.padding, 0.0);
-    assert_eq!(resolved.colors.bg, Some(crate::xilem::Color::TRANSPARENT));
+    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+    let rendered = |text: &str| {
+        find_widget_id_by_debug_text(runtime.render_root.get_layer_root(0), text).is_some()
+    };
+    assert!(rendered("before-panic"));
+    assert!(rendered("after-panic"));
+    assert!(rendered(&format!("[projector panicked at {panicking:?}]")));
 }
 
 #[test]
-fn stylesheet_box_shadow_token_parses_and_resolves() {
-    let ron = r##"(
-    tokens: {
-        "flyout-shadow": BoxShadow((
-            color: Rgba(0.0, 0.0, 0.0, 0.35),
-            offset_x: 0.0,
-            offset_y: 12.0,
-            blur: 24.0,
-        )),
-    },
-    rules: [
-        (
-            selector: Class("shadowed"),
-            setter: (
-                box_shadow: Var("flyout-shadow"),
-            ),
-        ),
-    ],
-)"##;
+fn spawning_then_despawning_node_emits_one_mount_and_one_unmount() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .insert_resource(crate::SynthesisConfig::default().with_lifecycle_events(true));
 
-    let sheet =
-        crate::styling::parse_stylesheet_ron_for_tests(ron).expect("stylesheet ron should parse");
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    app.update();
 
-    let mut world = World::new();
-    world.insert_resource(sheet);
-    let entity = world
-        .spawn((crate::StyleClass(vec!["shadowed".to_string()]),))
+    let panel = app
+        .world_mut()
+        .spawn((crate::UiLabel::new("lifecycle-panel"), ChildOf(root)))
         .id();
+    app.update();
+    app.update();
+    assert!(
+        app.world()
+            .resource::<crate::UiMountedNodes>()
+            .contains(panel)
+    );
 
-    crate::mark_style_dirty(&mut world);
-    crate::sync_style_targets(&mut world);
+    app.world_mut().entity_mut(panel).despawn();
+    app.update();
+    app.update();
+    assert!(
+        !app.world()
+            .resource::<crate::UiMountedNodes>()
+            .contains(panel)
+    );
 
-    let resolved = crate::resolve_style(&world, entity);
-    let expected = crate::xilem::style::BoxShadow::new(
-        crate::xilem::Color::from_rgba8(0, 0, 0, 89),
-        (0.0, 12.0),
-    )
-    .blur(24.0);
+    let mut queue = app.world_mut().resource_mut::<UiEventQueue>();
+    let mounted = queue
+        .drain_actions::<crate::UiNodeMounted>()
+        .into_iter()
+        .filter(|event| event.entity == panel)
+        .count();
+    let unmounted = queue
+        .drain_actions::<crate::UiNodeUnmounted>()
+        .into_iter()
+        .filter(|event| event.action.entity == panel)
+        .count();
+    assert_eq!(mounted, 1);
+    assert_eq!(unmounted, 1);
+}
 
-    assert_eq!(resolved.box_shadow, Some(expected));
+#[test]
+fn stats_history_keeps_latest_samples_in_frame_order() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default())
+        .insert_resource(crate::UiStatsHistory::with_capacity(3));
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    for index in 0..5 {
+        app.world_mut().spawn((
+            crate::UiLabel::new(format!("history-{index}")),
+            ChildOf(root),
+        ));
+        app.update();
+    }
+
+    let history = app.world().resource::<crate::UiStatsHistory>();
+    assert_eq!(history.len(), 3);
+    let node_counts = history
+        .iter()
+        .map(|stats| stats.node_count)
+        .collect::<Vec<_>>();
+    assert!(
+        node_counts.windows(2).all(|pair| pair[1] == pair[0] + 1),
+        "samples should be oldest first, one label apart: {node_counts:?}"
+    );
+    assert_eq!(
+        history.latest(),
+        Some(app.world().resource::<crate::UiSynthesisStats>())
+    );
+}
+
+fn write_key(app: &mut App, window: Entity, key_code: KeyCode, text: &str, state: ButtonState) {
+    let logical_key = if text.is_empty() {
+        Key::Unidentified(NativeKey::Unidentified)
+    } else {
+        Key::Character(text.into())
+    };
+    app.world_mut().write_message(KeyboardInput {
+        key_code,
+        logical_key,
+        state,
+        text: None,
+        repeat: false,
+        window,
+    });
 }
 
 #[test]
-fn template_expansion_and_widget_actions_update_checkbox_state() {
-    let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+fn alt_mnemonic_opens_menu_and_arrows_move_highlight() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    let checkbox = world
-        .spawn((crate::UiCheckbox::new("Receive updates", false),))
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let menu_bar = app
+        .world_mut()
+        .spawn((crate::UiMenuBar, ChildOf(root)))
+        .id();
+    let file_menu = app
+        .world_mut()
+        .spawn((
+            crate::UiMenuBarItem::new(
+                "&File",
+                [
+                    crate::UiMenuItem::new("Open", "file.open"),
+                    crate::UiMenuItem::new("Save", "file.save"),
+                ],
+            ),
+            ChildOf(menu_bar),
+        ))
+        .id();
+    let edit_menu = app
+        .world_mut()
+        .spawn((
+            crate::UiMenuBarItem::new("&Edit", [crate::UiMenuItem::new("Undo", "edit.undo")]),
+            ChildOf(menu_bar),
+        ))
         .id();
 
-    crate::expand_builtin_ui_component_templates(&mut world);
+    app.update();
 
-    let indicator = crate::find_template_part::<crate::PartCheckboxIndicator>(&world, checkbox)
-        .expect("checkbox indicator part should be expanded");
-    let label = crate::find_template_part::<crate::PartCheckboxLabel>(&world, checkbox)
-        .expect("checkbox label part should be expanded");
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::AltLeft,
+        "",
+        ButtonState::Pressed,
+    );
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::KeyF,
+        "f",
+        ButtonState::Pressed,
+    );
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::KeyF,
+        "f",
+        ButtonState::Released,
+    );
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::AltLeft,
+        "",
+        ButtonState::Released,
+    );
+    app.update();
 
-    assert_eq!(
-        world
-            .get::<crate::UiLabel>(indicator)
-            .expect("indicator label should exist")
-            .text,
-        "☐"
+    let file = app
+        .world()
+        .get::<crate::UiMenuBarItem>(file_menu)
+        .expect("file menu should exist");
+    assert!(file.is_open);
+    assert_eq!(file.highlighted, Some(0));
+    assert!(
+        !app.world()
+            .get::<crate::UiMenuBarItem>(edit_menu)
+            .expect("edit menu should exist")
+            .is_open
+    );
+
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::ArrowDown,
+        "",
+        ButtonState::Pressed,
     );
+    app.update();
     assert_eq!(
-        world
-            .get::<crate::UiLabel>(label)
-            .expect("label part should have text")
-            .text,
-        "Receive updates"
+        app.world()
+            .get::<crate::UiMenuBarItem>(file_menu)
+            .and_then(|item| item.highlighted),
+        Some(1)
     );
 
-    world
-        .resource::<UiEventQueue>()
-        .push_typed(checkbox, crate::WidgetUiAction::ToggleCheckbox { checkbox });
-    crate::handle_widget_actions(&mut world);
-    crate::expand_builtin_ui_component_templates(&mut world);
-
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::ArrowRight,
+        "",
+        ButtonState::Pressed,
+    );
+    app.update();
     assert!(
-        world
-            .get::<crate::UiCheckbox>(checkbox)
-            .expect("checkbox should exist")
-            .checked
+        !app.world()
+            .get::<crate::UiMenuBarItem>(file_menu)
+            .unwrap()
+            .is_open
     );
-    assert_eq!(
-        world
-            .resource_mut::<UiEventQueue>()
-            .drain_actions::<crate::UiCheckboxChanged>()
-            .len(),
-        1
+    assert!(
+        app.world()
+            .get::<crate::UiMenuBarItem>(edit_menu)
+            .unwrap()
+            .is_open
     );
-    assert_eq!(
-        world
-            .get::<crate::UiLabel>(indicator)
-            .expect("indicator label should exist")
-            .text,
-        "☑"
+
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::Enter,
+        "",
+        ButtonState::Pressed,
+    );
+    app.update();
+    let selected = app
+        .world_mut()
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiMenuItemSelected>();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].action.value, "edit.undo");
+    assert!(
+        !app.world()
+            .get::<crate::UiMenuBarItem>(edit_menu)
+            .unwrap()
+            .is_open
     );
 }
 
 #[test]
-fn widget_actions_update_radio_group_selection() {
-    let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+fn typing_prefix_in_open_locale_combo_selects_matching_option() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    let group = world
-        .spawn((crate::UiRadioGroup::new(["Apple", "Banana", "Cherry"]),))
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let combo = app
+        .world_mut()
+        .spawn((
+            crate::UiComboBox::from_pairs([
+                ("en-US", "English"),
+                ("zh-CN", "简体中文"),
+                ("ja-JP", "日本語"),
+            ])
+            .with_selected(0),
+            ChildOf(root),
+        ))
         .id();
 
-    world.resource::<UiEventQueue>().push_typed(
-        group,
-        crate::WidgetUiAction::SelectRadioItem { group, index: 2 },
+    app.update();
+    app.world()
+        .resource::<UiEventQueue>()
+        .push_typed(combo, crate::OverlayUiAction::ToggleCombo);
+    app.update();
+    assert!(
+        app.world()
+            .get::<crate::UiComboBox>(combo)
+            .is_some_and(|combo| combo.is_open)
     );
 
-    crate::handle_widget_actions(&mut world);
-
-    assert_eq!(
-        world
-            .get::<crate::UiRadioGroup>(group)
-            .expect("radio group should exist")
-            .selected,
-        2
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::KeyJ,
+        "J",
+        ButtonState::Pressed,
     );
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::KeyA,
+        "a",
+        ButtonState::Pressed,
+    );
+    app.update();
 
-    let changed = world
+    let combo_box = app
+        .world()
+        .get::<crate::UiComboBox>(combo)
+        .expect("combo should exist");
+    assert_eq!(combo_box.selected, 2);
+    assert!(combo_box.is_open);
+
+    let changes = app
+        .world_mut()
         .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiRadioGroupChanged>();
-    assert_eq!(changed.len(), 1);
-    assert_eq!(changed[0].entity, group);
-    assert_eq!(changed[0].action.selected, 2);
+        .drain_actions::<crate::UiComboBoxChanged>();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].action.value, "ja-JP");
 }
 
-#[test]
-fn third_party_ui_component_can_register_via_trait_api() {
-    #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
-    struct UiKnob;
-
-    #[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
-    struct PartKnobIndicator;
+fn focus_table_cell_editor(app: &mut App, window_entity: Entity, table: Entity) {
+    let editor_center = {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let editor_id = find_widget_id_by_debug_text(
+            runtime.render_root.get_layer_root(0),
+            &crate::widgets::entity_debug_text(
+                Some(crate::widgets::TABLE_CELL_EDITOR_DEBUG_KIND),
+                table,
+            ),
+        )
+        .expect("table cell editor should be projected");
+        widget_center_for_widget_id(app, editor_id)
+    };
+    send_primary_click(app, window_entity, editor_center);
+}
 
-    impl crate::UiComponentTemplate for UiKnob {
-        fn expand(world: &mut World, entity: Entity) {
-            let _ = crate::ensure_template_part::<PartKnobIndicator, _>(world, entity, || {
-                (
-                    crate::UiLabel::new("○"),
-                    crate::StyleClass(vec!["template.knob.indicator".to_string()]),
-                )
-            });
-        }
+#[test]
+fn double_clicking_editable_table_cell_projects_input_and_enter_commits_edit() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-        fn project(_: &Self, _ctx: crate::ProjectionCtx<'_>) -> crate::UiView {
-            Arc::new(crate::xilem::view::label("knob"))
-        }
-    }
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
 
-    let mut app = App::new();
-    app.add_plugins(PicusPlugin)
-        .register_ui_component::<UiKnob>();
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let table = app
+        .world_mut()
+        .spawn((
+            crate::UiTable::new(["Name", "Score"])
+                .with_row(["Alice", "98"])
+                .with_editable_column(1),
+            ChildOf(root),
+        ))
+        .id();
 
-    let knob = app.world_mut().spawn((UiRoot, UiKnob)).id();
     app.update();
 
+    let count_text_inputs = |app: &App| {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let mut bounds = Vec::new();
+        collect_widget_bounds_by_short_name(
+            runtime.render_root.get_layer_root(0),
+            "TextInput",
+            &mut bounds,
+        );
+        bounds.len()
+    };
+    assert_eq!(count_text_inputs(&app), 0);
+
+    // Double-clicking a non-editable cell does nothing.
+    for _ in 0..2 {
+        app.world().resource::<UiEventQueue>().push_typed(
+            table,
+            crate::WidgetUiAction::ClickTableCell {
+                table,
+                row: 0,
+                col: 0,
+            },
+        );
+    }
+    app.update();
     assert!(
         app.world()
-            .resource::<crate::StyleTypeRegistry>()
-            .resolve("UiKnob")
-            .is_some()
+            .get::<crate::UiTable>(table)
+            .unwrap()
+            .editing
+            .is_none()
     );
 
-    assert!(crate::find_template_part::<PartKnobIndicator>(app.world(), knob).is_some());
+    for _ in 0..2 {
+        app.world().resource::<UiEventQueue>().push_typed(
+            table,
+            crate::WidgetUiAction::ClickTableCell {
+                table,
+                row: 0,
+                col: 1,
+            },
+        );
+    }
+    app.update();
+
+    let editing = app
+        .world()
+        .get::<crate::UiTable>(table)
+        .unwrap()
+        .editing
+        .clone();
+    assert_eq!(
+        editing,
+        Some(crate::UiTableCellEdit {
+            row: 0,
+            col: 1,
+            value: "98".to_string(),
+        })
+    );
+    assert_eq!(count_text_inputs(&app), 1);
+
+    app.world().resource::<UiEventQueue>().push_typed(
+        table,
+        crate::WidgetUiAction::SetTableCellDraft {
+            table,
+            value: "99".to_string(),
+        },
+    );
+    app.update();
+    focus_table_cell_editor(&mut app, window_entity, table);
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::Enter,
+        "",
+        ButtonState::Pressed,
+    );
+    app.update();
+
+    let edited = app
+        .world_mut()
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<crate::UiCellEdited>();
+    assert_eq!(edited.len(), 1);
+    assert_eq!(
+        edited[0].action,
+        crate::UiCellEdited {
+            table,
+            row: 0,
+            col: 1,
+            value: "99".to_string(),
+        }
+    );
+    let ui_table = app.world().get::<crate::UiTable>(table).unwrap();
+    assert_eq!(ui_table.rows[0][1], "99");
+    assert!(ui_table.editing.is_none());
+    assert_eq!(count_text_inputs(&app), 0);
 }
 
 #[test]
-fn scroll_view_template_expands_required_parts() {
-    let mut world = World::new();
-
-    let scroll_view = world.spawn((crate::UiScrollView::default(),)).id();
-    crate::expand_builtin_ui_component_templates(&mut world);
+fn escape_cancels_only_the_focused_table_cell_edit() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    assert!(crate::find_template_part::<crate::PartScrollViewport>(&world, scroll_view).is_some());
-    assert!(
-        crate::find_template_part::<crate::PartScrollBarVertical>(&world, scroll_view).is_some()
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
+        .id();
+    let spawn_editing_table = |app: &mut App| {
+        let mut ui_table = crate::UiTable::new(["Score"])
+            .with_row(["98"])
+            .with_editable_column(0);
+        assert!(ui_table.begin_edit(0, 0));
+        app.world_mut().spawn((ui_table, ChildOf(root))).id()
+    };
+    let table = spawn_editing_table(&mut app);
+    let other_table = spawn_editing_table(&mut app);
+
+    app.world().resource::<UiEventQueue>().push_typed(
+        table,
+        crate::WidgetUiAction::SetTableCellDraft {
+            table,
+            value: "12".to_string(),
+        },
     );
-    assert!(
-        crate::find_template_part::<crate::PartScrollThumbVertical>(&world, scroll_view).is_some()
+    app.update();
+    focus_table_cell_editor(&mut app, window_entity, table);
+    write_key(
+        &mut app,
+        window_entity,
+        KeyCode::Escape,
+        "",
+        ButtonState::Pressed,
     );
+    app.update();
+
+    let ui_table = app.world().get::<crate::UiTable>(table).unwrap();
+    assert!(ui_table.editing.is_none());
+    assert_eq!(ui_table.rows[0][0], "98");
     assert!(
-        crate::find_template_part::<crate::PartScrollBarHorizontal>(&world, scroll_view).is_some()
+        app.world()
+            .get::<crate::UiTable>(other_table)
+            .unwrap()
+            .editing
+            .is_some(),
+        "Escape must not reach editors that do not have focus"
     );
     assert!(
-        crate::find_template_part::<crate::PartScrollThumbHorizontal>(&world, scroll_view)
-            .is_some()
+        app.world_mut()
+            .resource_mut::<UiEventQueue>()
+            .drain_actions::<crate::UiCellEdited>()
+            .is_empty()
     );
 }
 
 #[test]
-fn drag_scroll_thumb_action_updates_scroll_view_offset() {
-    let mut world = World::new();
-    world.insert_resource(UiEventQueue::default());
+fn moving_focus_out_of_table_cell_editor_commits_edit() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
 
-    let scroll_view = world
-        .spawn((crate::UiScrollView {
-            scroll_offset: bevy_math::Vec2::ZERO,
-            content_size: bevy_math::Vec2::new(400.0, 1200.0),
-            viewport_size: bevy_math::Vec2::new(300.0, 200.0),
-            show_horizontal_scrollbar: false,
-            show_vertical_scrollbar: true,
-        },))
+    let mut window = Window::default();
+    window.resolution.set(900.0, 680.0);
+    let window_entity = app.world_mut().spawn((window, PrimaryWindow)).id();
+    let root = app
+        .world_mut()
+        .spawn((UiRoot, crate::UiFlexColumn::default()))
         .id();
-
-    crate::expand_builtin_ui_component_templates(&mut world);
-
-    let thumb = crate::find_template_part::<crate::PartScrollThumbVertical>(&world, scroll_view)
-        .expect("vertical thumb part should exist");
-
-    world.resource::<UiEventQueue>().push_typed(
-        thumb,
-        crate::WidgetUiAction::DragScrollThumb {
-            thumb,
-            axis: crate::ScrollAxis::Vertical,
-            delta_pixels: 18.0,
+    let spawn_editing_table = |app: &mut App| {
+        let mut ui_table = crate::UiTable::new(["Score"])
+            .with_row(["98"])
+            .with_editable_column(0);
+        assert!(ui_table.begin_edit(0, 0));
+        app.world_mut().spawn((ui_table, ChildOf(root))).id()
+    };
+    let table = spawn_editing_table(&mut app);
+    let other_table = spawn_editing_table(&mut app);
+
+    app.update();
+    focus_table_cell_editor(&mut app, window_entity, table);
+    app.world().resource::<UiEventQueue>().push_typed(
+        table,
+        crate::WidgetUiAction::SetTableCellDraft {
+            table,
+            value: "42".to_string(),
         },
     );
+    app.update();
 
-    crate::handle_widget_actions(&mut world);
-
-    let offset = world
-        .get::<crate::UiScrollView>(scroll_view)
-        .expect("scroll view should exist")
-        .scroll_offset;
-    assert!(offset.y > 0.0);
+    // Focusing the other table's editor blurs the first one.
+    focus_table_cell_editor(&mut app, window_entity, other_table);
+    app.update();
 
-    let changed = world
+    let edited = app
+        .world_mut()
         .resource_mut::<UiEventQueue>()
-        .drain_actions::<crate::UiScrollViewChanged>();
-    assert_eq!(changed.len(), 1);
-    assert_eq!(changed[0].entity, scroll_view);
+        .drain_actions::<crate::UiCellEdited>();
+    assert_eq!(edited.len(), 1);
+    assert_eq!(
+        edited[0].action,
+        crate::UiCellEdited {
+            table,
+            row: 0,
+            col: 0,
+            value: "42".to_string(),
+        }
+    );
+    let ui_table = app.world().get::<crate::UiTable>(table).unwrap();
+    assert_eq!(ui_table.rows[0][0], "42");
+    assert!(ui_table.editing.is_none());
+    assert!(
+        app.world()
+            .get::<crate::UiTable>(other_table)
+            .unwrap()
+            .editing
+            .is_some()
+    );
 }
 
 #[test]
-fn tooltip_hover_spawns_and_despawns_overlay_entity() {
+fn type_ahead_match_prefers_label_then_value_ignoring_case() {
+    let options = vec![
+        crate::UiComboOption::new("en-US", "English"),
+        crate::UiComboOption::new("es-ES", "Español"),
+        crate::UiComboOption::new("ja-JP", "日本語"),
+    ];
+
+    assert_eq!(crate::type_ahead_match(&options, "e"), Some(0));
+    assert_eq!(crate::type_ahead_match(&options, "ES"), Some(1));
+    assert_eq!(crate::type_ahead_match(&options, "ja"), Some(2));
+    assert_eq!(crate::type_ahead_match(&options, "日"), Some(2));
+    assert_eq!(crate::type_ahead_match(&options, "x"), None);
+    assert_eq!(crate::type_ahead_match(&options, ""), None);
+}
+
+#[test]
+fn confirm_dialog_emits_confirmed_result_when_confirm_is_clicked() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+    app.add_plugins(PicusPlugin::default());
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let source = app
-        .world_mut()
-        .spawn((
-            crate::UiButton::new("Hover me"),
-            crate::HasTooltip::new("Tooltip text"),
-            crate::InteractionState {
-                hovered: true,
-                pressed: false,
-            },
-            ChildOf(root),
-        ))
-        .id();
+    let handle = crate::spawn_dialog_with_result::<()>(
+        app.world_mut(),
+        crate::UiDialog::new("Delete file?", "This cannot be undone."),
+    );
+    app.update();
+
+    {
+        let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
+        let layer_root = runtime.render_root.get_layer_root(0);
+        assert!(find_widget_id_by_debug_text(layer_root, "OK").is_some());
+        assert!(find_widget_id_by_debug_text(layer_root, "Cancel").is_some());
+    }
 
+    app.world()
+        .resource::<UiEventQueue>()
+        .push_typed(handle.dialog, crate::OverlayUiAction::ConfirmDialog);
     app.update();
 
-    let mut tooltip_query = app.world_mut().query::<(
-        Entity,
-        &crate::UiTooltip,
-        &crate::OverlayState,
-        &crate::OverlayConfig,
-    )>();
-    let spawned_tooltips = tooltip_query
-        .iter(app.world())
-        .filter_map(|(entity, tooltip, state, config)| {
-            (tooltip.anchor == source
-                && state.anchor == Some(source)
-                && config.anchor == Some(source)
-                && config.placement == crate::OverlayPlacement::Top)
-                .then_some(entity)
-        })
-        .collect::<Vec<_>>();
+    let result = handle.poll(&mut app.world_mut().resource_mut::<UiEventQueue>());
+    assert_eq!(result, Some(crate::DialogResult::Confirmed(())));
+    assert!(app.world().get_entity(handle.dialog).is_err());
+}
 
-    assert_eq!(spawned_tooltips.len(), 1);
+#[test]
+fn prompt_dialog_reports_input_text_and_cancel() {
+    let mut app = App::new();
+    app.add_plugins(PicusPlugin::default());
+
+    let prompt = crate::spawn_dialog_with_result::<String>(
+        app.world_mut(),
+        crate::UiDialog::new("Rename", "New name:").with_actions("Rename", "Cancel"),
+    );
+    let cancelled = crate::spawn_dialog_with_result::<String>(
+        app.world_mut(),
+        crate::UiDialog::new("Rename", "New name:"),
+    );
+    app.update();
 
+    let input = crate::find_template_part::<crate::PartDialogPrompt>(app.world(), prompt.dialog)
+        .expect("prompt dialog should own a text input part");
     app.world_mut()
-        .entity_mut(source)
-        .insert(crate::InteractionState {
-            hovered: false,
-            pressed: false,
-        });
+        .get_mut::<crate::UiTextInput>(input)
+        .expect("prompt part should be a text input")
+        .value = "notes.txt".to_string();
+
+    let queue = app.world().resource::<UiEventQueue>().clone();
+    queue.push_typed(prompt.dialog, crate::OverlayUiAction::ConfirmDialog);
+    queue.push_typed(cancelled.dialog, crate::OverlayUiAction::DismissDialog);
     app.update();
 
-    let mut tooltip_query = app.world_mut().query::<&crate::UiTooltip>();
-    assert!(
-        tooltip_query
-            .iter(app.world())
-            .all(|tooltip| tooltip.anchor != source)
+    let mut queue = app.world_mut().resource_mut::<UiEventQueue>();
+    assert_eq!(
+        cancelled.poll(&mut queue),
+        Some(crate::DialogResult::Cancelled)
+    );
+    assert_eq!(
+        prompt.poll(&mut queue),
+        Some(crate::DialogResult::Confirmed("notes.txt".to_string()))
     );
+    assert_eq!(prompt.poll(&mut queue), None);
 }
 
 #[test]
-fn scroll_view_geometry_sync_clamps_out_of_bounds_offset() {
+fn default_dialog_buttons_use_localized_labels_with_english_fallback() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
-    let mut window = Window::default();
-    window.resolution.set(900.0, 640.0);
-    app.world_mut().spawn((window, PrimaryWindow));
+    app.add_plugins(PicusPlugin::default())
+        .register_i18n_bundle(
+            "de-DE",
+            SyncTextSource::String(
+                "picus-dialog-confirm = Bestätigen\npicus-dialog-cancel = Abbrechen\n",
+            ),
+            vec![],
+        );
+    app.world_mut()
+        .resource_mut::<AppI18n>()
+        .set_active_locale("de-DE".parse().expect("valid locale"));
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let scroll_view = app
-        .world_mut()
-        .spawn((
-            crate::UiScrollView {
-                scroll_offset: bevy_math::Vec2::new(4_000.0, 4_000.0),
-                content_size: bevy_math::Vec2::new(320.0, 10_000.0),
-                viewport_size: bevy_math::Vec2::new(320.0, 220.0),
-                show_horizontal_scrollbar: false,
-                show_vertical_scrollbar: true,
-            },
-            ChildOf(root),
-        ))
-        .id();
+    let localized = crate::spawn_dialog_with_result::<()>(
+        app.world_mut(),
+        crate::UiDialog::new("Löschen?", "Endgültig."),
+    );
+    let dialog = app
+        .world()
+        .get::<crate::UiDialog>(localized.dialog)
+        .expect("dialog component");
+    assert_eq!(dialog.confirm_label.as_deref(), Some("Bestätigen"));
+    assert_eq!(dialog.cancel_label.as_deref(), Some("Abbrechen"));
 
-    app.world_mut().spawn((
-        crate::UiLabel::new("Only one line of content"),
-        ChildOf(scroll_view),
-    ));
+    app.world_mut()
+        .resource_mut::<AppI18n>()
+        .set_active_locale("fr-FR".parse().expect("valid locale"));
+    let fallback = crate::spawn_dialog_with_result::<()>(
+        app.world_mut(),
+        crate::UiDialog::new("Supprimer ?", "Définitivement."),
+    );
+    let dialog = app
+        .world()
+        .get::<crate::UiDialog>(fallback.dialog)
+        .expect("dialog component");
+    assert_eq!(dialog.confirm_label.as_deref(), Some("OK"));
+    assert_eq!(dialog.cancel_label.as_deref(), Some("Cancel"));
+}
 
-    app.update();
-    app.update();
+#[test]
+fn dialog_handle_poll_keeps_other_results_in_queue_order() {
+    let mut world = World::new();
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+    let third = world.spawn_empty().id();
+    let handle = crate::DialogHandle::<()>::new(second);
 
-    let scroll = app
-        .world()
-        .get::<crate::UiScrollView>(scroll_view)
-        .expect("scroll view should exist");
+    let mut queue = UiEventQueue::default();
+    queue.push_typed(first, crate::DialogResult::<()>::Cancelled);
+    queue.push_typed(first, "unrelated");
+    queue.push_typed(second, crate::DialogResult::Confirmed(()));
+    queue.push_typed(third, crate::DialogResult::<()>::Confirmed(()));
 
-    let max_y = (scroll.content_size.y - scroll.viewport_size.y).max(0.0);
-    assert!(scroll.scroll_offset.x.abs() <= f32::EPSILON);
-    assert!(scroll.content_size.y < 2_000.0);
-    assert!(scroll.scroll_offset.y <= max_y + f32::EPSILON);
+    assert_eq!(
+        handle.poll(&mut queue),
+        Some(crate::DialogResult::Confirmed(()))
+    );
+    assert_eq!(handle.poll(&mut queue), None);
+
+    let remaining = queue.drain_all();
+    assert_eq!(remaining.len(), 3);
+    assert_eq!(remaining[0].entity, first);
+    assert!(remaining[0].action.is::<crate::DialogResult<()>>());
+    assert_eq!(remaining[1].entity, first);
+    assert!(remaining[1].action.is::<&str>());
+    assert_eq!(remaining[2].entity, third);
 }
 
 #[test]
-fn scroll_view_geometry_sync_expands_viewport_width_to_parent_width() {
-    let mut app = App::new();
-    app.add_plugins(PicusPlugin);
+fn teleport_moves_subtree_to_overlay_root_and_restores_original_parent() {
+    let mut world = World::new();
+    let root = world.spawn((UiRoot, crate::UiFlexColumn::default())).id();
+    let before = world
+        .spawn((crate::UiLabel::new("before"), ChildOf(root)))
+        .id();
+    let panel = world
+        .spawn((crate::UiFlexColumn::default(), ChildOf(root)))
+        .id();
+    let panel_child = world
+        .spawn((crate::UiLabel::new("panel body"), ChildOf(panel)))
+        .id();
+    let after = world
+        .spawn((crate::UiLabel::new("after"), ChildOf(root)))
+        .id();
 
-    let mut window = Window::default();
-    window.resolution.set(900.0, 640.0);
-    app.world_mut().spawn((window, PrimaryWindow));
+    world.entity_mut(panel).insert(crate::Teleport);
+    crate::sync_teleported_entities(&mut world);
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let scroll_view = app
-        .world_mut()
+    let overlay_root = ensure_overlay_root_entity(&mut world);
+    assert_eq!(
+        world.get::<ChildOf>(panel).map(ChildOf::parent),
+        Some(overlay_root)
+    );
+    assert_eq!(
+        world.get::<ChildOf>(panel_child).map(ChildOf::parent),
+        Some(panel)
+    );
+    assert_eq!(
+        world
+            .get::<Children>(root)
+            .map(|children| children.to_vec()),
+        Some(vec![before, after])
+    );
+
+    world.entity_mut(panel).remove::<crate::Teleport>();
+    crate::sync_teleported_entities(&mut world);
+
+    assert_eq!(world.get::<ChildOf>(panel).map(ChildOf::parent), Some(root));
+    assert_eq!(
+        world
+            .get::<Children>(root)
+            .map(|children| children.to_vec()),
+        Some(vec![before, panel, after])
+    );
+    assert!(world.get::<crate::TeleportOrigin>(panel).is_none());
+}
+
+#[test]
+fn despawning_original_parent_despawns_teleported_subtree() {
+    let mut world = World::new();
+    let root = world.spawn((UiRoot, crate::UiFlexColumn::default())).id();
+    let section = world
+        .spawn((crate::UiFlexColumn::default(), ChildOf(root)))
+        .id();
+    let panel = world
         .spawn((
-            crate::UiScrollView {
-                scroll_offset: bevy_math::Vec2::ZERO,
-                content_size: bevy_math::Vec2::new(200.0, 1_200.0),
-                viewport_size: bevy_math::Vec2::new(200.0, 220.0),
-                show_horizontal_scrollbar: false,
-                show_vertical_scrollbar: true,
-            },
+            crate::UiFlexColumn::default(),
+            crate::Teleport,
+            ChildOf(section),
+        ))
+        .id();
+    let panel_child = world
+        .spawn((crate::UiLabel::new("panel body"), ChildOf(panel)))
+        .id();
+    let untouched = world
+        .spawn((
+            crate::UiFlexColumn::default(),
+            crate::Teleport,
             ChildOf(root),
         ))
         .id();
 
-    app.world_mut().spawn((
-        crate::UiLabel::new("A short row that should not dictate viewport width"),
-        ChildOf(scroll_view),
-    ));
-
-    app.update();
-    app.update();
+    crate::sync_teleported_entities(&mut world);
+    let overlay_root = ensure_overlay_root_entity(&mut world);
+    assert_eq!(
+        world.get::<ChildOf>(panel).map(ChildOf::parent),
+        Some(overlay_root)
+    );
 
-    let scroll = app
-        .world()
-        .get::<crate::UiScrollView>(scroll_view)
-        .expect("scroll view should exist");
+    world.entity_mut(section).despawn();
+    crate::sync_teleported_entities(&mut world);
 
+    assert!(world.get_entity(panel).is_err());
+    assert!(world.get_entity(panel_child).is_err());
+    assert_eq!(
+        world.get::<ChildOf>(untouched).map(ChildOf::parent),
+        Some(overlay_root)
+    );
     assert!(
-        scroll.viewport_size.x > 400.0,
-        "viewport width should stretch beyond the initial seed width, got {}",
-        scroll.viewport_size.x
+        world
+            .get::<Children>(overlay_root)
+            .is_some_and(|children| !children.contains(&panel))
     );
-    assert_eq!(scroll.viewport_size.y, 220.0);
 }
 
 #[test]
-fn scroll_view_left_aligns_narrow_content_after_viewport_stretch() {
+fn runtime_clears_with_bottom_root_background_color() {
     let mut app = App::new();
-    app.add_plugins(PicusPlugin);
-
-    let mut window = Window::default();
-    window.resolution.set(900.0, 640.0);
-    app.world_mut().spawn((window, PrimaryWindow));
+    app.add_plugins(PicusPlugin::default());
 
-    let root = app.world_mut().spawn((UiRoot, crate::UiFlexColumn)).id();
-    let scroll_view = app
-        .world_mut()
-        .spawn((
-            crate::UiScrollView {
-                scroll_offset: bevy_math::Vec2::ZERO,
-                content_size: bevy_math::Vec2::new(200.0, 600.0),
-                viewport_size: bevy_math::Vec2::new(200.0, 220.0),
-                show_horizontal_scrollbar: false,
-                show_vertical_scrollbar: true,
-            },
-            ChildOf(root),
-        ))
-        .id();
+    app.update();
+    assert_eq!(
+        app.world()
+            .non_send_resource::<crate::MasonryRuntime>()
+            .clear_color(),
+        crate::DEFAULT_CLEAR_COLOR
+    );
 
+    let canvas = crate::xilem::Color::from_rgb8(0x20, 0x24, 0x30);
     app.world_mut().spawn((
-        crate::UiLabel::new("Left aligned scroll content"),
-        ChildOf(scroll_view),
+        UiRoot,
+        crate::UiFlexColumn::default(),
+        crate::RootBackground(canvas),
     ));
-
-    app.update();
+    crate::ensure_overlay_root_entity(app.world_mut());
     app.update();
 
-    let runtime = app.world().non_send_resource::<crate::MasonryRuntime>();
-    let scroll_widget_id = runtime
-        .find_widget_id_for_entity_bits(scroll_view.to_bits(), true)
-        .or_else(|| runtime.find_widget_id_for_entity_bits(scroll_view.to_bits(), false))
-        .expect("scroll view should resolve to a Masonry widget");
-    let label_widget_id = find_widget_id_by_debug_text(
-        runtime.render_root.get_layer_root(0),
-        "Left aligned scroll content",
-    )
-    .expect("label widget should exist in render tree");
-
-    let scroll_widget = runtime
-        .render_root
-        .get_widget(scroll_widget_id)
-        .expect("scroll widget id should resolve");
-    let label_widget = runtime
-        .render_root
-        .get_widget(label_widget_id)
-        .expect("label widget id should resolve");
-
-    let scroll_x = scroll_widget.ctx().window_origin().x;
-    let label_x = label_widget.ctx().window_origin().x;
-
-    assert!(
-        (label_x - scroll_x).abs() <= 4.0,
-        "scroll content should start at the viewport left edge, got scroll_x={scroll_x}, label_x={label_x}"
+    assert_eq!(
+        app.world()
+            .non_send_resource::<crate::MasonryRuntime>()
+            .clear_color(),
+        canvas
     );
 }
@@ -0,0 +1,89 @@
+use std::marker::PhantomData;
+
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use xilem_masonry::{Pod, ViewCtx, WidgetView};
+
+use crate::widgets::ClipBoxWidget;
+
+/// Clip `child` to its layout bounds with the given corner radius.
+#[must_use]
+pub fn clip_box<Child, State, Action>(
+    corner_radius: f64,
+    child: Child,
+) -> ClipBoxView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+{
+    ClipBoxView {
+        corner_radius,
+        child,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`clip_box`].
+pub struct ClipBoxView<Child, State, Action> {
+    corner_radius: f64,
+    child: Child,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Child, State, Action> ViewMarker for ClipBoxView<Child, State, Action> {}
+
+impl<Child, State, Action> View<State, Action, ViewCtx> for ClipBoxView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<ClipBoxWidget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx, app_state);
+        (
+            ctx.create_pod(ClipBoxWidget::new(self.corner_radius, child.new_widget)),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.corner_radius != prev.corner_radius {
+            ClipBoxWidget::set_corner_radius(&mut element, self.corner_radius);
+        }
+
+        let mut child = ClipBoxWidget::child_mut(&mut element);
+        self.child
+            .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = ClipBoxWidget::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut child = ClipBoxWidget::child_mut(&mut element);
+        self.child
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
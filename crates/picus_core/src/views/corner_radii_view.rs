@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use xilem_masonry::{Pod, ViewCtx, WidgetView};
+
+use crate::widgets::{CornerRadiiPaint, CornerRadiiWidget};
+
+/// Paint `child`'s box decoration with per-corner radii, or pass it through when `paint`
+/// is `None`.
+#[must_use]
+pub fn corner_radii_box<Child, State, Action>(
+    paint: Option<CornerRadiiPaint>,
+    child: Child,
+) -> CornerRadiiView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+{
+    CornerRadiiView {
+        paint,
+        child,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`corner_radii_box`].
+pub struct CornerRadiiView<Child, State, Action> {
+    paint: Option<CornerRadiiPaint>,
+    child: Child,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Child, State, Action> ViewMarker for CornerRadiiView<Child, State, Action> {}
+
+impl<Child, State, Action> View<State, Action, ViewCtx> for CornerRadiiView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<CornerRadiiWidget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx, app_state);
+        (
+            ctx.create_pod(CornerRadiiWidget::new(self.paint.clone(), child.new_widget)),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.paint != prev.paint {
+            CornerRadiiWidget::set_paint(&mut element, self.paint.clone());
+        }
+
+        let mut child = CornerRadiiWidget::child_mut(&mut element);
+        self.child
+            .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = CornerRadiiWidget::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut child = CornerRadiiWidget::child_mut(&mut element);
+        self.child
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
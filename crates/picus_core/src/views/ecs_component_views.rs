@@ -5,7 +5,7 @@ use masonry::{
     core::{ArcStr, NewWidget, PointerButton, PropertySet},
     parley::Alignment as TextAlign,
     parley::StyleProperty,
-    parley::style::FontStack,
+    parley::style::{FontStack, FontStyle},
     properties::{CheckmarkColor, ContentColor, DisabledContentColor, PlaceholderColor},
     widgets::{self, CheckboxToggled, RadioButtonSelected, TextAction},
 };
@@ -14,6 +14,7 @@ use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
 use xilem_masonry::view::{Button, Label, Slider, Switch, slider, switch, text_button};
 use xilem_masonry::{Pod, ViewCtx};
 
+use crate::components::{InputFilter, masked_text, unmask_edit};
 use crate::events::emit_ui_action;
 
 /// ECS-dispatching variant of `xilem_masonry::view::text_button`.
@@ -418,6 +419,7 @@ where
         entity,
         contents,
         map_action: Box::new(map_action),
+        on_enter: None,
         text_color: None,
         disabled_text_color: None,
         placeholder_color: None,
@@ -425,8 +427,11 @@ where
         text_alignment: TextAlign::default(),
         text_size: masonry::theme::TEXT_SIZE_NORMAL,
         font: FontStack::List(Cow::Borrowed(&[])),
+        font_style: FontStyle::Normal,
         disabled: false,
         clip: true,
+        filter: None,
+        mask: None,
     }
 }
 
@@ -438,6 +443,7 @@ pub struct EcsTextInputView<A> {
     entity: Entity,
     contents: String,
     map_action: EcsTextInputCallback<A>,
+    on_enter: Option<EcsTextInputCallback<A>>,
     text_color: Option<Color>,
     disabled_text_color: Option<Color>,
     placeholder_color: Option<Color>,
@@ -445,8 +451,11 @@ pub struct EcsTextInputView<A> {
     text_alignment: TextAlign,
     text_size: f32,
     font: FontStack<'static>,
+    font_style: FontStyle,
     disabled: bool,
     clip: bool,
+    filter: Option<InputFilter>,
+    mask: Option<char>,
 }
 
 impl<A> EcsTextInputView<A>
@@ -479,6 +488,11 @@ where
         self
     }
 
+    pub fn font_style(mut self, font_style: FontStyle) -> Self {
+        self.font_style = font_style;
+        self
+    }
+
     pub fn text_color(mut self, color: Color) -> Self {
         self.text_color = Some(color);
         self
@@ -498,6 +512,35 @@ where
         self.clip = clip;
         self
     }
+
+    /// Emit `on_enter(text)` when Enter is pressed while this input has focus.
+    pub fn on_enter<F>(mut self, on_enter: F) -> Self
+    where
+        F: Fn(String) -> A + Send + Sync + 'static,
+    {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Filter each edit before the change action is emitted.
+    pub fn filter(mut self, filter: Option<InputFilter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Display `mask` for every character while actions carry the real text.
+    pub fn mask(mut self, mask: Option<char>) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Text shown by the widget for `value`.
+    fn display_text(&self, value: &str) -> String {
+        match self.mask {
+            Some(mask) => masked_text(value, mask),
+            None => value.to_string(),
+        }
+    }
 }
 
 impl<A> ViewMarker for EcsTextInputView<A> where A: Send + Sync + 'static {}
@@ -510,10 +553,11 @@ where
     type ViewState = ();
 
     fn build(&self, ctx: &mut ViewCtx, _: &mut ()) -> (Self::Element, Self::ViewState) {
-        let text_area = widgets::TextArea::new_editable(&self.contents)
+        let text_area = widgets::TextArea::new_editable(&self.display_text(&self.contents))
             .with_text_alignment(self.text_alignment)
             .with_style(StyleProperty::FontSize(self.text_size))
-            .with_style(StyleProperty::FontStack(self.font.clone()));
+            .with_style(StyleProperty::FontStack(self.font.clone()))
+            .with_style(StyleProperty::FontStyle(self.font_style));
 
         let mut props = PropertySet::new();
         if let Some(color) = self.text_color {
@@ -586,8 +630,11 @@ where
 
         let mut text_area = widgets::TextInput::text_mut(&mut element);
 
-        if self.contents != prev.contents && text_area.widget.text() != &self.contents {
-            widgets::TextArea::reset_text(&mut text_area, &self.contents);
+        let display = self.display_text(&self.contents);
+        if (self.contents != prev.contents || self.mask != prev.mask)
+            && text_area.widget.text() != &display
+        {
+            widgets::TextArea::reset_text(&mut text_area, &display);
         }
 
         if self.text_size != prev.text_size {
@@ -602,6 +649,12 @@ where
                 StyleProperty::FontStack(self.font.clone()),
             );
         }
+        if self.font_style != prev.font_style {
+            widgets::TextArea::insert_style(
+                &mut text_area,
+                StyleProperty::FontStyle(self.font_style),
+            );
+        }
     }
 
     fn teardown(
@@ -617,7 +670,7 @@ where
         &self,
         (): &mut Self::ViewState,
         message: &mut MessageCtx,
-        _: Mut<'_, Self::Element>,
+        mut element: Mut<'_, Self::Element>,
         _: &mut (),
     ) -> MessageResult<()> {
         debug_assert!(
@@ -627,10 +680,36 @@ where
         match message.take_message::<TextAction>() {
             Some(action) => match *action {
                 TextAction::Changed(text) => {
-                    emit_ui_action(self.entity, (self.map_action)(text));
+                    let mut value = match self.mask {
+                        Some(mask) => unmask_edit(&self.contents, &text, mask),
+                        None => text.clone(),
+                    };
+                    if let Some(filter) = &self.filter {
+                        value = filter.apply(&self.contents, &value);
+                    }
+                    // Keep the widget in sync when filtering dropped characters or the
+                    // user typed plain text into a masked field.
+                    let display = self.display_text(&value);
+                    if display != text {
+                        let mut text_area = widgets::TextInput::text_mut(&mut element);
+                        widgets::TextArea::reset_text(&mut text_area, &display);
+                    }
+                    if value != self.contents {
+                        emit_ui_action(self.entity, (self.map_action)(value));
+                    }
                     MessageResult::Action(())
                 }
-                TextAction::Entered(_) => MessageResult::Stale,
+                TextAction::Entered(text) => match &self.on_enter {
+                    Some(on_enter) => {
+                        let value = match self.mask {
+                            Some(mask) => unmask_edit(&self.contents, &text, mask),
+                            None => text.clone(),
+                        };
+                        emit_ui_action(self.entity, on_enter(value));
+                        MessageResult::Action(())
+                    }
+                    None => MessageResult::Stale,
+                },
             },
             None => MessageResult::Stale,
         }
@@ -753,4 +832,37 @@ mod tests {
             assert_eq!(text_area.widget.text(), "synced");
         });
     }
+
+    #[test]
+    fn masked_text_input_renders_mask_but_keeps_real_contents() {
+        let entity = World::new().spawn_empty().id();
+        let prev = ecs_text_input(entity, "secret".to_string(), |_: String| ()).mask(Some('•'));
+        let next = ecs_text_input(entity, "secret!".to_string(), |_: String| ()).mask(Some('•'));
+        assert_eq!(next.contents, "secret!");
+
+        let mut view_ctx = test_view_ctx();
+        let (element, mut view_state) =
+            <EcsTextInputView<()> as View<(), (), ViewCtx>>::build(&prev, &mut view_ctx, &mut ());
+        let mut render_root = test_render_root(element);
+
+        render_root.edit_base_layer(|mut root| {
+            let mut input = root.downcast::<widgets::TextInput>();
+            {
+                let text_area = widgets::TextInput::text_mut(&mut input);
+                assert_eq!(text_area.widget.text(), "••••••");
+            }
+
+            <EcsTextInputView<()> as View<(), (), ViewCtx>>::rebuild(
+                &next,
+                &prev,
+                &mut view_state,
+                &mut view_ctx,
+                input.reborrow_mut(),
+                &mut (),
+            );
+
+            let text_area = widgets::TextInput::text_mut(&mut input);
+            assert_eq!(text_area.widget.text(), "•••••••");
+        });
+    }
 }
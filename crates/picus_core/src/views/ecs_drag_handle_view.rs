@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::entity::Entity;
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use xilem_masonry::{Pod, ViewCtx, WidgetView};
+
+use crate::{
+    FloatingPanelHandle,
+    widgets::{EcsDragHandleWidget, EcsDragHandleWidgetAction},
+};
+
+const DRAG_HANDLE_CONTENT_VIEW_ID: ViewId = ViewId::new(0);
+
+/// Make `child` a drag handle that moves or resizes a [`UiFloatingPanel`](crate::UiFloatingPanel).
+#[must_use]
+pub fn ecs_drag_handle<Child>(
+    panel: Entity,
+    handle: FloatingPanelHandle,
+    child: Child,
+) -> EcsDragHandleView<Child>
+where
+    Child: WidgetView<(), ()>,
+{
+    EcsDragHandleView {
+        panel,
+        handle,
+        child,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`ecs_drag_handle`].
+#[must_use = "View values do nothing unless returned into the synthesized UI tree."]
+pub struct EcsDragHandleView<Child> {
+    panel: Entity,
+    handle: FloatingPanelHandle,
+    child: Child,
+    phantom: PhantomData<fn()>,
+}
+
+impl<Child> ViewMarker for EcsDragHandleView<Child> {}
+
+impl<Child> View<(), (), ViewCtx> for EcsDragHandleView<Child>
+where
+    Child: WidgetView<(), ()>,
+{
+    type Element = Pod<EcsDragHandleWidget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut ()) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(DRAG_HANDLE_CONTENT_VIEW_ID, |ctx| {
+            self.child.build(ctx, app_state)
+        });
+        let element = ctx.with_action_widget(|ctx| {
+            ctx.create_pod(EcsDragHandleWidget::new(
+                self.panel,
+                self.handle,
+                child.new_widget,
+            ))
+        });
+        (element, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut (),
+    ) {
+        if self.panel != prev.panel {
+            EcsDragHandleWidget::set_panel(&mut element, self.panel);
+        }
+        if self.handle != prev.handle {
+            EcsDragHandleWidget::set_handle(&mut element, self.handle);
+        }
+
+        ctx.with_id(DRAG_HANDLE_CONTENT_VIEW_ID, |ctx| {
+            let mut child = EcsDragHandleWidget::child_mut(&mut element);
+            self.child
+                .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(DRAG_HANDLE_CONTENT_VIEW_ID, |ctx| {
+            let mut child = EcsDragHandleWidget::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child.downcast());
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut (),
+    ) -> MessageResult<()> {
+        match message.take_first() {
+            Some(DRAG_HANDLE_CONTENT_VIEW_ID) => {
+                let mut child = EcsDragHandleWidget::child_mut(&mut element);
+                self.child
+                    .message(view_state, message, child.downcast(), app_state)
+            }
+            None => match message.take_message::<EcsDragHandleWidgetAction>() {
+                Some(_) => MessageResult::Action(()),
+                None => MessageResult::Stale,
+            },
+            _ => MessageResult::Stale,
+        }
+    }
+}
@@ -17,6 +17,7 @@ where
 {
     EntityScopeView {
         entity,
+        remount_key: None,
         child,
         phantom: PhantomData,
     }
@@ -25,10 +26,24 @@ where
 /// Wrap a child view with an entity-bound Masonry widget scope.
 pub struct EntityScopeView<Child, State, Action> {
     entity: Entity,
+    remount_key: Option<u64>,
     child: Child,
     phantom: PhantomData<fn() -> (State, Action)>,
 }
 
+impl<Child, State, Action> EntityScopeView<Child, State, Action> {
+    /// Remount the wrapped child whenever `key` changes.
+    ///
+    /// A different key tears the old child down and builds a new widget; an
+    /// unchanged key leaves the usual in-place rebuild alone. See
+    /// [`UiRemountKey`](crate::UiRemountKey) for what the key does not do.
+    #[must_use]
+    pub fn with_remount_key(mut self, key: Option<u64>) -> Self {
+        self.remount_key = key;
+        self
+    }
+}
+
 impl<Child, State, Action> ViewMarker for EntityScopeView<Child, State, Action> {}
 
 impl<Child, State, Action> View<State, Action, ViewCtx> for EntityScopeView<Child, State, Action>
@@ -60,6 +75,17 @@ where
             EntityScopeWidget::set_entity(&mut element, self.entity);
         }
 
+        if self.remount_key != prev.remount_key {
+            {
+                let mut child = EntityScopeWidget::child_mut(&mut element);
+                prev.child.teardown(view_state, ctx, child.downcast());
+            }
+            let (child, child_state) = self.child.build(ctx, app_state);
+            EntityScopeWidget::set_child(&mut element, child.new_widget);
+            *view_state = child_state;
+            return;
+        }
+
         let mut child = EntityScopeWidget::child_mut(&mut element);
         self.child
             .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
@@ -0,0 +1,71 @@
+use masonry::{
+    parley::{StyleProperty, style::FontStyle},
+    widgets,
+};
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use xilem_masonry::{Pod, ViewCtx, view::Label};
+
+/// Give `label` the parley `font_style`, which Xilem's [`Label`] view has no setter for.
+#[must_use]
+pub fn label_font_style(font_style: FontStyle, label: Label) -> LabelFontStyleView {
+    LabelFontStyleView { font_style, label }
+}
+
+/// The [`View`] created by [`label_font_style`].
+pub struct LabelFontStyleView {
+    font_style: FontStyle,
+    label: Label,
+}
+
+impl ViewMarker for LabelFontStyleView {}
+
+impl View<(), (), ViewCtx> for LabelFontStyleView {
+    type Element = Pod<widgets::Label>;
+    type ViewState = <Label as View<(), (), ViewCtx>>::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut ()) -> (Self::Element, Self::ViewState) {
+        let (mut pod, view_state) = self.label.build(ctx, app_state);
+        let label = std::mem::replace(&mut *pod.new_widget.widget, widgets::Label::new(""));
+        *pod.new_widget.widget = label.with_style(StyleProperty::FontStyle(self.font_style));
+        (pod, view_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut (),
+    ) {
+        self.label.rebuild(
+            &prev.label,
+            view_state,
+            ctx,
+            element.reborrow_mut(),
+            app_state,
+        );
+        if self.font_style != prev.font_style {
+            widgets::Label::insert_style(&mut element, StyleProperty::FontStyle(self.font_style));
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        self.label.teardown(view_state, ctx, element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut (),
+    ) -> MessageResult<()> {
+        self.label.message(view_state, message, element, app_state)
+    }
+}
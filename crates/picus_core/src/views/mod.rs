@@ -20,14 +20,23 @@
 //! let _ecs_adapted = button(entity, (), "ECS event button");
 //! let _raw_xilem = xilem_button::<(), (), _, _>(label("Raw xilem button"), |_| ());
 //! ```
+mod clip_box_view;
+mod corner_radii_view;
 mod ecs_button_view;
 mod ecs_button_with_child_view;
 mod ecs_component_views;
+mod ecs_drag_handle_view;
 mod ecs_drag_thumb_view;
 mod entity_scope_view;
+mod label_font_style_view;
 mod opaque_hitbox_view;
 mod scroll_portal_view;
+mod size_bounds_view;
+mod spinner_arc_view;
+mod table_cell_editor_view;
 
+pub use clip_box_view::{ClipBoxView, clip_box};
+pub use corner_radii_view::{CornerRadiiView, corner_radii_box};
 pub use ecs_button_view::ecs_button as button;
 pub use ecs_button_view::{EcsButtonView, ecs_button};
 pub use ecs_button_with_child_view::ecs_button_with_child as button_with_child;
@@ -41,10 +50,15 @@ pub use ecs_component_views::ecs_text_input as text_input;
 pub use ecs_component_views::{
     ecs_checkbox, ecs_slider, ecs_switch, ecs_text_button, ecs_text_input,
 };
+pub use ecs_drag_handle_view::{EcsDragHandleView, ecs_drag_handle};
 pub use ecs_drag_thumb_view::{EcsDragThumbView, ecs_drag_thumb};
 pub use entity_scope_view::entity_scope;
+pub use label_font_style_view::{LabelFontStyleView, label_font_style};
 pub use opaque_hitbox_view::{OpaqueHitboxView, opaque_hitbox, opaque_hitbox_for_entity};
 pub use scroll_portal_view::{ScrollPortalView, scroll_portal};
+pub use size_bounds_view::{SizeBoundsView, size_bounds};
+pub use spinner_arc_view::{SpinnerArcView, spinner_arc};
+pub(crate) use table_cell_editor_view::table_cell_editor;
 pub use xilem_masonry::view::{
     badge as xilem_badge, badge_count as xilem_badge_count, badge_text as xilem_badge_text,
     button as xilem_button, button_any_pointer as xilem_button_any_pointer,
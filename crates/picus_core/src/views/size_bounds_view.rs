@@ -0,0 +1,95 @@
+use std::marker::PhantomData;
+
+use masonry::kurbo::Size;
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use xilem_masonry::{Pod, ViewCtx, WidgetView};
+
+use crate::widgets::SizeBoundsWidget;
+
+/// Clamp `child`'s measured size between `min` and `max`.
+///
+/// Use `0.0` and `f64::INFINITY` for axes without a bound.
+#[must_use]
+pub fn size_bounds<Child, State, Action>(
+    min: Size,
+    max: Size,
+    child: Child,
+) -> SizeBoundsView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+{
+    SizeBoundsView {
+        min,
+        max,
+        child,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`size_bounds`].
+pub struct SizeBoundsView<Child, State, Action> {
+    min: Size,
+    max: Size,
+    child: Child,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Child, State, Action> ViewMarker for SizeBoundsView<Child, State, Action> {}
+
+impl<Child, State, Action> View<State, Action, ViewCtx> for SizeBoundsView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<SizeBoundsWidget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx, app_state);
+        (
+            ctx.create_pod(SizeBoundsWidget::new(self.min, self.max, child.new_widget)),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.min != prev.min || self.max != prev.max {
+            SizeBoundsWidget::set_bounds(&mut element, self.min, self.max);
+        }
+
+        let mut child = SizeBoundsWidget::child_mut(&mut element);
+        self.child
+            .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = SizeBoundsWidget::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut child = SizeBoundsWidget::child_mut(&mut element);
+        self.child
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
@@ -0,0 +1,96 @@
+use xilem::Color;
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use xilem_masonry::{Pod, ViewCtx};
+
+use crate::widgets::SpinnerArcWidget;
+
+/// Sized spinner ring that paints a stroked arc.
+#[must_use = "View values do nothing unless returned into the synthesized UI tree."]
+pub struct SpinnerArcView {
+    diameter: f64,
+    thickness: f64,
+    start_angle: f64,
+    sweep: f64,
+    color: Color,
+}
+
+/// Build a spinner ring of `diameter` pixels whose arc starts at `start_angle`
+/// and spans `sweep` radians.
+pub fn spinner_arc(
+    diameter: f64,
+    thickness: f64,
+    start_angle: f64,
+    sweep: f64,
+    color: Color,
+) -> SpinnerArcView {
+    SpinnerArcView {
+        diameter,
+        thickness,
+        start_angle,
+        sweep,
+        color,
+    }
+}
+
+impl ViewMarker for SpinnerArcView {}
+
+impl View<(), (), ViewCtx> for SpinnerArcView {
+    type Element = Pod<SpinnerArcWidget>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _app_state: &mut ()) -> (Self::Element, Self::ViewState) {
+        (
+            ctx.create_pod(SpinnerArcWidget::new(
+                self.diameter,
+                self.thickness,
+                self.start_angle,
+                self.sweep,
+                self.color,
+            )),
+            (),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        _view_state: &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _app_state: &mut (),
+    ) {
+        if self.diameter != prev.diameter {
+            SpinnerArcWidget::set_diameter(&mut element, self.diameter);
+        }
+
+        if self.thickness != prev.thickness {
+            SpinnerArcWidget::set_thickness(&mut element, self.thickness);
+        }
+
+        if self.start_angle != prev.start_angle || self.sweep != prev.sweep {
+            SpinnerArcWidget::set_arc(&mut element, self.start_angle, self.sweep);
+        }
+
+        if self.color != prev.color {
+            SpinnerArcWidget::set_color(&mut element, self.color);
+        }
+    }
+
+    fn teardown(
+        &self,
+        _view_state: &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        _element: Mut<'_, Self::Element>,
+    ) {
+    }
+
+    fn message(
+        &self,
+        _view_state: &mut Self::ViewState,
+        _message: &mut MessageCtx,
+        _element: Mut<'_, Self::Element>,
+        _app_state: &mut (),
+    ) -> MessageResult<()> {
+        MessageResult::Stale
+    }
+}
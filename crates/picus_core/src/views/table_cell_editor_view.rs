@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::entity::Entity;
+use xilem_core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use xilem_masonry::{Pod, ViewCtx, WidgetView};
+
+use crate::widgets::TableCellEditorWidget;
+
+/// Wrap the inline editor of `table` so Escape cancels and blur commits the edit.
+#[must_use]
+pub(crate) fn table_cell_editor<Child, State, Action>(
+    table: Entity,
+    child: Child,
+) -> TableCellEditorView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+{
+    TableCellEditorView {
+        table,
+        child,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`table_cell_editor`].
+pub(crate) struct TableCellEditorView<Child, State, Action> {
+    table: Entity,
+    child: Child,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Child, State, Action> ViewMarker for TableCellEditorView<Child, State, Action> {}
+
+impl<Child, State, Action> View<State, Action, ViewCtx>
+    for TableCellEditorView<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<TableCellEditorWidget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx, app_state);
+        (
+            ctx.create_pod(TableCellEditorWidget::new(self.table, child.new_widget)),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.table != prev.table {
+            TableCellEditorWidget::set_table(&mut element, self.table);
+        }
+
+        let mut child = TableCellEditorWidget::child_mut(&mut element);
+        self.child
+            .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = TableCellEditorWidget::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut child = TableCellEditorWidget::child_mut(&mut element);
+        self.child
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
@@ -1,19 +1,32 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
 use bevy_ecs::{entity::Entity, hierarchy::ChildOf, message::MessageReader, prelude::*};
-use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_input::{
+    ButtonInput,
+    keyboard::KeyCode,
+    mouse::{MouseScrollUnit, MouseWheel},
+};
 use bevy_math::Vec2;
 use bevy_time::Time;
+use bevy_tween::{
+    bevy_time_runner::{Repeat, RepeatStyle, TimeContext, TimeRunner, TimeSpan},
+    tween::ComponentTween,
+};
 use bevy_window::{PrimaryWindow, Window};
 use masonry::core::{Widget, WidgetRef};
 
 use crate::{
-    AnchoredTo, AutoDismiss, HasTooltip, InteractionState, MasonryRuntime, OverlayAnchorRect,
-    OverlayComputedPosition, OverlayConfig, OverlayPlacement, OverlayState, ScrollAxis, UiCheckbox,
-    UiCheckboxChanged, UiOverlayRoot, UiRadioGroup, UiRadioGroupChanged, UiScrollView,
-    UiScrollViewChanged, UiSlider, UiSliderChanged, UiSwitch, UiSwitchChanged, UiTabBar,
-    UiTabChanged, UiTextInput, UiTextInputChanged, UiTooltip, UiTreeNode, UiTreeNodeToggled,
-    events::UiEventQueue,
+    AnchoredTo, AnimatedCollapse, AutoDismiss, FloatingPanelHandle, HasTooltip, InteractionState,
+    MasonryRuntime, OverlayAnchorRect, OverlayComputedPosition, OverlayConfig, OverlayPlacement,
+    OverlayState, PartGroupBoxTitleAction, ScrollAxis, Selected, Selection, SelectionChanged,
+    SelectionModifiers, StickyHeader, StickyHeaderLayout, StyleAnimationDefaults, StyleDirty,
+    TABLE_DOUBLE_CLICK_SECS, UiCellEdited, UiCheckbox, UiCheckboxChanged, UiCollapseHeight,
+    UiCollapseHeightLens, UiFloatingPanel, UiFloatingPanelChanged, UiGroupBox, UiGroupBoxToggled,
+    UiOverlayRoot, UiRadioGroup, UiRadioGroupChanged, UiScrollView, UiScrollViewChanged, UiSlider,
+    UiSliderChanged, UiSpinner, UiSpinnerRotation, UiSpinnerRotationLens, UiSwitch,
+    UiSwitchChanged, UiTabBar, UiTabChanged, UiTable, UiTextInput, UiTextInputChanged, UiTooltip,
+    UiTreeNode, UiTreeNodeToggled, UiVirtualGrid, UiVirtualGridLayout, events::UiEventQueue,
+    sticky_header_offset, widgets::parse_entity_debug_text,
 };
 
 /// Internal action enum for non-overlay widget interactions.
@@ -28,6 +41,8 @@ pub enum WidgetUiAction {
     SelectTab { bar: Entity, index: usize },
     /// Expand or collapse a tree node.
     ToggleTreeNode { node: Entity },
+    /// Collapse or expand a collapsible group box.
+    ToggleGroupBox { group_box: Entity },
     /// Toggle a checkbox.
     ToggleCheckbox { checkbox: Entity },
     /// Set a checkbox to an explicit checked state.
@@ -40,12 +55,51 @@ pub enum WidgetUiAction {
     ToggleSwitch { switch: Entity },
     /// Update text input contents.
     SetTextInput { input: Entity, value: String },
+    /// Click an item of a [`Selection`] container using the live keyboard modifiers.
+    ClickSelectionItem { list: Entity, item: Entity },
+    /// Click an item of a [`Selection`] container with explicit modifiers.
+    SelectItem {
+        list: Entity,
+        item: Entity,
+        modifiers: SelectionModifiers,
+    },
+    /// Click a [`UiTable`] cell; a second click within [`TABLE_DOUBLE_CLICK_SECS`] starts editing it.
+    ClickTableCell {
+        table: Entity,
+        row: usize,
+        col: usize,
+    },
+    /// Update the draft text of the table's in-progress cell edit.
+    SetTableCellDraft { table: Entity, value: String },
+    /// Write the draft back to the cell and emit [`UiCellEdited`].
+    CommitTableCellEdit { table: Entity },
+    /// Discard the table's in-progress cell edit.
+    CancelTableCellEdit { table: Entity },
     /// Drag an ECS scroll-thumb by a physical pixel delta.
     DragScrollThumb {
         thumb: Entity,
         axis: ScrollAxis,
         delta_pixels: f64,
     },
+    /// Move or resize a [`UiFloatingPanel`] by a logical pixel delta.
+    DragFloatingPanel {
+        panel: Entity,
+        handle: FloatingPanelHandle,
+        delta: (f64, f64),
+    },
+}
+
+/// Logical size of the primary window (or any window when none is primary).
+fn primary_window_logical_size(world: &mut World) -> Option<(f64, f64)> {
+    let mut primary = world.query_filtered::<&Window, With<PrimaryWindow>>();
+    let window = match primary.iter(world).next() {
+        Some(window) => window,
+        None => {
+            let mut any = world.query::<&Window>();
+            any.iter(world).next()?
+        }
+    };
+    Some((f64::from(window.width()), f64::from(window.height())))
 }
 
 const SCROLLBAR_MIN_THUMB: f64 = 24.0;
@@ -114,16 +168,7 @@ fn find_ancestor_scroll_view(world: &World, mut entity: Entity) -> Option<Entity
 }
 
 fn parse_entity_bits_from_debug(debug: &str) -> Option<u64> {
-    if let Some(bits) = debug.strip_prefix("opaque_hitbox_entity=") {
-        return bits.parse::<u64>().ok();
-    }
-    if let Some(bits) = debug.strip_prefix("entity_scope=") {
-        return bits.parse::<u64>().ok();
-    }
-    if let Some(bits) = debug.strip_prefix("entity=") {
-        return bits.parse::<u64>().ok();
-    }
-    None
+    parse_entity_debug_text(debug).map(|(_, bits)| bits)
 }
 
 fn collect_scroll_view_targets_from_hit_path(
@@ -245,6 +290,256 @@ pub fn sync_scroll_view_layout_geometry(
     }
 }
 
+/// Synchronize [`UiVirtualGridLayout`] from each grid's live Masonry width.
+///
+/// Grids that have not been laid out yet fall back to the runtime viewport
+/// width, so the column count follows window resizes automatically.
+pub fn sync_virtual_grid_layouts(
+    mut commands: Commands,
+    runtime: Option<NonSend<MasonryRuntime>>,
+    mut grids: Query<(Entity, &UiVirtualGrid, Option<&mut UiVirtualGridLayout>)>,
+) {
+    let Some(runtime) = runtime else {
+        return;
+    };
+
+    for (entity, grid, layout) in &mut grids {
+        let available_width = runtime
+            .find_widget_id_for_entity_bits(entity.to_bits(), false)
+            .and_then(|widget_id| runtime.render_root.get_widget(widget_id))
+            .map(|widget| widget.ctx().border_box_size().width)
+            .filter(|width| *width > 1.0)
+            .unwrap_or_else(|| runtime.viewport_size().0);
+        let next = UiVirtualGridLayout {
+            available_width,
+            columns: grid.column_count(available_width),
+        };
+
+        match layout {
+            Some(mut layout) => {
+                if *layout != next {
+                    *layout = next;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(next);
+            }
+        }
+    }
+}
+
+fn portal_content_origin_y(widget: WidgetRef<'_, dyn Widget>) -> Option<f64> {
+    if widget.ctx().is_stashed() {
+        return None;
+    }
+
+    if widget.short_type_name() == "Portal" {
+        return widget
+            .children()
+            .into_iter()
+            .find(|child| !child.ctx().is_stashed() && child.short_type_name() != "ScrollBar")
+            .map(|child| child.ctx().window_origin().y);
+    }
+
+    widget
+        .children()
+        .into_iter()
+        .find_map(portal_content_origin_y)
+}
+
+/// Read back [`StickyHeader`] section geometry from Masonry.
+///
+/// Positions are converted to scroll-content space and the previous frame's
+/// pin translation is subtracted, so `section_top` reflects natural layout.
+pub fn measure_sticky_headers(
+    mut commands: Commands,
+    runtime: Option<NonSend<MasonryRuntime>>,
+    scroll_views: Query<(Entity, &Children), With<UiScrollView>>,
+    mut headers: Query<Option<&mut StickyHeaderLayout>, With<StickyHeader>>,
+) {
+    let Some(runtime) = runtime else {
+        return;
+    };
+
+    for (scroll_entity, children) in &scroll_views {
+        if !children.iter().any(|child| headers.contains(child)) {
+            continue;
+        }
+
+        let content_origin_y = runtime
+            .find_widget_id_for_entity_bits(scroll_entity.to_bits(), false)
+            .and_then(|widget_id| runtime.render_root.get_widget(widget_id))
+            .and_then(portal_content_origin_y);
+        let Some(content_origin_y) = content_origin_y else {
+            continue;
+        };
+
+        for child in children.iter() {
+            let Ok(layout) = headers.get_mut(child) else {
+                continue;
+            };
+
+            let Some(header_widget) = runtime
+                .find_widget_id_for_entity_bits(child.to_bits(), false)
+                .and_then(|widget_id| runtime.render_root.get_widget(widget_id))
+            else {
+                continue;
+            };
+
+            let previous_offset = layout.as_ref().map_or(0.0, |layout| layout.pinned_offset);
+            let window_y = header_widget.ctx().window_origin().y as f32;
+            let section_top = window_y - previous_offset - content_origin_y as f32;
+            let header_height = header_widget.ctx().border_box_size().height as f32;
+
+            match layout {
+                Some(mut layout) => {
+                    if layout.section_top != section_top || layout.header_height != header_height {
+                        layout.section_top = section_top;
+                        layout.header_height = header_height;
+                    }
+                }
+                None => {
+                    commands.entity(child).insert(StickyHeaderLayout {
+                        section_top,
+                        header_height,
+                        pinned_offset: 0.0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Recompute [`StickyHeaderLayout::pinned_offset`] from each scroll view's offset.
+pub fn update_sticky_header_offsets(
+    scroll_views: Query<(&UiScrollView, &Children)>,
+    mut headers: Query<&mut StickyHeaderLayout, With<StickyHeader>>,
+) {
+    for (scroll_view, children) in &scroll_views {
+        let sections = children
+            .iter()
+            .filter_map(|child| headers.get(child).ok().map(|layout| (child, *layout)))
+            .collect::<Vec<_>>();
+
+        for (index, (entity, layout)) in sections.iter().enumerate() {
+            let next_section_top = sections.get(index + 1).map(|(_, next)| next.section_top);
+            let pinned_offset = sticky_header_offset(
+                scroll_view.scroll_offset.y,
+                layout.section_top,
+                layout.header_height,
+                next_section_top,
+            );
+
+            if let Ok(mut header) = headers.get_mut(*entity)
+                && header.pinned_offset != pinned_offset
+            {
+                header.pinned_offset = pinned_offset;
+            }
+        }
+    }
+}
+
+fn apply_selection_click(
+    world: &mut World,
+    list: Entity,
+    item: Entity,
+    modifiers: SelectionModifiers,
+) {
+    if world.get_entity(list).is_err() || world.get_entity(item).is_err() {
+        return;
+    }
+
+    let order = world
+        .get::<Children>(list)
+        .map(|children| children.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let changed = world
+        .get_mut::<Selection>(list)
+        .is_some_and(|mut selection| selection.apply_click(item, &order, modifiers));
+    if !changed {
+        return;
+    }
+
+    let Some(selected_set) = world
+        .get::<Selection>(list)
+        .map(|selection| selection.selected.clone())
+    else {
+        return;
+    };
+
+    for child in &order {
+        let should_select = selected_set.contains(child);
+        let is_selected = world.get::<Selected>(*child).is_some();
+        if should_select == is_selected {
+            continue;
+        }
+
+        let mut entity = world.entity_mut(*child);
+        if should_select {
+            entity.insert((Selected, StyleDirty));
+        } else {
+            entity.remove::<Selected>();
+            entity.insert(StyleDirty);
+        }
+    }
+
+    let selected = order
+        .into_iter()
+        .filter(|child| selected_set.contains(child))
+        .collect::<Vec<_>>();
+    world
+        .resource::<UiEventQueue>()
+        .push_typed(list, SelectionChanged { list, selected });
+}
+
+/// Last table cell click, used to recognise double-clicks.
+#[derive(Resource, Default)]
+struct TableCellClickTracker {
+    last: Option<(Entity, usize, usize, f64)>,
+}
+
+fn apply_table_cell_click(world: &mut World, table: Entity, row: usize, col: usize) {
+    let now = world
+        .get_resource::<Time>()
+        .map_or(0.0, |time| time.elapsed_secs_f64());
+    let mut tracker = world.get_resource_or_init::<TableCellClickTracker>();
+    let is_double_click = tracker
+        .last
+        .is_some_and(|(last_table, last_row, last_col, at)| {
+            (last_table, last_row, last_col) == (table, row, col)
+                && now - at <= TABLE_DOUBLE_CLICK_SECS
+        });
+    tracker.last = (!is_double_click).then_some((table, row, col, now));
+    if !is_double_click {
+        return;
+    }
+
+    // Starting a new edit commits the one in progress, mirroring focus moving away.
+    commit_table_cell_edit(world, table);
+    if let Some(mut ui_table) = world.get_mut::<UiTable>(table) {
+        ui_table.begin_edit(row, col);
+    }
+}
+
+fn commit_table_cell_edit(world: &mut World, table: Entity) {
+    let Some(edit) = world
+        .get_mut::<UiTable>(table)
+        .and_then(|mut ui_table| ui_table.commit_edit())
+    else {
+        return;
+    };
+    world.resource::<UiEventQueue>().push_typed(
+        table,
+        UiCellEdited {
+            table,
+            row: edit.row,
+            col: edit.col,
+            value: edit.value,
+        },
+    );
+}
+
 /// Consume [`WidgetUiAction`] entries from [`UiEventQueue`] and apply the
 /// corresponding state mutations.
 ///
@@ -313,6 +608,46 @@ pub fn handle_widget_actions(world: &mut World) {
                 }
             }
 
+            WidgetUiAction::ToggleGroupBox { group_box } => {
+                if world.get_entity(group_box).is_err() {
+                    continue;
+                }
+
+                let toggled = world
+                    .get::<UiGroupBox>(group_box)
+                    .filter(|group| group.collapsible)
+                    .map(|group| !group.collapsed);
+
+                if let Some(collapsed) = toggled {
+                    if let Some(mut group) = world.get_mut::<UiGroupBox>(group_box) {
+                        group.collapsed = collapsed;
+                    }
+                    world.resource::<UiEventQueue>().push_typed(
+                        group_box,
+                        UiGroupBoxToggled {
+                            group_box,
+                            collapsed,
+                        },
+                    );
+                }
+            }
+
+            WidgetUiAction::ClickSelectionItem { list, item } => {
+                let modifiers = world
+                    .get_resource::<ButtonInput<KeyCode>>()
+                    .map(SelectionModifiers::from_keyboard)
+                    .unwrap_or_default();
+                apply_selection_click(world, list, item, modifiers);
+            }
+
+            WidgetUiAction::SelectItem {
+                list,
+                item,
+                modifiers,
+            } => {
+                apply_selection_click(world, list, item, modifiers);
+            }
+
             WidgetUiAction::ToggleCheckbox { checkbox } => {
                 if world.get_entity(checkbox).is_err() {
                     continue;
@@ -427,6 +762,11 @@ pub fn handle_widget_actions(world: &mut World) {
                 }
 
                 if let Some(mut text_input) = world.get_mut::<UiTextInput>(input) {
+                    // Programmatic actions skip the widget, so enforce the filter here too.
+                    let value = match &text_input.filter {
+                        Some(filter) => filter.apply(&text_input.value, &value),
+                        None => value,
+                    };
                     text_input.value = value.clone();
                     world
                         .resource::<UiEventQueue>()
@@ -434,6 +774,64 @@ pub fn handle_widget_actions(world: &mut World) {
                 }
             }
 
+            WidgetUiAction::ClickTableCell { table, row, col } => {
+                if world.get_entity(table).is_err() {
+                    continue;
+                }
+                apply_table_cell_click(world, table, row, col);
+            }
+
+            WidgetUiAction::SetTableCellDraft { table, value } => {
+                if let Some(mut ui_table) = world.get_mut::<UiTable>(table)
+                    && let Some(edit) = ui_table.editing.as_mut()
+                {
+                    edit.value = value;
+                }
+            }
+
+            WidgetUiAction::CommitTableCellEdit { table } => {
+                if world.get_entity(table).is_err() {
+                    continue;
+                }
+                commit_table_cell_edit(world, table);
+            }
+
+            WidgetUiAction::CancelTableCellEdit { table } => {
+                if let Some(mut ui_table) = world.get_mut::<UiTable>(table) {
+                    ui_table.editing = None;
+                }
+            }
+
+            WidgetUiAction::DragFloatingPanel {
+                panel,
+                handle,
+                delta,
+            } => {
+                if world.get_entity(panel).is_err() {
+                    continue;
+                }
+
+                let bounds = primary_window_logical_size(world);
+                let changed = world
+                    .get_mut::<UiFloatingPanel>(panel)
+                    .and_then(|mut floating| {
+                        floating
+                            .apply_drag(handle, delta, bounds)
+                            .then(|| (floating.position, floating.size))
+                    });
+
+                if let Some((position, size)) = changed {
+                    world.resource::<UiEventQueue>().push_typed(
+                        panel,
+                        UiFloatingPanelChanged {
+                            panel,
+                            position,
+                            size,
+                        },
+                    );
+                }
+            }
+
             WidgetUiAction::DragScrollThumb {
                 thumb,
                 axis,
@@ -578,6 +976,213 @@ pub fn tick_auto_dismiss(
     }
 }
 
+/// Tween entity currently driving a group box's [`UiCollapseHeight`].
+#[derive(Component, Debug, Clone, Copy)]
+struct CollapseHeightTween(Entity);
+
+/// Height spanned by the projected body children of `group_box`, from the last layout.
+fn measure_group_box_body(world: &World, group_box: Entity) -> Option<f64> {
+    let runtime = world.get_non_send_resource::<MasonryRuntime>()?;
+    let children = world.get::<Children>(group_box)?;
+
+    let mut top = f64::INFINITY;
+    let mut bottom = f64::NEG_INFINITY;
+    for child in children.iter() {
+        if world.get::<PartGroupBoxTitleAction>(child).is_some() {
+            continue;
+        }
+        let Some(widget) = runtime
+            .find_widget_id_for_entity_bits(child.to_bits(), false)
+            .and_then(|widget_id| runtime.render_root.get_widget(widget_id))
+        else {
+            continue;
+        };
+        let y = widget.ctx().window_origin().y;
+        top = top.min(y);
+        bottom = bottom.max(y + widget.ctx().border_box_size().height);
+    }
+
+    (bottom > top).then_some(bottom - top)
+}
+
+fn restart_collapse_tween(world: &mut World, group_box: Entity, start: f64, end: f64, secs: f32) {
+    if let Some(CollapseHeightTween(previous)) =
+        world.get::<CollapseHeightTween>(group_box).copied()
+        && let Ok(previous) = world.get_entity_mut(previous)
+    {
+        previous.despawn();
+    }
+
+    let duration = Duration::from_secs_f32(secs.max(0.0));
+    let Ok(span) = TimeSpan::try_from(Duration::ZERO..duration) else {
+        world.entity_mut(group_box).remove::<CollapseHeightTween>();
+        if let Some(mut height) = world.get_mut::<UiCollapseHeight>(group_box) {
+            height.current = end;
+        }
+        return;
+    };
+    let ease = world
+        .get_resource::<StyleAnimationDefaults>()
+        .copied()
+        .unwrap_or_default()
+        .ease;
+
+    // The tween lives on its own entity so it never collides with the group box's
+    // style transition tween.
+    let tween = world
+        .spawn((
+            span,
+            ease,
+            ComponentTween::new_target(group_box, UiCollapseHeightLens { start, end }),
+            TimeRunner::new(duration),
+            TimeContext::<()>::default(),
+        ))
+        .id();
+    world
+        .entity_mut(group_box)
+        .insert(CollapseHeightTween(tween));
+}
+
+/// Drive [`UiCollapseHeight`] for group boxes with [`AnimatedCollapse`].
+///
+/// While a group box is expanded and settled, its body height is re-measured from
+/// the last layout. Toggling `collapsed` tweens the height from its current value to
+/// 0 or to the measured full height; finished tweens are despawned.
+pub fn animate_group_box_collapse(world: &mut World) {
+    let mut query = world.query::<(Entity, &UiGroupBox, &AnimatedCollapse)>();
+    let group_boxes = query
+        .iter(world)
+        .map(|(entity, group_box, animation)| {
+            (entity, group_box.is_body_visible(), animation.duration)
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, expanded, duration) in group_boxes {
+        let previous = world.get::<UiCollapseHeight>(entity).copied();
+        let mut height = previous.unwrap_or_default();
+
+        if !height.is_animating() {
+            height.current = height.target;
+            if let Some(CollapseHeightTween(tween)) =
+                world.get::<CollapseHeightTween>(entity).copied()
+            {
+                if let Ok(tween) = world.get_entity_mut(tween) {
+                    tween.despawn();
+                }
+                world.entity_mut(entity).remove::<CollapseHeightTween>();
+            }
+
+            if expanded && let Some(measured) = measure_group_box_body(world, entity) {
+                height.full = measured;
+                height.current = measured;
+                height.target = measured;
+            }
+        }
+
+        let desired = if expanded { height.full } else { 0.0 };
+        let restart = (desired - height.target).abs() > 0.5;
+        if restart {
+            height.target = desired;
+        }
+
+        if previous != Some(height) {
+            world.entity_mut(entity).insert(height);
+        }
+        if restart {
+            restart_collapse_tween(world, entity, height.current, desired, duration);
+        }
+    }
+}
+
+/// Looping tween entity driving a spinner's [`UiSpinnerRotation`], and the speed it was built for.
+#[derive(Component, Debug, Clone, Copy)]
+struct SpinnerRotationTween {
+    tween: Entity,
+    speed: f32,
+}
+
+/// Spinner whose rotation a tween entity drives, so orphaned tweens can be despawned.
+#[derive(Component, Debug, Clone, Copy)]
+struct SpinnerRotationTweenOf(Entity);
+
+/// Keep one looping rotation tween per indeterminate [`UiSpinner`].
+///
+/// Each tween turns the spinner once every `1 / speed` seconds and repeats forever.
+/// Changing `speed` restarts it from the current angle; determinate or stopped
+/// spinners lose their tween, and tweens of despawned spinners are despawned.
+pub fn sync_spinner_rotation_tweens(
+    mut commands: Commands,
+    spinners: Query<(
+        Entity,
+        &UiSpinner,
+        Option<&UiSpinnerRotation>,
+        Option<&SpinnerRotationTween>,
+    )>,
+    tweens: Query<(Entity, &SpinnerRotationTweenOf)>,
+) {
+    for (tween, SpinnerRotationTweenOf(spinner)) in &tweens {
+        if !spinners.contains(*spinner) {
+            commands.entity(tween).despawn();
+        }
+    }
+
+    for (entity, spinner, rotation, current) in &spinners {
+        let turn = spinner_turn(spinner);
+        let spinning = turn.is_some();
+        if current.is_some_and(|current| spinning && current.speed == spinner.speed) {
+            continue;
+        }
+        if let Some(current) = current {
+            commands.entity(current.tween).despawn();
+            commands.entity(entity).remove::<SpinnerRotationTween>();
+        }
+        if spinner.is_determinate() {
+            continue;
+        }
+
+        if rotation.is_none() {
+            commands.entity(entity).insert(UiSpinnerRotation::default());
+        }
+        let Some(turn) = turn else {
+            continue;
+        };
+        let Ok(span) = TimeSpan::try_from(Duration::ZERO..turn) else {
+            continue;
+        };
+        let start = rotation.map_or(0.0, |rotation| rotation.angle);
+        let end = start + std::f32::consts::TAU.copysign(spinner.speed);
+        // Like the collapse tween, this lives on its own entity so it never
+        // collides with the spinner's style transition tween.
+        let tween = commands
+            .spawn((
+                span,
+                ComponentTween::new_target(entity, UiSpinnerRotationLens { start, end }),
+                TimeRunner::new(turn)
+                    .with_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround))),
+                TimeContext::<()>::default(),
+                SpinnerRotationTweenOf(entity),
+            ))
+            .id();
+        commands.entity(entity).insert(SpinnerRotationTween {
+            tween,
+            speed: spinner.speed,
+        });
+    }
+}
+
+/// Duration of one full turn, or `None` when the spinner should stand still.
+///
+/// Non-finite speeds and speeds whose turn overflows or rounds to zero count as
+/// not spinning instead of panicking in [`Duration`] conversion.
+fn spinner_turn(spinner: &UiSpinner) -> Option<Duration> {
+    if spinner.is_determinate() || !spinner.speed.is_finite() || spinner.speed == 0.0 {
+        return None;
+    }
+    Duration::try_from_secs_f32(spinner.speed.abs().recip())
+        .ok()
+        .filter(|turn| !turn.is_zero())
+}
+
 /// Backward-compatible alias retained for existing call sites.
 pub fn tick_toasts(
     commands: Commands,
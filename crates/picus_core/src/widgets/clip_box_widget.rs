@@ -0,0 +1,114 @@
+use std::any::TypeId;
+
+use masonry::{
+    accesskit::{Node, Role},
+    core::{
+        AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesRef,
+        QueryCtx, RegisterCtx, UpdateCtx, Widget, WidgetMut, WidgetPod, WidgetRef,
+    },
+    kurbo::{Axis, Point, RoundedRect, Size},
+    layout::LenReq,
+};
+use vello::Scene;
+
+/// Wrapper that clips its child's painting and hit-testing to its own bounds.
+///
+/// Masonry clips painting to the rectangular layout box; `corner_radius` is
+/// additionally honored for hit-testing so pointer events in the cut-off
+/// corners fall through.
+pub struct ClipBoxWidget {
+    corner_radius: f64,
+    child: WidgetPod<dyn Widget>,
+}
+
+impl ClipBoxWidget {
+    #[must_use]
+    pub fn new(corner_radius: f64, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            corner_radius,
+            child: child.erased().to_pod(),
+        }
+    }
+
+    #[must_use]
+    pub fn corner_radius(&self) -> f64 {
+        self.corner_radius
+    }
+
+    pub fn set_corner_radius(this: &mut WidgetMut<'_, Self>, corner_radius: f64) {
+        this.widget.corner_radius = corner_radius;
+        this.ctx.request_layout();
+    }
+
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+impl Widget for ClipBoxWidget {
+    type Action = ();
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn find_widget_under_pointer<'c>(
+        &'c self,
+        ctx: QueryCtx<'c>,
+        pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        if ctx.is_stashed() {
+            return None;
+        }
+
+        let local_pos = ctx.window_transform().inverse() * pos;
+        let bounds = RoundedRect::from_rect(ctx.border_box(), self.corner_radius.max(0.0));
+        if !bounds.contains(local_pos) {
+            return None;
+        }
+
+        ctx.get(self.child.id()).find_widget_under_pointer(pos)
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!("clip_box radius={}", self.corner_radius))
+    }
+}
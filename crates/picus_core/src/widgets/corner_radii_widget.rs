@@ -0,0 +1,181 @@
+use std::any::TypeId;
+
+use masonry::{
+    accesskit::{Node, Role},
+    core::{
+        AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesRef,
+        QueryCtx, RegisterCtx, UpdateCtx, Widget, WidgetMut, WidgetPod, WidgetRef,
+    },
+    kurbo::{Affine, Axis, Point, Rect, RoundedRect, RoundedRectRadii, Size, Stroke},
+    layout::LenReq,
+    peniko::Fill,
+    properties::{Background, BoxShadow},
+};
+use vello::Scene;
+use xilem::Color;
+
+/// Box decoration painted with a different radius per corner.
+///
+/// Radii are in top-left, top-right, bottom-right, bottom-left order, matching
+/// [`LayoutStyle::corner_radii`](crate::LayoutStyle::corner_radii).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CornerRadiiPaint {
+    pub radii: [f64; 4],
+    pub background: Background,
+    pub border_color: Color,
+    pub border_width: f64,
+    pub box_shadow: Option<BoxShadow>,
+}
+
+impl CornerRadiiPaint {
+    fn rounded_rect(&self, rect: Rect) -> RoundedRect {
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii.map(|r| r.max(0.0));
+        RoundedRect::from_rect(
+            rect,
+            RoundedRectRadii::new(top_left, top_right, bottom_right, bottom_left),
+        )
+    }
+}
+
+/// Wrapper that paints its child's background, border and shadow with per-corner radii.
+///
+/// Masonry's [`CornerRadius`](masonry::properties::CornerRadius) rounds every corner
+/// alike, so boxes with `corner_radii` leave those properties unset and let this widget
+/// paint them underneath the child instead. Hit-testing follows the same rounded shape.
+/// Without a decoration the widget is a plain pass-through.
+pub struct CornerRadiiWidget {
+    paint: Option<CornerRadiiPaint>,
+    child: WidgetPod<dyn Widget>,
+}
+
+impl CornerRadiiWidget {
+    #[must_use]
+    pub fn new(paint: Option<CornerRadiiPaint>, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            paint,
+            child: child.erased().to_pod(),
+        }
+    }
+
+    #[must_use]
+    pub fn paint(&self) -> Option<&CornerRadiiPaint> {
+        self.paint.as_ref()
+    }
+
+    pub fn set_paint(this: &mut WidgetMut<'_, Self>, paint: Option<CornerRadiiPaint>) {
+        this.widget.paint = paint;
+        this.ctx.request_paint_only();
+    }
+
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+impl Widget for CornerRadiiWidget {
+    type Action = ();
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let Some(paint) = &self.paint else {
+            return;
+        };
+        let rect = ctx.size().to_rect();
+
+        if let Some(shadow) = &paint.box_shadow {
+            let max_radius = paint.radii.into_iter().fold(0.0, f64::max);
+            scene.draw_blurred_rounded_rect(
+                Affine::translate(shadow.offset.to_vec2()),
+                rect,
+                shadow.color,
+                max_radius,
+                shadow.blur_radius * 0.5,
+            );
+        }
+
+        let brush = paint.background.get_peniko_brush_for_rect(rect);
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            &brush,
+            None,
+            &paint.rounded_rect(rect),
+        );
+
+        if paint.border_width > 0.0 {
+            // Stroke inside the box so the border matches Masonry's inset borders.
+            let inset = rect.inset(-paint.border_width * 0.5);
+            scene.stroke(
+                &Stroke::new(paint.border_width),
+                Affine::IDENTITY,
+                paint.border_color,
+                None,
+                &paint.rounded_rect(inset),
+            );
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn find_widget_under_pointer<'c>(
+        &'c self,
+        ctx: QueryCtx<'c>,
+        pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        if ctx.is_stashed() {
+            return None;
+        }
+
+        if let Some(paint) = &self.paint {
+            let local_pos = ctx.window_transform().inverse() * pos;
+            if !paint.rounded_rect(ctx.border_box()).contains(local_pos) {
+                return None;
+            }
+        }
+
+        ctx.get(self.child.id()).find_widget_under_pointer(pos)
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        self.paint.as_ref().map(|paint| {
+            let [top_left, top_right, bottom_right, bottom_left] = paint.radii;
+            format!("corner_radii {top_left},{top_right},{bottom_right},{bottom_left}")
+        })
+    }
+}
@@ -267,6 +267,6 @@ where
     }
 
     fn get_debug_text(&self) -> Option<String> {
-        Some(format!("entity={}", self.entity.to_bits()))
+        Some(super::entity_debug_text(None, self.entity))
     }
 }
@@ -0,0 +1,178 @@
+use std::any::TypeId;
+
+use bevy_ecs::entity::Entity;
+use masonry::{
+    accesskit::{Node, Role},
+    core::{
+        AccessCtx, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+        PointerButton, PointerButtonEvent, PointerEvent, PointerState, PropertiesMut,
+        PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetMut, WidgetPod,
+    },
+    kurbo::{Axis, Point, Size},
+    layout::LenReq,
+};
+use vello::Scene;
+
+use crate::{
+    FloatingPanelHandle, WidgetUiAction,
+    events::{UiEvent, push_global_ui_event},
+};
+
+/// Internal action used to force Xilem driver ticks while a handle is dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcsDragHandleWidgetAction {
+    StateChanged,
+}
+
+/// Masonry widget that turns pointer drags over its child into 2D panel deltas.
+pub struct EcsDragHandleWidget {
+    panel: Entity,
+    handle: FloatingPanelHandle,
+    child: WidgetPod<dyn Widget>,
+    last_position: Option<Point>,
+}
+
+impl EcsDragHandleWidget {
+    #[must_use]
+    pub fn new(
+        panel: Entity,
+        handle: FloatingPanelHandle,
+        child: NewWidget<impl Widget + ?Sized>,
+    ) -> Self {
+        Self {
+            panel,
+            handle,
+            child: child.erased().to_pod(),
+            last_position: None,
+        }
+    }
+
+    pub fn set_panel(this: &mut WidgetMut<'_, Self>, panel: Entity) {
+        this.widget.panel = panel;
+    }
+
+    pub fn set_handle(this: &mut WidgetMut<'_, Self>, handle: FloatingPanelHandle) {
+        this.widget.handle = handle;
+    }
+
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    fn logical_position(state: &PointerState) -> Point {
+        let scale = state.scale_factor.max(f64::EPSILON);
+        Point::new(state.position.x / scale, state.position.y / scale)
+    }
+
+    fn push_drag_delta(&self, dx: f64, dy: f64) {
+        if dx.abs() <= f64::EPSILON && dy.abs() <= f64::EPSILON {
+            return;
+        }
+
+        push_global_ui_event(UiEvent::typed(
+            self.panel,
+            WidgetUiAction::DragFloatingPanel {
+                panel: self.panel,
+                handle: self.handle,
+                delta: (dx, dy),
+            },
+        ));
+    }
+}
+
+impl Widget for EcsDragHandleWidget {
+    type Action = EcsDragHandleWidgetAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent { button, state, .. }) => {
+                if matches!(button, Some(PointerButton::Primary)) {
+                    ctx.capture_pointer();
+                    ctx.set_handled();
+                    self.last_position = Some(Self::logical_position(state));
+                }
+            }
+            PointerEvent::Move(update) => {
+                if ctx.is_active() {
+                    let position = Self::logical_position(&update.current);
+                    if let Some(last) = self.last_position {
+                        self.push_drag_delta(position.x - last.x, position.y - last.y);
+                        ctx.submit_action::<Self::Action>(EcsDragHandleWidgetAction::StateChanged);
+                    }
+                    self.last_position = Some(position);
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { button, .. }) => {
+                if matches!(button, Some(PointerButton::Primary)) {
+                    self.last_position = None;
+                    ctx.submit_action::<Self::Action>(EcsDragHandleWidgetAction::StateChanged);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, _ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if matches!(
+            event,
+            Update::DisabledChanged(true) | Update::ActiveChanged(false)
+        ) {
+            self.last_position = None;
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        true
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        let kind = format!("drag_handle={:?}", self.handle);
+        Some(super::entity_debug_text(Some(&kind), self.panel))
+    }
+}
@@ -286,6 +286,6 @@ impl Widget for EcsDragThumbWidget {
     }
 
     fn get_debug_text(&self) -> Option<String> {
-        Some(format!("entity={}", self.entity.to_bits()))
+        Some(super::entity_debug_text(None, self.entity))
     }
 }
@@ -29,6 +29,13 @@ impl EntityScopeWidget {
         this.widget.entity = entity;
     }
 
+    /// Replace the wrapped child with a freshly built widget.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old = std::mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old);
+        this.ctx.children_changed();
+    }
+
     pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
         this.ctx.get_mut(&mut this.widget.child)
     }
@@ -114,6 +121,9 @@ impl Widget for EntityScopeWidget {
     }
 
     fn get_debug_text(&self) -> Option<String> {
-        Some(format!("entity_scope={}", self.entity.to_bits()))
+        Some(super::entity_debug_text(
+            Some(super::ENTITY_SCOPE_DEBUG_KIND),
+            self.entity,
+        ))
     }
 }
@@ -1,13 +1,61 @@
+use bevy_ecs::entity::Entity;
+
+mod clip_box_widget;
+mod corner_radii_widget;
 mod ecs_button_widget;
 mod ecs_button_with_child_widget;
+mod ecs_drag_handle_widget;
 mod ecs_drag_thumb_widget;
 mod entity_scope_widget;
 mod hit_transparent_widget;
 mod opaque_hitbox_widget;
+mod size_bounds_widget;
+mod spinner_arc_widget;
+mod table_cell_editor_widget;
 
+pub use clip_box_widget::ClipBoxWidget;
+pub use corner_radii_widget::{CornerRadiiPaint, CornerRadiiWidget};
 pub use ecs_button_widget::{EcsButtonWidget, EcsButtonWidgetAction};
 pub use ecs_button_with_child_widget::EcsButtonWithChildWidget;
+pub use ecs_drag_handle_widget::{EcsDragHandleWidget, EcsDragHandleWidgetAction};
 pub use ecs_drag_thumb_widget::{EcsDragThumbWidget, EcsDragThumbWidgetAction};
 pub use entity_scope_widget::EntityScopeWidget;
 pub use hit_transparent_widget::HitTransparentWidget;
 pub use opaque_hitbox_widget::OpaqueHitboxWidget;
+pub use size_bounds_widget::SizeBoundsWidget;
+pub use spinner_arc_widget::SpinnerArcWidget;
+pub use table_cell_editor_widget::TableCellEditorWidget;
+
+/// Debug-text kind of [`EntityScopeWidget`] bindings.
+pub const ENTITY_SCOPE_DEBUG_KIND: &str = "entity_scope";
+/// Debug-text kind of [`OpaqueHitboxWidget`] bindings.
+pub const OPAQUE_HITBOX_DEBUG_KIND: &str = "opaque_hitbox";
+/// Debug-text kind of [`TableCellEditorWidget`] bindings.
+pub const TABLE_CELL_EDITOR_DEBUG_KIND: &str = "table_cell_editor";
+
+/// Debug text binding an ECS-backed widget to its entity.
+///
+/// Every ECS-backed widget reports `entity={bits}`, prefixed with `{kind} ` when
+/// it needs to be told apart from action widgets (e.g. `entity_scope entity=42`),
+/// so tooling and tests can locate any widget by entity.
+#[must_use]
+pub fn entity_debug_text(kind: Option<&str>, entity: Entity) -> String {
+    match kind {
+        Some(kind) => format!("{kind} entity={}", entity.to_bits()),
+        None => format!("entity={}", entity.to_bits()),
+    }
+}
+
+/// Parse [`entity_debug_text`] output into `(kind, entity bits)`.
+///
+/// `kind` is empty for plain `entity={bits}` bindings.
+#[must_use]
+pub fn parse_entity_debug_text(debug: &str) -> Option<(&str, u64)> {
+    let (head, bits) = debug.rsplit_once("entity=")?;
+    let kind = if head.is_empty() {
+        head
+    } else {
+        head.strip_suffix(' ')?
+    };
+    Some((kind, bits.parse::<u64>().ok()?))
+}
@@ -138,7 +138,7 @@ impl Widget for OpaqueHitboxWidget {
 
     fn get_debug_text(&self) -> Option<String> {
         self.entity
-            .map(|entity| format!("opaque_hitbox_entity={}", entity.to_bits()))
+            .map(|entity| super::entity_debug_text(Some(super::OPAQUE_HITBOX_DEBUG_KIND), entity))
     }
 
     fn make_trace_span(&self, id: WidgetId) -> tracing::Span {
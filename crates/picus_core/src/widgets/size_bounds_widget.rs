@@ -0,0 +1,103 @@
+use std::any::TypeId;
+
+use masonry::{
+    accesskit::{Node, Role},
+    core::{
+        AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesRef,
+        RegisterCtx, UpdateCtx, Widget, WidgetMut, WidgetPod,
+    },
+    kurbo::{Axis, Point, Size},
+    layout::LenReq,
+};
+use vello::Scene;
+
+/// Wrapper that clamps its child's measured size between `min` and `max`.
+///
+/// Unlike a fixed [`Dim`](masonry::layout::Dim), content smaller than `max` keeps its
+/// natural size and content larger than `min` is not stretched. When the bounds cross,
+/// `min` wins.
+pub struct SizeBoundsWidget {
+    min: Size,
+    max: Size,
+    child: WidgetPod<dyn Widget>,
+}
+
+impl SizeBoundsWidget {
+    #[must_use]
+    pub fn new(min: Size, max: Size, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            min,
+            max,
+            child: child.erased().to_pod(),
+        }
+    }
+
+    pub fn set_bounds(this: &mut WidgetMut<'_, Self>, min: Size, max: Size) {
+        this.widget.min = min;
+        this.widget.max = max;
+        this.ctx.request_layout();
+    }
+
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    fn clamp(&self, axis: Axis, length: f64) -> f64 {
+        let (min, max) = match axis {
+            Axis::Horizontal => (self.min.width, self.max.width),
+            Axis::Vertical => (self.min.height, self.max.height),
+        };
+        length.min(max).max(min)
+    }
+}
+
+impl Widget for SizeBoundsWidget {
+    type Action = ();
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let cross_length = cross_length.map(|length| self.clamp(axis.cross(), length));
+        let length = ctx.redirect_measurement(&mut self.child, axis, cross_length);
+        self.clamp(axis, length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let child_size = Size::new(
+            self.clamp(Axis::Horizontal, size.width),
+            self.clamp(Axis::Vertical, size.height),
+        );
+        ctx.run_layout(&mut self.child, child_size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+}
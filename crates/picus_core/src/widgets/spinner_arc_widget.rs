@@ -0,0 +1,124 @@
+use std::any::TypeId;
+
+use masonry::{
+    accesskit::{Node, Role},
+    core::{
+        AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, PaintCtx, PropertiesRef, RegisterCtx,
+        UpdateCtx, Widget, WidgetMut,
+    },
+    kurbo::{Affine, Arc, Axis, Point, Size, Stroke},
+    layout::LenReq,
+};
+use vello::Scene;
+use xilem::Color;
+
+/// Leaf widget that paints a spinner ring as a stroked arc.
+///
+/// The arc starts at `start_angle` (radians, clockwise from 12 o'clock) and
+/// covers `sweep` radians. A faint full-circle track is painted underneath.
+pub struct SpinnerArcWidget {
+    diameter: f64,
+    thickness: f64,
+    start_angle: f64,
+    sweep: f64,
+    color: Color,
+    layout_size: Size,
+}
+
+impl SpinnerArcWidget {
+    #[must_use]
+    pub fn new(diameter: f64, thickness: f64, start_angle: f64, sweep: f64, color: Color) -> Self {
+        Self {
+            diameter: diameter.max(0.0),
+            thickness: thickness.max(0.0),
+            start_angle,
+            sweep,
+            color,
+            layout_size: Size::ZERO,
+        }
+    }
+
+    pub fn set_diameter(this: &mut WidgetMut<'_, Self>, diameter: f64) {
+        this.widget.diameter = diameter.max(0.0);
+        this.ctx.request_layout();
+        this.ctx.request_render();
+    }
+
+    pub fn set_thickness(this: &mut WidgetMut<'_, Self>, thickness: f64) {
+        this.widget.thickness = thickness.max(0.0);
+        this.ctx.request_render();
+    }
+
+    pub fn set_arc(this: &mut WidgetMut<'_, Self>, start_angle: f64, sweep: f64) {
+        this.widget.start_angle = start_angle;
+        this.widget.sweep = sweep;
+        this.ctx.request_render();
+    }
+
+    pub fn set_color(this: &mut WidgetMut<'_, Self>, color: Color) {
+        this.widget.color = color;
+        this.ctx.request_render();
+    }
+}
+
+impl Widget for SpinnerArcWidget {
+    type Action = ();
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        self.diameter
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        self.layout_size = size;
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = self.layout_size;
+        let radius = (size.width.min(size.height) - self.thickness) * 0.5;
+        if radius <= 0.0 || self.thickness <= 0.0 {
+            return;
+        }
+
+        let center = Point::new(size.width * 0.5, size.height * 0.5);
+        let stroke = Stroke::new(self.thickness);
+        let rgba = self.color.to_rgba8();
+        let track_color = Color::from_rgba8(rgba.r, rgba.g, rgba.b, rgba.a / 4);
+
+        let track = Arc::new(center, (radius, radius), 0.0, std::f64::consts::TAU, 0.0);
+        scene.stroke(&stroke, Affine::IDENTITY, track_color, None, &track);
+
+        if self.sweep.abs() > f64::EPSILON {
+            // kurbo measures angles from the +x axis; shift so 0 points up.
+            let start = self.start_angle - std::f64::consts::FRAC_PI_2;
+            let arc = Arc::new(center, (radius, radius), start, self.sweep, 0.0);
+            scene.stroke(&stroke, Affine::IDENTITY, self.color, None, &arc);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ProgressIndicator
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[])
+    }
+}
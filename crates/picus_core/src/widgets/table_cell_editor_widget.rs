@@ -0,0 +1,127 @@
+use std::any::TypeId;
+
+use bevy_ecs::entity::Entity;
+use masonry::{
+    accesskit::{Node, Role},
+    core::keyboard::{Key, KeyState, NamedKey},
+    core::{
+        AccessCtx, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+        PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetMut,
+        WidgetPod,
+    },
+    kurbo::{Axis, Point, Size},
+    layout::LenReq,
+};
+use vello::Scene;
+
+use crate::{
+    WidgetUiAction,
+    events::{UiEvent, push_global_ui_event},
+    widgets::{TABLE_CELL_EDITOR_DEBUG_KIND, entity_debug_text},
+};
+
+/// Wrapper around an inline table cell editor.
+///
+/// Escape pressed while focus is inside the editor cancels the edit, and
+/// focus leaving the editor commits it. Only the focused editor sees these
+/// events, so other tables with an edit in progress are left alone.
+pub struct TableCellEditorWidget {
+    table: Entity,
+    child: WidgetPod<dyn Widget>,
+}
+
+impl TableCellEditorWidget {
+    #[must_use]
+    pub fn new(table: Entity, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            table,
+            child: child.erased().to_pod(),
+        }
+    }
+
+    pub fn set_table(this: &mut WidgetMut<'_, Self>, table: Entity) {
+        this.widget.table = table;
+    }
+
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    fn push_action(&self, action: WidgetUiAction) {
+        push_global_ui_event(UiEvent::typed(self.table, action));
+    }
+}
+
+impl Widget for TableCellEditorWidget {
+    type Action = ();
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::Keyboard(event) = event
+            && event.state == KeyState::Down
+            && event.key == Key::Named(NamedKey::Escape)
+        {
+            self.push_action(WidgetUiAction::CancelTableCellEdit { table: self.table });
+            ctx.set_handled();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, _ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if matches!(event, Update::ChildFocusChanged(false)) {
+            // Committing is a no-op once Enter/Escape already ended the edit.
+            self.push_action(WidgetUiAction::CommitTableCellEdit { table: self.table });
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(entity_debug_text(
+            Some(TABLE_CELL_EDITOR_DEBUG_KIND),
+            self.table,
+        ))
+    }
+}
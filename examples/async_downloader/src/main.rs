@@ -557,7 +557,7 @@ fn build_async_downloader_app() -> App {
     init_logging();
 
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .load_style_sheet_ron(include_str!("../assets/themes/async_downloader.ron"))
         .insert_resource(DownloadState::default())
         .register_ui_component::<DownloadRootView>()
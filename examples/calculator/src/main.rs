@@ -501,7 +501,7 @@ fn build_bevy_calculator_app() -> App {
     init_logging();
 
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .load_style_sheet_ron(include_str!("../assets/themes/calculator.ron"))
         .insert_resource(CalculatorEngine::default())
         .register_ui_component::<CalcRoot>()
@@ -655,7 +655,7 @@ fn build_bevy_chess_app() -> App {
     let ui = ChessUiResource::from_game(&game);
 
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .load_style_sheet_ron(include_str!("../assets/themes/chess_game.ron"))
         .insert_resource(ChessGameResource::new(game))
         .insert_resource(ui)
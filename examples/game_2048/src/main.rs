@@ -1274,7 +1274,7 @@ fn build_2048_app() -> App {
     init_logging();
 
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .load_style_sheet_ron(include_str!("../assets/themes/game_2048.ron"))
         .insert_resource(ButtonInput::<KeyCode>::default())
         .insert_resource(GameViewport::default())
@@ -28,7 +28,7 @@ fn project_ui_toast(toast: &UiToast, _ctx: ProjectionCtx<'_>) -> UiView {
 picus_core::impl_ui_component_template!(UiToast, project_ui_toast);
 
 fn setup_overlay_hit_routing_world(mut commands: Commands) {
-    let root = commands.spawn((UiRoot, UiFlexColumn)).id();
+    let root = commands.spawn((UiRoot, UiFlexColumn::default())).id();
 
     commands.spawn((UiThemePicker::fluent(), ChildOf(root)));
 
@@ -97,7 +97,7 @@ fn build_overlay_hit_routing_app() -> App {
     init_logging();
 
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .load_style_sheet_ron(include_str!("../assets/themes/overlay_hit_routing.ron"))
         .register_ui_component::<UiToast>()
         .add_systems(Startup, setup_overlay_hit_routing_world)
@@ -81,7 +81,7 @@ pixiv-status-loading-manga = Loading Manga feed…
 pixiv-status-loading-novels = Loading Novels feed…
 pixiv-status-search-ready = Search panel ready.
 pixiv-status-search-keyword-required = Please enter a search keyword.
-pixiv-status-searching = Searching for
+pixiv-status-searching = Searching for ‘{ $query }’…
 pixiv-status-no-response-to-copy = Nothing to copy.
 pixiv-status-response-copied = Response copied to clipboard.
 pixiv-status-copy-failed = Clipboard copy failed
@@ -100,7 +100,16 @@ pixiv-status-idp-discovered = IdP endpoint discovered. Enter auth_code or refres
 pixiv-status-authenticated-loading-home = Authenticated. Loading home feed…
 pixiv-status-logged-out = Logged out. Saved Pixiv auth was cleared.
 pixiv-status-logout-persist-clear-failed = Logged out locally, but clearing saved auth failed
-pixiv-status-loaded-illustrations = Loaded illustrations:
+pixiv-status-loaded-illustrations = { $count ->
+        [0] No illustrations loaded ({ $source })
+        [one] Loaded { $count } illustration ({ $source })
+       *[other] Loaded { $count } illustrations ({ $source })
+    }
+pixiv-status-appended-illustrations = { $count ->
+        [0] No more illustrations ({ $source })
+        [one] Appended { $count } illustration ({ $source })
+       *[other] Appended { $count } illustrations ({ $source })
+    }
 pixiv-status-bookmark-synced = Bookmark synced for illust
 pixiv-status-network-error = Network error
 pixiv-status-response-detail-title = Response details
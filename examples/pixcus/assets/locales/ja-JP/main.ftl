@@ -81,7 +81,7 @@ pixiv-status-loading-manga = マンガフィードを読み込み中…
 pixiv-status-loading-novels = 小説フィードを読み込み中…
 pixiv-status-search-ready = 検索パネルの準備ができました。
 pixiv-status-search-keyword-required = 検索キーワードを入力してください。
-pixiv-status-searching = 検索中
+pixiv-status-searching = 「{ $query }」を検索中…
 pixiv-status-no-response-to-copy = コピーする内容がありません。
 pixiv-status-response-copied = レスポンスをクリップボードにコピーしました。
 pixiv-status-copy-failed = クリップボードへのコピーに失敗しました
@@ -100,7 +100,14 @@ pixiv-status-idp-discovered = IdP エンドポイントを検出しました。
 pixiv-status-authenticated-loading-home = 認証完了。ホームフィードを読み込み中…
 pixiv-status-logged-out = ログアウトし、保存済みの Pixiv 認証情報を削除しました。
 pixiv-status-logout-persist-clear-failed = ローカルではログアウトしましたが、保存済み認証情報の削除に失敗しました
-pixiv-status-loaded-illustrations = 読み込み済み作品数:
+pixiv-status-loaded-illustrations = { $count ->
+        [0] 作品が見つかりませんでした（{ $source }）
+       *[other] { $count } 件の作品を読み込みました（{ $source }）
+    }
+pixiv-status-appended-illustrations = { $count ->
+        [0] これ以上の作品はありません（{ $source }）
+       *[other] { $count } 件の作品を追加しました（{ $source }）
+    }
 pixiv-status-bookmark-synced = 作品のブックマーク状態を同期しました
 pixiv-status-network-error = ネットワークエラー
 pixiv-status-response-detail-title = レスポンス詳細
@@ -81,7 +81,7 @@ pixiv-status-loading-manga = 正在加载漫画内容…
 pixiv-status-loading-novels = 正在加载小说内容…
 pixiv-status-search-ready = 搜索面板已就绪。
 pixiv-status-search-keyword-required = 请输入搜索关键词。
-pixiv-status-searching = 正在搜索
+pixiv-status-searching = 正在搜索“{ $query }”…
 pixiv-status-no-response-to-copy = 没有可复制的内容。
 pixiv-status-response-copied = 响应已复制到剪贴板。
 pixiv-status-copy-failed = 复制到剪贴板失败
@@ -100,7 +100,14 @@ pixiv-status-idp-discovered = 已发现 IdP 端点，请输入授权码或刷新
 pixiv-status-authenticated-loading-home = 认证成功，正在加载首页内容…
 pixiv-status-logged-out = 已退出登录，已清除保存的 Pixiv 凭据。
 pixiv-status-logout-persist-clear-failed = 已在本地退出登录，但清除保存的凭据失败
-pixiv-status-loaded-illustrations = 已加载作品数：
+pixiv-status-loaded-illustrations = { $count ->
+        [0] 没有加载到作品（{ $source }）
+       *[other] 已加载 { $count } 个作品（{ $source }）
+    }
+pixiv-status-appended-illustrations = { $count ->
+        [0] 没有更多作品（{ $source }）
+       *[other] 已追加 { $count } 个作品（{ $source }）
+    }
 pixiv-status-bookmark-synced = 作品收藏状态已同步
 pixiv-status-network-error = 网络错误
 pixiv-status-response-detail-title = 响应详情
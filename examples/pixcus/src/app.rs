@@ -1,11 +1,11 @@
 #[cfg(target_os = "macos")]
 use std::path::PathBuf;
-use std::{process::Command, sync::Arc, time::Duration};
+use std::{sync::Arc, time::Duration};
 
 #[cfg(not(target_os = "macos"))]
 use std::sync::Mutex;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use bevy_asset::{AssetPlugin, Assets, Handle, RenderAssetUsages};
 use bevy_embedded_assets::{EmbeddedAssetPlugin, PluginMode};
 use bevy_image::Image as BevyImage;
@@ -20,11 +20,12 @@ use picus_activation::{
 #[cfg(test)]
 use picus_core::bevy_app::PreUpdate;
 use picus_core::{
-    AppI18n, AppPicusExt, LUCIDE_FONT_FAMILY, OverlayComputedPosition, PicusPlugin, ProjectionCtx,
-    ResolvedStyle, StyleClass, StyleSheet, StyleValue, SyncAssetSource, SyncTextSource, ToastKind,
-    UiComboBox, UiComboBoxChanged, UiComboOption, UiDialog, UiEventQueue, UiRoot, UiTextInput,
-    UiTextInputChanged, UiThemePicker, UiToast, UiView, apply_direct_widget_style,
-    apply_label_style, apply_widget_style,
+    AppI18n, AppPicusExt, ClipboardCopied, ClipboardCopyFailed, CopyToClipboard, FluentArgs,
+    LUCIDE_FONT_FAMILY, OpenUrl, OpenUrlFailed, OverlayComputedPosition, PicusPlugin,
+    ProjectionCtx, ResolvedStyle, StyleClass, StyleSheet, StyleValue, SyncAssetSource,
+    SyncTextSource, ToastKind, UiComboBox, UiComboBoxChanged, UiDialog, UiEventQueue, UiRoot,
+    UiTextInput, UiTextInputChanged, UiThemePicker, UiToast, UiView, UrlOpened,
+    apply_direct_widget_style, apply_label_style, apply_widget_style,
     bevy_app::{App, Startup, Update},
     bevy_ecs::{hierarchy::ChildOf, prelude::*},
     bevy_tasks::{AsyncComputeTaskPool, IoTaskPool, TaskPool},
@@ -51,9 +52,9 @@ use picus_core::{
     },
 };
 use pixcus::{
-    AuthSession, AuthUserSummary, DecodedImageRgba, IdpUrlResponse, Illust, PixivApiClient,
-    PixivContentKind, PixivResponse, build_browser_login_url, generate_pkce_code_verifier,
-    pkce_s256_challenge,
+    AuthSession, AuthUserSummary, DecodedImageRgba, IdpUrlResponse, Illust, ManagedSession,
+    PixivApiClient, PixivContentKind, PixivResponse, build_browser_login_url,
+    generate_pkce_code_verifier, pkce_s256_challenge,
 };
 use reqwest::Url;
 use shared_utils::init_logging;
@@ -86,7 +87,8 @@ use ui::{
 mod tests {
     use super::*;
     use picus_core::{
-        OverlayPlacement, UiScrollView, bevy_ecs::schedule::Schedule, bevy_math::Vec2,
+        DeferredDespawn, OverlayPlacement, UiScrollView, apply_deferred_despawns,
+        bevy_ecs::schedule::Schedule, bevy_math::Vec2,
     };
 
     fn mock_illust(title: &str) -> Illust {
@@ -404,6 +406,34 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(ids, vec![1, 2, 3]);
         assert_eq!(world.resource::<FeedSeenIds>().0.len(), 3);
+
+        let previous_order = world.resource::<FeedOrder>().0.clone();
+        world.resource_mut::<FeedPagination>().loading = true;
+        result_tx
+            .send(NetworkResult::FeedLoaded {
+                source: NavTab::Home,
+                payload: PixivResponse {
+                    illusts: vec![mock_illust_with_id(3), mock_illust_with_id(4)],
+                    next_url: None,
+                },
+                generation: 1,
+                append: false,
+            })
+            .expect("replacement feed result should send");
+        network::apply_network_results(&mut world);
+
+        let order = &world.resource::<FeedOrder>().0;
+        assert_eq!(order.len(), 2);
+        assert_eq!(
+            order[0], previous_order[2],
+            "unchanged card should be reused"
+        );
+        assert!(world.get_entity(previous_order[0]).is_err());
+        assert!(world.get_entity(previous_order[1]).is_err());
+        assert_eq!(
+            world.get::<Illust>(order[1]).map(|illust| illust.id),
+            Some(4)
+        );
     }
 
     #[test]
@@ -531,10 +561,10 @@ mod tests {
         world.insert_resource(UiState::default());
 
         let locale_combo = world
-            .spawn((UiComboBox::new(vec![
-                UiComboOption::new("en-US", "English"),
-                UiComboOption::new("zh-CN", "简体中文"),
-                UiComboOption::new("ja-JP", "日本語"),
+            .spawn((UiComboBox::from_pairs([
+                ("en-US", "English"),
+                ("zh-CN", "简体中文"),
+                ("ja-JP", "日本語"),
             ]),))
             .id();
         world.insert_resource(PixivUiComponents {
@@ -780,6 +810,8 @@ mod tests {
         assert!(!pagination.loading);
         assert!(pagination.next_url.is_none());
         assert!(world.resource::<OverlayTags>().0.is_empty());
+        assert!(world.resource::<DeferredDespawn>().contains(feed_card));
+        apply_deferred_despawns(&mut world);
         assert!(world.get_entity(feed_card).is_err());
         assert!(world.get_entity(overlay_tag).is_err());
         assert_eq!(
@@ -1775,6 +1807,76 @@ mod tests {
         assert_eq!(gap, "space-xs");
     }
 
+    fn pseudo_rule_bg_token<'a>(
+        sheet: &'a picus_core::StyleSheet,
+        class_name: &str,
+        pseudo: picus_core::PseudoClass,
+    ) -> &'a str {
+        let selector = picus_core::Selector::and([
+            picus_core::Selector::class(class_name),
+            picus_core::Selector::pseudo(pseudo),
+        ]);
+        let rule = sheet
+            .rules
+            .iter()
+            .find(|rule| rule.selector == selector)
+            .unwrap_or_else(|| panic!("{class_name} should have a {pseudo:?} rule"));
+        match rule.setter.colors.bg.as_ref() {
+            Some(picus_core::StyleValue::Var(token)) => token.as_str(),
+            _ => panic!("{class_name} {pseudo:?} bg should come from a theme token"),
+        }
+    }
+
+    #[test]
+    fn pixiv_theme_uses_pseudo_class_rules_instead_of_hover_fields() {
+        let sheet = picus_core::parse_stylesheet_ron(include_str!("../assets/themes/pixcus.ron"))
+            .expect("embedded pixcus stylesheet should parse");
+
+        for rule in &sheet.rules {
+            assert!(
+                rule.setter.colors.hover_bg.is_none() && rule.setter.colors.pressed_bg.is_none(),
+                "{:?} should style hover/pressed through pseudo-class rules",
+                rule.selector
+            );
+        }
+    }
+
+    #[test]
+    fn hovered_tag_resolves_bg_from_pseudo_class_rule() {
+        let mut sheet =
+            picus_core::parse_stylesheet_ron(include_str!("../assets/themes/pixcus.ron"))
+                .expect("embedded pixcus stylesheet should parse");
+        let idle = Color::from_rgb8(0x2A, 0x2E, 0x36);
+        let hovered = Color::from_rgb8(0x3A, 0x40, 0x4C);
+        sheet.tokens.insert(
+            "surface-subtle".to_string(),
+            picus_core::TokenValue::Color(idle),
+        );
+        sheet.tokens.insert(
+            "surface-subtle-hover".to_string(),
+            picus_core::TokenValue::Color(hovered),
+        );
+
+        let mut world = World::new();
+        world.insert_resource(sheet);
+        let tag = world
+            .spawn((
+                OverlayTag {
+                    text: "landscape".to_string(),
+                },
+                StyleClass(vec!["pixiv.tag".to_string()]),
+            ))
+            .id();
+
+        assert_eq!(resolve_style(&world, tag).colors.bg, Some(idle));
+
+        world.entity_mut(tag).insert(picus_core::InteractionState {
+            hovered: true,
+            pressed: false,
+        });
+        assert_eq!(resolve_style(&world, tag).colors.bg, Some(hovered));
+    }
+
     #[test]
     fn pixiv_warn_button_uses_fluent_tokens() {
         let sheet = picus_core::parse_stylesheet_ron(include_str!("../assets/themes/pixcus.ron"))
@@ -1787,14 +1889,16 @@ mod tests {
             Some(picus_core::StyleValue::Var(token)) => token.as_str(),
             _ => panic!("pixiv.button.warn bg should come from a theme token"),
         };
-        let hover_bg = match warn.colors.hover_bg.as_ref() {
-            Some(picus_core::StyleValue::Var(token)) => token.as_str(),
-            _ => panic!("pixiv.button.warn hover_bg should come from a theme token"),
-        };
-        let pressed_bg = match warn.colors.pressed_bg.as_ref() {
-            Some(picus_core::StyleValue::Var(token)) => token.as_str(),
-            _ => panic!("pixiv.button.warn pressed_bg should come from a theme token"),
-        };
+        let hover_bg = pseudo_rule_bg_token(
+            &sheet,
+            "pixiv.button.warn",
+            picus_core::PseudoClass::Hovered,
+        );
+        let pressed_bg = pseudo_rule_bg_token(
+            &sheet,
+            "pixiv.button.warn",
+            picus_core::PseudoClass::Pressed,
+        );
         let border = match warn.colors.border.as_ref() {
             Some(picus_core::StyleValue::Var(token)) => token.as_str(),
             _ => panic!("pixiv.button.warn border should come from a theme token"),
@@ -1950,4 +2054,77 @@ mod tests {
                 .expect("should parse without next_url");
         assert!(parsed.next_url.is_none());
     }
+
+    #[test]
+    fn feed_and_search_messages_pluralize_per_locale() {
+        let mut app = App::new();
+        app.insert_resource(AppI18n::new(parse_locale("en-US")).with_use_isolating(false))
+            .register_i18n_bundle(
+                "en-US",
+                SyncTextSource::String(include_str!("../assets/locales/en-US/main.ftl")),
+                vec![],
+            )
+            .register_i18n_bundle(
+                "zh-CN",
+                SyncTextSource::String(include_str!("../assets/locales/zh-CN/main.ftl")),
+                vec![],
+            )
+            .register_i18n_bundle(
+                "ja-JP",
+                SyncTextSource::String(include_str!("../assets/locales/ja-JP/main.ftl")),
+                vec![],
+            );
+
+        let cases = [
+            (
+                "en-US",
+                [
+                    "No illustrations loaded (Home)",
+                    "Loaded 1 illustration (Home)",
+                    "Loaded 2 illustrations (Home)",
+                ],
+                "Appended 2 illustrations (Home)",
+                "Searching for ‘cats’…",
+            ),
+            (
+                "zh-CN",
+                [
+                    "没有加载到作品（Home）",
+                    "已加载 1 个作品（Home）",
+                    "已加载 2 个作品（Home）",
+                ],
+                "已追加 2 个作品（Home）",
+                "正在搜索“cats”…",
+            ),
+            (
+                "ja-JP",
+                [
+                    "作品が見つかりませんでした（Home）",
+                    "1 件の作品を読み込みました（Home）",
+                    "2 件の作品を読み込みました（Home）",
+                ],
+                "2 件の作品を追加しました（Home）",
+                "「cats」を検索中…",
+            ),
+        ];
+
+        for (locale, loaded, appended, searching) in cases {
+            app.world_mut()
+                .resource_mut::<AppI18n>()
+                .set_active_locale(parse_locale(locale));
+            let world = app.world();
+            for (count, expected) in loaded.iter().enumerate() {
+                assert_eq!(
+                    illustrations_loaded_message(world, count, false, NavTab::Home),
+                    *expected,
+                    "{locale} count {count}"
+                );
+            }
+            assert_eq!(
+                illustrations_loaded_message(world, 2, true, NavTab::Home),
+                appended
+            );
+            assert_eq!(searching_message(world, "cats"), searching);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use super::*;
 
-use picus_core::UiScrollView;
 use picus_core::bevy_math::Vec2;
+use picus_core::{UiScrollView, defer_despawn};
 
 use super::ui;
 
@@ -10,10 +10,9 @@ pub(super) fn clear_authenticated_runtime(world: &mut World) {
         .get_resource_mut::<FeedOrder>()
         .map(|mut order| std::mem::take(&mut order.0))
         .unwrap_or_default();
+    // Other systems this frame may still hold feed cards; despawn after synthesis.
     for entity in feed_entities {
-        if world.get_entity(entity).is_ok() {
-            world.entity_mut(entity).despawn();
-        }
+        defer_despawn(world, entity);
     }
 
     if world.get_resource::<OverlayTags>().is_some() {
@@ -45,6 +44,10 @@ pub(super) fn clear_authenticated_runtime(world: &mut World) {
         ui.selected_illust = None;
     }
 
+    if let Some(client) = world.get_resource::<PixivApiClient>() {
+        client.set_session(None);
+    }
+
     if let Some(mut auth) = world.get_resource_mut::<AuthState>() {
         auth.session = None;
         auth.user_summary = None;
@@ -89,53 +92,28 @@ fn queue_search_command(world: &mut World, word: String) {
         .send(NetworkCommand::Search { word, generation });
 }
 
-fn open_in_system_browser(url: &str) -> Result<()> {
-    if webbrowser::open(url).is_ok() {
-        return Ok(());
-    }
-
-    #[cfg(target_os = "macos")]
-    {
-        let status = Command::new("open")
-            .arg(url)
-            .status()
-            .context("failed to run `open`")?;
-        if status.success() {
-            return Ok(());
-        }
-        return Err(anyhow::anyhow!("`open` exited with status {status}"));
-    }
-
-    #[cfg(target_os = "linux")]
-    {
-        let status = Command::new("xdg-open")
-            .arg(url)
-            .status()
-            .context("failed to run `xdg-open`")?;
-        if status.success() {
-            return Ok(());
-        }
-        return Err(anyhow::anyhow!("`xdg-open` exited with status {status}"));
-    }
-
-    #[cfg(target_os = "windows")]
-    {
-        let status = Command::new("cmd")
-            .args(["/C", "start", "", url])
-            .status()
-            .context("failed to run `cmd /C start`")?;
-        if status.success() {
-            return Ok(());
-        }
-        return Err(anyhow::anyhow!(
-            "`cmd /C start` exited with status {status}"
-        ));
+/// Toast shown once the browser login page has been launched.
+fn browser_opened_message(world: &World) -> String {
+    let idp_redirect = world
+        .resource::<AuthState>()
+        .idp_urls
+        .as_ref()
+        .map(|idp| idp.auth_token_redirect_url.clone());
+    match idp_redirect {
+        Some(redirect_uri) => format!(
+            "{} {redirect_uri}.",
+            tr(
+                world,
+                "pixiv.status.browser_opened_ready",
+                "Browser login page opened. Official callback should look like pixiv://account/login?code=...&via=login. Token exchange uses redirect_uri from /idp-urls (current:)"
+            )
+        ),
+        None => tr(
+            world,
+            "pixiv.status.browser_opened_fallback",
+            "Browser login page opened. /idp-urls is not ready yet, so token exchange will use fallback redirect_uri. If Login fails, wait for IdP discovery and retry.",
+        ),
     }
-
-    #[allow(unreachable_code)]
-    Err(anyhow::anyhow!(
-        "no browser launcher available on this platform"
-    ))
 }
 
 pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
@@ -219,11 +197,7 @@ pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
 
                 spawn_toast(
                     world,
-                    format!(
-                        "{} ‘{}’…",
-                        tr(world, "pixiv.status.searching", "Searching for"),
-                        query.trim()
-                    ),
+                    searching_message(world, query.trim()),
                     ToastKind::Info,
                 );
                 world.resource_mut::<UiState>().active_tab = NavTab::Search;
@@ -279,15 +253,7 @@ pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
                     ui.search_text = tag.clone();
                     ui.active_tab = NavTab::Search;
                 }
-                spawn_toast(
-                    world,
-                    format!(
-                        "{} ‘{}’…",
-                        tr(world, "pixiv.status.searching", "Searching for"),
-                        tag.trim()
-                    ),
-                    ToastKind::Info,
-                );
+                spawn_toast(world, searching_message(world, tag.trim()), ToastKind::Info);
                 queue_search_command(world, tag);
             }
             AppAction::CopyResponseBody => {
@@ -302,26 +268,9 @@ pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
                     continue;
                 }
 
-                match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(body)) {
-                    Ok(_) => {
-                        spawn_toast_key(
-                            world,
-                            ToastKind::Success,
-                            "pixiv.status.response_copied",
-                            "Response body copied to clipboard.",
-                        );
-                    }
-                    Err(err) => {
-                        spawn_toast(
-                            world,
-                            format!(
-                                "{}: {err}",
-                                tr(world, "pixiv.status.copy_failed", "Clipboard copy failed")
-                            ),
-                            ToastKind::Error,
-                        );
-                    }
-                }
+                world
+                    .resource::<UiEventQueue>()
+                    .push_typed(event.entity, CopyToClipboard(body));
             }
             AppAction::ClearResponseBody => {
                 *world.resource_mut::<ResponsePanelState>() = ResponsePanelState::default();
@@ -372,9 +321,8 @@ pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
                 ensure_account_menu_overlay(world);
             }
             AppAction::OpenBrowserLogin => {
-                let (idp_urls, verifier) = {
+                let verifier = {
                     let mut auth = world.resource_mut::<AuthState>();
-                    let idp_urls = auth.idp_urls.clone();
                     auth.login_dialog_open = true;
                     auth.account_menu_open = false;
 
@@ -382,56 +330,17 @@ pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
                         auth.code_verifier_input = generate_pkce_code_verifier();
                     }
 
-                    (idp_urls, auth.code_verifier_input.clone())
+                    auth.code_verifier_input.clone()
                 };
 
-                let redirect_uri = idp_urls
-                    .as_ref()
-                    .map(|idp| idp.auth_token_redirect_url.as_str())
-                    .unwrap_or(PIXIV_WEB_REDIRECT_FALLBACK);
                 let challenge = pkce_s256_challenge(&verifier);
 
                 match build_browser_login_url(&challenge) {
-                    Ok(login_url) => match open_in_system_browser(&login_url) {
-                        Ok(_) => {
-                            let message = if idp_urls.is_some() {
-                                format!(
-                                    "{} {redirect_uri}.",
-                                    tr(
-                                        world,
-                                        "pixiv.status.browser_opened_ready",
-                                        "Browser login page opened. Official callback should look like pixiv://account/login?code=...&via=login. Token exchange uses redirect_uri from /idp-urls (current:)"
-                                    )
-                                )
-                            } else {
-                                tr(
-                                    world,
-                                    "pixiv.status.browser_opened_fallback",
-                                    "Browser login page opened. /idp-urls is not ready yet, so token exchange will use fallback redirect_uri. If Login fails, wait for IdP discovery and retry.",
-                                )
-                            };
-                            spawn_toast(world, message, ToastKind::Info);
-                        }
-                        Err(err) => {
-                            spawn_toast(
-                                world,
-                                format!(
-                                    "{}: {err}. {}: {login_url}",
-                                    tr(
-                                        world,
-                                        "pixiv.status.browser_open_failed",
-                                        "Could not open browser automatically"
-                                    ),
-                                    tr(
-                                        world,
-                                        "pixiv.status.open_url_manually",
-                                        "Open this URL manually"
-                                    )
-                                ),
-                                ToastKind::Warning,
-                            );
-                        }
-                    },
+                    Ok(login_url) => {
+                        world
+                            .resource::<UiEventQueue>()
+                            .push_typed(event.entity, OpenUrl(login_url));
+                    }
                     Err(err) => {
                         spawn_toast(
                             world,
@@ -524,9 +433,69 @@ pub(super) fn drain_ui_actions_and_dispatch(world: &mut World) {
         }
     }
 
+    let opened = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<UrlOpened>();
+    if !opened.is_empty() {
+        let message = browser_opened_message(world);
+        spawn_toast(world, message, ToastKind::Info);
+    }
+
+    let open_failures = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<OpenUrlFailed>();
+    for failure in open_failures {
+        spawn_toast(
+            world,
+            format!(
+                "{}: {}. {}: {}",
+                tr(
+                    world,
+                    "pixiv.status.browser_open_failed",
+                    "Could not open browser automatically"
+                ),
+                failure.action.error,
+                tr(
+                    world,
+                    "pixiv.status.open_url_manually",
+                    "Open this URL manually"
+                ),
+                failure.action.url
+            ),
+            ToastKind::Warning,
+        );
+    }
+
+    let copied = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<ClipboardCopied>();
+    if !copied.is_empty() {
+        spawn_toast_key(
+            world,
+            ToastKind::Success,
+            "pixiv.status.response_copied",
+            "Response body copied to clipboard.",
+        );
+    }
+
+    let copy_failures = world
+        .resource_mut::<UiEventQueue>()
+        .drain_actions::<ClipboardCopyFailed>();
+    for failure in copy_failures {
+        spawn_toast(
+            world,
+            format!(
+                "{}: {}",
+                tr(world, "pixiv.status.copy_failed", "Clipboard copy failed"),
+                failure.action.error
+            ),
+            ToastKind::Error,
+        );
+    }
+
     let text_input_events = world
         .resource_mut::<UiEventQueue>()
-        .drain_actions::<UiTextInputChanged>();
+        .drain_actions_coalesced::<UiTextInputChanged>();
     let ui_components = *world.resource::<PixivUiComponents>();
 
     for event in text_input_events {
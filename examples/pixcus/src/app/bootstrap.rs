@@ -2,7 +2,8 @@ use super::*;
 
 use picus_core::bevy_math::Vec2;
 use picus_core::{
-    OverlayPlacement, UiDialogCloseAction, UiPopover, UiScrollView, spawn_popover_in_overlay_root,
+    EmptyState, OverlayPlacement, UiDialogCloseAction, UiPopover, UiScrollView,
+    spawn_popover_in_overlay_root,
 };
 
 use super::actions::{
@@ -271,6 +272,10 @@ pub(super) fn ensure_auth_dialog_overlay(world: &mut World) {
             "Refresh token",
         ),
     );
+    // Refresh tokens are long-lived credentials; keep them off screen.
+    if let Some(mut input) = world.get_mut::<UiTextInput>(refresh_token_input) {
+        input.mask = Some('•');
+    }
 
     if let Some(mut ui_components) = world.get_resource_mut::<PixivUiComponents>() {
         ui_components.code_verifier_input = code_verifier_input;
@@ -536,7 +541,14 @@ pub(super) fn setup(mut commands: Commands, i18n: Res<AppI18n>) {
     commands.insert_resource(OverlayTags::default());
     commands.insert_resource(ResponsePanelState::default());
     commands.init_resource::<ViewportMetrics>();
-    commands.insert_resource(PixivApiClient::default());
+    let client = PixivApiClient::default();
+    if let Some(session) = restored_session.clone() {
+        client.set_session(Some(ManagedSession {
+            session,
+            auth_token_url: PIXIV_AUTH_TOKEN_FALLBACK.to_string(),
+        }));
+    }
+    commands.insert_resource(client);
     commands.insert_resource(AuthAvatarVisual::default());
     commands.insert_resource(Assets::<BevyImage>::default());
 
@@ -625,13 +637,15 @@ pub(super) fn setup(mut commands: Commands, i18n: Res<AppI18n>) {
         ))
         .id();
 
-    let locale_options = vec![
-        UiComboOption::new("en-US", "English"),
-        UiComboOption::new("zh-CN", "简体中文"),
-        UiComboOption::new("ja-JP", "日本語"),
-    ];
+    let locale_combo = UiComboBox::from_pairs([
+        ("en-US", "English"),
+        ("zh-CN", "简体中文"),
+        ("ja-JP", "日本語"),
+    ])
+    .with_placeholder("Language");
     let active_locale_tag = i18n.active_locale.to_string();
-    let selected_locale = locale_options
+    let selected_locale = locale_combo
+        .options
         .iter()
         .position(|option| {
             option
@@ -639,9 +653,7 @@ pub(super) fn setup(mut commands: Commands, i18n: Res<AppI18n>) {
                 .eq_ignore_ascii_case(active_locale_tag.as_str())
         })
         .unwrap_or(0);
-
-    let mut locale_combo = UiComboBox::new(locale_options).with_placeholder("Language");
-    locale_combo.selected = selected_locale;
+    let locale_combo = locale_combo.with_selected(selected_locale);
 
     commands
         .entity(ui_components.locale_combo)
@@ -677,7 +689,14 @@ pub(super) fn setup(mut commands: Commands, i18n: Res<AppI18n>) {
         ))
         .id();
 
-    let home_feed = commands.spawn((PixivHomeFeed, ChildOf(feed_scroll))).id();
+    let home_feed = commands
+        .spawn((
+            PixivHomeFeed,
+            EmptyState::new("No data yet. Login first, then switch tabs.")
+                .localized("pixiv.feed.empty"),
+            ChildOf(feed_scroll),
+        ))
+        .id();
 
     let restored_avatar_url = restored_auth
         .as_ref()
@@ -689,7 +708,7 @@ pub(super) fn setup(mut commands: Commands, i18n: Res<AppI18n>) {
         let overlay_tags = world.spawn(PixivOverlayTags).id();
 
         let boot_message = if restored_auth.is_some() {
-            "Booting Pixiv MVP… restored saved credentials, refreshing token…"
+            "Booting Pixiv MVP… restored saved credentials, loading home feed…"
         } else {
             "Booting Pixiv MVP…"
         };
@@ -713,15 +732,18 @@ pub(super) fn setup(mut commands: Commands, i18n: Res<AppI18n>) {
             detail_scroll: Entity::PLACEHOLDER,
             overlay_tags,
         });
+
+        // The client holds the restored session and refreshes it on the first 401.
+        if restored_auth.is_some() {
+            let generation = begin_feed_request(world);
+            let _ = world
+                .resource::<NetworkBridge>()
+                .cmd_tx
+                .send(NetworkCommand::FetchHome { generation });
+        }
     });
 
     let _ = cmd_tx.send(NetworkCommand::DiscoverIdp);
-
-    if let Some(session) = restored_session {
-        let _ = cmd_tx.send(NetworkCommand::Refresh {
-            refresh_token: session.refresh_token,
-        });
-    }
 }
 
 pub(super) fn setup_styles(mut sheet: ResMut<StyleSheet>, i18n: Option<Res<AppI18n>>) {
@@ -776,10 +798,12 @@ pub(super) fn build_app(mut activation_service: Option<ActivationService>) -> Ap
         },
         AssetPlugin::default(),
         TextPlugin,
-        PicusPlugin,
+        PicusPlugin::default(),
     ))
     .load_style_sheet_ron(include_str!("../../assets/themes/pixcus.ron"))
-    .insert_resource(AppI18n::new(parse_locale("en-US")))
+    // Pixcus only ships left-to-right locales, so skip bidi isolation marks
+    // around interpolated toast values.
+    .insert_resource(AppI18n::new(parse_locale("en-US")).with_use_isolating(false))
     .register_i18n_bundle(
         "en-US",
         SyncTextSource::String(include_str!("../../assets/locales/en-US/main.ftl")),
@@ -1,5 +1,5 @@
 use super::*;
-use picus_core::{UiScrollView, bevy_math::Vec2};
+use picus_core::{UiScrollView, bevy_math::Vec2, reconcile_children_with};
 
 fn summarize_error(details: &str) -> String {
     let first = details
@@ -71,24 +71,32 @@ fn spawn_feed_card(
     image_cmd_tx: &Sender<ImageCommand>,
     illust: Illust,
 ) -> Entity {
+    let entity = world.spawn((illust.clone(), ChildOf(home_feed))).id();
+    init_feed_card(world, entity, image_cmd_tx, &illust);
+    entity
+}
+
+/// Attach the card bundle to an entity already carrying `illust` and queue its images.
+fn init_feed_card(
+    world: &mut World,
+    entity: Entity,
+    image_cmd_tx: &Sender<ImageCommand>,
+    illust: &Illust,
+) {
     let open_thumbnail = world.spawn_empty().id();
     let bookmark = world.spawn_empty().id();
-    let entity = world
-        .spawn((
-            PixivIllustCard,
-            illust.clone(),
-            IllustVisual::default(),
-            CardAnimState::default(),
-            IllustActionEntities {
-                open_thumbnail,
-                bookmark,
-            },
-            StyleClass(vec!["pixiv.card".to_string()]),
-            ChildOf(home_feed),
-        ))
-        .id();
-
-    if let Some(url) = preferred_thumbnail_url(&illust) {
+    world.entity_mut(entity).insert((
+        PixivIllustCard,
+        IllustVisual::default(),
+        CardAnimState::default(),
+        IllustActionEntities {
+            open_thumbnail,
+            bookmark,
+        },
+        StyleClass(vec!["pixiv.card".to_string()]),
+    ));
+
+    if let Some(url) = preferred_thumbnail_url(illust) {
         let _ = image_cmd_tx.send(ImageCommand::Download {
             target: ImageTarget::Illust(entity),
             kind: ImageKind::Thumb,
@@ -102,8 +110,6 @@ fn spawn_feed_card(
             url: illust.user.profile_image_urls.medium.clone(),
         });
     }
-
-    entity
 }
 
 fn queue_auth_avatar_download(world: &mut World, user_summary: Option<&AuthUserSummary>) {
@@ -199,6 +205,10 @@ fn run_network_command(
                 &code,
                 redirect_uri,
             )?;
+            client.set_session(Some(ManagedSession {
+                session: response.clone().into(),
+                auth_token_url: auth_token_url.to_string(),
+            }));
             let user_summary = response.user_summary();
             Ok(NetworkResult::Authenticated {
                 session: response.into(),
@@ -211,7 +221,7 @@ fn run_network_command(
                 .as_ref()
                 .map(|value| value.auth_token_url.as_str())
                 .unwrap_or(PIXIV_AUTH_TOKEN_FALLBACK);
-            let response = client.refresh_access_token(auth_token_url, &refresh_token)?;
+            let response = client.login_with_refresh_token(auth_token_url, &refresh_token)?;
             let user_summary = response.user_summary();
             Ok(NetworkResult::Authenticated {
                 session: response.into(),
@@ -326,7 +336,32 @@ pub(super) fn apply_authenticated_session(
     resolved_user_summary
 }
 
+/// Mirror tokens the client refreshed on its own into [`AuthState`] and the saved credentials.
+fn sync_refreshed_session(world: &mut World) {
+    let Some(session) = world.resource::<PixivApiClient>().session() else {
+        return;
+    };
+    let user_summary = {
+        let mut auth = world.resource_mut::<AuthState>();
+        if auth.session.is_none() || auth.session.as_ref() == Some(&session) {
+            return;
+        }
+        auth.session = Some(session.clone());
+        auth.refresh_token_input = session.refresh_token.clone();
+        auth.user_summary.clone()
+    };
+
+    if let Err(error) = super::persistence::save_auth_state(&super::persistence::StoredAuthState {
+        session,
+        user_summary,
+    }) {
+        eprintln!("pixiv credential persist failed: {error}");
+    }
+}
+
 pub(super) fn apply_network_results(world: &mut World) {
+    sync_refreshed_session(world);
+
     let result_rx = world.resource::<NetworkBridge>().result_rx.clone();
     let image_cmd_tx = world.resource::<ImageBridge>().cmd_tx.clone();
 
@@ -376,22 +411,6 @@ pub(super) fn apply_network_results(world: &mut World) {
                 let tree = *world.resource::<PixivUiTree>();
                 world.resource_mut::<UiState>().active_tab = source;
 
-                let mut next_order = if append {
-                    std::mem::take(&mut world.resource_mut::<FeedOrder>().0)
-                } else {
-                    for entity in std::mem::take(&mut world.resource_mut::<FeedOrder>().0) {
-                        if world.get_entity(entity).is_ok() {
-                            world.entity_mut(entity).despawn();
-                        }
-                    }
-
-                    if let Some(mut scroll_view) = world.get_mut::<UiScrollView>(tree.feed_scroll) {
-                        scroll_view.scroll_offset = Vec2::ZERO;
-                        scroll_view.clamp_scroll_offset();
-                    }
-
-                    Vec::new()
-                };
                 let mut seen_ids = if append {
                     std::mem::take(&mut world.resource_mut::<FeedSeenIds>().0)
                 } else {
@@ -400,16 +419,40 @@ pub(super) fn apply_network_results(world: &mut World) {
                 };
 
                 let next_url = payload.next_url.clone();
-                let mut added = 0_usize;
-                for illust in payload.illusts {
-                    if !seen_ids.insert(illust.id) {
-                        continue;
+                let illusts = payload
+                    .illusts
+                    .into_iter()
+                    .filter(|illust| seen_ids.insert(illust.id))
+                    .collect::<Vec<_>>();
+                let added = illusts.len();
+
+                let next_order = if append {
+                    let mut next_order = std::mem::take(&mut world.resource_mut::<FeedOrder>().0);
+                    for illust in illusts {
+                        next_order.push(spawn_feed_card(
+                            world,
+                            tree.home_feed,
+                            &image_cmd_tx,
+                            illust,
+                        ));
+                    }
+                    next_order
+                } else {
+                    if let Some(mut scroll_view) = world.get_mut::<UiScrollView>(tree.feed_scroll) {
+                        scroll_view.scroll_offset = Vec2::ZERO;
+                        scroll_view.clamp_scroll_offset();
                     }
 
-                    let entity = spawn_feed_card(world, tree.home_feed, &image_cmd_tx, illust);
-                    next_order.push(entity);
-                    added += 1;
-                }
+                    // Keep cards whose illust is still in the feed instead of respawning them.
+                    reconcile_children_with(
+                        world,
+                        tree.home_feed,
+                        &illusts,
+                        |world, entity, illust| {
+                            init_feed_card(world, entity, &image_cmd_tx, illust);
+                        },
+                    )
+                };
 
                 world.resource_mut::<FeedOrder>().0 = next_order;
                 world.resource_mut::<FeedSeenIds>().0 = seen_ids;
@@ -419,27 +462,7 @@ pub(super) fn apply_network_results(world: &mut World) {
                     pagination.loading = false;
                 }
 
-                let message = if append {
-                    format!(
-                        "{} {} ({source:?})",
-                        tr(
-                            world,
-                            "pixiv.status.appended_illustrations",
-                            "Appended illustrations",
-                        ),
-                        added
-                    )
-                } else {
-                    format!(
-                        "{} {} ({source:?})",
-                        tr(
-                            world,
-                            "pixiv.status.loaded_illustrations",
-                            "Loaded illustrations",
-                        ),
-                        added
-                    )
-                };
+                let message = illustrations_loaded_message(world, added, append, source);
                 spawn_toast(world, message, ToastKind::Success);
             }
             NetworkResult::BookmarkDone { illust_id } => {
@@ -502,7 +525,11 @@ pub(super) fn spawn_image_tasks(world: &mut World) {
             .spawn(async move {
                 let result = match cmd {
                     ImageCommand::Download { target, kind, url } => {
-                        match client.download_image_rgba8(&url) {
+                        let downloaded = match kind.max_dimension() {
+                            Some(max_dim) => client.download_image_rgba8_max(&url, max_dim),
+                            None => client.download_image_rgba8(&url),
+                        };
+                        match downloaded {
                             Ok(decoded) => ImageResult::Loaded {
                                 target,
                                 kind,
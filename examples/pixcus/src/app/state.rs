@@ -55,6 +55,55 @@ pub(super) fn tr(world: &World, key: &str, fallback: &str) -> String {
     fallback.to_string()
 }
 
+/// Format a Fluent message with arguments, using `fallback` when no bundle provides `key`.
+pub(super) fn tr_args(
+    world: &World,
+    key: &str,
+    args: &FluentArgs<'_>,
+    fallback: impl FnOnce() -> String,
+) -> String {
+    let Some(i18n) = world.get_resource::<AppI18n>() else {
+        return fallback();
+    };
+
+    let translated = i18n.translate_args(key, args);
+    if translated != key {
+        return translated;
+    }
+
+    fallback()
+}
+
+/// Toast text for a finished feed page, pluralized per the active locale.
+pub(super) fn illustrations_loaded_message(
+    world: &World,
+    count: usize,
+    append: bool,
+    source: NavTab,
+) -> String {
+    let source = format!("{source:?}");
+    let mut args = FluentArgs::new();
+    args.set("count", count);
+    args.set("source", source.clone());
+
+    let (key, verb) = if append {
+        ("pixiv-status-appended-illustrations", "Appended")
+    } else {
+        ("pixiv-status-loaded-illustrations", "Loaded")
+    };
+    tr_args(world, key, &args, || {
+        format!("{verb} {count} illustrations ({source})")
+    })
+}
+
+pub(super) fn searching_message(world: &World, query: &str) -> String {
+    let mut args = FluentArgs::new();
+    args.set("query", query.to_string());
+    tr_args(world, "pixiv-status-searching", &args, || {
+        format!("Searching for ‘{query}’…")
+    })
+}
+
 pub(super) fn spawn_toast(world: &mut World, message: impl Into<String>, kind: ToastKind) {
     spawn_in_overlay_root(world, (UiToast::new(message).with_kind(kind),));
 }
@@ -314,6 +363,19 @@ pub(super) enum ImageKind {
     HighRes,
 }
 
+/// Twice the ~270px feed card width, so thumbnails stay sharp on HiDPI screens.
+pub(super) const FEED_THUMBNAIL_MAX_DIM: u32 = 540;
+
+impl ImageKind {
+    /// Largest dimension worth decoding for this image kind; `None` keeps full resolution.
+    pub(super) const fn max_dimension(self) -> Option<u32> {
+        match self {
+            Self::Thumb => Some(FEED_THUMBNAIL_MAX_DIM),
+            Self::Avatar | Self::HighRes => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum ImageTarget {
     Illust(Entity),
@@ -3,7 +3,7 @@ use super::*;
 use picus_core::{
     UiScrollView,
     bevy_math::Vec2,
-    opaque_hitbox_for_entity,
+    empty_state_view, opaque_hitbox_for_entity,
     xilem::{
         masonry::layout::UnitPoint,
         view::{transformed, zstack},
@@ -353,6 +353,11 @@ pub(super) fn estimate_illust_card_height(
     image_height + 64.0 + title_lines * 18.0
 }
 
+/// Build a button from a style resolved against the button entity itself.
+///
+/// Hover/pressed colors come from `:hover`/`:pressed` selector rules in the
+/// theme, so callers must resolve with the entity (not bare class names) for
+/// its `InteractionState` to take part in the cascade.
 fn button_from_style(
     entity: Entity,
     action: AppAction,
@@ -400,18 +405,19 @@ fn sidebar_button_view(
     active: bool,
 ) -> UiView {
     let style = if active {
-        resolve_style_for_classes(
+        resolve_style_for_entity_classes(
             world,
+            entity,
             ["pixiv.sidebar.button", "pixiv.sidebar.button.active"],
         )
     } else {
-        resolve_style_for_classes(world, ["pixiv.sidebar.button"])
+        resolve_style_for_entity_classes(world, entity, ["pixiv.sidebar.button"])
     };
     button_from_style(entity, action, label_text, &style)
 }
 
 fn sidebar_toggle_button_view(world: &World, entity: Entity, sidebar_collapsed: bool) -> UiView {
-    let style = resolve_style_for_classes(world, ["pixiv.sidebar.button"]);
+    let style = resolve_style_for_entity_classes(world, entity, ["pixiv.sidebar.button"]);
     let text_color = style.colors.text.unwrap_or(Color::WHITE);
 
     let (toggle_text, toggle_icon, icon_first) = if sidebar_collapsed {
@@ -884,15 +890,7 @@ pub(super) fn project_search_panel(_: &PixivSearchPanel, ctx: ProjectionCtx<'_>)
 
 pub(super) fn project_home_feed(_: &PixivHomeFeed, ctx: ProjectionCtx<'_>) -> UiView {
     if ctx.children.is_empty() {
-        let style = resolve_style_for_classes(ctx.world, ["pixiv.root"]);
-        return Arc::new(apply_label_style(
-            label(tr(
-                ctx.world,
-                "pixiv.feed.empty",
-                "No data yet. Login first, then switch tabs.",
-            )),
-            &style,
-        ));
+        return empty_state_view(ctx.world, ctx.entity).unwrap_or_else(empty_ui);
     }
 
     let available_width = feed_layout_width(ctx.world, ctx.entity);
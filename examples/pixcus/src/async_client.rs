@@ -0,0 +1,229 @@
+//! Async counterpart to [`PixivApiClient`](crate::PixivApiClient) built on `reqwest::Client`.
+
+use anyhow::{Context, Result, anyhow};
+use bevy_ecs::prelude::Resource;
+use reqwest::{Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    APP_VERSION, AuthTokenResponse, DecodedImageRgba, IdpUrlResponse, NovelResponse,
+    PixivApiClient, PixivBaseUrls, PixivResponse, REQUIRED_REFERER, endpoints, signed_app_headers,
+    x_client_time_now,
+};
+
+/// Non-blocking Pixiv app API client.
+///
+/// Mirrors the [`PixivApiClient`] method surface, but every request returns a
+/// future that can be awaited on any async runtime. Dropping a future before it
+/// resolves cancels the in-flight request. Request signing, endpoints, and
+/// response decoding are shared with the blocking client.
+#[derive(Clone, Resource)]
+pub struct PixivAsyncClient {
+    http: Client,
+    base_urls: PixivBaseUrls,
+}
+
+impl Default for PixivAsyncClient {
+    fn default() -> Self {
+        let http = Client::builder()
+            .user_agent(format!("PixivAndroidApp/{APP_VERSION}"))
+            .build()
+            .expect("reqwest client should build");
+        Self {
+            http,
+            base_urls: PixivBaseUrls::default(),
+        }
+    }
+}
+
+impl PixivAsyncClient {
+    /// Send requests to `base_urls` instead of the production Pixiv hosts.
+    #[must_use]
+    pub fn with_base_urls(mut self, base_urls: PixivBaseUrls) -> Self {
+        self.base_urls = base_urls;
+        self
+    }
+
+    fn app_headers(&self, req: RequestBuilder, bearer: Option<&str>) -> RequestBuilder {
+        signed_app_headers(&x_client_time_now(), bearer)
+            .into_iter()
+            .fold(req, |req, (name, value)| req.header(name, value))
+    }
+
+    async fn decode_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|err| format!("<unreadable body: {err}>"));
+        PixivApiClient::decode_json_from_body(status, &body)
+    }
+
+    pub async fn discover_idp_urls(&self) -> Result<IdpUrlResponse> {
+        let req = self.app_headers(self.http.get(endpoints::idp_urls(&self.base_urls)), None);
+        let response = req.send().await.context("discover idp-urls failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn exchange_authorization_code(
+        &self,
+        auth_token_url: &str,
+        code_verifier: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<AuthTokenResponse> {
+        let form = endpoints::authorization_code_form(code_verifier, code, redirect_uri);
+        let req = self
+            .app_headers(self.http.post(auth_token_url), None)
+            .form(&form);
+        let response = req
+            .send()
+            .await
+            .context("exchange authorization_code failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn refresh_access_token(
+        &self,
+        auth_token_url: &str,
+        refresh_token: &str,
+    ) -> Result<AuthTokenResponse> {
+        let form = endpoints::refresh_token_form(refresh_token);
+        let req = self
+            .app_headers(self.http.post(auth_token_url), None)
+            .form(&form);
+        let response = req.send().await.context("refresh token failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn recommended_illusts(&self, access_token: &str) -> Result<PixivResponse> {
+        let url = endpoints::recommended_illusts(&self.base_urls);
+        let req = self.app_headers(self.http.get(url), Some(access_token));
+        let response = req.send().await.context("recommended illusts failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn ranking_illusts(&self, access_token: &str, mode: &str) -> Result<PixivResponse> {
+        let url = endpoints::ranking_illusts(&self.base_urls, mode);
+        let req = self.app_headers(self.http.get(url), Some(access_token));
+        let response = req.send().await.context("ranking illusts failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn recommended_manga(&self, access_token: &str) -> Result<PixivResponse> {
+        let url = endpoints::recommended_manga(&self.base_urls);
+        let req = self.app_headers(self.http.get(url), Some(access_token));
+        let response = req.send().await.context("recommended manga failed")?;
+        let payload: PixivResponse = Self::decode_json(response).await?;
+        Ok(payload.into_manga())
+    }
+
+    pub async fn recommended_novels(&self, access_token: &str) -> Result<PixivResponse> {
+        let url = endpoints::recommended_novels(&self.base_urls);
+        let req = self.app_headers(self.http.get(url), Some(access_token));
+        let response = req.send().await.context("recommended novels failed")?;
+        let payload: NovelResponse = Self::decode_json(response).await?;
+        Ok(payload.into_pixiv_response())
+    }
+
+    pub async fn search_illusts(&self, access_token: &str, word: &str) -> Result<PixivResponse> {
+        let url = endpoints::search_illusts(&self.base_urls, word);
+        let req = self.app_headers(self.http.get(url), Some(access_token));
+        let response = req.send().await.context("search illusts failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn fetch_page_json<T: DeserializeOwned>(
+        &self,
+        access_token: &str,
+        url: &str,
+    ) -> Result<T> {
+        let req = self.app_headers(self.http.get(url), Some(access_token));
+        let response = req
+            .send()
+            .await
+            .with_context(|| format!("feed page fetch failed: {url}"))?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn fetch_novel_page(
+        &self,
+        access_token: &str,
+        next_url: &str,
+    ) -> Result<PixivResponse> {
+        let payload = self
+            .fetch_page_json::<NovelResponse>(access_token, next_url)
+            .await?;
+        Ok(payload.into_pixiv_response())
+    }
+
+    /// Async counterpart to [`PixivApiClient::fetch_next_page`].
+    pub async fn fetch_next_page(
+        &self,
+        access_token: &str,
+        next_url: Option<&str>,
+    ) -> Result<PixivResponse> {
+        let Some(next_url) = next_url else {
+            return Ok(PixivResponse::default());
+        };
+        let req = self.app_headers(self.http.get(next_url), Some(access_token));
+        let response = req.send().await.context("fetch next page failed")?;
+        Self::decode_json(response).await
+    }
+
+    pub async fn bookmark_illust(&self, access_token: &str, illust_id: u64) -> Result<()> {
+        let url = endpoints::bookmark_add(&self.base_urls);
+        let form = endpoints::bookmark_form(illust_id);
+        let req = self
+            .app_headers(self.http.post(url), Some(access_token))
+            .form(&form);
+        let response = req.send().await.context("bookmark add failed")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "<unreadable body>".to_string());
+            return Err(anyhow!("bookmark failed: status={status}, body={body}"));
+        }
+        Ok(())
+    }
+
+    pub async fn download_image_rgba8(&self, image_url: &str) -> Result<DecodedImageRgba> {
+        let bytes = self.download_image_bytes(image_url).await?;
+        DecodedImageRgba::decode(&bytes, image_url)
+    }
+
+    /// Async counterpart to [`PixivApiClient::download_image_rgba8_max`].
+    pub async fn download_image_rgba8_max(
+        &self,
+        image_url: &str,
+        max_dim: u32,
+    ) -> Result<DecodedImageRgba> {
+        let bytes = self.download_image_bytes(image_url).await?;
+        DecodedImageRgba::decode_max(&bytes, image_url, Some(max_dim))
+    }
+
+    async fn download_image_bytes(&self, image_url: &str) -> Result<Vec<u8>> {
+        let response = self
+            .http
+            .get(image_url)
+            .header("Referer", REQUIRED_REFERER)
+            .send()
+            .await
+            .with_context(|| format!("image request failed: {image_url}"))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "image request failed with status {status} for {image_url}"
+            ));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(Vec::from)
+            .with_context(|| format!("failed to read image bytes: {image_url}"))
+    }
+}
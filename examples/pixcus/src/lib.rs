@@ -1,13 +1,21 @@
-use std::{collections::HashMap, time::UNIX_EPOCH};
+use std::{
+    sync::{Arc, Mutex},
+    time::UNIX_EPOCH,
+};
 
 use anyhow::{Context, Result, anyhow};
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use bevy_ecs::prelude::{Component, Resource};
-use chrono::Local;
 use reqwest::blocking::{Client, RequestBuilder};
 use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
 use sha2::{Digest, Sha256};
 
+mod async_client;
+mod signing;
+
+pub use async_client::PixivAsyncClient;
+pub use signing::{signed_app_headers, x_client_hash, x_client_time_now};
+
 pub const APP_API_BASE: &str = "https://app-api.pixiv.net";
 pub const ACCOUNTS_BASE: &str = "https://accounts.pixiv.net";
 /// Reverse-engineered from APK (`fn/b.java`): `/idp-urls` is under app-api base.
@@ -25,17 +33,6 @@ pub const CLIENT_SECRET: &str = "lsACyCD94FhDUtGTXi3QzcFE2uU1hqtDaKeqrdwj";
 /// Pixiv image host access requirement from APK network stack.
 pub const REQUIRED_REFERER: &str = "https://app-api.pixiv.net/";
 
-#[must_use]
-pub fn x_client_time_now() -> String {
-    Local::now().format("%Y-%m-%dT%H:%M:%S%:z").to_string()
-}
-
-#[must_use]
-pub fn x_client_hash(time_string: &str) -> String {
-    let seed = format!("{time_string}{HASH_SECRET}");
-    format!("{:x}", md5::compute(seed))
-}
-
 /// Build an RFC 7636-compatible PKCE code verifier.
 #[must_use]
 pub fn generate_pkce_code_verifier() -> String {
@@ -129,14 +126,14 @@ pub struct IdpUrlResponse {
     pub auth_token_redirect_url: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Component)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Component)]
 pub struct Tag {
     pub name: String,
     #[serde(default)]
     pub translated_name: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ProfileImageUrls {
     pub medium: String,
 }
@@ -217,7 +214,7 @@ where
     })
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Component)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Component)]
 pub struct User {
     #[serde(deserialize_with = "deserialize_u64_from_string_or_number")]
     pub id: u64,
@@ -241,20 +238,20 @@ impl From<&User> for AuthUserSummary {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageUrls {
     pub medium: String,
     pub large: String,
     pub square_medium: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetaPageUrl {
     #[serde(default)]
     pub original_image_url: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Component)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Component)]
 pub struct Illust {
     pub id: u64,
     pub title: String,
@@ -288,6 +285,14 @@ pub struct Illust {
     pub height: u32,
 }
 
+impl picus_core::Keyed for Illust {
+    type Key = u64;
+
+    fn key(&self) -> u64 {
+        self.id
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct PixivResponse {
     #[serde(default)]
@@ -296,6 +301,16 @@ pub struct PixivResponse {
     pub next_url: Option<String>,
 }
 
+impl PixivResponse {
+    /// The manga endpoint returns illust-shaped entries; tag them so cards render as manga.
+    fn into_manga(mut self) -> Self {
+        for illust in &mut self.illusts {
+            illust.content_kind = PixivContentKind::Manga;
+        }
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NovelImageUrls {
     #[serde(default)]
@@ -393,9 +408,121 @@ pub struct DecodedImageRgba {
     pub rgba8: Vec<u8>,
 }
 
+impl DecodedImageRgba {
+    fn decode(bytes: &[u8], image_url: &str) -> Result<Self> {
+        Self::decode_max(bytes, image_url, None)
+    }
+
+    /// Decode `bytes`, downscaling with a Lanczos filter so neither side exceeds `max_dim`.
+    ///
+    /// Aspect ratio is preserved and images already within bounds are never upscaled.
+    fn decode_max(bytes: &[u8], image_url: &str, max_dim: Option<u32>) -> Result<Self> {
+        let mut image = image::load_from_memory(bytes)
+            .with_context(|| format!("failed to decode image: {image_url}"))?;
+        if let Some(max_dim) = max_dim.map(|max_dim| max_dim.max(1))
+            && (image.width() > max_dim || image.height() > max_dim)
+        {
+            image = image.resize(max_dim, max_dim, image::imageops::FilterType::Lanczos3);
+        }
+        let decoded = image.into_rgba8();
+
+        Ok(Self {
+            width: decoded.width(),
+            height: decoded.height(),
+            rgba8: decoded.into_vec(),
+        })
+    }
+}
+
+/// Hosts the Pixiv clients send requests to.
+///
+/// Defaults to the production [`APP_API_BASE`] and [`IDP_BASE`]; tests point
+/// these at a local mock server to exercise the real request/response path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PixivBaseUrls {
+    pub app_api: String,
+    pub idp: String,
+}
+
+impl Default for PixivBaseUrls {
+    fn default() -> Self {
+        Self {
+            app_api: APP_API_BASE.to_string(),
+            idp: IDP_BASE.to_string(),
+        }
+    }
+}
+
+impl PixivBaseUrls {
+    /// Serve both the app API and IdP discovery from `base`.
+    #[must_use]
+    pub fn single(base: impl Into<String>) -> Self {
+        let base = base.into();
+        let base = base.trim_end_matches('/').to_string();
+        Self {
+            app_api: base.clone(),
+            idp: base,
+        }
+    }
+}
+
+/// Session a [`PixivApiClient`] refreshes on its own when a bearer request returns 401.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedSession {
+    pub session: AuthSession,
+    pub auth_token_url: String,
+}
+
+/// Raw HTTP outcome (`status`, `body`) before JSON decoding.
+type RawResponse = (reqwest::StatusCode, String);
+
+/// Send an authorized request, refreshing the held session and retrying once on 401.
+///
+/// `send` performs the request with the given access token and `refresh` exchanges
+/// a refresh token at the given token URL. The held session's access token wins over
+/// `access_token`, which is only used when no session is held; without one, a 401 is
+/// returned as-is. The retry never refreshes a second time, so a token the server
+/// keeps rejecting surfaces as a normal request failure instead of looping.
+fn send_with_session_refresh(
+    session: &Mutex<Option<ManagedSession>>,
+    access_token: &str,
+    mut send: impl FnMut(&str) -> Result<RawResponse>,
+    refresh: impl FnOnce(&str, &str) -> Result<AuthTokenResponse>,
+) -> Result<RawResponse> {
+    let held = session.lock().ok().and_then(|slot| slot.clone());
+    let response = send(held.as_ref().map_or(access_token, |managed| {
+        managed.session.access_token.as_str()
+    }))?;
+    if response.0 != reqwest::StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+
+    let Some(managed) = held else {
+        return Ok(response);
+    };
+    let refreshed: AuthSession = refresh(&managed.auth_token_url, &managed.session.refresh_token)
+        .context("access token expired and automatic refresh failed")?
+        .into();
+    let access_token = refreshed.access_token.clone();
+    if let Ok(mut slot) = session.lock() {
+        *slot = Some(ManagedSession {
+            session: refreshed,
+            auth_token_url: managed.auth_token_url,
+        });
+    }
+
+    send(&access_token)
+}
+
+/// Blocking Pixiv app API client.
+///
+/// Clones share the optional [`ManagedSession`], so a refresh performed by one
+/// clone is visible to all of them.
 #[derive(Clone, Resource)]
 pub struct PixivApiClient {
     http: Client,
+    base_urls: PixivBaseUrls,
+    session: Arc<Mutex<Option<ManagedSession>>>,
 }
 
 impl Default for PixivApiClient {
@@ -404,33 +531,19 @@ impl Default for PixivApiClient {
             .user_agent(format!("PixivAndroidApp/{APP_VERSION}"))
             .build()
             .expect("reqwest client should build");
-        Self { http }
+        Self {
+            http,
+            base_urls: PixivBaseUrls::default(),
+            session: Arc::default(),
+        }
     }
 }
 
 impl PixivApiClient {
     fn app_headers(&self, req: RequestBuilder, bearer: Option<&str>) -> RequestBuilder {
-        let x_time = x_client_time_now();
-        let x_hash = x_client_hash(&x_time);
-
-        let req = req
-            .header("Accept-Language", "en")
-            .header("app-accept-language", "en")
-            .header("App-OS", APP_OS)
-            .header("App-OS-Version", "14")
-            .header("App-Version", APP_VERSION)
-            .header("X-Client-Time", x_time)
-            .header("X-Client-Hash", x_hash)
-            .header(
-                "Content-Type",
-                "application/x-www-form-urlencoded;charset=UTF-8",
-            );
-
-        if let Some(token) = bearer {
-            req.header("Authorization", format!("Bearer {token}"))
-        } else {
-            req
-        }
+        signed_app_headers(&x_client_time_now(), bearer)
+            .into_iter()
+            .fold(req, |req, (name, value)| req.header(name, value))
     }
 
     fn decode_json<T: DeserializeOwned>(response: reqwest::blocking::Response) -> Result<T> {
@@ -441,6 +554,77 @@ impl PixivApiClient {
         Self::decode_json_from_body(status, &body)
     }
 
+    fn read_raw(response: reqwest::blocking::Response) -> RawResponse {
+        let status = response.status();
+        let body = response
+            .text()
+            .unwrap_or_else(|err| format!("<unreadable body: {err}>"));
+        (status, body)
+    }
+
+    /// Send requests to `base_urls` instead of the production Pixiv hosts.
+    #[must_use]
+    pub fn with_base_urls(mut self, base_urls: PixivBaseUrls) -> Self {
+        self.base_urls = base_urls;
+        self
+    }
+
+    /// Hold `session` so feed requests refresh it automatically when it expires.
+    #[must_use]
+    pub fn with_session(self, session: AuthSession, auth_token_url: impl Into<String>) -> Self {
+        self.set_session(Some(ManagedSession {
+            session,
+            auth_token_url: auth_token_url.into(),
+        }));
+        self
+    }
+
+    pub fn set_session(&self, session: Option<ManagedSession>) {
+        if let Ok(mut slot) = self.session.lock() {
+            *slot = session;
+        }
+    }
+
+    /// The currently held session, including any tokens refreshed after a 401.
+    #[must_use]
+    pub fn session(&self) -> Option<AuthSession> {
+        self.session
+            .lock()
+            .ok()
+            .and_then(|slot| slot.as_ref().map(|managed| managed.session.clone()))
+    }
+
+    fn send_authorized(
+        &self,
+        access_token: &str,
+        context: &str,
+        build: impl Fn(&str) -> RequestBuilder,
+    ) -> Result<RawResponse> {
+        send_with_session_refresh(
+            &self.session,
+            access_token,
+            |token| {
+                let response = build(token).send().with_context(|| context.to_string())?;
+                Ok(Self::read_raw(response))
+            },
+            |auth_token_url, refresh_token| {
+                self.refresh_access_token(auth_token_url, refresh_token)
+            },
+        )
+    }
+
+    fn get_authorized<T: DeserializeOwned>(
+        &self,
+        access_token: &str,
+        url: &str,
+        context: &str,
+    ) -> Result<T> {
+        let (status, body) = self.send_authorized(access_token, context, |token| {
+            self.app_headers(self.http.get(url), Some(token))
+        })?;
+        Self::decode_json_from_body(status, &body)
+    }
+
     pub fn decode_json_from_body<T: DeserializeOwned>(
         status: reqwest::StatusCode,
         body: &str,
@@ -454,8 +638,7 @@ impl PixivApiClient {
     }
 
     pub fn discover_idp_urls(&self) -> Result<IdpUrlResponse> {
-        let url = format!("{IDP_BASE}/idp-urls");
-        let req = self.app_headers(self.http.get(url), None);
+        let req = self.app_headers(self.http.get(endpoints::idp_urls(&self.base_urls)), None);
         let response = req.send().context("discover idp-urls failed")?;
         Self::decode_json(response)
     }
@@ -467,15 +650,7 @@ impl PixivApiClient {
         code: &str,
         redirect_uri: &str,
     ) -> Result<AuthTokenResponse> {
-        let mut form = HashMap::<&str, String>::new();
-        form.insert("code_verifier", code_verifier.to_string());
-        form.insert("code", code.to_string());
-        form.insert("grant_type", "authorization_code".to_string());
-        form.insert("redirect_uri", redirect_uri.to_string());
-        form.insert("client_id", CLIENT_ID.to_string());
-        form.insert("client_secret", CLIENT_SECRET.to_string());
-        form.insert("include_policy", "true".to_string());
-
+        let form = endpoints::authorization_code_form(code_verifier, code, redirect_uri);
         let req = self
             .app_headers(self.http.post(auth_token_url), None)
             .form(&form);
@@ -488,13 +663,7 @@ impl PixivApiClient {
         auth_token_url: &str,
         refresh_token: &str,
     ) -> Result<AuthTokenResponse> {
-        let mut form = HashMap::<&str, String>::new();
-        form.insert("client_id", CLIENT_ID.to_string());
-        form.insert("client_secret", CLIENT_SECRET.to_string());
-        form.insert("grant_type", "refresh_token".to_string());
-        form.insert("refresh_token", refresh_token.to_string());
-        form.insert("include_policy", "true".to_string());
-
+        let form = endpoints::refresh_token_form(refresh_token);
         let req = self
             .app_headers(self.http.post(auth_token_url), None)
             .form(&form);
@@ -502,56 +671,51 @@ impl PixivApiClient {
         Self::decode_json(response)
     }
 
+    /// Refresh `refresh_token` and hold the resulting session, as a login would.
+    pub fn login_with_refresh_token(
+        &self,
+        auth_token_url: &str,
+        refresh_token: &str,
+    ) -> Result<AuthTokenResponse> {
+        let response = self.refresh_access_token(auth_token_url, refresh_token)?;
+        self.set_session(Some(ManagedSession {
+            session: response.clone().into(),
+            auth_token_url: auth_token_url.to_string(),
+        }));
+        Ok(response)
+    }
+
     pub fn recommended_illusts(&self, access_token: &str) -> Result<PixivResponse> {
-        let url = format!(
-            "{APP_API_BASE}/v1/illust/recommended?filter=for_android&include_ranking_illusts=true&include_privacy_policy=false"
-        );
-        let req = self.app_headers(self.http.get(url), Some(access_token));
-        let response = req.send().context("recommended illusts failed")?;
-        Self::decode_json(response)
+        let url = endpoints::recommended_illusts(&self.base_urls);
+        self.get_authorized(access_token, &url, "recommended illusts failed")
     }
 
     pub fn ranking_illusts(&self, access_token: &str, mode: &str) -> Result<PixivResponse> {
-        let url = format!("{APP_API_BASE}/v1/illust/ranking?filter=for_android&mode={mode}");
-        let req = self.app_headers(self.http.get(url), Some(access_token));
-        let response = req.send().context("ranking illusts failed")?;
-        Self::decode_json(response)
+        let url = endpoints::ranking_illusts(&self.base_urls, mode);
+        self.get_authorized(access_token, &url, "ranking illusts failed")
     }
 
     pub fn recommended_manga(&self, access_token: &str) -> Result<PixivResponse> {
-        let url = format!("{APP_API_BASE}/v1/manga/recommended?filter=for_android");
-        let req = self.app_headers(self.http.get(url), Some(access_token));
-        let response = req.send().context("recommended manga failed")?;
-        let mut payload: PixivResponse = Self::decode_json(response)?;
-        for illust in &mut payload.illusts {
-            illust.content_kind = PixivContentKind::Manga;
-        }
-        Ok(payload)
+        let url = endpoints::recommended_manga(&self.base_urls);
+        let payload: PixivResponse =
+            self.get_authorized(access_token, &url, "recommended manga failed")?;
+        Ok(payload.into_manga())
     }
 
     pub fn recommended_novels(&self, access_token: &str) -> Result<PixivResponse> {
-        let url = format!("{APP_API_BASE}/v1/novel/recommended?filter=for_android");
-        let req = self.app_headers(self.http.get(url), Some(access_token));
-        let response = req.send().context("recommended novels failed")?;
-        let payload: NovelResponse = Self::decode_json(response)?;
+        let url = endpoints::recommended_novels(&self.base_urls);
+        let payload: NovelResponse =
+            self.get_authorized(access_token, &url, "recommended novels failed")?;
         Ok(payload.into_pixiv_response())
     }
 
     pub fn search_illusts(&self, access_token: &str, word: &str) -> Result<PixivResponse> {
-        let url = format!(
-            "{APP_API_BASE}/v1/search/illust?filter=for_android&include_translated_tag_results=true&merge_plain_keyword_results=true&word={word}&search_target=partial_match_for_tags"
-        );
-        let req = self.app_headers(self.http.get(url), Some(access_token));
-        let response = req.send().context("search illusts failed")?;
-        Self::decode_json(response)
+        let url = endpoints::search_illusts(&self.base_urls, word);
+        self.get_authorized(access_token, &url, "search illusts failed")
     }
 
     pub fn fetch_page_json<T: DeserializeOwned>(&self, access_token: &str, url: &str) -> Result<T> {
-        let req = self.app_headers(self.http.get(url), Some(access_token));
-        let response = req
-            .send()
-            .with_context(|| format!("feed page fetch failed: {url}"))?;
-        Self::decode_json(response)
+        self.get_authorized(access_token, url, &format!("feed page fetch failed: {url}"))
     }
 
     pub fn fetch_novel_page(&self, access_token: &str, next_url: &str) -> Result<PixivResponse> {
@@ -559,33 +723,66 @@ impl PixivApiClient {
         Ok(payload.into_pixiv_response())
     }
 
-    pub fn fetch_next_page(&self, access_token: &str, next_url: &str) -> Result<PixivResponse> {
-        let req = self.app_headers(self.http.get(next_url), Some(access_token));
-        let response = req.send().context("fetch next page failed")?;
-        Self::decode_json(response)
+    /// Build the signed request that follows a feed's `next_url`.
+    ///
+    /// Returns `None` when the previous page was the last one.
+    pub fn next_page_request(
+        &self,
+        access_token: &str,
+        next_url: Option<&str>,
+    ) -> Option<RequestBuilder> {
+        let next_url = next_url?;
+        Some(self.app_headers(self.http.get(next_url), Some(access_token)))
     }
 
-    pub fn bookmark_illust(&self, access_token: &str, illust_id: u64) -> Result<()> {
-        let url = format!("{APP_API_BASE}/v2/illust/bookmark/add");
-        let mut form = HashMap::<&str, String>::new();
-        form.insert("illust_id", illust_id.to_string());
-        form.insert("restrict", "public".to_string());
+    /// Follow a feed's `next_url` to load the following page.
+    ///
+    /// A missing `next_url` marks the end of the feed and yields an empty,
+    /// terminal [`PixivResponse`] without issuing a request.
+    pub fn fetch_next_page(
+        &self,
+        access_token: &str,
+        next_url: Option<&str>,
+    ) -> Result<PixivResponse> {
+        let Some(next_url) = next_url else {
+            return Ok(PixivResponse::default());
+        };
+        self.get_authorized(access_token, next_url, "fetch next page failed")
+    }
 
-        let req = self
-            .app_headers(self.http.post(url), Some(access_token))
-            .form(&form);
-        let response = req.send().context("bookmark add failed")?;
-        let status = response.status();
+    pub fn bookmark_illust(&self, access_token: &str, illust_id: u64) -> Result<()> {
+        let url = endpoints::bookmark_add(&self.base_urls);
+        let form = endpoints::bookmark_form(illust_id);
+        let (status, body) =
+            self.send_authorized(access_token, "bookmark add failed", |token| {
+                self.app_headers(self.http.post(&url), Some(token))
+                    .form(&form)
+            })?;
         if !status.is_success() {
-            let body = response
-                .text()
-                .unwrap_or_else(|_| "<unreadable body>".to_string());
             return Err(anyhow!("bookmark failed: status={status}, body={body}"));
         }
         Ok(())
     }
 
     pub fn download_image_rgba8(&self, image_url: &str) -> Result<DecodedImageRgba> {
+        let bytes = self.download_image_bytes(image_url)?;
+        DecodedImageRgba::decode(&bytes, image_url)
+    }
+
+    /// Download an image and downscale it to fit within `max_dim` on both axes.
+    ///
+    /// Feed thumbnails only need a few hundred pixels, so this keeps dozens of
+    /// decoded cards from holding full-resolution buffers.
+    pub fn download_image_rgba8_max(
+        &self,
+        image_url: &str,
+        max_dim: u32,
+    ) -> Result<DecodedImageRgba> {
+        let bytes = self.download_image_bytes(image_url)?;
+        DecodedImageRgba::decode_max(&bytes, image_url, Some(max_dim))
+    }
+
+    fn download_image_bytes(&self, image_url: &str) -> Result<Vec<u8>> {
         let response = self
             .http
             .get(image_url)
@@ -600,18 +797,87 @@ impl PixivApiClient {
             ));
         }
 
-        let bytes = response
+        response
             .bytes()
-            .with_context(|| format!("failed to read image bytes: {image_url}"))?;
-        let decoded = image::load_from_memory(&bytes)
-            .with_context(|| format!("failed to decode image: {image_url}"))?
-            .into_rgba8();
+            .map(Vec::from)
+            .with_context(|| format!("failed to read image bytes: {image_url}"))
+    }
+}
 
-        Ok(DecodedImageRgba {
-            width: decoded.width(),
-            height: decoded.height(),
-            rgba8: decoded.into_vec(),
-        })
+/// Endpoint URLs and form bodies shared by [`PixivApiClient`] and [`PixivAsyncClient`].
+mod endpoints {
+    use std::collections::HashMap;
+
+    use crate::{CLIENT_ID, CLIENT_SECRET, PixivBaseUrls};
+
+    pub(crate) fn idp_urls(base: &PixivBaseUrls) -> String {
+        format!("{}/idp-urls", base.idp)
+    }
+
+    pub(crate) fn recommended_illusts(base: &PixivBaseUrls) -> String {
+        format!(
+            "{}/v1/illust/recommended?filter=for_android&include_ranking_illusts=true&include_privacy_policy=false",
+            base.app_api
+        )
+    }
+
+    pub(crate) fn ranking_illusts(base: &PixivBaseUrls, mode: &str) -> String {
+        format!(
+            "{}/v1/illust/ranking?filter=for_android&mode={mode}",
+            base.app_api
+        )
+    }
+
+    pub(crate) fn recommended_manga(base: &PixivBaseUrls) -> String {
+        format!("{}/v1/manga/recommended?filter=for_android", base.app_api)
+    }
+
+    pub(crate) fn recommended_novels(base: &PixivBaseUrls) -> String {
+        format!("{}/v1/novel/recommended?filter=for_android", base.app_api)
+    }
+
+    pub(crate) fn search_illusts(base: &PixivBaseUrls, word: &str) -> String {
+        format!(
+            "{}/v1/search/illust?filter=for_android&include_translated_tag_results=true&merge_plain_keyword_results=true&word={word}&search_target=partial_match_for_tags",
+            base.app_api
+        )
+    }
+
+    pub(crate) fn bookmark_add(base: &PixivBaseUrls) -> String {
+        format!("{}/v2/illust/bookmark/add", base.app_api)
+    }
+
+    pub(crate) fn authorization_code_form(
+        code_verifier: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> HashMap<&'static str, String> {
+        let mut form = HashMap::new();
+        form.insert("code_verifier", code_verifier.to_string());
+        form.insert("code", code.to_string());
+        form.insert("grant_type", "authorization_code".to_string());
+        form.insert("redirect_uri", redirect_uri.to_string());
+        form.insert("client_id", CLIENT_ID.to_string());
+        form.insert("client_secret", CLIENT_SECRET.to_string());
+        form.insert("include_policy", "true".to_string());
+        form
+    }
+
+    pub(crate) fn refresh_token_form(refresh_token: &str) -> HashMap<&'static str, String> {
+        let mut form = HashMap::new();
+        form.insert("client_id", CLIENT_ID.to_string());
+        form.insert("client_secret", CLIENT_SECRET.to_string());
+        form.insert("grant_type", "refresh_token".to_string());
+        form.insert("refresh_token", refresh_token.to_string());
+        form.insert("include_policy", "true".to_string());
+        form
+    }
+
+    pub(crate) fn bookmark_form(illust_id: u64) -> HashMap<&'static str, String> {
+        let mut form = HashMap::new();
+        form.insert("illust_id", illust_id.to_string());
+        form.insert("restrict", "public".to_string());
+        form
     }
 }
 
@@ -659,6 +925,272 @@ mod tests {
         assert!(!url.contains("response_type="));
     }
 
+    #[test]
+    fn next_page_request_follows_next_url_with_signed_headers() {
+        let body = r#"{
+            "illusts": [],
+            "next_url": "https://app-api.pixiv.net/v1/illust/recommended?offset=30"
+        }"#;
+        let page =
+            PixivApiClient::decode_json_from_body::<PixivResponse>(reqwest::StatusCode::OK, body)
+                .expect("page should parse");
+
+        let client = PixivApiClient::default();
+        let request = client
+            .next_page_request("token", page.next_url.as_deref())
+            .expect("next_url should produce a request")
+            .build()
+            .expect("request should build");
+
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.url().as_str(),
+            "https://app-api.pixiv.net/v1/illust/recommended?offset=30"
+        );
+        let headers = request.headers();
+        assert_eq!(headers["Authorization"], "Bearer token");
+        let x_time = headers["X-Client-Time"].to_str().expect("ascii time");
+        assert_eq!(headers["X-Client-Hash"], x_client_hash(x_time).as_str());
+    }
+
+    #[test]
+    fn fetch_next_page_without_next_url_is_terminal() {
+        let client = PixivApiClient::default();
+        assert!(client.next_page_request("token", None).is_none());
+
+        let page = client
+            .fetch_next_page("token", None)
+            .expect("terminal page should not fail");
+        assert!(page.illusts.is_empty());
+        assert!(page.next_url.is_none());
+    }
+
+    fn token_response(access_token: &str) -> AuthTokenResponse {
+        AuthTokenResponse {
+            access_token: access_token.to_string(),
+            refresh_token: "refresh-2".to_string(),
+            token_type: "bearer".to_string(),
+            expires_in: 3600,
+            scope: String::new(),
+            user: None,
+        }
+    }
+
+    fn held_session() -> Mutex<Option<ManagedSession>> {
+        Mutex::new(Some(ManagedSession {
+            session: AuthSession {
+                access_token: "expired".to_string(),
+                refresh_token: "refresh-1".to_string(),
+                ..AuthSession::default()
+            },
+            auth_token_url: "https://example.com/auth/token".to_string(),
+        }))
+    }
+
+    #[test]
+    fn unauthorized_response_refreshes_session_and_retries_once() {
+        let session = held_session();
+        let mut sent_tokens = Vec::new();
+        let mut refresh_calls = Vec::new();
+
+        let (status, body) = send_with_session_refresh(
+            &session,
+            "expired",
+            |token| {
+                sent_tokens.push(token.to_string());
+                Ok(if token == "expired" {
+                    (reqwest::StatusCode::UNAUTHORIZED, "expired".to_string())
+                } else {
+                    (reqwest::StatusCode::OK, r#"{"illusts": []}"#.to_string())
+                })
+            },
+            |auth_token_url, refresh_token| {
+                refresh_calls.push((auth_token_url.to_string(), refresh_token.to_string()));
+                Ok(token_response("fresh"))
+            },
+        )
+        .expect("retry should succeed");
+
+        assert_eq!(status, reqwest::StatusCode::OK);
+        assert!(PixivApiClient::decode_json_from_body::<PixivResponse>(status, &body).is_ok());
+        assert_eq!(sent_tokens, ["expired", "fresh"]);
+        assert_eq!(
+            refresh_calls,
+            [(
+                "https://example.com/auth/token".to_string(),
+                "refresh-1".to_string()
+            )]
+        );
+        let stored = session.lock().unwrap().clone().expect("session kept");
+        assert_eq!(stored.session.access_token, "fresh");
+        assert_eq!(stored.session.refresh_token, "refresh-2");
+    }
+
+    #[test]
+    fn retry_after_refresh_happens_at_most_once() {
+        let session = held_session();
+        let mut sends = 0;
+        let (status, _) = send_with_session_refresh(
+            &session,
+            "expired",
+            |_| {
+                sends += 1;
+                Ok((reqwest::StatusCode::UNAUTHORIZED, String::new()))
+            },
+            |_, _| Ok(token_response("still-rejected")),
+        )
+        .expect("second 401 is returned, not retried");
+
+        assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+        assert_eq!(sends, 2);
+    }
+
+    #[test]
+    fn refresh_failure_is_surfaced() {
+        let session = held_session();
+        let err = send_with_session_refresh(
+            &session,
+            "expired",
+            |_| Ok((reqwest::StatusCode::UNAUTHORIZED, String::new())),
+            |_, _| Err(anyhow!("invalid_grant")),
+        )
+        .expect_err("refresh failure should fail the request");
+
+        assert!(err.to_string().contains("automatic refresh failed"));
+        assert_eq!(
+            session
+                .lock()
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .session
+                .access_token,
+            "expired"
+        );
+    }
+
+    #[test]
+    fn held_session_token_is_sent_instead_of_callers_stale_token() {
+        let session = held_session();
+        if let Some(managed) = session.lock().unwrap().as_mut() {
+            managed.session.access_token = "current".to_string();
+        }
+        let mut sent_tokens = Vec::new();
+
+        let (status, _) = send_with_session_refresh(
+            &session,
+            "stale",
+            |token| {
+                sent_tokens.push(token.to_string());
+                Ok((reqwest::StatusCode::OK, String::new()))
+            },
+            |_, _| panic!("a valid held token needs no refresh"),
+        )
+        .expect("request should succeed");
+
+        assert_eq!(status, reqwest::StatusCode::OK);
+        assert_eq!(sent_tokens, ["current"]);
+    }
+
+    #[test]
+    fn unauthorized_without_held_session_is_not_retried() {
+        let session = Mutex::new(None);
+        let mut sends = 0;
+        let (status, _) = send_with_session_refresh(
+            &session,
+            "expired",
+            |_| {
+                sends += 1;
+                Ok((reqwest::StatusCode::UNAUTHORIZED, String::new()))
+            },
+            |_, _| panic!("refresh requires a held session"),
+        )
+        .expect("401 is returned to the caller");
+
+        assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+        assert_eq!(sends, 1);
+    }
+
+    #[test]
+    fn recommended_illusts_hits_configured_base_url_with_signed_headers() {
+        use httptest::{Expectation, Server, all_of, matchers::*, responders::*};
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/v1/illust/recommended"),
+                request::query(url_decoded(contains(("filter", "for_android")))),
+                request::headers(contains(("authorization", "Bearer token"))),
+                request::headers(contains(("app-os", APP_OS))),
+                request::headers(contains(("app-version", APP_VERSION))),
+                request::headers(contains(key("x-client-time"))),
+                request::headers(contains(key("x-client-hash"))),
+            ])
+            .respond_with(json_encoded(serde_json::json!({
+                "illusts": [{
+                    "id": 5,
+                    "title": "mocked",
+                    "image_urls": {
+                        "medium": "https://example.com/m.jpg",
+                        "large": "https://example.com/l.jpg",
+                        "square_medium": "https://example.com/s.jpg"
+                    },
+                    "user": {
+                        "id": 9,
+                        "name": "artist",
+                        "profile_image_urls": { "medium": "https://example.com/u.jpg" }
+                    }
+                }],
+                "next_url": null
+            }))),
+        );
+
+        let client =
+            PixivApiClient::default().with_base_urls(PixivBaseUrls::single(server.url_str("")));
+        let page = client
+            .recommended_illusts("token")
+            .expect("mock server response should decode");
+
+        assert_eq!(page.illusts.len(), 1);
+        assert_eq!(page.illusts[0].title, "mocked");
+        assert!(page.next_url.is_none());
+    }
+
+    fn encoded_png(width: u32, height: u32) -> Vec<u8> {
+        let image = image::RgbaImage::from_pixel(width, height, image::Rgba([200, 40, 90, 255]));
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        image
+            .write_to(&mut bytes, image::ImageFormat::Png)
+            .expect("png should encode");
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn decode_max_downscales_to_fit_and_keeps_aspect_ratio() {
+        let bytes = encoded_png(1200, 600);
+        let decoded = DecodedImageRgba::decode_max(&bytes, "landscape.png", Some(270))
+            .expect("image should decode");
+
+        assert!(decoded.width <= 270 && decoded.height <= 270);
+        assert_eq!((decoded.width, decoded.height), (270, 135));
+        assert_eq!(
+            decoded.rgba8.len(),
+            (decoded.width * decoded.height * 4) as usize
+        );
+
+        let portrait =
+            DecodedImageRgba::decode_max(&encoded_png(300, 900), "portrait.png", Some(270))
+                .expect("image should decode");
+        assert_eq!((portrait.width, portrait.height), (90, 270));
+    }
+
+    #[test]
+    fn decode_max_never_upscales_small_images() {
+        let decoded = DecodedImageRgba::decode_max(&encoded_png(64, 32), "small.png", Some(270))
+            .expect("image should decode");
+        assert_eq!((decoded.width, decoded.height), (64, 32));
+    }
+
     #[test]
     fn decode_json_error_includes_response_body() {
         let err = PixivApiClient::decode_json_from_body::<AuthTokenResponse>(
@@ -0,0 +1,90 @@
+//! Transport-independent request signing shared by the blocking and async clients.
+//!
+//! Pixiv's app API rejects requests that do not carry the Android app identity
+//! headers plus an `X-Client-Hash` derived from `X-Client-Time`. The helpers here
+//! only produce header name/value pairs, so each HTTP transport applies them to
+//! its own request builder.
+
+use chrono::Local;
+
+use crate::{APP_OS, APP_VERSION, HASH_SECRET};
+
+#[must_use]
+pub fn x_client_time_now() -> String {
+    Local::now().format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+}
+
+#[must_use]
+pub fn x_client_hash(time_string: &str) -> String {
+    let seed = format!("{time_string}{HASH_SECRET}");
+    format!("{:x}", md5::compute(seed))
+}
+
+/// Build the app identity and signature headers for a request signed at `x_time`.
+///
+/// `bearer` adds an `Authorization: Bearer ...` header for authenticated endpoints.
+#[must_use]
+pub fn signed_app_headers(x_time: &str, bearer: Option<&str>) -> Vec<(&'static str, String)> {
+    let mut headers = vec![
+        ("Accept-Language", "en".to_string()),
+        ("app-accept-language", "en".to_string()),
+        ("App-OS", APP_OS.to_string()),
+        ("App-OS-Version", "14".to_string()),
+        ("App-Version", APP_VERSION.to_string()),
+        ("X-Client-Time", x_time.to_string()),
+        ("X-Client-Hash", x_client_hash(x_time)),
+        (
+            "Content-Type",
+            "application/x-www-form-urlencoded;charset=UTF-8".to_string(),
+        ),
+    ];
+
+    if let Some(token) = bearer {
+        headers.push(("Authorization", format!("Bearer {token}")));
+    }
+
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header<'a>(headers: &'a [(&'static str, String)], name: &str) -> Option<&'a str> {
+        headers
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn x_client_hash_is_stable_for_a_fixed_time() {
+        let sample_time = "2026-02-17T12:34:56+09:00";
+        assert_eq!(x_client_hash(sample_time), x_client_hash(sample_time));
+        assert_ne!(
+            x_client_hash(sample_time),
+            x_client_hash("2026-02-17T12:34:57+09:00")
+        );
+    }
+
+    #[test]
+    fn signed_headers_pair_client_time_with_its_hash() {
+        let sample_time = "2026-02-17T12:34:56+09:00";
+        let headers = signed_app_headers(sample_time, None);
+
+        assert_eq!(header(&headers, "X-Client-Time"), Some(sample_time));
+        assert_eq!(
+            header(&headers, "X-Client-Hash"),
+            Some(x_client_hash(sample_time).as_str())
+        );
+        assert_eq!(header(&headers, "App-OS"), Some(APP_OS));
+        assert_eq!(header(&headers, "App-Version"), Some(APP_VERSION));
+        assert_eq!(header(&headers, "Authorization"), None);
+    }
+
+    #[test]
+    fn signed_headers_add_bearer_only_when_authenticated() {
+        let headers = signed_app_headers("2026-02-17T12:34:56+09:00", Some("token"));
+        assert_eq!(header(&headers, "Authorization"), Some("Bearer token"));
+    }
+}
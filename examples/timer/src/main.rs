@@ -409,7 +409,7 @@ fn build_bevy_timer_app() -> App {
     init_logging();
 
     let mut app = App::new();
-    app.add_plugins(PicusPlugin)
+    app.add_plugins(PicusPlugin::default())
         .load_style_sheet_ron(include_str!("../assets/themes/timer.ron"))
         .insert_resource(TimerState::default())
         .register_ui_component::<TimerRootView>()
@@ -207,7 +207,7 @@ fn setup_showcase(mut commands: Commands) {
 
     let body = commands
         .spawn((
-            UiFlexRow,
+            UiFlexRow::default(),
             StyleClass(vec!["showcase.body".to_string()]),
             ChildOf(root),
         ))
@@ -265,7 +265,7 @@ fn setup_showcase(mut commands: Commands) {
 
     let components_col = commands
         .spawn((
-            UiFlexColumn,
+            UiFlexColumn::default(),
             StyleClass(vec!["showcase.page.column".to_string()]),
             ChildOf(components_page),
         ))
@@ -354,7 +354,9 @@ fn setup_showcase(mut commands: Commands) {
     let forms_section = commands
         .spawn((UiGroupBox::new("Form Inputs"), ChildOf(components_col)))
         .id();
-    let forms_col = commands.spawn((UiFlexColumn, ChildOf(forms_section))).id();
+    let forms_col = commands
+        .spawn((UiFlexColumn::default(), ChildOf(forms_section)))
+        .id();
     commands.spawn((
         UiCheckbox::new("Enable desktop notifications", false),
         ChildOf(forms_col),
@@ -454,7 +456,9 @@ fn setup_showcase(mut commands: Commands) {
             ChildOf(components_col),
         ))
         .id();
-    let spinner_row = commands.spawn((UiFlexRow, ChildOf(spinner_section))).id();
+    let spinner_row = commands
+        .spawn((UiFlexRow::default(), ChildOf(spinner_section)))
+        .id();
     commands.spawn((UiSpinner::new(), ChildOf(spinner_row)));
     commands.spawn((
         UiSpinner::new().with_label("Processing…"),
@@ -464,6 +468,13 @@ fn setup_showcase(mut commands: Commands) {
         UiSpinner::new().with_label("Uploading files…"),
         ChildOf(spinner_row),
     ));
+    commands.spawn((
+        UiSpinner::determinate(0.65)
+            .with_size(28.0)
+            .with_thickness(3.0)
+            .with_label("65% done"),
+        ChildOf(spinner_row),
+    ));
 
     let color_section = commands
         .spawn((UiGroupBox::new("Color Picker"), ChildOf(components_col)))
@@ -482,12 +493,12 @@ fn setup_showcase(mut commands: Commands) {
         .spawn((UiSplitPane::new(0.4), ChildOf(split_section)))
         .id();
     commands.spawn((
-        UiFlexColumn,
+        UiFlexColumn::default(),
         StyleClass(vec!["showcase.split.panel".to_string()]),
         ChildOf(split_pane),
     ));
     commands.spawn((
-        UiFlexColumn,
+        UiFlexColumn::default(),
         StyleClass(vec!["showcase.split.panel".to_string()]),
         ChildOf(split_pane),
     ));
@@ -522,7 +533,9 @@ fn setup_showcase(mut commands: Commands) {
             ChildOf(components_col),
         ))
         .id();
-    let toast_btn_row = commands.spawn((UiFlexRow, ChildOf(toast_section))).id();
+    let toast_btn_row = commands
+        .spawn((UiFlexRow::default(), ChildOf(toast_section)))
+        .id();
     let toast_info_btn = commands
         .spawn((UiButton::new("Info Toast"), ChildOf(toast_btn_row)))
         .id();
@@ -539,7 +552,9 @@ fn setup_showcase(mut commands: Commands) {
     let tooltip_section = commands
         .spawn((UiGroupBox::new("Tooltip"), ChildOf(components_col)))
         .id();
-    let tooltip_row = commands.spawn((UiFlexRow, ChildOf(tooltip_section))).id();
+    let tooltip_row = commands
+        .spawn((UiFlexRow::default(), ChildOf(tooltip_section)))
+        .id();
     commands.spawn((
         UiButton::new("Hover me!"),
         HasTooltip::new("This is a tooltip shown on hover."),
@@ -563,7 +578,7 @@ fn setup_showcase(mut commands: Commands) {
 
     let theming_col = commands
         .spawn((
-            UiFlexColumn,
+            UiFlexColumn::default(),
             StyleClass(vec!["showcase.page.column".to_string()]),
             ChildOf(theming_page),
         ))
@@ -591,7 +606,7 @@ fn setup_showcase(mut commands: Commands) {
         .spawn((UiGroupBox::new("Button Styles"), ChildOf(theming_col)))
         .id();
     let theme_buttons_row = commands
-        .spawn((UiFlexRow, ChildOf(theme_buttons_section)))
+        .spawn((UiFlexRow::default(), ChildOf(theme_buttons_section)))
         .id();
     let theme_primary_btn = commands
         .spawn((
@@ -655,7 +670,7 @@ fn setup_showcase(mut commands: Commands) {
 
     let localization_col = commands
         .spawn((
-            UiFlexColumn,
+            UiFlexColumn::default(),
             StyleClass(vec!["showcase.page.column".to_string()]),
             ChildOf(localization_page),
         ))
@@ -932,7 +947,7 @@ fn drain_showcase_events(world: &mut World) {
 
     let slider_events = world
         .resource_mut::<UiEventQueue>()
-        .drain_actions::<UiSliderChanged>();
+        .drain_actions_coalesced::<UiSliderChanged>();
     for event in slider_events {
         let msg = format!(
             "Slider {:?}: value={:.2}",
@@ -943,7 +958,7 @@ fn drain_showcase_events(world: &mut World) {
 
     let text_input_events = world
         .resource_mut::<UiEventQueue>()
-        .drain_actions::<UiTextInputChanged>();
+        .drain_actions_coalesced::<UiTextInputChanged>();
     for event in text_input_events {
         let msg = format!(
             "TextInput {:?}: \"{}\"",
@@ -1041,7 +1056,7 @@ fn build_showcase_app() -> App {
         },
         AssetPlugin::default(),
         TextPlugin,
-        PicusPlugin,
+        PicusPlugin::default(),
     ))
     .load_style_sheet_ron(include_str!("../assets/themes/ui_showcase.ron"))
     .insert_resource(AppI18n::new(parse_locale("en-US")))